@@ -0,0 +1,1427 @@
+library simple_arithmetic;
+
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io" show Platform, File, Directory;
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+class UniffiInternalError implements Exception {
+    static const int bufferOverflow = 0;
+    static const int incompleteData = 1;
+    static const int unexpectedOptionalTag = 2;
+    static const int unexpectedEnumCase = 3;
+    static const int unexpectedNullPointer = 4;
+    static const int unexpectedRustCallStatusCode = 5;
+    static const int unexpectedRustCallError = 6;
+    static const int unexpectedStaleHandle = 7;
+    static const int rustPanic = 8;
+
+    final int errorCode;
+    final String? panicMessage;
+
+    const UniffiInternalError(this.errorCode, this.panicMessage);
+
+    static UniffiInternalError panicked(String message) {
+    return UniffiInternalError(rustPanic, message);
+    }
+
+    @override
+    String toString() {
+    switch (errorCode) {
+        case bufferOverflow:
+        return "UniFfi::BufferOverflow";
+        case incompleteData:
+        return "UniFfi::IncompleteData";
+        case unexpectedOptionalTag:
+        return "UniFfi::UnexpectedOptionalTag";
+        case unexpectedEnumCase:
+        return "UniFfi::UnexpectedEnumCase";
+        case unexpectedNullPointer:
+        return "UniFfi::UnexpectedNullPointer";
+        case unexpectedRustCallStatusCode:
+        return "UniFfi::UnexpectedRustCallStatusCode";
+        case unexpectedRustCallError:
+        return "UniFfi::UnexpectedRustCallError";
+        case unexpectedStaleHandle:
+        return "UniFfi::UnexpectedStaleHandle";
+        case rustPanic:
+        return "UniFfi::rustPanic: \$\$panicMessage";
+        default:
+        return "UniFfi::UnknownError: \$\$errorCode";
+    }
+    }
+}
+
+const int CALL_SUCCESS = 0;
+const int CALL_ERROR = 1;
+const int CALL_UNEXPECTED_ERROR = 2;
+
+final class RustCallStatus extends Struct {
+    @Int8()
+    external int code;
+
+    external RustBuffer errorBuf;
+
+}
+
+void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
+
+    if (status.ref.code == CALL_SUCCESS) {
+    return;
+    } else if (status.ref.code == CALL_ERROR) {
+    throw errorHandler.lift(status.ref.errorBuf);
+    } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+    if (status.ref.errorBuf.len > 0) {
+        throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
+    } else {
+        throw UniffiInternalError.panicked("Rust panic");
+    }
+    } else {
+    throw UniffiInternalError.panicked("Unexpected RustCallStatus code: \${status.ref.code}");
+    }
+}
+
+#[doc=" Allocates the native memory the bindings pass to Rust for the duration of a call."]
+const Allocator _uniffiAllocator = calloc;
+
+T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+    final status = _uniffiAllocator<RustCallStatus>();
+    try {
+    final result = callback(status);
+    checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+    return result;
+    } finally {
+    _uniffiAllocator.free(status);
+    }
+}
+
+class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+    errorBuf.free();
+    return UniffiInternalError.panicked("Unexpected CALL_ERROR");
+    }
+}
+
+abstract class UniffiRustCallStatusErrorHandler {
+    Exception lift(RustBuffer errorBuf);
+}
+
+final class RustBuffer extends Struct {
+    @Uint64()
+    external int capacity;
+
+    @Uint64()
+    external int len;
+
+    external Pointer<Uint8> data;
+
+    static RustBuffer alloc(int size) {
+        return rustCall((status) => _UniffiLib.instance.ffi_simple_arithmetic_rustbuffer_alloc(size, status));
+    }
+
+    static RustBuffer fromBytes(ForeignBytes bytes) {
+        return rustCall((status) => _UniffiLib.instance.ffi_simple_arithmetic_rustbuffer_from_bytes(bytes, status));
+    }
+
+    void free() {
+        rustCall((status) => _UniffiLib.instance.ffi_simple_arithmetic_rustbuffer_free(this, status));
+    }
+
+    RustBuffer reserve(int additionalCapacity) {
+    return rustCall((status) => _UniffiLib.instance.ffi_simple_arithmetic_rustbuffer_reserve(this, additionalCapacity, status));
+    }
+
+    Uint8List asUint8List() {
+    final dataList = data.asTypedList(len);
+    final byteData = ByteData.sublistView(dataList);
+    return Uint8List.view(byteData.buffer);
+    }
+
+    @override
+    String toString() {
+    return "RustBuffer{capacity: \$capacity, len: \$len, data: \$data}";
+    }
+}
+
+RustBuffer toRustBuffer(Uint8List data) {
+    final length = data.length;
+
+    final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length);
+    final pointerList = frameData.asTypedList(length);
+    pointerList.setAll(0, data);
+
+    final bytes = _uniffiAllocator<ForeignBytes>();
+    bytes.ref.len = length;
+    bytes.ref.data = frameData;
+    try {
+        return RustBuffer.fromBytes(bytes.ref);
+    } finally {
+        _uniffiAllocator.free(frameData);
+        _uniffiAllocator.free(bytes);
+    }
+}
+
+T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+    try {
+        return lift(buf.asUint8List());
+    } finally {
+        buf.free();
+    }
+}
+
+final class ForeignBytes extends Struct {
+    @Int32()
+    external int len;
+    external Pointer<Uint8> data;
+
+    void free() {
+    _uniffiAllocator.free(data);
+    }
+}
+
+class LiftRetVal<T> {
+    final T value;
+    final int bytesRead;
+    const LiftRetVal(this.value, this.bytesRead);
+
+    LiftRetVal<T> copyWithOffset(int offset) {
+        return LiftRetVal(value, bytesRead + offset);
+    }
+}
+
+abstract class FfiConverter<D, F> {
+    const FfiConverter();
+
+    D lift(F value);
+    F lower(D value);
+    D read(ByteData buffer, int offset);
+    void write(D value, ByteData buffer, int offset);
+    int size(D value);
+}
+
+mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+    @override
+    T lift(T value) => value;
+
+    @override
+    T lower(T value) => value;
+}
+
+class FfiConverterBool {
+
+    static bool lift( int value) {
+        return value == 1;
+    }
+
+    static int lower( bool value) {
+        return value ? 1 :0;
+    }
+
+    static LiftRetVal<bool> read( Uint8List buf) {
+        return LiftRetVal(FfiConverterBool.lift(buf.first), 1);
+    }
+
+    static RustBuffer lowerIntoRustBuffer( bool value) {
+        return toRustBuffer(Uint8List.fromList([FfiConverterBool.lower(value)]));
+    }
+
+    static int allocationSize([bool value = false]) {
+        return 1;
+    }
+
+    static int write( bool value, Uint8List buf) {
+        buf.setAll(0, [value ? 1 : 0]);
+        return allocationSize();
+    }
+}class FfiConverterDouble32 {
+
+    static double lift(double value) => value;
+
+    static LiftRetVal<double> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat32(0), 4);
+    }
+
+    static double lower(double value) => value;
+
+    static int allocationSize([double value = 0]) {
+        return 4;
+    }
+
+    static int write(double value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setFloat32(0, value);
+        return FfiConverterDouble32.allocationSize();
+    }
+
+}class FfiConverterDouble64 {
+
+    static double lift(double value) => value;
+
+    static LiftRetVal<double> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat64(0), 8);
+    }
+
+    static double lower(double value) => value;
+
+    static int allocationSize([double value = 0]) {
+        return 8;
+    }
+
+    static int write(double value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setFloat64(0, value);
+        return FfiConverterDouble64.allocationSize();
+    }
+
+}class FfiConverterInt16 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt16(0), 2);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 2;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt16(0, value);
+        return FfiConverterInt16.allocationSize();
+    }
+
+}class FfiConverterInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value);
+        return FfiConverterInt32.allocationSize();
+    }
+
+}class FfiConverterInt64 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt64(0), 8);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 8;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, value);
+        return FfiConverterInt64.allocationSize();
+    }
+
+}class FfiConverterInt8 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt8(0), 1);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 1;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt8(0, value);
+        return FfiConverterInt8.allocationSize();
+    }
+
+}class FfiConverterOptionalBool {
+
+    static bool? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalBool.read(data).value);
+    }
+
+    static LiftRetVal<bool?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterBool.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([bool? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterBool.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( bool? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalBool.allocationSize(value));
+        FfiConverterOptionalBool.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( bool? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterBool.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalDouble32 {
+
+    static double? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalDouble32.read(data).value);
+    }
+
+    static LiftRetVal<double?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterDouble32.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([double? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterDouble32.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( double? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalDouble32.allocationSize(value));
+        FfiConverterOptionalDouble32.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( double? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterDouble32.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalDouble64 {
+
+    static double? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalDouble64.read(data).value);
+    }
+
+    static LiftRetVal<double?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterDouble64.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([double? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterDouble64.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( double? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalDouble64.allocationSize(value));
+        FfiConverterOptionalDouble64.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( double? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterDouble64.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalInt16 {
+
+    static int? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalInt16.read(data).value);
+    }
+
+    static LiftRetVal<int?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterInt16.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([int? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterInt16.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( int? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalInt16.allocationSize(value));
+        FfiConverterOptionalInt16.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( int? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterInt16.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalInt32 {
+
+    static int? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalInt32.read(data).value);
+    }
+
+    static LiftRetVal<int?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterInt32.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([int? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterInt32.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( int? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalInt32.allocationSize(value));
+        FfiConverterOptionalInt32.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( int? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterInt32.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalInt64 {
+
+    static int? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalInt64.read(data).value);
+    }
+
+    static LiftRetVal<int?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterInt64.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([int? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterInt64.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( int? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalInt64.allocationSize(value));
+        FfiConverterOptionalInt64.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( int? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterInt64.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalInt8 {
+
+    static int? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalInt8.read(data).value);
+    }
+
+    static LiftRetVal<int?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterInt8.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([int? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterInt8.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( int? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalInt8.allocationSize(value));
+        FfiConverterOptionalInt8.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( int? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterInt8.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalUInt16 {
+
+    static int? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalUInt16.read(data).value);
+    }
+
+    static LiftRetVal<int?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterUInt16.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([int? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterUInt16.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( int? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalUInt16.allocationSize(value));
+        FfiConverterOptionalUInt16.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( int? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterUInt16.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalUInt32 {
+
+    static int? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalUInt32.read(data).value);
+    }
+
+    static LiftRetVal<int?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterUInt32.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([int? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterUInt32.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( int? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalUInt32.allocationSize(value));
+        FfiConverterOptionalUInt32.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( int? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterUInt32.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalUInt64 {
+
+    static int? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalUInt64.read(data).value);
+    }
+
+    static LiftRetVal<int?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterUInt64.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([int? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterUInt64.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( int? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalUInt64.allocationSize(value));
+        FfiConverterOptionalUInt64.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( int? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterUInt64.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalUInt8 {
+
+    static int? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalUInt8.read(data).value);
+    }
+
+    static LiftRetVal<int?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterUInt8.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([int? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterUInt8.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( int? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalUInt8.allocationSize(value));
+        FfiConverterOptionalUInt8.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( int? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterUInt8.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterString {
+    static String lift( RustBuffer buf) {
+
+        return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
+    }
+
+    static RustBuffer lower( String value) {
+        return toRustBuffer(Utf8Encoder().convert(value));
+    }
+
+    static LiftRetVal<String> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+        return LiftRetVal(utf8.decoder.convert(buf, 4, end), end);
+    }
+
+    static int allocationSize([String value = ""]) {
+
+        return utf8.encoder.convert(value).length + 4;
+    }
+
+    static int write( String value, Uint8List buf) {
+
+        final list = utf8.encoder.convert(value);
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length);
+        buf.setAll(4, list);
+        return list.length + 4;
+    }
+}class FfiConverterUInt16 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint16(0), 2);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 2;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint16(0, value);
+        return FfiConverterUInt16.allocationSize();
+    }
+
+}class FfiConverterUInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, value);
+        return FfiConverterUInt32.allocationSize();
+    }
+
+}class FfiConverterUInt64 {
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0), 8);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 8;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, value);
+        return FfiConverterUInt64.allocationSize();
+    }
+}class FfiConverterUInt8 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint8(0), 1);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 1;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint8(0, value);
+        return FfiConverterUInt8.allocationSize();
+    }
+
+}
+
+const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
+
+typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+Future<T> uniffiRustCallAsync<T, F>(
+    Pointer<Void> Function() rustFutureFunc,
+    void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc,
+    F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc,
+    void Function(Pointer<Void>) freeFunc,
+    T Function(F) liftFunc, [
+    UniffiRustCallStatusErrorHandler? errorHandler,
+]) async {
+    final rustFuture = rustFutureFunc();
+    final completer = Completer<int>();
+
+    late final NativeCallable<UniffiRustFutureContinuationCallback> callback;
+
+    void poll() {
+        pollFunc(
+            rustFuture,
+            callback.nativeFunction,
+            Pointer<Void>.fromAddress(0),
+        );
+    }
+    void onResponse(int _idx, int pollResult) {
+        if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+            completer.complete(pollResult);
+        } else {
+            poll();
+        }
+    }
+    callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener(onResponse);
+
+    try {
+        poll();
+        await completer.future;
+        callback.close();
+
+        final status = _uniffiAllocator<RustCallStatus>();
+        try {
+            final result = completeFunc(rustFuture, status);
+            checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+            return liftFunc(result);
+        } finally {
+            _uniffiAllocator.free(status);
+        }
+    } finally {
+        freeFunc(rustFuture);
+    }
+}
+
+class UniffiHandleMap<T> {
+    final Map<int, T> _map = {};
+    int _counter = 0;
+
+    int insert(T obj) {
+    final handle = _counter++;
+    _map[handle] = obj;
+    return handle;
+    }
+
+    T get(int handle) {
+    final obj = _map[handle];
+    if (obj == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    return obj;
+    }
+
+    void remove(int handle) {
+    if (_map.remove(handle) == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    }
+}
+
+int add(int left,int right,) {
+    return rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_add(left,right, uniffiStatus), NullRustCallStatusErrorHandler());
+}double? addF32(double left,double right,) {
+    return FfiConverterOptionalDouble32.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_add_f32(left,right, uniffiStatus), NullRustCallStatusErrorHandler()));
+}double? addF64(double left,double right,) {
+    return FfiConverterOptionalDouble64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_add_f64(left,right, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int? addI16(int left,int right,) {
+    return FfiConverterOptionalInt16.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_add_i16(left,right, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int? addI32(int left,int right,) {
+    return FfiConverterOptionalInt32.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_add_i32(left,right, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int? addI64(int left,int right,) {
+    return FfiConverterOptionalInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_add_i64(left,right, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int? addI8(int left,int right,) {
+    return FfiConverterOptionalInt8.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_add_i8(left,right, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int? addU16(int left,int right,) {
+    return FfiConverterOptionalUInt16.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_add_u16(left,right, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int? addU64(int left,int right,) {
+    return FfiConverterOptionalUInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_add_u64(FfiConverterUInt64.lower(left),FfiConverterUInt64.lower(right), uniffiStatus), NullRustCallStatusErrorHandler()));
+}int? addU8(int left,int right,) {
+    return FfiConverterOptionalUInt8.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_add_u8(left,right, uniffiStatus), NullRustCallStatusErrorHandler()));
+}bool? canDivide(int left,int right,) {
+    return FfiConverterOptionalBool.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_can_divide(left,right, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int? divide(int left,int right,) {
+    return FfiConverterOptionalUInt32.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_divide(left,right, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int? divideChecked(int left,int right,) {
+    return FfiConverterOptionalUInt32.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_divide_checked(left,right, uniffiStatus), NullRustCallStatusErrorHandler()));
+}double? getBackF32(double value,) {
+    return FfiConverterOptionalDouble32.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_get_back_f32(value, uniffiStatus), NullRustCallStatusErrorHandler()));
+}double? getBackF64(double value,) {
+    return FfiConverterOptionalDouble64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_get_back_f64(value, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int? getBackI16(int value,) {
+    return FfiConverterOptionalInt16.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_get_back_i16(value, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int? getBackI32(int value,) {
+    return FfiConverterOptionalInt32.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_get_back_i32(value, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int? getBackI64(int value,) {
+    return FfiConverterOptionalInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_get_back_i64(value, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int? getBackI8(int value,) {
+    return FfiConverterOptionalInt8.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_get_back_i8(value, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int? getBackU16(int value,) {
+    return FfiConverterOptionalUInt16.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_get_back_u16(value, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int? getBackU32(int value,) {
+    return FfiConverterOptionalUInt32.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_get_back_u32(value, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int? getBackU64(int value,) {
+    return FfiConverterOptionalUInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_get_back_u64(FfiConverterUInt64.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}int? getBackU8(int value,) {
+    return FfiConverterOptionalUInt8.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_get_back_u8(value, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int multiply(int left,int right,) {
+    return rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_simple_arithmetic_fn_func_multiply(left,right, uniffiStatus), NullRustCallStatusErrorHandler());
+}
+
+class _UniffiLib {
+    _UniffiLib._();
+
+    static final DynamicLibrary _dylib = _open();
+
+    static DynamicLibrary _open() {
+        if (Platform.isAndroid) return DynamicLibrary.open("libsimple_arithmetic.so");
+        if (Platform.isIOS) return DynamicLibrary.executable();
+        if (Platform.isLinux) return DynamicLibrary.open("libsimple_arithmetic.so");
+        if (Platform.isMacOS) return DynamicLibrary.open("libsimple_arithmetic.dylib");
+        if (Platform.isWindows) return DynamicLibrary.open("simple_arithmetic.dll");
+        throw UnsupportedError("Unsupported platform: \${Platform.operatingSystem}");
+    }
+
+    static final _UniffiLib instance = _UniffiLib._();
+
+    late final int Function(int,int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_add = _dylib.lookupFunction<
+        Uint32 Function(Uint32,Uint32,Pointer<RustCallStatus>),
+        int Function(int,int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_add");late final RustBuffer Function(double,double,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_add_f32 = _dylib.lookupFunction<
+        RustBuffer Function(Float,Float,Pointer<RustCallStatus>),
+        RustBuffer Function(double,double,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_add_f32");late final RustBuffer Function(double,double,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_add_f64 = _dylib.lookupFunction<
+        RustBuffer Function(Double,Double,Pointer<RustCallStatus>),
+        RustBuffer Function(double,double,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_add_f64");late final RustBuffer Function(int,int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_add_i16 = _dylib.lookupFunction<
+        RustBuffer Function(Int16,Int16,Pointer<RustCallStatus>),
+        RustBuffer Function(int,int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_add_i16");late final RustBuffer Function(int,int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_add_i32 = _dylib.lookupFunction<
+        RustBuffer Function(Int32,Int32,Pointer<RustCallStatus>),
+        RustBuffer Function(int,int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_add_i32");late final RustBuffer Function(int,int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_add_i64 = _dylib.lookupFunction<
+        RustBuffer Function(Int64,Int64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_add_i64");late final RustBuffer Function(int,int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_add_i8 = _dylib.lookupFunction<
+        RustBuffer Function(Int8,Int8,Pointer<RustCallStatus>),
+        RustBuffer Function(int,int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_add_i8");late final RustBuffer Function(int,int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_add_u16 = _dylib.lookupFunction<
+        RustBuffer Function(Uint16,Uint16,Pointer<RustCallStatus>),
+        RustBuffer Function(int,int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_add_u16");late final RustBuffer Function(int,int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_add_u64 = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_add_u64");late final RustBuffer Function(int,int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_add_u8 = _dylib.lookupFunction<
+        RustBuffer Function(Uint8,Uint8,Pointer<RustCallStatus>),
+        RustBuffer Function(int,int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_add_u8");late final RustBuffer Function(int,int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_can_divide = _dylib.lookupFunction<
+        RustBuffer Function(Uint32,Uint32,Pointer<RustCallStatus>),
+        RustBuffer Function(int,int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_can_divide");late final RustBuffer Function(int,int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_divide = _dylib.lookupFunction<
+        RustBuffer Function(Uint32,Uint32,Pointer<RustCallStatus>),
+        RustBuffer Function(int,int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_divide");late final RustBuffer Function(int,int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_divide_checked = _dylib.lookupFunction<
+        RustBuffer Function(Uint32,Uint32,Pointer<RustCallStatus>),
+        RustBuffer Function(int,int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_divide_checked");late final RustBuffer Function(double,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_get_back_f32 = _dylib.lookupFunction<
+        RustBuffer Function(Float,Pointer<RustCallStatus>),
+        RustBuffer Function(double,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_get_back_f32");late final RustBuffer Function(double,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_get_back_f64 = _dylib.lookupFunction<
+        RustBuffer Function(Double,Pointer<RustCallStatus>),
+        RustBuffer Function(double,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_get_back_f64");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_get_back_i16 = _dylib.lookupFunction<
+        RustBuffer Function(Int16,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_get_back_i16");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_get_back_i32 = _dylib.lookupFunction<
+        RustBuffer Function(Int32,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_get_back_i32");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_get_back_i64 = _dylib.lookupFunction<
+        RustBuffer Function(Int64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_get_back_i64");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_get_back_i8 = _dylib.lookupFunction<
+        RustBuffer Function(Int8,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_get_back_i8");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_get_back_u16 = _dylib.lookupFunction<
+        RustBuffer Function(Uint16,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_get_back_u16");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_get_back_u32 = _dylib.lookupFunction<
+        RustBuffer Function(Uint32,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_get_back_u32");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_get_back_u64 = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_get_back_u64");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_get_back_u8 = _dylib.lookupFunction<
+        RustBuffer Function(Uint8,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_get_back_u8");late final int Function(int,int,Pointer<RustCallStatus>) uniffi_simple_arithmetic_fn_func_multiply = _dylib.lookupFunction<
+        Uint32 Function(Uint32,Uint32,Pointer<RustCallStatus>),
+        int Function(int,int,Pointer<RustCallStatus>)
+    >("uniffi_simple_arithmetic_fn_func_multiply");late final RustBuffer Function(int,Pointer<RustCallStatus>) ffi_simple_arithmetic_rustbuffer_alloc = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("ffi_simple_arithmetic_rustbuffer_alloc");late final RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>) ffi_simple_arithmetic_rustbuffer_from_bytes = _dylib.lookupFunction<
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>),
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>)
+    >("ffi_simple_arithmetic_rustbuffer_from_bytes");late final void Function(RustBuffer,Pointer<RustCallStatus>) ffi_simple_arithmetic_rustbuffer_free = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("ffi_simple_arithmetic_rustbuffer_free");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) ffi_simple_arithmetic_rustbuffer_reserve = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("ffi_simple_arithmetic_rustbuffer_reserve");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_simple_arithmetic_rust_future_poll_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_poll_u8");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_cancel_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_cancel_u8");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_free_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_free_u8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_simple_arithmetic_rust_future_complete_u8 = _dylib.lookupFunction<
+        Uint8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_simple_arithmetic_rust_future_complete_u8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_simple_arithmetic_rust_future_poll_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_poll_i8");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_cancel_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_cancel_i8");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_free_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_free_i8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_simple_arithmetic_rust_future_complete_i8 = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_simple_arithmetic_rust_future_complete_i8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_simple_arithmetic_rust_future_poll_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_poll_u16");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_cancel_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_cancel_u16");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_free_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_free_u16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_simple_arithmetic_rust_future_complete_u16 = _dylib.lookupFunction<
+        Uint16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_simple_arithmetic_rust_future_complete_u16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_simple_arithmetic_rust_future_poll_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_poll_i16");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_cancel_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_cancel_i16");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_free_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_free_i16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_simple_arithmetic_rust_future_complete_i16 = _dylib.lookupFunction<
+        Int16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_simple_arithmetic_rust_future_complete_i16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_simple_arithmetic_rust_future_poll_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_poll_u32");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_cancel_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_cancel_u32");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_free_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_free_u32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_simple_arithmetic_rust_future_complete_u32 = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_simple_arithmetic_rust_future_complete_u32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_simple_arithmetic_rust_future_poll_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_poll_i32");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_cancel_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_cancel_i32");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_free_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_free_i32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_simple_arithmetic_rust_future_complete_i32 = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_simple_arithmetic_rust_future_complete_i32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_simple_arithmetic_rust_future_poll_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_poll_u64");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_cancel_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_cancel_u64");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_free_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_free_u64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_simple_arithmetic_rust_future_complete_u64 = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_simple_arithmetic_rust_future_complete_u64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_simple_arithmetic_rust_future_poll_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_poll_i64");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_cancel_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_cancel_i64");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_free_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_free_i64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_simple_arithmetic_rust_future_complete_i64 = _dylib.lookupFunction<
+        Int64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_simple_arithmetic_rust_future_complete_i64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_simple_arithmetic_rust_future_poll_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_poll_f32");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_cancel_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_cancel_f32");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_free_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_free_f32");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_simple_arithmetic_rust_future_complete_f32 = _dylib.lookupFunction<
+        Float Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_simple_arithmetic_rust_future_complete_f32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_simple_arithmetic_rust_future_poll_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_poll_f64");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_cancel_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_cancel_f64");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_free_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_free_f64");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_simple_arithmetic_rust_future_complete_f64 = _dylib.lookupFunction<
+        Double Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_simple_arithmetic_rust_future_complete_f64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_simple_arithmetic_rust_future_poll_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_poll_pointer");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_cancel_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_cancel_pointer");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_free_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_free_pointer");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_simple_arithmetic_rust_future_complete_pointer = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_simple_arithmetic_rust_future_complete_pointer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_simple_arithmetic_rust_future_poll_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_poll_rust_buffer");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_cancel_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_cancel_rust_buffer");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_free_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_free_rust_buffer");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_simple_arithmetic_rust_future_complete_rust_buffer = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_simple_arithmetic_rust_future_complete_rust_buffer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_simple_arithmetic_rust_future_poll_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_poll_void");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_cancel_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_cancel_void");late final void Function(Pointer<Void>,) ffi_simple_arithmetic_rust_future_free_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_simple_arithmetic_rust_future_free_void");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_simple_arithmetic_rust_future_complete_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_simple_arithmetic_rust_future_complete_void");late final int Function() uniffi_simple_arithmetic_checksum_func_add = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_add");late final int Function() uniffi_simple_arithmetic_checksum_func_add_f32 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_add_f32");late final int Function() uniffi_simple_arithmetic_checksum_func_add_f64 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_add_f64");late final int Function() uniffi_simple_arithmetic_checksum_func_add_i16 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_add_i16");late final int Function() uniffi_simple_arithmetic_checksum_func_add_i32 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_add_i32");late final int Function() uniffi_simple_arithmetic_checksum_func_add_i64 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_add_i64");late final int Function() uniffi_simple_arithmetic_checksum_func_add_i8 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_add_i8");late final int Function() uniffi_simple_arithmetic_checksum_func_add_u16 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_add_u16");late final int Function() uniffi_simple_arithmetic_checksum_func_add_u64 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_add_u64");late final int Function() uniffi_simple_arithmetic_checksum_func_add_u8 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_add_u8");late final int Function() uniffi_simple_arithmetic_checksum_func_can_divide = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_can_divide");late final int Function() uniffi_simple_arithmetic_checksum_func_divide = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_divide");late final int Function() uniffi_simple_arithmetic_checksum_func_divide_checked = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_divide_checked");late final int Function() uniffi_simple_arithmetic_checksum_func_get_back_f32 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_get_back_f32");late final int Function() uniffi_simple_arithmetic_checksum_func_get_back_f64 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_get_back_f64");late final int Function() uniffi_simple_arithmetic_checksum_func_get_back_i16 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_get_back_i16");late final int Function() uniffi_simple_arithmetic_checksum_func_get_back_i32 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_get_back_i32");late final int Function() uniffi_simple_arithmetic_checksum_func_get_back_i64 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_get_back_i64");late final int Function() uniffi_simple_arithmetic_checksum_func_get_back_i8 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_get_back_i8");late final int Function() uniffi_simple_arithmetic_checksum_func_get_back_u16 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_get_back_u16");late final int Function() uniffi_simple_arithmetic_checksum_func_get_back_u32 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_get_back_u32");late final int Function() uniffi_simple_arithmetic_checksum_func_get_back_u64 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_get_back_u64");late final int Function() uniffi_simple_arithmetic_checksum_func_get_back_u8 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_get_back_u8");late final int Function() uniffi_simple_arithmetic_checksum_func_multiply = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_simple_arithmetic_checksum_func_multiply");late final int Function() ffi_simple_arithmetic_uniffi_contract_version = _dylib.lookupFunction<
+        Uint32 Function(),
+        int Function()
+    >("ffi_simple_arithmetic_uniffi_contract_version");
+
+    static void _checkApiVersion() {
+        final bindingsVersion = 26;
+        final scaffoldingVersion = _UniffiLib.instance.ffi_simple_arithmetic_uniffi_contract_version();
+        if (bindingsVersion != scaffoldingVersion) {
+            throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version \$bindingsVersion, scaffolding version \$scaffoldingVersion");
+        }
+    }
+
+    static void _checkApiChecksums() {
+        if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_add() != 54507) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_add_f32() != 9289) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_add_f64() != 63279) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_add_i16() != 29192) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_add_i32() != 6063) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_add_i64() != 10343) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_add_i8() != 38917) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_add_u16() != 7352) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_add_u64() != 48723) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_add_u8() != 9945) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_can_divide() != 6927) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_divide() != 60468) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_divide_checked() != 46135) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_get_back_f32() != 49070) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_get_back_f64() != 64872) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_get_back_i16() != 33524) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_get_back_i32() != 44924) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_get_back_i64() != 4920) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_get_back_i8() != 57927) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_get_back_u16() != 49101) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_get_back_u32() != 46306) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_get_back_u64() != 59630) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_get_back_u8() != 8951) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_simple_arithmetic_checksum_func_multiply() != 27814) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }
+    }
+}
+
+void initialize() {
+    _UniffiLib._open();
+}
+
+void ensureInitialized() {
+    _UniffiLib._checkApiVersion();
+    _UniffiLib._checkApiChecksums();
+}
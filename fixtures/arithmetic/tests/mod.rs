@@ -4,3 +4,8 @@ use anyhow::Result;
 fn simple_arithmetic() -> Result<()> {
     uniffi_dart::testing::run_test("simple_arithmetic", "src/api.udl", None)
 }
+
+#[test]
+fn simple_arithmetic_golden() -> Result<()> {
+    uniffi_dart::testing::assert_golden("simple_arithmetic", "src/api.udl", None)
+}
@@ -0,0 +1,927 @@
+library bytes_type_test;
+
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io" show Platform, File, Directory;
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+class Image {
+    final String name;final Uint8List data;final Uint8List? thumbnail;
+
+    Image({required this.name,required this.data,required this.thumbnail,});
+}
+
+class FfiConverterImage {
+    static Image lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterImage.read(data).value);
+    }
+
+    static LiftRetVal<Image> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final name_lifted = FfiConverterString.read(Uint8List.view(buf.buffer, new_offset));
+        final name = name_lifted.value;
+        new_offset += name_lifted.bytesRead;final data_lifted = FfiConverterBytes.read(Uint8List.view(buf.buffer, new_offset));
+        final data = data_lifted.value;
+        new_offset += data_lifted.bytesRead;final thumbnail_lifted = FfiConverterOptionalBytes.read(Uint8List.view(buf.buffer, new_offset));
+        final thumbnail = thumbnail_lifted.value;
+        new_offset += thumbnail_lifted.bytesRead;
+        return LiftRetVal(Image(
+            name: name,data: data,thumbnail: thumbnail,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( Image value) {
+        final total_length = FfiConverterString.allocationSize(value.name) +FfiConverterBytes.allocationSize(value.data) +FfiConverterOptionalBytes.allocationSize(value.thumbnail) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( Image value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterString.write(value.name, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterBytes.write(value.data, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterOptionalBytes.write(value.thumbnail, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Image value) {
+        return FfiConverterString.allocationSize(value.name) +FfiConverterBytes.allocationSize(value.data) +FfiConverterOptionalBytes.allocationSize(value.thumbnail) + 0;
+    }
+}
+
+abstract class Payload {
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterPayload {
+    static Payload lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterPayload.read(data).value);
+    }
+
+    static LiftRetVal<Payload> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4);
+        switch(index) {
+            case 1:
+                return EmptyPayload.read(subview).copyWithOffset(4);
+            case 2:
+                return RawPayload.read(subview).copyWithOffset(4);
+            case 3:
+                return ImagePayload.read(subview).copyWithOffset(4);
+            default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( Payload value) {
+        return value.lower();
+    }
+
+    static int allocationSize(Payload value) {
+        return value.allocationSize();
+    }
+
+    static int write( Payload value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class EmptyPayload extends Payload {
+
+    EmptyPayload();
+
+    EmptyPayload._();
+
+    static LiftRetVal<EmptyPayload> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        return LiftRetVal(EmptyPayload._(), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        int new_offset = buf.offsetInBytes + 4;
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class RawPayload extends Payload {
+    final Uint8List data;
+
+    RawPayload(this.data,);
+
+    RawPayload._(this.data,);
+
+    static LiftRetVal<RawPayload> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final data_lifted = FfiConverterBytes.read(Uint8List.view(buf.buffer, new_offset));
+        final data = data_lifted.value;
+        new_offset += data_lifted.bytesRead;
+        return LiftRetVal(RawPayload._(
+            data,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterBytes.allocationSize(data) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 2);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterBytes.write(data, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class ImagePayload extends Payload {
+    final Image image;
+
+    ImagePayload(this.image,);
+
+    ImagePayload._(this.image,);
+
+    static LiftRetVal<ImagePayload> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final image_lifted = FfiConverterImage.read(Uint8List.view(buf.buffer, new_offset));
+        final image = image_lifted.value;
+        new_offset += image_lifted.bytesRead;
+        return LiftRetVal(ImagePayload._(
+            image,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterImage.allocationSize(image) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 3);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterImage.write(image, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}
+
+class UniffiInternalError implements Exception {
+    static const int bufferOverflow = 0;
+    static const int incompleteData = 1;
+    static const int unexpectedOptionalTag = 2;
+    static const int unexpectedEnumCase = 3;
+    static const int unexpectedNullPointer = 4;
+    static const int unexpectedRustCallStatusCode = 5;
+    static const int unexpectedRustCallError = 6;
+    static const int unexpectedStaleHandle = 7;
+    static const int rustPanic = 8;
+
+    final int errorCode;
+    final String? panicMessage;
+
+    const UniffiInternalError(this.errorCode, this.panicMessage);
+
+    static UniffiInternalError panicked(String message) {
+    return UniffiInternalError(rustPanic, message);
+    }
+
+    @override
+    String toString() {
+    switch (errorCode) {
+        case bufferOverflow:
+        return "UniFfi::BufferOverflow";
+        case incompleteData:
+        return "UniFfi::IncompleteData";
+        case unexpectedOptionalTag:
+        return "UniFfi::UnexpectedOptionalTag";
+        case unexpectedEnumCase:
+        return "UniFfi::UnexpectedEnumCase";
+        case unexpectedNullPointer:
+        return "UniFfi::UnexpectedNullPointer";
+        case unexpectedRustCallStatusCode:
+        return "UniFfi::UnexpectedRustCallStatusCode";
+        case unexpectedRustCallError:
+        return "UniFfi::UnexpectedRustCallError";
+        case unexpectedStaleHandle:
+        return "UniFfi::UnexpectedStaleHandle";
+        case rustPanic:
+        return "UniFfi::rustPanic: \$\$panicMessage";
+        default:
+        return "UniFfi::UnknownError: \$\$errorCode";
+    }
+    }
+}
+
+const int CALL_SUCCESS = 0;
+const int CALL_ERROR = 1;
+const int CALL_UNEXPECTED_ERROR = 2;
+
+final class RustCallStatus extends Struct {
+    @Int8()
+    external int code;
+
+    external RustBuffer errorBuf;
+
+}
+
+void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
+
+    if (status.ref.code == CALL_SUCCESS) {
+    return;
+    } else if (status.ref.code == CALL_ERROR) {
+    throw errorHandler.lift(status.ref.errorBuf);
+    } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+    if (status.ref.errorBuf.len > 0) {
+        throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
+    } else {
+        throw UniffiInternalError.panicked("Rust panic");
+    }
+    } else {
+    throw UniffiInternalError.panicked("Unexpected RustCallStatus code: \${status.ref.code}");
+    }
+}
+
+#[doc=" Allocates the native memory the bindings pass to Rust for the duration of a call."]
+const Allocator _uniffiAllocator = calloc;
+
+T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+    final status = _uniffiAllocator<RustCallStatus>();
+    try {
+    final result = callback(status);
+    checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+    return result;
+    } finally {
+    _uniffiAllocator.free(status);
+    }
+}
+
+class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+    errorBuf.free();
+    return UniffiInternalError.panicked("Unexpected CALL_ERROR");
+    }
+}
+
+abstract class UniffiRustCallStatusErrorHandler {
+    Exception lift(RustBuffer errorBuf);
+}
+
+final class RustBuffer extends Struct {
+    @Uint64()
+    external int capacity;
+
+    @Uint64()
+    external int len;
+
+    external Pointer<Uint8> data;
+
+    static RustBuffer alloc(int size) {
+        return rustCall((status) => _UniffiLib.instance.ffi_bytes_type_test_rustbuffer_alloc(size, status));
+    }
+
+    static RustBuffer fromBytes(ForeignBytes bytes) {
+        return rustCall((status) => _UniffiLib.instance.ffi_bytes_type_test_rustbuffer_from_bytes(bytes, status));
+    }
+
+    void free() {
+        rustCall((status) => _UniffiLib.instance.ffi_bytes_type_test_rustbuffer_free(this, status));
+    }
+
+    RustBuffer reserve(int additionalCapacity) {
+    return rustCall((status) => _UniffiLib.instance.ffi_bytes_type_test_rustbuffer_reserve(this, additionalCapacity, status));
+    }
+
+    Uint8List asUint8List() {
+    final dataList = data.asTypedList(len);
+    final byteData = ByteData.sublistView(dataList);
+    return Uint8List.view(byteData.buffer);
+    }
+
+    @override
+    String toString() {
+    return "RustBuffer{capacity: \$capacity, len: \$len, data: \$data}";
+    }
+}
+
+RustBuffer toRustBuffer(Uint8List data) {
+    final length = data.length;
+
+    final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length);
+    final pointerList = frameData.asTypedList(length);
+    pointerList.setAll(0, data);
+
+    final bytes = _uniffiAllocator<ForeignBytes>();
+    bytes.ref.len = length;
+    bytes.ref.data = frameData;
+    try {
+        return RustBuffer.fromBytes(bytes.ref);
+    } finally {
+        _uniffiAllocator.free(frameData);
+        _uniffiAllocator.free(bytes);
+    }
+}
+
+T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+    try {
+        return lift(buf.asUint8List());
+    } finally {
+        buf.free();
+    }
+}
+
+final class ForeignBytes extends Struct {
+    @Int32()
+    external int len;
+    external Pointer<Uint8> data;
+
+    void free() {
+    _uniffiAllocator.free(data);
+    }
+}
+
+class LiftRetVal<T> {
+    final T value;
+    final int bytesRead;
+    const LiftRetVal(this.value, this.bytesRead);
+
+    LiftRetVal<T> copyWithOffset(int offset) {
+        return LiftRetVal(value, bytesRead + offset);
+    }
+}
+
+abstract class FfiConverter<D, F> {
+    const FfiConverter();
+
+    D lift(F value);
+    F lower(D value);
+    D read(ByteData buffer, int offset);
+    void write(D value, ByteData buffer, int offset);
+    int size(D value);
+}
+
+mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+    @override
+    T lift(T value) => value;
+
+    @override
+    T lower(T value) => value;
+}
+
+class FfiConverterBytes {
+    static Uint8List lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterBytes.read(data).value);
+    }
+
+    static RustBuffer lower( Uint8List value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static LiftRetVal<Uint8List> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+
+        return LiftRetVal(buf.sublist(4, end), end);
+    }
+
+    static int allocationSize([Uint8List? value]) {
+        return (value?.length ?? 0) + 4;
+    }
+
+    static int write( Uint8List value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        buf.setAll(4, value);
+        return value.length + 4;
+    }
+}class FfiConverterOptionalBytes {
+
+    static Uint8List? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalBytes.read(data).value);
+    }
+
+    static LiftRetVal<Uint8List?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterBytes.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([Uint8List? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterBytes.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( Uint8List? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalBytes.allocationSize(value));
+        FfiConverterOptionalBytes.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( Uint8List? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterBytes.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterSequenceBytes {
+
+    static List<Uint8List> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceBytes.read(data).value);
+    }
+
+    static LiftRetVal<List<Uint8List>> read( Uint8List buf) {
+        List<Uint8List> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterBytes.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<Uint8List> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterBytes.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<Uint8List> value) {
+        return value.map((l) => FfiConverterBytes.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<Uint8List> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterString {
+    static String lift( RustBuffer buf) {
+
+        return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
+    }
+
+    static RustBuffer lower( String value) {
+        return toRustBuffer(Utf8Encoder().convert(value));
+    }
+
+    static LiftRetVal<String> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+        return LiftRetVal(utf8.decoder.convert(buf, 4, end), end);
+    }
+
+    static int allocationSize([String value = ""]) {
+
+        return utf8.encoder.convert(value).length + 4;
+    }
+
+    static int write( String value, Uint8List buf) {
+
+        final list = utf8.encoder.convert(value);
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length);
+        buf.setAll(4, list);
+        return list.length + 4;
+    }
+}class FfiConverterUInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, value);
+        return FfiConverterUInt32.allocationSize();
+    }
+
+}
+
+const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
+
+typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+Future<T> uniffiRustCallAsync<T, F>(
+    Pointer<Void> Function() rustFutureFunc,
+    void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc,
+    F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc,
+    void Function(Pointer<Void>) freeFunc,
+    T Function(F) liftFunc, [
+    UniffiRustCallStatusErrorHandler? errorHandler,
+]) async {
+    final rustFuture = rustFutureFunc();
+    final completer = Completer<int>();
+
+    late final NativeCallable<UniffiRustFutureContinuationCallback> callback;
+
+    void poll() {
+        pollFunc(
+            rustFuture,
+            callback.nativeFunction,
+            Pointer<Void>.fromAddress(0),
+        );
+    }
+    void onResponse(int _idx, int pollResult) {
+        if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+            completer.complete(pollResult);
+        } else {
+            poll();
+        }
+    }
+    callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener(onResponse);
+
+    try {
+        poll();
+        await completer.future;
+        callback.close();
+
+        final status = _uniffiAllocator<RustCallStatus>();
+        try {
+            final result = completeFunc(rustFuture, status);
+            checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+            return liftFunc(result);
+        } finally {
+            _uniffiAllocator.free(status);
+        }
+    } finally {
+        freeFunc(rustFuture);
+    }
+}
+
+class UniffiHandleMap<T> {
+    final Map<int, T> _map = {};
+    int _counter = 0;
+
+    int insert(T obj) {
+    final handle = _counter++;
+    _map[handle] = obj;
+    return handle;
+    }
+
+    T get(int handle) {
+    final obj = _map[handle];
+    if (obj == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    return obj;
+    }
+
+    void remove(int handle) {
+    if (_map.remove(handle) == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    }
+}
+
+Uint8List reverse(Uint8List value,) {
+    return FfiConverterBytes.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_bytes_type_test_fn_func_reverse(FfiConverterBytes.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Image echoImage(Image image,) {
+    return FfiConverterImage.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_bytes_type_test_fn_func_echo_image(FfiConverterImage.lower(image), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Uint8List? echoOptional(Uint8List? value,) {
+    return FfiConverterOptionalBytes.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_bytes_type_test_fn_func_echo_optional(FfiConverterOptionalBytes.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}List<Uint8List> echoSequence(List<Uint8List> value,) {
+    return FfiConverterSequenceBytes.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_bytes_type_test_fn_func_echo_sequence(FfiConverterSequenceBytes.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}int length(Uint8List value,) {
+    return rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_bytes_type_test_fn_func_length(FfiConverterBytes.lower(value), uniffiStatus), NullRustCallStatusErrorHandler());
+}Uint8List makeBytes(int length,) {
+    return FfiConverterBytes.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_bytes_type_test_fn_func_make_bytes(length, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int payloadSize(Payload payload,) {
+    return rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_bytes_type_test_fn_func_payload_size(FfiConverterPayload.lower(payload), uniffiStatus), NullRustCallStatusErrorHandler());
+}Payload rawPayload(Uint8List data,) {
+    return FfiConverterPayload.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_bytes_type_test_fn_func_raw_payload(FfiConverterBytes.lower(data), uniffiStatus), NullRustCallStatusErrorHandler()));
+}
+
+class _UniffiLib {
+    _UniffiLib._();
+
+    static final DynamicLibrary _dylib = _open();
+
+    static DynamicLibrary _open() {
+        if (Platform.isAndroid) return DynamicLibrary.open("libbytes_type_test.so");
+        if (Platform.isIOS) return DynamicLibrary.executable();
+        if (Platform.isLinux) return DynamicLibrary.open("libbytes_type_test.so");
+        if (Platform.isMacOS) return DynamicLibrary.open("libbytes_type_test.dylib");
+        if (Platform.isWindows) return DynamicLibrary.open("bytes_type_test.dll");
+        throw UnsupportedError("Unsupported platform: \${Platform.operatingSystem}");
+    }
+
+    static final _UniffiLib instance = _UniffiLib._();
+
+    late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_bytes_type_test_fn_func_reverse = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_bytes_type_test_fn_func_reverse");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_bytes_type_test_fn_func_echo_image = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_bytes_type_test_fn_func_echo_image");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_bytes_type_test_fn_func_echo_optional = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_bytes_type_test_fn_func_echo_optional");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_bytes_type_test_fn_func_echo_sequence = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_bytes_type_test_fn_func_echo_sequence");late final int Function(RustBuffer,Pointer<RustCallStatus>) uniffi_bytes_type_test_fn_func_length = _dylib.lookupFunction<
+        Uint32 Function(RustBuffer,Pointer<RustCallStatus>),
+        int Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_bytes_type_test_fn_func_length");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_bytes_type_test_fn_func_make_bytes = _dylib.lookupFunction<
+        RustBuffer Function(Uint32,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_bytes_type_test_fn_func_make_bytes");late final int Function(RustBuffer,Pointer<RustCallStatus>) uniffi_bytes_type_test_fn_func_payload_size = _dylib.lookupFunction<
+        Uint32 Function(RustBuffer,Pointer<RustCallStatus>),
+        int Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_bytes_type_test_fn_func_payload_size");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_bytes_type_test_fn_func_raw_payload = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_bytes_type_test_fn_func_raw_payload");late final RustBuffer Function(int,Pointer<RustCallStatus>) ffi_bytes_type_test_rustbuffer_alloc = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("ffi_bytes_type_test_rustbuffer_alloc");late final RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>) ffi_bytes_type_test_rustbuffer_from_bytes = _dylib.lookupFunction<
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>),
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>)
+    >("ffi_bytes_type_test_rustbuffer_from_bytes");late final void Function(RustBuffer,Pointer<RustCallStatus>) ffi_bytes_type_test_rustbuffer_free = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("ffi_bytes_type_test_rustbuffer_free");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) ffi_bytes_type_test_rustbuffer_reserve = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("ffi_bytes_type_test_rustbuffer_reserve");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_bytes_type_test_rust_future_poll_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_poll_u8");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_cancel_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_cancel_u8");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_free_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_free_u8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_bytes_type_test_rust_future_complete_u8 = _dylib.lookupFunction<
+        Uint8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_bytes_type_test_rust_future_complete_u8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_bytes_type_test_rust_future_poll_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_poll_i8");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_cancel_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_cancel_i8");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_free_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_free_i8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_bytes_type_test_rust_future_complete_i8 = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_bytes_type_test_rust_future_complete_i8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_bytes_type_test_rust_future_poll_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_poll_u16");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_cancel_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_cancel_u16");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_free_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_free_u16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_bytes_type_test_rust_future_complete_u16 = _dylib.lookupFunction<
+        Uint16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_bytes_type_test_rust_future_complete_u16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_bytes_type_test_rust_future_poll_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_poll_i16");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_cancel_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_cancel_i16");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_free_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_free_i16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_bytes_type_test_rust_future_complete_i16 = _dylib.lookupFunction<
+        Int16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_bytes_type_test_rust_future_complete_i16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_bytes_type_test_rust_future_poll_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_poll_u32");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_cancel_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_cancel_u32");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_free_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_free_u32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_bytes_type_test_rust_future_complete_u32 = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_bytes_type_test_rust_future_complete_u32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_bytes_type_test_rust_future_poll_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_poll_i32");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_cancel_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_cancel_i32");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_free_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_free_i32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_bytes_type_test_rust_future_complete_i32 = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_bytes_type_test_rust_future_complete_i32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_bytes_type_test_rust_future_poll_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_poll_u64");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_cancel_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_cancel_u64");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_free_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_free_u64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_bytes_type_test_rust_future_complete_u64 = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_bytes_type_test_rust_future_complete_u64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_bytes_type_test_rust_future_poll_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_poll_i64");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_cancel_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_cancel_i64");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_free_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_free_i64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_bytes_type_test_rust_future_complete_i64 = _dylib.lookupFunction<
+        Int64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_bytes_type_test_rust_future_complete_i64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_bytes_type_test_rust_future_poll_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_poll_f32");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_cancel_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_cancel_f32");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_free_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_free_f32");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_bytes_type_test_rust_future_complete_f32 = _dylib.lookupFunction<
+        Float Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_bytes_type_test_rust_future_complete_f32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_bytes_type_test_rust_future_poll_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_poll_f64");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_cancel_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_cancel_f64");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_free_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_free_f64");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_bytes_type_test_rust_future_complete_f64 = _dylib.lookupFunction<
+        Double Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_bytes_type_test_rust_future_complete_f64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_bytes_type_test_rust_future_poll_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_poll_pointer");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_cancel_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_cancel_pointer");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_free_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_free_pointer");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_bytes_type_test_rust_future_complete_pointer = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_bytes_type_test_rust_future_complete_pointer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_bytes_type_test_rust_future_poll_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_poll_rust_buffer");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_cancel_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_cancel_rust_buffer");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_free_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_free_rust_buffer");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_bytes_type_test_rust_future_complete_rust_buffer = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_bytes_type_test_rust_future_complete_rust_buffer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_bytes_type_test_rust_future_poll_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_poll_void");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_cancel_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_cancel_void");late final void Function(Pointer<Void>,) ffi_bytes_type_test_rust_future_free_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_bytes_type_test_rust_future_free_void");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_bytes_type_test_rust_future_complete_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_bytes_type_test_rust_future_complete_void");late final int Function() uniffi_bytes_type_test_checksum_func_reverse = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_bytes_type_test_checksum_func_reverse");late final int Function() uniffi_bytes_type_test_checksum_func_echo_image = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_bytes_type_test_checksum_func_echo_image");late final int Function() uniffi_bytes_type_test_checksum_func_echo_optional = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_bytes_type_test_checksum_func_echo_optional");late final int Function() uniffi_bytes_type_test_checksum_func_echo_sequence = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_bytes_type_test_checksum_func_echo_sequence");late final int Function() uniffi_bytes_type_test_checksum_func_length = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_bytes_type_test_checksum_func_length");late final int Function() uniffi_bytes_type_test_checksum_func_make_bytes = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_bytes_type_test_checksum_func_make_bytes");late final int Function() uniffi_bytes_type_test_checksum_func_payload_size = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_bytes_type_test_checksum_func_payload_size");late final int Function() uniffi_bytes_type_test_checksum_func_raw_payload = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_bytes_type_test_checksum_func_raw_payload");late final int Function() ffi_bytes_type_test_uniffi_contract_version = _dylib.lookupFunction<
+        Uint32 Function(),
+        int Function()
+    >("ffi_bytes_type_test_uniffi_contract_version");
+
+    static void _checkApiVersion() {
+        final bindingsVersion = 26;
+        final scaffoldingVersion = _UniffiLib.instance.ffi_bytes_type_test_uniffi_contract_version();
+        if (bindingsVersion != scaffoldingVersion) {
+            throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version \$bindingsVersion, scaffolding version \$scaffoldingVersion");
+        }
+    }
+
+    static void _checkApiChecksums() {
+        if (_UniffiLib.instance.uniffi_bytes_type_test_checksum_func_reverse() != 25092) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_bytes_type_test_checksum_func_echo_image() != 42706) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_bytes_type_test_checksum_func_echo_optional() != 33568) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_bytes_type_test_checksum_func_echo_sequence() != 12364) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_bytes_type_test_checksum_func_length() != 26551) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_bytes_type_test_checksum_func_make_bytes() != 5292) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_bytes_type_test_checksum_func_payload_size() != 8800) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_bytes_type_test_checksum_func_raw_payload() != 24096) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }
+    }
+}
+
+void initialize() {
+    _UniffiLib._open();
+}
+
+void ensureInitialized() {
+    _UniffiLib._checkApiVersion();
+    _UniffiLib._checkApiChecksums();
+}
@@ -0,0 +1,61 @@
+#[derive(uniffi::Record)]
+pub struct Image {
+    name: String,
+    data: Vec<u8>,
+    thumbnail: Option<Vec<u8>>,
+}
+
+#[derive(uniffi::Enum)]
+pub enum Payload {
+    Empty,
+    Raw { data: Vec<u8> },
+    Image { image: Image },
+}
+
+fn reverse(mut value: Vec<u8>) -> Vec<u8> {
+    value.reverse();
+    value
+}
+
+#[uniffi::export]
+pub fn length(value: Vec<u8>) -> u32 {
+    value.len() as u32
+}
+
+#[uniffi::export]
+pub fn make_bytes(length: u32) -> Vec<u8> {
+    (0..length).map(|i| i as u8).collect()
+}
+
+#[uniffi::export]
+pub fn echo_optional(value: Option<Vec<u8>>) -> Option<Vec<u8>> {
+    value
+}
+
+#[uniffi::export]
+pub fn echo_sequence(value: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+    value
+}
+
+#[uniffi::export]
+pub fn echo_image(image: Image) -> Image {
+    image
+}
+
+#[uniffi::export]
+pub fn payload_size(payload: Payload) -> u32 {
+    match payload {
+        Payload::Empty => 0,
+        Payload::Raw { data } => data.len() as u32,
+        Payload::Image { image } => {
+            (image.data.len() + image.thumbnail.map(|t| t.len()).unwrap_or_default()) as u32
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn raw_payload(data: Vec<u8>) -> Payload {
+    Payload::Raw { data }
+}
+
+uniffi::include_scaffolding!("api");
@@ -0,0 +1,59 @@
+import 'dart:typed_data';
+
+import 'package:test/test.dart';
+import '../bytes_type_test.dart';
+
+void main() {
+  test('round trip', () {
+    expect(reverse(Uint8List.fromList([1, 2, 3])), [3, 2, 1]);
+    expect(reverse(Uint8List(0)), isEmpty);
+    expect(length(Uint8List(1024)), 1024);
+  });
+
+  test('lifted bytes are Uint8List', () {
+    final bytes = makeBytes(256);
+    expect(bytes, isA<Uint8List>());
+    expect(bytes.length, 256);
+    expect(bytes[0], 0);
+    expect(bytes[255], 255);
+  });
+
+  test('optionals', () {
+    expect(echoOptional(Uint8List.fromList([0, 255])), [0, 255]);
+    expect(echoOptional(Uint8List(0)), isEmpty);
+    expect(echoOptional(null), null);
+  });
+
+  test('sequences', () {
+    final value = [
+      Uint8List.fromList([1]),
+      Uint8List(0),
+      Uint8List.fromList([2, 3]),
+    ];
+    expect(echoSequence(value), value);
+  });
+
+  test('records', () {
+    final image = echoImage(Image(
+      name: 'pixel',
+      data: Uint8List.fromList([0xff, 0x00, 0x7f]),
+      thumbnail: Uint8List.fromList([0x01]),
+    ));
+    expect(image.name, 'pixel');
+    expect(image.data, [0xff, 0x00, 0x7f]);
+    expect(image.thumbnail, [0x01]);
+  });
+
+  test('enums', () {
+    expect(payloadSize(EmptyPayload()), 0);
+    expect(payloadSize(RawPayload(Uint8List(10))), 10);
+    expect(
+        payloadSize(ImagePayload(Image(
+            name: 'pixel', data: Uint8List(3), thumbnail: Uint8List(2)))),
+        5);
+
+    final payload = rawPayload(Uint8List.fromList([4, 2]));
+    expect(payload, isA<RawPayload>());
+    expect((payload as RawPayload).data, [4, 2]);
+  });
+}
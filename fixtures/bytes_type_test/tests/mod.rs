@@ -0,0 +1,11 @@
+use anyhow::Result;
+
+#[test]
+fn bytes_type_test() -> Result<()> {
+    uniffi_dart::testing::run_test("bytes_type_test", "src/api.udl", None)
+}
+
+#[test]
+fn bytes_type_test_golden() -> Result<()> {
+    uniffi_dart::testing::assert_golden("bytes_type_test", "src/api.udl", None)
+}
@@ -123,4 +123,5 @@ impl RustStringifier {
     }
 }
 
-uniffi::include_scaffolding!("api");
\ No newline at end of file
+uniffi::include_scaffolding!("api");
+uniffi_dart::setup_finalizers!();
@@ -9,11 +9,11 @@ class DartGetters extends ForeignGetters {
   String getString(String v, bool arg2) {
     if (v == 'BadArgument') {
       // Throw a UniFFI-generated exception type corresponding to BadArgument
-      throw SimpleException.badArgument;
+      throw BadArgumentSimpleException('BadArgument');
     }
     if (v == 'UnexpectedException') {
       // Throw a UniFFI-generated exception type corresponding to UnexpectedError
-      throw SimpleException.unexpectedError;
+      throw UnexpectedExceptionSimpleException('UnexpectedError');
     }
     return arg2 ? v : '1234567890123';
   }
@@ -35,10 +35,10 @@ class DartGetters extends ForeignGetters {
   @override
   void getNothing(String v) {
     if (v == 'BadArgument') {
-      throw SimpleException.badArgument;
+      throw BadArgumentSimpleException('BadArgument');
     }
     if (v == 'UnexpectedError') {
-      throw SimpleException.unexpectedError;
+      throw UnexpectedExceptionSimpleException('UnexpectedError');
     }
   }
 }
@@ -115,6 +115,17 @@ void main() {
     rustGetters.getNothing(callback, "1234567890123");
   });
 
+  test('callback errors surface as flat SimpleException with Rust message', () {
+    // Rust maps the failed callback to `SimpleError::UnexpectedError`, whose
+    // `Display` message crosses the FFI together with the variant.
+    expect(
+        () => rustGetters.getString(callback, 'BadArgument', false),
+        throwsA(isA<UnexpectedExceptionSimpleException>().having(
+            (e) => e.message, 'message', 'InternalTelephoneError')));
+    expect(() => rustGetters.getNothing(callback, 'BadArgument'),
+        throwsA(isA<SimpleException>()));
+  });
+
   // test('getString throws SimpleException.BadArgument', () {
   //   final v = rustGetters.getString(callback, "BadArgument", true);
   //   expect(v, throwsA(isA<Exception>()));
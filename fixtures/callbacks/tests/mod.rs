@@ -4,3 +4,8 @@ use anyhow::Result;
 fn callbacks() -> Result<()> {
     uniffi_dart::testing::run_test("callbacks", "src/api.udl", None)
 }
+
+#[test]
+fn callbacks_golden() -> Result<()> {
+    uniffi_dart::testing::assert_golden("callbacks", "src/api.udl", None)
+}
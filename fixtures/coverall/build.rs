@@ -1,3 +1,3 @@
 fn main() {
-    //uniffi_dart::generate_scaffolding("./src/api.udl".into()).unwrap();
+    uniffi_dart::generate_scaffolding("./src/api.udl".into()).unwrap();
 }
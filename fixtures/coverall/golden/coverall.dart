@@ -0,0 +1,3166 @@
+library coverall;
+
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io" show Platform, File, Directory;
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+class DictWithDefaults {
+    final String name;final String? category;final int integer;
+
+    DictWithDefaults({this.name = 'default-value',this.category = null,this.integer = 31,});
+}
+
+class FfiConverterDictWithDefaults {
+    static DictWithDefaults lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterDictWithDefaults.read(data).value);
+    }
+
+    static LiftRetVal<DictWithDefaults> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final name_lifted = FfiConverterString.read(Uint8List.view(buf.buffer, new_offset));
+        final name = name_lifted.value;
+        new_offset += name_lifted.bytesRead;final category_lifted = FfiConverterOptionalString.read(Uint8List.view(buf.buffer, new_offset));
+        final category = category_lifted.value;
+        new_offset += category_lifted.bytesRead;final integer_lifted = FfiConverterUInt64.read(Uint8List.view(buf.buffer, new_offset));
+        final integer = integer_lifted.value;
+        new_offset += integer_lifted.bytesRead;
+        return LiftRetVal(DictWithDefaults(
+            name: name,category: category,integer: integer,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( DictWithDefaults value) {
+        final total_length = FfiConverterString.allocationSize(value.name) +FfiConverterOptionalString.allocationSize(value.category) +FfiConverterUInt64.allocationSize(value.integer) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( DictWithDefaults value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterString.write(value.name, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterOptionalString.write(value.category, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterUInt64.write(value.integer, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(DictWithDefaults value) {
+        return FfiConverterString.allocationSize(value.name) +FfiConverterOptionalString.allocationSize(value.category) +FfiConverterUInt64.allocationSize(value.integer) + 0;
+    }
+}class Repair {
+    final DateTime when;final Patch patch;
+
+    Repair({required this.when,required this.patch,});
+}
+
+class FfiConverterRepair {
+    static Repair lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterRepair.read(data).value);
+    }
+
+    static LiftRetVal<Repair> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final when_lifted = FfiConverterTimestamp.read(Uint8List.view(buf.buffer, new_offset));
+        final when = when_lifted.value;
+        new_offset += when_lifted.bytesRead;final patch_lifted = FfiConverterPatch.read(Uint8List.view(buf.buffer, new_offset));
+        final patch = patch_lifted.value;
+        new_offset += patch_lifted.bytesRead;
+        return LiftRetVal(Repair(
+            when: when,patch: patch,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( Repair value) {
+        final total_length = FfiConverterTimestamp.allocationSize(value.when) +FfiConverterPatch.allocationSize(value.patch) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( Repair value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterTimestamp.write(value.when, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterPatch.write(value.patch, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Repair value) {
+        return FfiConverterTimestamp.allocationSize(value.when) +FfiConverterPatch.allocationSize(value.patch) + 0;
+    }
+}class SimpleDict {
+    final String text;final String? maybeText;final Uint8List someBytes;final Uint8List? maybeSomeBytes;final bool aBool;final bool? maybeABool;final int unsigned8;final int? maybeUnsigned8;final int unsigned16;final int? maybeUnsigned16;final int unsigned64;final int? maybeUnsigned64;final int signed8;final int? maybeSigned8;final int signed64;final int? maybeSigned64;final double float32;final double? maybeFloat32;final double float64;final double? maybeFloat64;final Coveralls? coveralls;
+
+    SimpleDict({required this.text,required this.maybeText,required this.someBytes,required this.maybeSomeBytes,required this.aBool,required this.maybeABool,required this.unsigned8,required this.maybeUnsigned8,required this.unsigned16,required this.maybeUnsigned16,required this.unsigned64,required this.maybeUnsigned64,required this.signed8,required this.maybeSigned8,required this.signed64,required this.maybeSigned64,required this.float32,required this.maybeFloat32,required this.float64,required this.maybeFloat64,required this.coveralls,});
+}
+
+class FfiConverterSimpleDict {
+    static SimpleDict lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSimpleDict.read(data).value);
+    }
+
+    static LiftRetVal<SimpleDict> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final text_lifted = FfiConverterString.read(Uint8List.view(buf.buffer, new_offset));
+        final text = text_lifted.value;
+        new_offset += text_lifted.bytesRead;final maybeText_lifted = FfiConverterOptionalString.read(Uint8List.view(buf.buffer, new_offset));
+        final maybeText = maybeText_lifted.value;
+        new_offset += maybeText_lifted.bytesRead;final someBytes_lifted = FfiConverterBytes.read(Uint8List.view(buf.buffer, new_offset));
+        final someBytes = someBytes_lifted.value;
+        new_offset += someBytes_lifted.bytesRead;final maybeSomeBytes_lifted = FfiConverterOptionalBytes.read(Uint8List.view(buf.buffer, new_offset));
+        final maybeSomeBytes = maybeSomeBytes_lifted.value;
+        new_offset += maybeSomeBytes_lifted.bytesRead;final aBool_lifted = FfiConverterBool.read(Uint8List.view(buf.buffer, new_offset));
+        final aBool = aBool_lifted.value;
+        new_offset += aBool_lifted.bytesRead;final maybeABool_lifted = FfiConverterOptionalBool.read(Uint8List.view(buf.buffer, new_offset));
+        final maybeABool = maybeABool_lifted.value;
+        new_offset += maybeABool_lifted.bytesRead;final unsigned8_lifted = FfiConverterUInt8.read(Uint8List.view(buf.buffer, new_offset));
+        final unsigned8 = unsigned8_lifted.value;
+        new_offset += unsigned8_lifted.bytesRead;final maybeUnsigned8_lifted = FfiConverterOptionalUInt8.read(Uint8List.view(buf.buffer, new_offset));
+        final maybeUnsigned8 = maybeUnsigned8_lifted.value;
+        new_offset += maybeUnsigned8_lifted.bytesRead;final unsigned16_lifted = FfiConverterUInt16.read(Uint8List.view(buf.buffer, new_offset));
+        final unsigned16 = unsigned16_lifted.value;
+        new_offset += unsigned16_lifted.bytesRead;final maybeUnsigned16_lifted = FfiConverterOptionalUInt16.read(Uint8List.view(buf.buffer, new_offset));
+        final maybeUnsigned16 = maybeUnsigned16_lifted.value;
+        new_offset += maybeUnsigned16_lifted.bytesRead;final unsigned64_lifted = FfiConverterUInt64.read(Uint8List.view(buf.buffer, new_offset));
+        final unsigned64 = unsigned64_lifted.value;
+        new_offset += unsigned64_lifted.bytesRead;final maybeUnsigned64_lifted = FfiConverterOptionalUInt64.read(Uint8List.view(buf.buffer, new_offset));
+        final maybeUnsigned64 = maybeUnsigned64_lifted.value;
+        new_offset += maybeUnsigned64_lifted.bytesRead;final signed8_lifted = FfiConverterInt8.read(Uint8List.view(buf.buffer, new_offset));
+        final signed8 = signed8_lifted.value;
+        new_offset += signed8_lifted.bytesRead;final maybeSigned8_lifted = FfiConverterOptionalInt8.read(Uint8List.view(buf.buffer, new_offset));
+        final maybeSigned8 = maybeSigned8_lifted.value;
+        new_offset += maybeSigned8_lifted.bytesRead;final signed64_lifted = FfiConverterInt64.read(Uint8List.view(buf.buffer, new_offset));
+        final signed64 = signed64_lifted.value;
+        new_offset += signed64_lifted.bytesRead;final maybeSigned64_lifted = FfiConverterOptionalInt64.read(Uint8List.view(buf.buffer, new_offset));
+        final maybeSigned64 = maybeSigned64_lifted.value;
+        new_offset += maybeSigned64_lifted.bytesRead;final float32_lifted = FfiConverterDouble32.read(Uint8List.view(buf.buffer, new_offset));
+        final float32 = float32_lifted.value;
+        new_offset += float32_lifted.bytesRead;final maybeFloat32_lifted = FfiConverterOptionalDouble32.read(Uint8List.view(buf.buffer, new_offset));
+        final maybeFloat32 = maybeFloat32_lifted.value;
+        new_offset += maybeFloat32_lifted.bytesRead;final float64_lifted = FfiConverterDouble64.read(Uint8List.view(buf.buffer, new_offset));
+        final float64 = float64_lifted.value;
+        new_offset += float64_lifted.bytesRead;final maybeFloat64_lifted = FfiConverterOptionalDouble64.read(Uint8List.view(buf.buffer, new_offset));
+        final maybeFloat64 = maybeFloat64_lifted.value;
+        new_offset += maybeFloat64_lifted.bytesRead;final coveralls_lifted = FfiConverterOptionalCoveralls.read(Uint8List.view(buf.buffer, new_offset));
+        final coveralls = coveralls_lifted.value;
+        new_offset += coveralls_lifted.bytesRead;
+        return LiftRetVal(SimpleDict(
+            text: text,maybeText: maybeText,someBytes: someBytes,maybeSomeBytes: maybeSomeBytes,aBool: aBool,maybeABool: maybeABool,unsigned8: unsigned8,maybeUnsigned8: maybeUnsigned8,unsigned16: unsigned16,maybeUnsigned16: maybeUnsigned16,unsigned64: unsigned64,maybeUnsigned64: maybeUnsigned64,signed8: signed8,maybeSigned8: maybeSigned8,signed64: signed64,maybeSigned64: maybeSigned64,float32: float32,maybeFloat32: maybeFloat32,float64: float64,maybeFloat64: maybeFloat64,coveralls: coveralls,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( SimpleDict value) {
+        final total_length = FfiConverterString.allocationSize(value.text) +FfiConverterOptionalString.allocationSize(value.maybeText) +FfiConverterBytes.allocationSize(value.someBytes) +FfiConverterOptionalBytes.allocationSize(value.maybeSomeBytes) +FfiConverterBool.allocationSize(value.aBool) +FfiConverterOptionalBool.allocationSize(value.maybeABool) +FfiConverterUInt8.allocationSize(value.unsigned8) +FfiConverterOptionalUInt8.allocationSize(value.maybeUnsigned8) +FfiConverterUInt16.allocationSize(value.unsigned16) +FfiConverterOptionalUInt16.allocationSize(value.maybeUnsigned16) +FfiConverterUInt64.allocationSize(value.unsigned64) +FfiConverterOptionalUInt64.allocationSize(value.maybeUnsigned64) +FfiConverterInt8.allocationSize(value.signed8) +FfiConverterOptionalInt8.allocationSize(value.maybeSigned8) +FfiConverterInt64.allocationSize(value.signed64) +FfiConverterOptionalInt64.allocationSize(value.maybeSigned64) +FfiConverterDouble32.allocationSize(value.float32) +FfiConverterOptionalDouble32.allocationSize(value.maybeFloat32) +FfiConverterDouble64.allocationSize(value.float64) +FfiConverterOptionalDouble64.allocationSize(value.maybeFloat64) +FfiConverterOptionalCoveralls.allocationSize(value.coveralls) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( SimpleDict value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterString.write(value.text, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterOptionalString.write(value.maybeText, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterBytes.write(value.someBytes, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterOptionalBytes.write(value.maybeSomeBytes, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterBool.write(value.aBool, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterOptionalBool.write(value.maybeABool, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterUInt8.write(value.unsigned8, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterOptionalUInt8.write(value.maybeUnsigned8, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterUInt16.write(value.unsigned16, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterOptionalUInt16.write(value.maybeUnsigned16, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterUInt64.write(value.unsigned64, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterOptionalUInt64.write(value.maybeUnsigned64, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterInt8.write(value.signed8, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterOptionalInt8.write(value.maybeSigned8, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterInt64.write(value.signed64, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterOptionalInt64.write(value.maybeSigned64, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterDouble32.write(value.float32, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterOptionalDouble32.write(value.maybeFloat32, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterDouble64.write(value.float64, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterOptionalDouble64.write(value.maybeFloat64, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterOptionalCoveralls.write(value.coveralls, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(SimpleDict value) {
+        return FfiConverterString.allocationSize(value.text) +FfiConverterOptionalString.allocationSize(value.maybeText) +FfiConverterBytes.allocationSize(value.someBytes) +FfiConverterOptionalBytes.allocationSize(value.maybeSomeBytes) +FfiConverterBool.allocationSize(value.aBool) +FfiConverterOptionalBool.allocationSize(value.maybeABool) +FfiConverterUInt8.allocationSize(value.unsigned8) +FfiConverterOptionalUInt8.allocationSize(value.maybeUnsigned8) +FfiConverterUInt16.allocationSize(value.unsigned16) +FfiConverterOptionalUInt16.allocationSize(value.maybeUnsigned16) +FfiConverterUInt64.allocationSize(value.unsigned64) +FfiConverterOptionalUInt64.allocationSize(value.maybeUnsigned64) +FfiConverterInt8.allocationSize(value.signed8) +FfiConverterOptionalInt8.allocationSize(value.maybeSigned8) +FfiConverterInt64.allocationSize(value.signed64) +FfiConverterOptionalInt64.allocationSize(value.maybeSigned64) +FfiConverterDouble32.allocationSize(value.float32) +FfiConverterOptionalDouble32.allocationSize(value.maybeFloat32) +FfiConverterDouble64.allocationSize(value.float64) +FfiConverterOptionalDouble64.allocationSize(value.maybeFloat64) +FfiConverterOptionalCoveralls.allocationSize(value.coveralls) + 0;
+    }
+}
+
+enum Color {
+    red,blue,green,
+    ;
+}
+
+class FfiConverterColor {
+    static Color lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterColor.read(data).value);
+    }
+
+    static LiftRetVal<Color> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        switch(index) {
+            case 1:
+                return LiftRetVal(Color.red, 4);
+            case 2:
+                return LiftRetVal(Color.blue, 4);
+            case 3:
+                return LiftRetVal(Color.green, 4);
+            default:
+                throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( Color input) {
+        final buf = Uint8List(allocationSize(input));
+        write(input, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int allocationSize(Color value) {
+        return 4;
+    }
+
+    static int write( Color value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.index + 1);
+        return 4;
+    }
+}abstract class ComplexException implements Exception {
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterComplexException {
+    static ComplexException lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterComplexException.read(data).value);
+    }
+
+    static LiftRetVal<ComplexException> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4);
+        switch(index) {
+            case 1:
+                return OsExceptionComplexException.read(subview).copyWithOffset(4);
+            case 2:
+                return PermissionDeniedComplexException.read(subview).copyWithOffset(4);
+            case 3:
+                return UnknownExceptionComplexException.read(subview).copyWithOffset(4);
+            default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( ComplexException value) {
+        return value.lower();
+    }
+
+    static int allocationSize(ComplexException value) {
+        return value.allocationSize();
+    }
+
+    static int write( ComplexException value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class OsExceptionComplexException extends ComplexException {
+    final int code;final int extendedCode;
+
+    OsExceptionComplexException({ required this.code,required this.extendedCode, });
+
+    OsExceptionComplexException._(this.code,this.extendedCode,);
+
+    static LiftRetVal<OsExceptionComplexException> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final code_lifted = FfiConverterInt16.read(Uint8List.view(buf.buffer, new_offset));
+        final code = code_lifted.value;
+        new_offset += code_lifted.bytesRead;final extendedCode_lifted = FfiConverterInt16.read(Uint8List.view(buf.buffer, new_offset));
+        final extendedCode = extendedCode_lifted.value;
+        new_offset += extendedCode_lifted.bytesRead;
+        return LiftRetVal(OsExceptionComplexException._(
+            code,extendedCode,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterInt16.allocationSize(code) +FfiConverterInt16.allocationSize(extendedCode) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterInt16.write(code, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterInt16.write(extendedCode, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class PermissionDeniedComplexException extends ComplexException {
+    final String reason;
+
+    PermissionDeniedComplexException(this.reason,);
+
+    PermissionDeniedComplexException._(this.reason,);
+
+    static LiftRetVal<PermissionDeniedComplexException> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final reason_lifted = FfiConverterString.read(Uint8List.view(buf.buffer, new_offset));
+        final reason = reason_lifted.value;
+        new_offset += reason_lifted.bytesRead;
+        return LiftRetVal(PermissionDeniedComplexException._(
+            reason,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterString.allocationSize(reason) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 2);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterString.write(reason, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class UnknownExceptionComplexException extends ComplexException {
+
+    UnknownExceptionComplexException();
+
+    UnknownExceptionComplexException._();
+
+    static LiftRetVal<UnknownExceptionComplexException> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        return LiftRetVal(UnknownExceptionComplexException._(), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 3);
+        int new_offset = buf.offsetInBytes + 4;
+
+        return new_offset - buf.offsetInBytes;
+    }
+}
+
+class ComplexExceptionErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+        return FfiConverterComplexException.lift(errorBuf);
+    }
+}abstract class ComplexMacroException implements Exception {
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterComplexMacroException {
+    static ComplexMacroException lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterComplexMacroException.read(data).value);
+    }
+
+    static LiftRetVal<ComplexMacroException> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4);
+        switch(index) {
+            case 1:
+                return OsExceptionComplexMacroException.read(subview).copyWithOffset(4);
+            case 2:
+                return PermissionDeniedComplexMacroException.read(subview).copyWithOffset(4);
+            case 3:
+                return UnknownExceptionComplexMacroException.read(subview).copyWithOffset(4);
+            default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( ComplexMacroException value) {
+        return value.lower();
+    }
+
+    static int allocationSize(ComplexMacroException value) {
+        return value.allocationSize();
+    }
+
+    static int write( ComplexMacroException value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class OsExceptionComplexMacroException extends ComplexMacroException {
+    final int code;final int extendedCode;
+
+    OsExceptionComplexMacroException({ required this.code,required this.extendedCode, });
+
+    OsExceptionComplexMacroException._(this.code,this.extendedCode,);
+
+    static LiftRetVal<OsExceptionComplexMacroException> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final code_lifted = FfiConverterInt16.read(Uint8List.view(buf.buffer, new_offset));
+        final code = code_lifted.value;
+        new_offset += code_lifted.bytesRead;final extendedCode_lifted = FfiConverterInt16.read(Uint8List.view(buf.buffer, new_offset));
+        final extendedCode = extendedCode_lifted.value;
+        new_offset += extendedCode_lifted.bytesRead;
+        return LiftRetVal(OsExceptionComplexMacroException._(
+            code,extendedCode,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterInt16.allocationSize(code) +FfiConverterInt16.allocationSize(extendedCode) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterInt16.write(code, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterInt16.write(extendedCode, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class PermissionDeniedComplexMacroException extends ComplexMacroException {
+    final String reason;
+
+    PermissionDeniedComplexMacroException(this.reason,);
+
+    PermissionDeniedComplexMacroException._(this.reason,);
+
+    static LiftRetVal<PermissionDeniedComplexMacroException> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final reason_lifted = FfiConverterString.read(Uint8List.view(buf.buffer, new_offset));
+        final reason = reason_lifted.value;
+        new_offset += reason_lifted.bytesRead;
+        return LiftRetVal(PermissionDeniedComplexMacroException._(
+            reason,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterString.allocationSize(reason) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 2);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterString.write(reason, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class UnknownExceptionComplexMacroException extends ComplexMacroException {
+
+    UnknownExceptionComplexMacroException();
+
+    UnknownExceptionComplexMacroException._();
+
+    static LiftRetVal<UnknownExceptionComplexMacroException> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        return LiftRetVal(UnknownExceptionComplexMacroException._(), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 3);
+        int new_offset = buf.offsetInBytes + 4;
+
+        return new_offset - buf.offsetInBytes;
+    }
+}
+
+class ComplexMacroExceptionErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+        return FfiConverterComplexMacroException.lift(errorBuf);
+    }
+}abstract class CoverallException implements Exception {
+    final String message;
+
+    CoverallException(this.message);
+
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterCoverallException {
+    static CoverallException lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterCoverallException.read(data).value);
+    }
+
+    static LiftRetVal<CoverallException> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+
+        final message = FfiConverterString.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 4));
+        switch(index) {
+            case 1:
+                return LiftRetVal(TooManyHolesCoverallException(message.value), message.bytesRead + 4);
+            default:
+                throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( CoverallException value) {
+        return value.lower();
+    }
+
+    static int allocationSize(CoverallException value) {
+        return value.allocationSize();
+    }
+
+    static int write( CoverallException value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class TooManyHolesCoverallException extends CoverallException {
+    TooManyHolesCoverallException(String message) : super(message);
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterString.allocationSize(message) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        return FfiConverterString.write(message, Uint8List.view(buf.buffer, buf.offsetInBytes + 4)) + 4;
+    }
+
+    @override
+    String toString() {
+        return "CoverallException.TooManyHoles: $message";
+    }
+}
+
+class CoverallExceptionErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+        return FfiConverterCoverallException.lift(errorBuf);
+    }
+}abstract class CoverallFlatException implements Exception {
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterCoverallFlatException {
+    static CoverallFlatException lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterCoverallFlatException.read(data).value);
+    }
+
+    static LiftRetVal<CoverallFlatException> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4);
+        switch(index) {
+            case 1:
+                return TooManyVariantsCoverallFlatException.read(subview).copyWithOffset(4);
+            default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( CoverallFlatException value) {
+        return value.lower();
+    }
+
+    static int allocationSize(CoverallFlatException value) {
+        return value.allocationSize();
+    }
+
+    static int write( CoverallFlatException value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class TooManyVariantsCoverallFlatException extends CoverallFlatException {
+    final int num;
+
+    TooManyVariantsCoverallFlatException(this.num,);
+
+    TooManyVariantsCoverallFlatException._(this.num,);
+
+    static LiftRetVal<TooManyVariantsCoverallFlatException> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final num_lifted = FfiConverterInt16.read(Uint8List.view(buf.buffer, new_offset));
+        final num = num_lifted.value;
+        new_offset += num_lifted.bytesRead;
+        return LiftRetVal(TooManyVariantsCoverallFlatException._(
+            num,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterInt16.allocationSize(num) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterInt16.write(num, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}
+
+class CoverallFlatExceptionErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+        return FfiConverterCoverallFlatException.lift(errorBuf);
+    }
+}abstract class CoverallFlatMacroException implements Exception {
+    final String message;
+
+    CoverallFlatMacroException(this.message);
+
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterCoverallFlatMacroException {
+    static CoverallFlatMacroException lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterCoverallFlatMacroException.read(data).value);
+    }
+
+    static LiftRetVal<CoverallFlatMacroException> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+
+        final message = FfiConverterString.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 4));
+        switch(index) {
+            case 1:
+                return LiftRetVal(TooManyVariantsCoverallFlatMacroException(message.value), message.bytesRead + 4);
+            default:
+                throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( CoverallFlatMacroException value) {
+        return value.lower();
+    }
+
+    static int allocationSize(CoverallFlatMacroException value) {
+        return value.allocationSize();
+    }
+
+    static int write( CoverallFlatMacroException value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class TooManyVariantsCoverallFlatMacroException extends CoverallFlatMacroException {
+    TooManyVariantsCoverallFlatMacroException(String message) : super(message);
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterString.allocationSize(message) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        return FfiConverterString.write(message, Uint8List.view(buf.buffer, buf.offsetInBytes + 4)) + 4;
+    }
+
+    @override
+    String toString() {
+        return "CoverallFlatMacroException.TooManyVariants: $message";
+    }
+}
+
+class CoverallFlatMacroExceptionErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+        return FfiConverterCoverallFlatMacroException.lift(errorBuf);
+    }
+}abstract class CoverallMacroException implements Exception {
+    final String message;
+
+    CoverallMacroException(this.message);
+
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterCoverallMacroException {
+    static CoverallMacroException lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterCoverallMacroException.read(data).value);
+    }
+
+    static LiftRetVal<CoverallMacroException> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+
+        final message = FfiConverterString.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 4));
+        switch(index) {
+            case 1:
+                return LiftRetVal(TooManyMacrosCoverallMacroException(message.value), message.bytesRead + 4);
+            default:
+                throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( CoverallMacroException value) {
+        return value.lower();
+    }
+
+    static int allocationSize(CoverallMacroException value) {
+        return value.allocationSize();
+    }
+
+    static int write( CoverallMacroException value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class TooManyMacrosCoverallMacroException extends CoverallMacroException {
+    TooManyMacrosCoverallMacroException(String message) : super(message);
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterString.allocationSize(message) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        return FfiConverterString.write(message, Uint8List.view(buf.buffer, buf.offsetInBytes + 4)) + 4;
+    }
+
+    @override
+    String toString() {
+        return "CoverallMacroException.TooManyMacros: $message";
+    }
+}
+
+class CoverallMacroExceptionErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+        return FfiConverterCoverallMacroException.lift(errorBuf);
+    }
+}abstract class CoverallRichExceptionNoVariantData implements Exception {
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterCoverallRichExceptionNoVariantData {
+    static CoverallRichExceptionNoVariantData lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterCoverallRichExceptionNoVariantData.read(data).value);
+    }
+
+    static LiftRetVal<CoverallRichExceptionNoVariantData> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4);
+        switch(index) {
+            case 1:
+                return TooManyPlainVariantsCoverallRichExceptionNoVariantData.read(subview).copyWithOffset(4);
+            default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( CoverallRichExceptionNoVariantData value) {
+        return value.lower();
+    }
+
+    static int allocationSize(CoverallRichExceptionNoVariantData value) {
+        return value.allocationSize();
+    }
+
+    static int write( CoverallRichExceptionNoVariantData value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class TooManyPlainVariantsCoverallRichExceptionNoVariantData extends CoverallRichExceptionNoVariantData {
+
+    TooManyPlainVariantsCoverallRichExceptionNoVariantData();
+
+    TooManyPlainVariantsCoverallRichExceptionNoVariantData._();
+
+    static LiftRetVal<TooManyPlainVariantsCoverallRichExceptionNoVariantData> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        return LiftRetVal(TooManyPlainVariantsCoverallRichExceptionNoVariantData._(), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        int new_offset = buf.offsetInBytes + 4;
+
+        return new_offset - buf.offsetInBytes;
+    }
+}
+
+class CoverallRichExceptionNoVariantDataErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+        return FfiConverterCoverallRichExceptionNoVariantData.lift(errorBuf);
+    }
+}abstract class MaybeSimpleDict {
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterMaybeSimpleDict {
+    static MaybeSimpleDict lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterMaybeSimpleDict.read(data).value);
+    }
+
+    static LiftRetVal<MaybeSimpleDict> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4);
+        switch(index) {
+            case 1:
+                return YeahMaybeSimpleDict.read(subview).copyWithOffset(4);
+            case 2:
+                return NahMaybeSimpleDict.read(subview).copyWithOffset(4);
+            default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( MaybeSimpleDict value) {
+        return value.lower();
+    }
+
+    static int allocationSize(MaybeSimpleDict value) {
+        return value.allocationSize();
+    }
+
+    static int write( MaybeSimpleDict value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class YeahMaybeSimpleDict extends MaybeSimpleDict {
+    final SimpleDict d;
+
+    YeahMaybeSimpleDict(this.d,);
+
+    YeahMaybeSimpleDict._(this.d,);
+
+    static LiftRetVal<YeahMaybeSimpleDict> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final d_lifted = FfiConverterSimpleDict.read(Uint8List.view(buf.buffer, new_offset));
+        final d = d_lifted.value;
+        new_offset += d_lifted.bytesRead;
+        return LiftRetVal(YeahMaybeSimpleDict._(
+            d,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterSimpleDict.allocationSize(d) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterSimpleDict.write(d, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class NahMaybeSimpleDict extends MaybeSimpleDict {
+
+    NahMaybeSimpleDict();
+
+    NahMaybeSimpleDict._();
+
+    static LiftRetVal<NahMaybeSimpleDict> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        return LiftRetVal(NahMaybeSimpleDict._(), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 2);
+        int new_offset = buf.offsetInBytes + 4;
+
+        return new_offset - buf.offsetInBytes;
+    }
+}
+
+abstract class SimpleFlatEnum {
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterSimpleFlatEnum {
+    static SimpleFlatEnum lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterSimpleFlatEnum.read(data).value);
+    }
+
+    static LiftRetVal<SimpleFlatEnum> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4);
+        switch(index) {
+            case 1:
+                return FirstSimpleFlatEnum.read(subview).copyWithOffset(4);
+            case 2:
+                return SecondSimpleFlatEnum.read(subview).copyWithOffset(4);
+            default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( SimpleFlatEnum value) {
+        return value.lower();
+    }
+
+    static int allocationSize(SimpleFlatEnum value) {
+        return value.allocationSize();
+    }
+
+    static int write( SimpleFlatEnum value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class FirstSimpleFlatEnum extends SimpleFlatEnum {
+    final String val;
+
+    FirstSimpleFlatEnum(this.val,);
+
+    FirstSimpleFlatEnum._(this.val,);
+
+    static LiftRetVal<FirstSimpleFlatEnum> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final val_lifted = FfiConverterString.read(Uint8List.view(buf.buffer, new_offset));
+        final val = val_lifted.value;
+        new_offset += val_lifted.bytesRead;
+        return LiftRetVal(FirstSimpleFlatEnum._(
+            val,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterString.allocationSize(val) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterString.write(val, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class SecondSimpleFlatEnum extends SimpleFlatEnum {
+    final int num;
+
+    SecondSimpleFlatEnum(this.num,);
+
+    SecondSimpleFlatEnum._(this.num,);
+
+    static LiftRetVal<SecondSimpleFlatEnum> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final num_lifted = FfiConverterUInt16.read(Uint8List.view(buf.buffer, new_offset));
+        final num = num_lifted.value;
+        new_offset += num_lifted.bytesRead;
+        return LiftRetVal(SecondSimpleFlatEnum._(
+            num,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterUInt16.allocationSize(num) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 2);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterUInt16.write(num, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}
+
+abstract class SimpleFlatMacroEnum {
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterSimpleFlatMacroEnum {
+    static SimpleFlatMacroEnum lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterSimpleFlatMacroEnum.read(data).value);
+    }
+
+    static LiftRetVal<SimpleFlatMacroEnum> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4);
+        switch(index) {
+            case 1:
+                return FirstSimpleFlatMacroEnum.read(subview).copyWithOffset(4);
+            case 2:
+                return SecondSimpleFlatMacroEnum.read(subview).copyWithOffset(4);
+            default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( SimpleFlatMacroEnum value) {
+        return value.lower();
+    }
+
+    static int allocationSize(SimpleFlatMacroEnum value) {
+        return value.allocationSize();
+    }
+
+    static int write( SimpleFlatMacroEnum value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class FirstSimpleFlatMacroEnum extends SimpleFlatMacroEnum {
+    final String val;
+
+    FirstSimpleFlatMacroEnum(this.val,);
+
+    FirstSimpleFlatMacroEnum._(this.val,);
+
+    static LiftRetVal<FirstSimpleFlatMacroEnum> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final val_lifted = FfiConverterString.read(Uint8List.view(buf.buffer, new_offset));
+        final val = val_lifted.value;
+        new_offset += val_lifted.bytesRead;
+        return LiftRetVal(FirstSimpleFlatMacroEnum._(
+            val,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterString.allocationSize(val) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterString.write(val, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class SecondSimpleFlatMacroEnum extends SimpleFlatMacroEnum {
+    final int num;
+
+    SecondSimpleFlatMacroEnum(this.num,);
+
+    SecondSimpleFlatMacroEnum._(this.num,);
+
+    static LiftRetVal<SecondSimpleFlatMacroEnum> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final num_lifted = FfiConverterUInt16.read(Uint8List.view(buf.buffer, new_offset));
+        final num = num_lifted.value;
+        new_offset += num_lifted.bytesRead;
+        return LiftRetVal(SecondSimpleFlatMacroEnum._(
+            num,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterUInt16.allocationSize(num) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 2);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterUInt16.write(num, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}
+
+abstract class CoverallsInterface {
+    void addPatch(Patch patch,);
+    void addRepair(Repair repair,);
+    Coveralls cloneMe();
+    void falliblePanic(String message,);
+    Map<String, int> getDict(String key,int value,);
+    Map<String, int> getDict2(String key,int value,);
+    Map<int, int> getDict3(int key,int value,);
+    String getName();
+    Coveralls? getOther();
+    List<Repair> getRepairs();
+    String getStatus(String status,);
+    bool maybeThrow(bool shouldThrow,);
+    bool maybeThrowComplex(int input,);
+    bool maybeThrowInto(bool shouldThrow,);
+    void panic(String message,);
+    Uint8List reverse(Uint8List value,);
+    int strongCount();
+    void takeOther(Coveralls? other,);
+    void takeOtherFallible();
+    void takeOtherPanic(String message,);
+}
+
+final _CoverallsFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _CoverallsFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_coverall_fn_free_coveralls");
+
+class Coveralls implements CoverallsInterface, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    Coveralls._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_CoverallsFree, _ptr);
+        _CoverallsFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    Coveralls.fallibleNew(String name,bool shouldFail,) : _ptr = rustCall((uniffiStatus) =>
+        _UniffiLib.instance.uniffi_coverall_fn_constructor_coveralls_fallible_new(
+            FfiConverterString.lower(name),FfiConverterBool.lower(shouldFail), uniffiStatus
+        ),
+        CoverallExceptionErrorHandler()
+    ) {
+        _attachFinalizer();
+    }Coveralls(String name,) : _ptr = rustCall((uniffiStatus) =>
+        _UniffiLib.instance.uniffi_coverall_fn_constructor_coveralls_new(
+            FfiConverterString.lower(name), uniffiStatus
+        ),
+        NullRustCallStatusErrorHandler()
+    ) {
+        _attachFinalizer();
+    }Coveralls.panickingNew(String message,) : _ptr = rustCall((uniffiStatus) =>
+        _UniffiLib.instance.uniffi_coverall_fn_constructor_coveralls_panicking_new(
+            FfiConverterString.lower(message), uniffiStatus
+        ),
+        NullRustCallStatusErrorHandler()
+    ) {
+        _attachFinalizer();
+    }
+
+    factory Coveralls.lift(Pointer<Void> ptr) {
+        return Coveralls._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("Coveralls has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_coverall_fn_clone_coveralls(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _CoverallsFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_coverall_fn_free_coveralls(_ptr, status));
+    }
+
+    @override
+    void addPatch(Patch patch,) {
+        rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_add_patch(uniffiClonePointer(),
+        FfiConverterPatch.lower(patch), uniffiStatus), NullRustCallStatusErrorHandler());
+    }@override
+    void addRepair(Repair repair,) {
+        rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_add_repair(uniffiClonePointer(),
+        FfiConverterRepair.lower(repair), uniffiStatus), NullRustCallStatusErrorHandler());
+    }@override
+    Coveralls cloneMe() {
+        return FfiConverterCoveralls.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_clone_me(uniffiClonePointer(),
+         uniffiStatus), NullRustCallStatusErrorHandler()));
+    }@override
+    void falliblePanic(String message,) {
+        rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_fallible_panic(uniffiClonePointer(),
+        FfiConverterString.lower(message), uniffiStatus), CoverallExceptionErrorHandler());
+    }@override
+    Map<String, int> getDict(String key,int value,) {
+        return FfiConverterMapStringUInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_get_dict(uniffiClonePointer(),
+        FfiConverterString.lower(key),FfiConverterUInt64.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+    }@override
+    Map<String, int> getDict2(String key,int value,) {
+        return FfiConverterMapStringUInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_get_dict2(uniffiClonePointer(),
+        FfiConverterString.lower(key),FfiConverterUInt64.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+    }@override
+    Map<int, int> getDict3(int key,int value,) {
+        return FfiConverterMapUInt32UInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_get_dict3(uniffiClonePointer(),
+        key,FfiConverterUInt64.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+    }@override
+    String getName() {
+        return FfiConverterString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_get_name(uniffiClonePointer(),
+         uniffiStatus), NullRustCallStatusErrorHandler()));
+    }@override
+    Coveralls? getOther() {
+        return FfiConverterOptionalCoveralls.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_get_other(uniffiClonePointer(),
+         uniffiStatus), NullRustCallStatusErrorHandler()));
+    }@override
+    List<Repair> getRepairs() {
+        return FfiConverterSequenceRepair.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_get_repairs(uniffiClonePointer(),
+         uniffiStatus), NullRustCallStatusErrorHandler()));
+    }@override
+    String getStatus(String status,) {
+        return FfiConverterString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_get_status(uniffiClonePointer(),
+        FfiConverterString.lower(status), uniffiStatus), NullRustCallStatusErrorHandler()));
+    }@override
+    bool maybeThrow(bool shouldThrow,) {
+        return FfiConverterBool.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_maybe_throw(uniffiClonePointer(),
+        FfiConverterBool.lower(shouldThrow), uniffiStatus), CoverallExceptionErrorHandler()));
+    }@override
+    bool maybeThrowComplex(int input,) {
+        return FfiConverterBool.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_maybe_throw_complex(uniffiClonePointer(),
+        input, uniffiStatus), ComplexExceptionErrorHandler()));
+    }@override
+    bool maybeThrowInto(bool shouldThrow,) {
+        return FfiConverterBool.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_maybe_throw_into(uniffiClonePointer(),
+        FfiConverterBool.lower(shouldThrow), uniffiStatus), CoverallExceptionErrorHandler()));
+    }@override
+    void panic(String message,) {
+        rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_panic(uniffiClonePointer(),
+        FfiConverterString.lower(message), uniffiStatus), NullRustCallStatusErrorHandler());
+    }@override
+    Uint8List reverse(Uint8List value,) {
+        return FfiConverterBytes.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_reverse(uniffiClonePointer(),
+        FfiConverterBytes.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+    }@override
+    int strongCount() {
+        return FfiConverterUInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_strong_count(uniffiClonePointer(),
+         uniffiStatus), NullRustCallStatusErrorHandler()));
+    }@override
+    void takeOther(Coveralls? other,) {
+        rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_take_other(uniffiClonePointer(),
+        FfiConverterOptionalCoveralls.lower(other), uniffiStatus), NullRustCallStatusErrorHandler());
+    }@override
+    void takeOtherFallible() {
+        rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_take_other_fallible(uniffiClonePointer(),
+         uniffiStatus), CoverallExceptionErrorHandler());
+    }@override
+    void takeOtherPanic(String message,) {
+        rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_coveralls_take_other_panic(uniffiClonePointer(),
+        FfiConverterString.lower(message), uniffiStatus), NullRustCallStatusErrorHandler());
+    }
+}
+
+class FfiConverterCoveralls {
+    static Coveralls lift( Pointer<Void> value) {
+        return Coveralls.lift(value);
+    }
+
+    static Pointer<Void> lower( Coveralls value) {
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<Coveralls> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([Coveralls? value]) {
+        return 8;
+    }
+
+    static int write( Coveralls value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+abstract class PatchInterface {
+    Color getColor();
+}
+
+final _PatchFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _PatchFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_coverall_fn_free_patch");
+
+class Patch implements PatchInterface, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    Patch._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_PatchFree, _ptr);
+        _PatchFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    Patch(Color color,) : _ptr = rustCall((uniffiStatus) =>
+        _UniffiLib.instance.uniffi_coverall_fn_constructor_patch_new(
+            FfiConverterColor.lower(color), uniffiStatus
+        ),
+        NullRustCallStatusErrorHandler()
+    ) {
+        _attachFinalizer();
+    }
+
+    factory Patch.lift(Pointer<Void> ptr) {
+        return Patch._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("Patch has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_coverall_fn_clone_patch(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _PatchFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_coverall_fn_free_patch(_ptr, status));
+    }
+
+    @override
+    Color getColor() {
+        return FfiConverterColor.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_method_patch_get_color(uniffiClonePointer(),
+         uniffiStatus), NullRustCallStatusErrorHandler()));
+    }
+}
+
+class FfiConverterPatch {
+    static Patch lift( Pointer<Void> value) {
+        return Patch.lift(value);
+    }
+
+    static Pointer<Void> lower( Patch value) {
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<Patch> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([Patch? value]) {
+        return 8;
+    }
+
+    static int write( Patch value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+class UniffiInternalError implements Exception {
+    static const int bufferOverflow = 0;
+    static const int incompleteData = 1;
+    static const int unexpectedOptionalTag = 2;
+    static const int unexpectedEnumCase = 3;
+    static const int unexpectedNullPointer = 4;
+    static const int unexpectedRustCallStatusCode = 5;
+    static const int unexpectedRustCallError = 6;
+    static const int unexpectedStaleHandle = 7;
+    static const int rustPanic = 8;
+
+    final int errorCode;
+    final String? panicMessage;
+
+    const UniffiInternalError(this.errorCode, this.panicMessage);
+
+    static UniffiInternalError panicked(String message) {
+    return UniffiInternalError(rustPanic, message);
+    }
+
+    @override
+    String toString() {
+    switch (errorCode) {
+        case bufferOverflow:
+        return "UniFfi::BufferOverflow";
+        case incompleteData:
+        return "UniFfi::IncompleteData";
+        case unexpectedOptionalTag:
+        return "UniFfi::UnexpectedOptionalTag";
+        case unexpectedEnumCase:
+        return "UniFfi::UnexpectedEnumCase";
+        case unexpectedNullPointer:
+        return "UniFfi::UnexpectedNullPointer";
+        case unexpectedRustCallStatusCode:
+        return "UniFfi::UnexpectedRustCallStatusCode";
+        case unexpectedRustCallError:
+        return "UniFfi::UnexpectedRustCallError";
+        case unexpectedStaleHandle:
+        return "UniFfi::UnexpectedStaleHandle";
+        case rustPanic:
+        return "UniFfi::rustPanic: \$\$panicMessage";
+        default:
+        return "UniFfi::UnknownError: \$\$errorCode";
+    }
+    }
+}
+
+const int CALL_SUCCESS = 0;
+const int CALL_ERROR = 1;
+const int CALL_UNEXPECTED_ERROR = 2;
+
+final class RustCallStatus extends Struct {
+    @Int8()
+    external int code;
+
+    external RustBuffer errorBuf;
+
+}
+
+void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
+
+    if (status.ref.code == CALL_SUCCESS) {
+    return;
+    } else if (status.ref.code == CALL_ERROR) {
+    throw errorHandler.lift(status.ref.errorBuf);
+    } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+    if (status.ref.errorBuf.len > 0) {
+        throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
+    } else {
+        throw UniffiInternalError.panicked("Rust panic");
+    }
+    } else {
+    throw UniffiInternalError.panicked("Unexpected RustCallStatus code: \${status.ref.code}");
+    }
+}
+
+#[doc=" Allocates the native memory the bindings pass to Rust for the duration of a call."]
+const Allocator _uniffiAllocator = calloc;
+
+T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+    final status = _uniffiAllocator<RustCallStatus>();
+    try {
+    final result = callback(status);
+    checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+    return result;
+    } finally {
+    _uniffiAllocator.free(status);
+    }
+}
+
+class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+    errorBuf.free();
+    return UniffiInternalError.panicked("Unexpected CALL_ERROR");
+    }
+}
+
+abstract class UniffiRustCallStatusErrorHandler {
+    Exception lift(RustBuffer errorBuf);
+}
+
+final class RustBuffer extends Struct {
+    @Uint64()
+    external int capacity;
+
+    @Uint64()
+    external int len;
+
+    external Pointer<Uint8> data;
+
+    static RustBuffer alloc(int size) {
+        return rustCall((status) => _UniffiLib.instance.ffi_coverall_rustbuffer_alloc(size, status));
+    }
+
+    static RustBuffer fromBytes(ForeignBytes bytes) {
+        return rustCall((status) => _UniffiLib.instance.ffi_coverall_rustbuffer_from_bytes(bytes, status));
+    }
+
+    void free() {
+        rustCall((status) => _UniffiLib.instance.ffi_coverall_rustbuffer_free(this, status));
+    }
+
+    RustBuffer reserve(int additionalCapacity) {
+    return rustCall((status) => _UniffiLib.instance.ffi_coverall_rustbuffer_reserve(this, additionalCapacity, status));
+    }
+
+    Uint8List asUint8List() {
+    final dataList = data.asTypedList(len);
+    final byteData = ByteData.sublistView(dataList);
+    return Uint8List.view(byteData.buffer);
+    }
+
+    @override
+    String toString() {
+    return "RustBuffer{capacity: \$capacity, len: \$len, data: \$data}";
+    }
+}
+
+RustBuffer toRustBuffer(Uint8List data) {
+    final length = data.length;
+
+    final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length);
+    final pointerList = frameData.asTypedList(length);
+    pointerList.setAll(0, data);
+
+    final bytes = _uniffiAllocator<ForeignBytes>();
+    bytes.ref.len = length;
+    bytes.ref.data = frameData;
+    try {
+        return RustBuffer.fromBytes(bytes.ref);
+    } finally {
+        _uniffiAllocator.free(frameData);
+        _uniffiAllocator.free(bytes);
+    }
+}
+
+T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+    try {
+        return lift(buf.asUint8List());
+    } finally {
+        buf.free();
+    }
+}
+
+final class ForeignBytes extends Struct {
+    @Int32()
+    external int len;
+    external Pointer<Uint8> data;
+
+    void free() {
+    _uniffiAllocator.free(data);
+    }
+}
+
+class LiftRetVal<T> {
+    final T value;
+    final int bytesRead;
+    const LiftRetVal(this.value, this.bytesRead);
+
+    LiftRetVal<T> copyWithOffset(int offset) {
+        return LiftRetVal(value, bytesRead + offset);
+    }
+}
+
+abstract class FfiConverter<D, F> {
+    const FfiConverter();
+
+    D lift(F value);
+    F lower(D value);
+    D read(ByteData buffer, int offset);
+    void write(D value, ByteData buffer, int offset);
+    int size(D value);
+}
+
+mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+    @override
+    T lift(T value) => value;
+
+    @override
+    T lower(T value) => value;
+}
+
+class FfiConverterBool {
+
+    static bool lift( int value) {
+        return value == 1;
+    }
+
+    static int lower( bool value) {
+        return value ? 1 :0;
+    }
+
+    static LiftRetVal<bool> read( Uint8List buf) {
+        return LiftRetVal(FfiConverterBool.lift(buf.first), 1);
+    }
+
+    static RustBuffer lowerIntoRustBuffer( bool value) {
+        return toRustBuffer(Uint8List.fromList([FfiConverterBool.lower(value)]));
+    }
+
+    static int allocationSize([bool value = false]) {
+        return 1;
+    }
+
+    static int write( bool value, Uint8List buf) {
+        buf.setAll(0, [value ? 1 : 0]);
+        return allocationSize();
+    }
+}class FfiConverterBytes {
+    static Uint8List lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterBytes.read(data).value);
+    }
+
+    static RustBuffer lower( Uint8List value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static LiftRetVal<Uint8List> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+
+        return LiftRetVal(buf.sublist(4, end), end);
+    }
+
+    static int allocationSize([Uint8List? value]) {
+        return (value?.length ?? 0) + 4;
+    }
+
+    static int write( Uint8List value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        buf.setAll(4, value);
+        return value.length + 4;
+    }
+}class FfiConverterDouble32 {
+
+    static double lift(double value) => value;
+
+    static LiftRetVal<double> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat32(0), 4);
+    }
+
+    static double lower(double value) => value;
+
+    static int allocationSize([double value = 0]) {
+        return 4;
+    }
+
+    static int write(double value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setFloat32(0, value);
+        return FfiConverterDouble32.allocationSize();
+    }
+
+}class FfiConverterDouble64 {
+
+    static double lift(double value) => value;
+
+    static LiftRetVal<double> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat64(0), 8);
+    }
+
+    static double lower(double value) => value;
+
+    static int allocationSize([double value = 0]) {
+        return 8;
+    }
+
+    static int write(double value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setFloat64(0, value);
+        return FfiConverterDouble64.allocationSize();
+    }
+
+}class FfiConverterInt16 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt16(0), 2);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 2;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt16(0, value);
+        return FfiConverterInt16.allocationSize();
+    }
+
+}class FfiConverterInt64 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt64(0), 8);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 8;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, value);
+        return FfiConverterInt64.allocationSize();
+    }
+
+}class FfiConverterInt8 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt8(0), 1);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 1;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt8(0, value);
+        return FfiConverterInt8.allocationSize();
+    }
+
+}class FfiConverterMapStringUInt64 {
+
+    static Map<String, int> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMapStringUInt64.read(data).value);
+    }
+
+    static LiftRetVal<Map<String, int>> read( Uint8List buf) {
+        final Map<String, int> res = <String, int>{};
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final key = FfiConverterString.read(Uint8List.view(buf.buffer, offset));
+            offset += key.bytesRead;
+            final value = FfiConverterUInt64.read(Uint8List.view(buf.buffer, offset));
+            offset += value.bytesRead;
+            res[key.value] = value.value;
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( Map<String, int> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (final entry in value.entries) {
+            offset += FfiConverterString.write(entry.key, Uint8List.view(buf.buffer, offset));
+            offset += FfiConverterUInt64.write(entry.value, Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Map<String, int> value) {
+        return value.entries
+            .map((e) => FfiConverterString.allocationSize(e.key) + FfiConverterUInt64.allocationSize(e.value))
+            .fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( Map<String, int> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterMapUInt32UInt64 {
+
+    static Map<int, int> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMapUInt32UInt64.read(data).value);
+    }
+
+    static LiftRetVal<Map<int, int>> read( Uint8List buf) {
+        final Map<int, int> res = <int, int>{};
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final key = FfiConverterUInt32.read(Uint8List.view(buf.buffer, offset));
+            offset += key.bytesRead;
+            final value = FfiConverterUInt64.read(Uint8List.view(buf.buffer, offset));
+            offset += value.bytesRead;
+            res[key.value] = value.value;
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( Map<int, int> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (final entry in value.entries) {
+            offset += FfiConverterUInt32.write(entry.key, Uint8List.view(buf.buffer, offset));
+            offset += FfiConverterUInt64.write(entry.value, Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Map<int, int> value) {
+        return value.entries
+            .map((e) => FfiConverterUInt32.allocationSize(e.key) + FfiConverterUInt64.allocationSize(e.value))
+            .fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( Map<int, int> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterOptionalBool {
+
+    static bool? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalBool.read(data).value);
+    }
+
+    static LiftRetVal<bool?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterBool.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([bool? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterBool.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( bool? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalBool.allocationSize(value));
+        FfiConverterOptionalBool.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( bool? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterBool.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalBytes {
+
+    static Uint8List? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalBytes.read(data).value);
+    }
+
+    static LiftRetVal<Uint8List?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterBytes.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([Uint8List? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterBytes.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( Uint8List? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalBytes.allocationSize(value));
+        FfiConverterOptionalBytes.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( Uint8List? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterBytes.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalCoveralls {
+
+    static Coveralls? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalCoveralls.read(data).value);
+    }
+
+    static LiftRetVal<Coveralls?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterCoveralls.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([Coveralls? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterCoveralls.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( Coveralls? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalCoveralls.allocationSize(value));
+        FfiConverterOptionalCoveralls.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( Coveralls? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterCoveralls.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalDouble32 {
+
+    static double? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalDouble32.read(data).value);
+    }
+
+    static LiftRetVal<double?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterDouble32.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([double? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterDouble32.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( double? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalDouble32.allocationSize(value));
+        FfiConverterOptionalDouble32.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( double? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterDouble32.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalDouble64 {
+
+    static double? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalDouble64.read(data).value);
+    }
+
+    static LiftRetVal<double?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterDouble64.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([double? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterDouble64.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( double? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalDouble64.allocationSize(value));
+        FfiConverterOptionalDouble64.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( double? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterDouble64.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalInt64 {
+
+    static int? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalInt64.read(data).value);
+    }
+
+    static LiftRetVal<int?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterInt64.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([int? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterInt64.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( int? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalInt64.allocationSize(value));
+        FfiConverterOptionalInt64.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( int? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterInt64.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalInt8 {
+
+    static int? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalInt8.read(data).value);
+    }
+
+    static LiftRetVal<int?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterInt8.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([int? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterInt8.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( int? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalInt8.allocationSize(value));
+        FfiConverterOptionalInt8.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( int? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterInt8.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalString {
+
+    static String? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalString.read(data).value);
+    }
+
+    static LiftRetVal<String?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterString.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([String? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterString.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( String? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalString.allocationSize(value));
+        FfiConverterOptionalString.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( String? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterString.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalUInt16 {
+
+    static int? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalUInt16.read(data).value);
+    }
+
+    static LiftRetVal<int?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterUInt16.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([int? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterUInt16.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( int? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalUInt16.allocationSize(value));
+        FfiConverterOptionalUInt16.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( int? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterUInt16.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalUInt64 {
+
+    static int? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalUInt64.read(data).value);
+    }
+
+    static LiftRetVal<int?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterUInt64.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([int? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterUInt64.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( int? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalUInt64.allocationSize(value));
+        FfiConverterOptionalUInt64.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( int? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterUInt64.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalUInt8 {
+
+    static int? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalUInt8.read(data).value);
+    }
+
+    static LiftRetVal<int?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterUInt8.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([int? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterUInt8.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( int? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalUInt8.allocationSize(value));
+        FfiConverterOptionalUInt8.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( int? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterUInt8.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterSequenceRepair {
+
+    static List<Repair> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceRepair.read(data).value);
+    }
+
+    static LiftRetVal<List<Repair>> read( Uint8List buf) {
+        List<Repair> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterRepair.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<Repair> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterRepair.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<Repair> value) {
+        return value.map((l) => FfiConverterRepair.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<Repair> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterString {
+    static String lift( RustBuffer buf) {
+
+        return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
+    }
+
+    static RustBuffer lower( String value) {
+        return toRustBuffer(Utf8Encoder().convert(value));
+    }
+
+    static LiftRetVal<String> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+        return LiftRetVal(utf8.decoder.convert(buf, 4, end), end);
+    }
+
+    static int allocationSize([String value = ""]) {
+
+        return utf8.encoder.convert(value).length + 4;
+    }
+
+    static int write( String value, Uint8List buf) {
+
+        final list = utf8.encoder.convert(value);
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length);
+        buf.setAll(4, list);
+        return list.length + 4;
+    }
+}class FfiConverterTimestamp {
+    static DateTime lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterTimestamp.read(data).value);
+    }
+
+    static RustBuffer lower( DateTime value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static LiftRetVal<DateTime> read( Uint8List buf) {
+        final bytes = buf.buffer.asByteData(buf.offsetInBytes, 12);
+        final seconds = bytes.getInt64(0);
+        final nanos = bytes.getUint32(8);
+        final micros = seconds.abs() * 1000000 + nanos ~/ 1000;
+        return LiftRetVal(DateTime.fromMicrosecondsSinceEpoch(seconds < 0 ? -micros : micros, isUtc: true), 12);
+    }
+
+    static int allocationSize([DateTime? value]) {
+        return 12;
+    }
+
+    static int write( DateTime value, Uint8List buf) {
+        final bytes = buf.buffer.asByteData(buf.offsetInBytes, 12);
+        final micros = value.microsecondsSinceEpoch;
+        final sign = micros < 0 ? -1 : 1;
+        bytes.setInt64(0, sign * (micros.abs() ~/ 1000000));
+        bytes.setUint32(8, (micros.abs() % 1000000) * 1000);
+        return 12;
+    }
+}class FfiConverterUInt16 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint16(0), 2);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 2;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint16(0, value);
+        return FfiConverterUInt16.allocationSize();
+    }
+
+}class FfiConverterUInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, value);
+        return FfiConverterUInt32.allocationSize();
+    }
+
+}class FfiConverterUInt64 {
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0), 8);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 8;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, value);
+        return FfiConverterUInt64.allocationSize();
+    }
+}class FfiConverterUInt8 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint8(0), 1);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 1;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint8(0, value);
+        return FfiConverterUInt8.allocationSize();
+    }
+
+}
+
+const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
+
+typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+Future<T> uniffiRustCallAsync<T, F>(
+    Pointer<Void> Function() rustFutureFunc,
+    void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc,
+    F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc,
+    void Function(Pointer<Void>) freeFunc,
+    T Function(F) liftFunc, [
+    UniffiRustCallStatusErrorHandler? errorHandler,
+]) async {
+    final rustFuture = rustFutureFunc();
+    final completer = Completer<int>();
+
+    late final NativeCallable<UniffiRustFutureContinuationCallback> callback;
+
+    void poll() {
+        pollFunc(
+            rustFuture,
+            callback.nativeFunction,
+            Pointer<Void>.fromAddress(0),
+        );
+    }
+    void onResponse(int _idx, int pollResult) {
+        if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+            completer.complete(pollResult);
+        } else {
+            poll();
+        }
+    }
+    callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener(onResponse);
+
+    try {
+        poll();
+        await completer.future;
+        callback.close();
+
+        final status = _uniffiAllocator<RustCallStatus>();
+        try {
+            final result = completeFunc(rustFuture, status);
+            checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+            return liftFunc(result);
+        } finally {
+            _uniffiAllocator.free(status);
+        }
+    } finally {
+        freeFunc(rustFuture);
+    }
+}
+
+class UniffiHandleMap<T> {
+    final Map<int, T> _map = {};
+    int _counter = 0;
+
+    int insert(T obj) {
+    final handle = _counter++;
+    _map[handle] = obj;
+    return handle;
+    }
+
+    T get(int handle) {
+    final obj = _map[handle];
+    if (obj == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    return obj;
+    }
+
+    void remove(int handle) {
+    if (_map.remove(handle) == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    }
+}
+
+SimpleDict createNoneDict() {
+    return FfiConverterSimpleDict.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_func_create_none_dict( uniffiStatus), NullRustCallStatusErrorHandler()));
+}SimpleDict createSomeDict() {
+    return FfiConverterSimpleDict.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_func_create_some_dict( uniffiStatus), NullRustCallStatusErrorHandler()));
+}MaybeSimpleDict getMaybeSimpleDict(int index,) {
+    return FfiConverterMaybeSimpleDict.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_func_get_maybe_simple_dict(index, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int getNumAlive() {
+    return FfiConverterUInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_func_get_num_alive( uniffiStatus), NullRustCallStatusErrorHandler()));
+}SimpleFlatMacroEnum getSimpleFlatMacroEnum(int index,) {
+    return FfiConverterSimpleFlatMacroEnum.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_func_get_simple_flat_macro_enum(index, uniffiStatus), NullRustCallStatusErrorHandler()));
+}void throwComplexMacroError() {
+    rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_func_throw_complex_macro_error( uniffiStatus), ComplexMacroExceptionErrorHandler());
+}void throwFlatError() {
+    rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_func_throw_flat_error( uniffiStatus), CoverallFlatExceptionErrorHandler());
+}void throwFlatMacroError() {
+    rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_func_throw_flat_macro_error( uniffiStatus), CoverallFlatMacroExceptionErrorHandler());
+}void throwMacroError() {
+    rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_func_throw_macro_error( uniffiStatus), CoverallMacroExceptionErrorHandler());
+}void throwRichErrorNoVariantData() {
+    rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_coverall_fn_func_throw_rich_error_no_variant_data( uniffiStatus), CoverallRichExceptionNoVariantDataErrorHandler());
+}
+
+class _UniffiLib {
+    _UniffiLib._();
+
+    static final DynamicLibrary _dylib = _open();
+
+    static DynamicLibrary _open() {
+        if (Platform.isAndroid) return DynamicLibrary.open("libcoverall.so");
+        if (Platform.isIOS) return DynamicLibrary.executable();
+        if (Platform.isLinux) return DynamicLibrary.open("libcoverall.so");
+        if (Platform.isMacOS) return DynamicLibrary.open("libcoverall.dylib");
+        if (Platform.isWindows) return DynamicLibrary.open("coverall.dll");
+        throw UnsupportedError("Unsupported platform: \${Platform.operatingSystem}");
+    }
+
+    static final _UniffiLib instance = _UniffiLib._();
+
+    late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_coverall_fn_clone_coveralls = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_clone_coveralls");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_coverall_fn_free_coveralls = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_free_coveralls");late final Pointer<Void> Function(RustBuffer,int,Pointer<RustCallStatus>) uniffi_coverall_fn_constructor_coveralls_fallible_new = _dylib.lookupFunction<
+        Pointer<Void> Function(RustBuffer,Int8,Pointer<RustCallStatus>),
+        Pointer<Void> Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_constructor_coveralls_fallible_new");late final Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>) uniffi_coverall_fn_constructor_coveralls_new = _dylib.lookupFunction<
+        Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>),
+        Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_constructor_coveralls_new");late final Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>) uniffi_coverall_fn_constructor_coveralls_panicking_new = _dylib.lookupFunction<
+        Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>),
+        Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_constructor_coveralls_panicking_new");late final void Function(Pointer<Void>,Pointer<Void>,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_add_patch = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_add_patch");late final void Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_add_repair = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_add_repair");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_clone_me = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_clone_me");late final void Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_fallible_panic = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_fallible_panic");late final RustBuffer Function(Pointer<Void>,RustBuffer,int,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_get_dict = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,RustBuffer,int,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_get_dict");late final RustBuffer Function(Pointer<Void>,RustBuffer,int,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_get_dict2 = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,RustBuffer,int,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_get_dict2");late final RustBuffer Function(Pointer<Void>,int,int,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_get_dict3 = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Uint32,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,int,int,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_get_dict3");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_get_name = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_get_name");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_get_other = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_get_other");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_get_repairs = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_get_repairs");late final RustBuffer Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_get_status = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_get_status");late final int Function(Pointer<Void>,int,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_maybe_throw = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Int8,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,int,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_maybe_throw");late final int Function(Pointer<Void>,int,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_maybe_throw_complex = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Int8,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,int,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_maybe_throw_complex");late final int Function(Pointer<Void>,int,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_maybe_throw_into = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Int8,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,int,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_maybe_throw_into");late final void Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_panic = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_panic");late final RustBuffer Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_reverse = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_reverse");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_strong_count = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_strong_count");late final void Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_take_other = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_take_other");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_take_other_fallible = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_take_other_fallible");late final void Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>) uniffi_coverall_fn_method_coveralls_take_other_panic = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_coveralls_take_other_panic");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_coverall_fn_clone_patch = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_clone_patch");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_coverall_fn_free_patch = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_free_patch");late final Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>) uniffi_coverall_fn_constructor_patch_new = _dylib.lookupFunction<
+        Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>),
+        Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_constructor_patch_new");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_coverall_fn_method_patch_get_color = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_method_patch_get_color");late final RustBuffer Function(Pointer<RustCallStatus>) uniffi_coverall_fn_func_create_none_dict = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_func_create_none_dict");late final RustBuffer Function(Pointer<RustCallStatus>) uniffi_coverall_fn_func_create_some_dict = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_func_create_some_dict");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_coverall_fn_func_get_maybe_simple_dict = _dylib.lookupFunction<
+        RustBuffer Function(Int8,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_func_get_maybe_simple_dict");late final int Function(Pointer<RustCallStatus>) uniffi_coverall_fn_func_get_num_alive = _dylib.lookupFunction<
+        Uint64 Function(Pointer<RustCallStatus>),
+        int Function(Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_func_get_num_alive");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_coverall_fn_func_get_simple_flat_macro_enum = _dylib.lookupFunction<
+        RustBuffer Function(Int8,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_func_get_simple_flat_macro_enum");late final void Function(Pointer<RustCallStatus>) uniffi_coverall_fn_func_throw_complex_macro_error = _dylib.lookupFunction<
+        Void Function(Pointer<RustCallStatus>),
+        void Function(Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_func_throw_complex_macro_error");late final void Function(Pointer<RustCallStatus>) uniffi_coverall_fn_func_throw_flat_error = _dylib.lookupFunction<
+        Void Function(Pointer<RustCallStatus>),
+        void Function(Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_func_throw_flat_error");late final void Function(Pointer<RustCallStatus>) uniffi_coverall_fn_func_throw_flat_macro_error = _dylib.lookupFunction<
+        Void Function(Pointer<RustCallStatus>),
+        void Function(Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_func_throw_flat_macro_error");late final void Function(Pointer<RustCallStatus>) uniffi_coverall_fn_func_throw_macro_error = _dylib.lookupFunction<
+        Void Function(Pointer<RustCallStatus>),
+        void Function(Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_func_throw_macro_error");late final void Function(Pointer<RustCallStatus>) uniffi_coverall_fn_func_throw_rich_error_no_variant_data = _dylib.lookupFunction<
+        Void Function(Pointer<RustCallStatus>),
+        void Function(Pointer<RustCallStatus>)
+    >("uniffi_coverall_fn_func_throw_rich_error_no_variant_data");late final RustBuffer Function(int,Pointer<RustCallStatus>) ffi_coverall_rustbuffer_alloc = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("ffi_coverall_rustbuffer_alloc");late final RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>) ffi_coverall_rustbuffer_from_bytes = _dylib.lookupFunction<
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>),
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>)
+    >("ffi_coverall_rustbuffer_from_bytes");late final void Function(RustBuffer,Pointer<RustCallStatus>) ffi_coverall_rustbuffer_free = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("ffi_coverall_rustbuffer_free");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) ffi_coverall_rustbuffer_reserve = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("ffi_coverall_rustbuffer_reserve");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_coverall_rust_future_poll_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_coverall_rust_future_poll_u8");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_cancel_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_cancel_u8");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_free_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_free_u8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_coverall_rust_future_complete_u8 = _dylib.lookupFunction<
+        Uint8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_coverall_rust_future_complete_u8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_coverall_rust_future_poll_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_coverall_rust_future_poll_i8");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_cancel_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_cancel_i8");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_free_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_free_i8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_coverall_rust_future_complete_i8 = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_coverall_rust_future_complete_i8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_coverall_rust_future_poll_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_coverall_rust_future_poll_u16");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_cancel_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_cancel_u16");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_free_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_free_u16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_coverall_rust_future_complete_u16 = _dylib.lookupFunction<
+        Uint16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_coverall_rust_future_complete_u16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_coverall_rust_future_poll_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_coverall_rust_future_poll_i16");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_cancel_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_cancel_i16");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_free_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_free_i16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_coverall_rust_future_complete_i16 = _dylib.lookupFunction<
+        Int16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_coverall_rust_future_complete_i16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_coverall_rust_future_poll_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_coverall_rust_future_poll_u32");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_cancel_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_cancel_u32");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_free_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_free_u32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_coverall_rust_future_complete_u32 = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_coverall_rust_future_complete_u32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_coverall_rust_future_poll_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_coverall_rust_future_poll_i32");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_cancel_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_cancel_i32");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_free_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_free_i32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_coverall_rust_future_complete_i32 = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_coverall_rust_future_complete_i32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_coverall_rust_future_poll_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_coverall_rust_future_poll_u64");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_cancel_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_cancel_u64");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_free_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_free_u64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_coverall_rust_future_complete_u64 = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_coverall_rust_future_complete_u64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_coverall_rust_future_poll_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_coverall_rust_future_poll_i64");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_cancel_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_cancel_i64");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_free_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_free_i64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_coverall_rust_future_complete_i64 = _dylib.lookupFunction<
+        Int64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_coverall_rust_future_complete_i64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_coverall_rust_future_poll_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_coverall_rust_future_poll_f32");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_cancel_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_cancel_f32");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_free_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_free_f32");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_coverall_rust_future_complete_f32 = _dylib.lookupFunction<
+        Float Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_coverall_rust_future_complete_f32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_coverall_rust_future_poll_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_coverall_rust_future_poll_f64");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_cancel_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_cancel_f64");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_free_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_free_f64");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_coverall_rust_future_complete_f64 = _dylib.lookupFunction<
+        Double Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_coverall_rust_future_complete_f64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_coverall_rust_future_poll_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_coverall_rust_future_poll_pointer");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_cancel_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_cancel_pointer");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_free_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_free_pointer");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_coverall_rust_future_complete_pointer = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_coverall_rust_future_complete_pointer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_coverall_rust_future_poll_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_coverall_rust_future_poll_rust_buffer");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_cancel_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_cancel_rust_buffer");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_free_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_free_rust_buffer");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_coverall_rust_future_complete_rust_buffer = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_coverall_rust_future_complete_rust_buffer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_coverall_rust_future_poll_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_coverall_rust_future_poll_void");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_cancel_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_cancel_void");late final void Function(Pointer<Void>,) ffi_coverall_rust_future_free_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_coverall_rust_future_free_void");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_coverall_rust_future_complete_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_coverall_rust_future_complete_void");late final int Function() uniffi_coverall_checksum_func_create_none_dict = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_func_create_none_dict");late final int Function() uniffi_coverall_checksum_func_create_some_dict = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_func_create_some_dict");late final int Function() uniffi_coverall_checksum_func_get_maybe_simple_dict = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_func_get_maybe_simple_dict");late final int Function() uniffi_coverall_checksum_func_get_num_alive = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_func_get_num_alive");late final int Function() uniffi_coverall_checksum_func_get_simple_flat_macro_enum = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_func_get_simple_flat_macro_enum");late final int Function() uniffi_coverall_checksum_func_throw_complex_macro_error = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_func_throw_complex_macro_error");late final int Function() uniffi_coverall_checksum_func_throw_flat_error = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_func_throw_flat_error");late final int Function() uniffi_coverall_checksum_func_throw_flat_macro_error = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_func_throw_flat_macro_error");late final int Function() uniffi_coverall_checksum_func_throw_macro_error = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_func_throw_macro_error");late final int Function() uniffi_coverall_checksum_func_throw_rich_error_no_variant_data = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_func_throw_rich_error_no_variant_data");late final int Function() uniffi_coverall_checksum_method_coveralls_add_patch = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_add_patch");late final int Function() uniffi_coverall_checksum_method_coveralls_add_repair = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_add_repair");late final int Function() uniffi_coverall_checksum_method_coveralls_clone_me = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_clone_me");late final int Function() uniffi_coverall_checksum_method_coveralls_fallible_panic = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_fallible_panic");late final int Function() uniffi_coverall_checksum_method_coveralls_get_dict = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_get_dict");late final int Function() uniffi_coverall_checksum_method_coveralls_get_dict2 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_get_dict2");late final int Function() uniffi_coverall_checksum_method_coveralls_get_dict3 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_get_dict3");late final int Function() uniffi_coverall_checksum_method_coveralls_get_name = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_get_name");late final int Function() uniffi_coverall_checksum_method_coveralls_get_other = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_get_other");late final int Function() uniffi_coverall_checksum_method_coveralls_get_repairs = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_get_repairs");late final int Function() uniffi_coverall_checksum_method_coveralls_get_status = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_get_status");late final int Function() uniffi_coverall_checksum_method_coveralls_maybe_throw = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_maybe_throw");late final int Function() uniffi_coverall_checksum_method_coveralls_maybe_throw_complex = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_maybe_throw_complex");late final int Function() uniffi_coverall_checksum_method_coveralls_maybe_throw_into = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_maybe_throw_into");late final int Function() uniffi_coverall_checksum_method_coveralls_panic = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_panic");late final int Function() uniffi_coverall_checksum_method_coveralls_reverse = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_reverse");late final int Function() uniffi_coverall_checksum_method_coveralls_strong_count = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_strong_count");late final int Function() uniffi_coverall_checksum_method_coveralls_take_other = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_take_other");late final int Function() uniffi_coverall_checksum_method_coveralls_take_other_fallible = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_take_other_fallible");late final int Function() uniffi_coverall_checksum_method_coveralls_take_other_panic = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_coveralls_take_other_panic");late final int Function() uniffi_coverall_checksum_method_patch_get_color = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_method_patch_get_color");late final int Function() uniffi_coverall_checksum_constructor_coveralls_fallible_new = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_constructor_coveralls_fallible_new");late final int Function() uniffi_coverall_checksum_constructor_coveralls_new = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_constructor_coveralls_new");late final int Function() uniffi_coverall_checksum_constructor_coveralls_panicking_new = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_constructor_coveralls_panicking_new");late final int Function() uniffi_coverall_checksum_constructor_patch_new = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_coverall_checksum_constructor_patch_new");late final int Function() ffi_coverall_uniffi_contract_version = _dylib.lookupFunction<
+        Uint32 Function(),
+        int Function()
+    >("ffi_coverall_uniffi_contract_version");late final Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>) uniffi_dart_finalizer_token_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>),
+        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>)
+    >("uniffi_dart_finalizer_token_new");
+    late final void Function(Pointer<Void>) uniffi_dart_finalizer_token_free = _dylib.lookupFunction<
+        Void Function(Pointer<Void>),
+        void Function(Pointer<Void>)
+    >("uniffi_dart_finalizer_token_free");
+    late final Pointer<NativeFinalizerFunction> uniffi_dart_finalizer_run = _dylib.lookup("uniffi_dart_finalizer_run");
+
+    static void _checkApiVersion() {
+        final bindingsVersion = 26;
+        final scaffoldingVersion = _UniffiLib.instance.ffi_coverall_uniffi_contract_version();
+        if (bindingsVersion != scaffoldingVersion) {
+            throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version \$bindingsVersion, scaffolding version \$scaffoldingVersion");
+        }
+    }
+
+    static void _checkApiChecksums() {
+        if (_UniffiLib.instance.uniffi_coverall_checksum_func_create_none_dict() != 11281) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_func_create_some_dict() != 9181) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_func_get_maybe_simple_dict() != 49200) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_func_get_num_alive() != 24174) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_func_get_simple_flat_macro_enum() != 48050) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_func_throw_complex_macro_error() != 55243) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_func_throw_flat_error() != 46548) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_func_throw_flat_macro_error() != 11328) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_func_throw_macro_error() != 13189) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_func_throw_rich_error_no_variant_data() != 30672) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_add_patch() != 22970) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_add_repair() != 26535) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_clone_me() != 54355) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_fallible_panic() != 20804) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_get_dict() != 1265) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_get_dict2() != 27445) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_get_dict3() != 45505) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_get_name() != 24956) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_get_other() != 54606) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_get_repairs() != 48154) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_get_status() != 57859) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_maybe_throw() != 39723) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_maybe_throw_complex() != 24190) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_maybe_throw_into() != 9325) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_panic() != 22319) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_reverse() != 36238) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_strong_count() != 13549) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_take_other() != 56574) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_take_other_fallible() != 23288) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_coveralls_take_other_panic() != 60651) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_method_patch_get_color() != 62814) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_constructor_coveralls_fallible_new() != 26246) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_constructor_coveralls_new() != 33462) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_constructor_coveralls_panicking_new() != 12893) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_coverall_checksum_constructor_patch_new() != 62029) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }
+    }
+}
+
+void initialize() {
+    _UniffiLib._open();
+}
+
+void ensureInitialized() {
+    _UniffiLib._checkApiVersion();
+    _UniffiLib._checkApiChecksums();
+}
@@ -1,165 +1,190 @@
 import 'dart:convert';
-import "dart:typed_data";
+import 'dart:typed_data';
+
 import 'package:test/test.dart';
 import '../coverall.dart';
 
+// Dart has no unsigned 64-bit integers, so u64::MAX arrives as its two's complement bit pattern
+const u64Max = 0xFFFFFFFFFFFFFFFF;
+
+// Only stubs what the tests use, the other methods throw a NoSuchMethodError
+class FakeCoveralls implements CoverallsInterface {
+  @override
+  String getName() => "fake";
+
+  @override
+  bool maybeThrow(bool shouldThrow) => !shouldThrow;
+
+  @override
+  dynamic noSuchMethod(Invocation invocation) => super.noSuchMethod(invocation);
+}
+
+String describe(CoverallsInterface coveralls) {
+  return "${coveralls.getName()}: ${coveralls.maybeThrow(false)}";
+}
+
+bool almostEquals(double a, double b) => (a - b).abs() < 0.000001;
+
 void main() {
-  final api = Api.load();
-  
-  test('Test some_dict()', () {
-    final d = api.createSomeDict();
+  test('some_dict', () {
+    final d = createSomeDict();
     expect(d.text, "text");
     expect(d.maybeText, "maybe_text");
-    expect(d.someBytes, Uint8List.fromList(Utf8Encoder().convert("some_bytes")));
-    expect(d.maybeSomeBytes, Uint8List.fromList(Utf8Encoder().convert("maybe_some_bytes")));
+    expect(d.someBytes, Uint8List.fromList(utf8.encode("some_bytes")));
+    expect(d.maybeSomeBytes, Uint8List.fromList(utf8.encode("maybe_some_bytes")));
     expect(d.aBool, true);
     expect(d.maybeABool, false);
     expect(d.unsigned8, 1);
     expect(d.maybeUnsigned8, 2);
     expect(d.unsigned16, 3);
     expect(d.maybeUnsigned16, 4);
-    expect(d.unsigned64, BigInt.parse("18446744073709551615"));
+    expect(d.unsigned64, u64Max);
     expect(d.maybeUnsigned64, 0);
     expect(d.signed8, 8);
     expect(d.maybeSigned8, 0);
     expect(d.signed64, 9223372036854775807);
     expect(d.maybeSigned64, 0);
-
-    bool almostEquals(double a, double b) => (a - b).abs() < 0.000001;
-    
     expect(almostEquals(d.float32, 1.2345), true);
     expect(almostEquals(d.maybeFloat32!, 22.0 / 7.0), true);
-    expect(almostEquals(d.float64, 0.0), true);
-    expect(almostEquals(d.maybeFloat64!, 1.0), true);
+    expect(d.float64, 0.0);
+    expect(d.maybeFloat64, 1.0);
 
-    d.coveralls?.free();
+    expect(getNumAlive(), 1);
+    expect(d.coveralls!.getName(), "some_dict");
+    d.coveralls!.dispose();
+    expect(getNumAlive(), 0);
   });
 
-  test('Test createNoneDict()', () {
-    final d = api.createNoneDict();
+  test('none_dict', () {
+    final d = createNoneDict();
     expect(d.text, "text");
     expect(d.maybeText, null);
-    expect(d.someBytes, Uint8List.fromList(utf8.encode("some_bytes")));
     expect(d.maybeSomeBytes, null);
-    expect(d.aBool, true);
     expect(d.maybeABool, null);
-    expect(d.unsigned8, 1);
     expect(d.maybeUnsigned8, null);
-    expect(d.unsigned16, 3);
     expect(d.maybeUnsigned16, null);
-    expect(d.unsigned64, BigInt.parse("18446744073709551615"));
+    expect(d.unsigned64, u64Max);
     expect(d.maybeUnsigned64, null);
-    expect(d.signed8, 8);
     expect(d.maybeSigned8, null);
-    expect(d.signed64, 9223372036854775807);
     expect(d.maybeSigned64, null);
-
-    bool almostEquals(double a, double b) => (a - b).abs() < 0.000001;
-    
-    expect(true, true);
     expect(d.maybeFloat32, null);
-    expect(almostEquals(d.float64, 0.0), true);
     expect(d.maybeFloat64, null);
+    expect(d.coveralls, null);
+  });
 
-    d.coveralls?.free();
+  test('objects in enums', () {
+    final yeah = getMaybeSimpleDict(0);
+    expect(yeah, isA<YeahMaybeSimpleDict>());
+    expect((yeah as YeahMaybeSimpleDict).d.coveralls, null);
+    expect(getMaybeSimpleDict(1), isA<NahMaybeSimpleDict>());
   });
 
-  test('Test test_arcs()', () {
-    final coveralls = new Coveralls("test_arcs");
-    expect(api.getNumAlive(), 1);
+  test('arcs', () {
+    final coveralls = Coveralls("test_arcs");
+    expect(getNumAlive(), 1);
+    // One reference is held by Dart, and one by the call itself
     expect(coveralls.strongCount(), 2);
     expect(coveralls.getOther(), null);
     coveralls.takeOther(coveralls);
     expect(coveralls.strongCount(), 3);
-    expect(api.getNumAlive(), 1);
-    
-    final other = coveralls.getOther();
+    expect(getNumAlive(), 1);
+
+    final other = coveralls.getOther()!;
     expect(other.getName(), "test_arcs");
-    other.free();
-    
-    expect(coveralls.takeOtherFallible() is CoverallError, true);
-    expect(coveralls.takeOtherPanic("expected panic: with an arc!") is CoverallError, true);
-    
-    expect(coveralls.falliblePanic("Expected panic in a fallible function!") is CoverallError, true);
+    other.dispose();
+
+    expect(() => coveralls.takeOtherFallible(), throwsA(isA<TooManyHolesCoverallException>()));
+    expect(() => coveralls.takeOtherPanic("expected panic: with an arc!"), throwsA(isA<UniffiInternalError>()));
+    expect(() => coveralls.falliblePanic("Expected panic in a fallible function!"), throwsA(isA<UniffiInternalError>()));
     coveralls.takeOther(null);
     expect(coveralls.strongCount(), 2);
+    coveralls.dispose();
+    expect(getNumAlive(), 0);
   });
 
-  test('Test test_return_objects()', () {
-    final coveralls = new Coveralls("test_return_objects");
-    expect(api.getNumAlive(), 1);
-    expect(coveralls.strongCount(), 2);
-    
+  test('return objects', () {
+    final coveralls = Coveralls("test_return_objects");
+    expect(getNumAlive(), 1);
     final c2 = coveralls.cloneMe();
     expect(c2.getName(), coveralls.getName());
-    expect(api.getNumAlive(), 2);
+    expect(getNumAlive(), 2);
     expect(c2.strongCount(), 2);
-  
+
     coveralls.takeOther(c2);
-    expect(api.etNumAlive(), 2);
+    expect(getNumAlive(), 2);
     expect(coveralls.strongCount(), 2);
     expect(c2.strongCount(), 3);
-    
-    expect(api.getNumAlive(), 2);
-    
-    coveralls.free();
-    expect(api.getNumAlive(), 0);
-  });
 
-  test('Test test_simple_errors()', () {
-    final coveralls = new Coveralls("test_simple_errors");
-    expect(coveralls.maybeThrow(true) is CoverallError, true);
-    expect(coveralls.maybeThrowInto(true) is CoverallError, true);
-    expect(coveralls.panic("oops") is CoverallError, true);
-    coveralls.free();
+    // `coveralls` holds the last reference to `c2` on the Rust side
+    c2.dispose();
+    expect(getNumAlive(), 2);
+    coveralls.dispose();
+    expect(getNumAlive(), 0);
   });
 
-  test('Test test_complex_errors()', () {
-    final coveralls = new Coveralls("test_complex_errors");
-    // TODO: refactor test to check specific variants
-    expect(coveralls.maybeThrowComplex(0) is ComplexResult, true);
-    expect(coveralls.maybeThrowComplex(1) is ComplexResult, true);
-    expect(coveralls.maybeThrowComplex(2) is ComplexResult, true);
-    expect(coveralls.maybeThrowComplex(3) is ComplexResult, true);
-    expect(coveralls.maybeThrowComplex(4) is ComplexResult, true);
-    coveralls.free();
+  test('simple errors', () {
+    final coveralls = Coveralls("test_simple_errors");
+    expect(coveralls.maybeThrow(false), true);
+    expect(() => coveralls.maybeThrow(true), throwsA(isA<TooManyHolesCoverallException>()));
+    expect(() => coveralls.maybeThrowInto(true), throwsA(isA<TooManyHolesCoverallException>()));
+    expect(() => coveralls.panic("oops"), throwsA(isA<UniffiInternalError>()));
+    expect(() => Coveralls.fallibleNew("fallible", true), throwsA(isA<TooManyHolesCoverallException>()));
+    Coveralls.fallibleNew("fallible", false).dispose();
+    coveralls.dispose();
+    expect(getNumAlive(), 0);
   });
 
-  test('Test test_interfaces_in_dicts()', () {
-    final coveralls = new Coveralls("test_interfaces_in_dicts");
-    coveralls.addPatch(new Patch(Color.RED));
-    coveralls.addRepair(
-      new Repair(when: DateTime.now(), patch: new Patch(Color.BLUE)),
+  test('complex errors', () {
+    final coveralls = Coveralls("test_complex_errors");
+    expect(coveralls.maybeThrowComplex(0), true);
+    expect(
+      () => coveralls.maybeThrowComplex(1),
+      throwsA(isA<OsExceptionComplexException>()
+          .having((e) => e.code, 'code', 10)
+          .having((e) => e.extendedCode, 'extendedCode', 20)),
     );
-    expect(coveralls.getRepairs().length, 2);
-    coveralls.free();
+    expect(
+      () => coveralls.maybeThrowComplex(2),
+      throwsA(isA<PermissionDeniedComplexException>().having((e) => e.reason, 'reason', "Forbidden")),
+    );
+    expect(() => coveralls.maybeThrowComplex(3), throwsA(isA<UnknownExceptionComplexException>()));
+    expect(() => coveralls.maybeThrowComplex(4), throwsA(isA<UniffiInternalError>()));
+    coveralls.dispose();
   });
 
-  test('Test test_regressions()', () {
-    final coveralls = new Coveralls("test_regressions");
-    expect(coveralls.getStatus("success"), "status: success");
-    coveralls.free();
+  test('interfaces in dicts', () {
+    final coveralls = Coveralls("test_interfaces_in_dicts");
+    coveralls.addPatch(Patch(Color.red));
+    coveralls.addRepair(Repair(when: DateTime.now(), patch: Patch(Color.blue)));
+    final repairs = coveralls.getRepairs();
+    expect(repairs.length, 2);
+    expect(repairs[0].patch.getColor(), Color.red);
+    expect(repairs[1].patch.getColor(), Color.blue);
+    coveralls.dispose();
   });
 
+  test('dicts and bytes', () {
+    final coveralls = Coveralls("test_dicts");
+    expect(coveralls.getDict("answer", 42), {"answer": 42});
+    expect(coveralls.getDict2("answer", 42), {"answer": 42});
+    expect(coveralls.getDict3(31, 42), {31: 42});
+    expect(coveralls.reverse(Uint8List.fromList([1, 2, 3])), [3, 2, 1]);
+    expect(coveralls.getStatus("success"), "status: success");
+    coveralls.dispose();
+  });
 
-  test('Test DictWithDefaults', () {
-    var d = new DictWithDefaults();
+  test('dict with defaults', () {
+    final d = DictWithDefaults();
     expect(d.name, "default-value");
     expect(d.category, null);
     expect(d.integer, 31);
-    d.free();
-
-    d = new DictWithDefaults(name: "this", category: "that", integer: 42);
-    expect(d.name, "this");
-    expect(d.category, "that");
-    expect(d.integer, 42);
-    d.free();
   });
 
-  test('Test test_bytes()', () {
-    final coveralls = new Coveralls("test_bytes");
-    final reversedBytes = coveralls.reverse(utf8.encode("123"));
-    expect(utf8.decode(reversedBytes), "321");
-    coveralls.free();
+  test('objects implement their interface', () {
+    final coveralls = Coveralls("test_interface");
+    expect(coveralls, isA<CoverallsInterface>());
+    expect(describe(coveralls), "test_interface: true");
+    coveralls.dispose();
+    expect(describe(FakeCoveralls()), "fake: true");
   });
 }
@@ -1,6 +1,11 @@
 use anyhow::Result;
 
-// #[test]
-// fn coverall() -> Result<()> {
-//     uniffi_dart::testing::run_test("coverall", "src/api.udl", None)
-// }
+#[test]
+fn coverall() -> Result<()> {
+    uniffi_dart::testing::run_test("coverall", "src/api.udl", None)
+}
+
+#[test]
+fn coverall_golden() -> Result<()> {
+    uniffi_dart::testing::assert_golden("coverall", "src/api.udl", None)
+}
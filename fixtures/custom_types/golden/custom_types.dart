@@ -0,0 +1,868 @@
+library custom_types;
+
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io" show Platform, File, Directory;
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+import "handle.dart";
+
+class CustomTypesDemo {
+    final Url url;final Handle handle;final TimeIntervalMs timeIntervalMs;final TimeIntervalSecDbl timeIntervalSecDbl;final TimeIntervalSecFlt timeIntervalSecFlt;
+
+    CustomTypesDemo({required this.url,required this.handle,required this.timeIntervalMs,required this.timeIntervalSecDbl,required this.timeIntervalSecFlt,});
+}
+
+class FfiConverterCustomTypesDemo {
+    static CustomTypesDemo lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterCustomTypesDemo.read(data).value);
+    }
+
+    static LiftRetVal<CustomTypesDemo> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final url_lifted = FfiConverterUrl.read(Uint8List.view(buf.buffer, new_offset));
+        final url = url_lifted.value;
+        new_offset += url_lifted.bytesRead;final handle_lifted = FfiConverterHandle.read(Uint8List.view(buf.buffer, new_offset));
+        final handle = handle_lifted.value;
+        new_offset += handle_lifted.bytesRead;final timeIntervalMs_lifted = FfiConverterTimeIntervalMs.read(Uint8List.view(buf.buffer, new_offset));
+        final timeIntervalMs = timeIntervalMs_lifted.value;
+        new_offset += timeIntervalMs_lifted.bytesRead;final timeIntervalSecDbl_lifted = FfiConverterTimeIntervalSecDbl.read(Uint8List.view(buf.buffer, new_offset));
+        final timeIntervalSecDbl = timeIntervalSecDbl_lifted.value;
+        new_offset += timeIntervalSecDbl_lifted.bytesRead;final timeIntervalSecFlt_lifted = FfiConverterTimeIntervalSecFlt.read(Uint8List.view(buf.buffer, new_offset));
+        final timeIntervalSecFlt = timeIntervalSecFlt_lifted.value;
+        new_offset += timeIntervalSecFlt_lifted.bytesRead;
+        return LiftRetVal(CustomTypesDemo(
+            url: url,handle: handle,timeIntervalMs: timeIntervalMs,timeIntervalSecDbl: timeIntervalSecDbl,timeIntervalSecFlt: timeIntervalSecFlt,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( CustomTypesDemo value) {
+        final total_length = FfiConverterUrl.allocationSize(value.url) +FfiConverterHandle.allocationSize(value.handle) +FfiConverterTimeIntervalMs.allocationSize(value.timeIntervalMs) +FfiConverterTimeIntervalSecDbl.allocationSize(value.timeIntervalSecDbl) +FfiConverterTimeIntervalSecFlt.allocationSize(value.timeIntervalSecFlt) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( CustomTypesDemo value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterUrl.write(value.url, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterHandle.write(value.handle, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterTimeIntervalMs.write(value.timeIntervalMs, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterTimeIntervalSecDbl.write(value.timeIntervalSecDbl, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterTimeIntervalSecFlt.write(value.timeIntervalSecFlt, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(CustomTypesDemo value) {
+        return FfiConverterUrl.allocationSize(value.url) +FfiConverterHandle.allocationSize(value.handle) +FfiConverterTimeIntervalMs.allocationSize(value.timeIntervalMs) +FfiConverterTimeIntervalSecDbl.allocationSize(value.timeIntervalSecDbl) +FfiConverterTimeIntervalSecFlt.allocationSize(value.timeIntervalSecFlt) + 0;
+    }
+}
+
+class UniffiInternalError implements Exception {
+    static const int bufferOverflow = 0;
+    static const int incompleteData = 1;
+    static const int unexpectedOptionalTag = 2;
+    static const int unexpectedEnumCase = 3;
+    static const int unexpectedNullPointer = 4;
+    static const int unexpectedRustCallStatusCode = 5;
+    static const int unexpectedRustCallError = 6;
+    static const int unexpectedStaleHandle = 7;
+    static const int rustPanic = 8;
+
+    final int errorCode;
+    final String? panicMessage;
+
+    const UniffiInternalError(this.errorCode, this.panicMessage);
+
+    static UniffiInternalError panicked(String message) {
+    return UniffiInternalError(rustPanic, message);
+    }
+
+    @override
+    String toString() {
+    switch (errorCode) {
+        case bufferOverflow:
+        return "UniFfi::BufferOverflow";
+        case incompleteData:
+        return "UniFfi::IncompleteData";
+        case unexpectedOptionalTag:
+        return "UniFfi::UnexpectedOptionalTag";
+        case unexpectedEnumCase:
+        return "UniFfi::UnexpectedEnumCase";
+        case unexpectedNullPointer:
+        return "UniFfi::UnexpectedNullPointer";
+        case unexpectedRustCallStatusCode:
+        return "UniFfi::UnexpectedRustCallStatusCode";
+        case unexpectedRustCallError:
+        return "UniFfi::UnexpectedRustCallError";
+        case unexpectedStaleHandle:
+        return "UniFfi::UnexpectedStaleHandle";
+        case rustPanic:
+        return "UniFfi::rustPanic: \$\$panicMessage";
+        default:
+        return "UniFfi::UnknownError: \$\$errorCode";
+    }
+    }
+}
+
+const int CALL_SUCCESS = 0;
+const int CALL_ERROR = 1;
+const int CALL_UNEXPECTED_ERROR = 2;
+
+final class RustCallStatus extends Struct {
+    @Int8()
+    external int code;
+
+    external RustBuffer errorBuf;
+
+}
+
+void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
+
+    if (status.ref.code == CALL_SUCCESS) {
+    return;
+    } else if (status.ref.code == CALL_ERROR) {
+    throw errorHandler.lift(status.ref.errorBuf);
+    } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+    if (status.ref.errorBuf.len > 0) {
+        throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
+    } else {
+        throw UniffiInternalError.panicked("Rust panic");
+    }
+    } else {
+    throw UniffiInternalError.panicked("Unexpected RustCallStatus code: \${status.ref.code}");
+    }
+}
+
+#[doc=" Allocates the native memory the bindings pass to Rust for the duration of a call."]
+const Allocator _uniffiAllocator = calloc;
+
+T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+    final status = _uniffiAllocator<RustCallStatus>();
+    try {
+    final result = callback(status);
+    checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+    return result;
+    } finally {
+    _uniffiAllocator.free(status);
+    }
+}
+
+class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+    errorBuf.free();
+    return UniffiInternalError.panicked("Unexpected CALL_ERROR");
+    }
+}
+
+abstract class UniffiRustCallStatusErrorHandler {
+    Exception lift(RustBuffer errorBuf);
+}
+
+final class RustBuffer extends Struct {
+    @Uint64()
+    external int capacity;
+
+    @Uint64()
+    external int len;
+
+    external Pointer<Uint8> data;
+
+    static RustBuffer alloc(int size) {
+        return rustCall((status) => _UniffiLib.instance.ffi_custom_types_rustbuffer_alloc(size, status));
+    }
+
+    static RustBuffer fromBytes(ForeignBytes bytes) {
+        return rustCall((status) => _UniffiLib.instance.ffi_custom_types_rustbuffer_from_bytes(bytes, status));
+    }
+
+    void free() {
+        rustCall((status) => _UniffiLib.instance.ffi_custom_types_rustbuffer_free(this, status));
+    }
+
+    RustBuffer reserve(int additionalCapacity) {
+    return rustCall((status) => _UniffiLib.instance.ffi_custom_types_rustbuffer_reserve(this, additionalCapacity, status));
+    }
+
+    Uint8List asUint8List() {
+    final dataList = data.asTypedList(len);
+    final byteData = ByteData.sublistView(dataList);
+    return Uint8List.view(byteData.buffer);
+    }
+
+    @override
+    String toString() {
+    return "RustBuffer{capacity: \$capacity, len: \$len, data: \$data}";
+    }
+}
+
+RustBuffer toRustBuffer(Uint8List data) {
+    final length = data.length;
+
+    final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length);
+    final pointerList = frameData.asTypedList(length);
+    pointerList.setAll(0, data);
+
+    final bytes = _uniffiAllocator<ForeignBytes>();
+    bytes.ref.len = length;
+    bytes.ref.data = frameData;
+    try {
+        return RustBuffer.fromBytes(bytes.ref);
+    } finally {
+        _uniffiAllocator.free(frameData);
+        _uniffiAllocator.free(bytes);
+    }
+}
+
+T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+    try {
+        return lift(buf.asUint8List());
+    } finally {
+        buf.free();
+    }
+}
+
+final class ForeignBytes extends Struct {
+    @Int32()
+    external int len;
+    external Pointer<Uint8> data;
+
+    void free() {
+    _uniffiAllocator.free(data);
+    }
+}
+
+class LiftRetVal<T> {
+    final T value;
+    final int bytesRead;
+    const LiftRetVal(this.value, this.bytesRead);
+
+    LiftRetVal<T> copyWithOffset(int offset) {
+        return LiftRetVal(value, bytesRead + offset);
+    }
+}
+
+abstract class FfiConverter<D, F> {
+    const FfiConverter();
+
+    D lift(F value);
+    F lower(D value);
+    D read(ByteData buffer, int offset);
+    void write(D value, ByteData buffer, int offset);
+    int size(D value);
+}
+
+mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+    @override
+    T lift(T value) => value;
+
+    @override
+    T lower(T value) => value;
+}
+
+class FfiConverterHandle {
+    static Handle lift( int value) {
+        final builtin = FfiConverterInt64.lift(value);
+        return Handle(builtin);
+    }
+
+    static int lower( Handle value) {
+        final builtin = value.value;
+        return FfiConverterInt64.lower(builtin);
+    }
+
+    static LiftRetVal<Handle> read( Uint8List buf) {
+        final builtin = FfiConverterInt64.read(buf);
+        return LiftRetVal(Handle(builtin.value), builtin.bytesRead);
+    }
+
+    static int allocationSize(Handle value) {
+        return FfiConverterInt64.allocationSize(value.value);
+    }
+
+    static int write( Handle value, Uint8List buf) {
+        return FfiConverterInt64.write(value.value, buf);
+    }
+}class FfiConverterOptionalCustomTypesDemo {
+
+    static CustomTypesDemo? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalCustomTypesDemo.read(data).value);
+    }
+
+    static LiftRetVal<CustomTypesDemo?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterCustomTypesDemo.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([CustomTypesDemo? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterCustomTypesDemo.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( CustomTypesDemo? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalCustomTypesDemo.allocationSize(value));
+        FfiConverterOptionalCustomTypesDemo.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( CustomTypesDemo? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterCustomTypesDemo.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterSequenceUrl {
+
+    static List<Url> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceUrl.read(data).value);
+    }
+
+    static LiftRetVal<List<Url>> read( Uint8List buf) {
+        List<Url> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterUrl.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<Url> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterUrl.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<Url> value) {
+        return value.map((l) => FfiConverterUrl.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<Url> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterString {
+    static String lift( RustBuffer buf) {
+
+        return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
+    }
+
+    static RustBuffer lower( String value) {
+        return toRustBuffer(Utf8Encoder().convert(value));
+    }
+
+    static LiftRetVal<String> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+        return LiftRetVal(utf8.decoder.convert(buf, 4, end), end);
+    }
+
+    static int allocationSize([String value = ""]) {
+
+        return utf8.encoder.convert(value).length + 4;
+    }
+
+    static int write( String value, Uint8List buf) {
+
+        final list = utf8.encoder.convert(value);
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length);
+        buf.setAll(4, list);
+        return list.length + 4;
+    }
+}typedef TimeIntervalMs = int;
+
+class FfiConverterTimeIntervalMs {
+    static TimeIntervalMs lift( int value) {
+        final builtin = FfiConverterInt64.lift(value);
+        return builtin;
+    }
+
+    static int lower( TimeIntervalMs value) {
+        final builtin = value;
+        return FfiConverterInt64.lower(builtin);
+    }
+
+    static LiftRetVal<TimeIntervalMs> read( Uint8List buf) {
+        final builtin = FfiConverterInt64.read(buf);
+        return LiftRetVal(builtin.value, builtin.bytesRead);
+    }
+
+    static int allocationSize(TimeIntervalMs value) {
+        return FfiConverterInt64.allocationSize(value);
+    }
+
+    static int write( TimeIntervalMs value, Uint8List buf) {
+        return FfiConverterInt64.write(value, buf);
+    }
+}typedef TimeIntervalSecDbl = double;
+
+class FfiConverterTimeIntervalSecDbl {
+    static TimeIntervalSecDbl lift( double value) {
+        final builtin = FfiConverterDouble64.lift(value);
+        return builtin;
+    }
+
+    static double lower( TimeIntervalSecDbl value) {
+        final builtin = value;
+        return FfiConverterDouble64.lower(builtin);
+    }
+
+    static LiftRetVal<TimeIntervalSecDbl> read( Uint8List buf) {
+        final builtin = FfiConverterDouble64.read(buf);
+        return LiftRetVal(builtin.value, builtin.bytesRead);
+    }
+
+    static int allocationSize(TimeIntervalSecDbl value) {
+        return FfiConverterDouble64.allocationSize(value);
+    }
+
+    static int write( TimeIntervalSecDbl value, Uint8List buf) {
+        return FfiConverterDouble64.write(value, buf);
+    }
+}typedef TimeIntervalSecFlt = double;
+
+class FfiConverterTimeIntervalSecFlt {
+    static TimeIntervalSecFlt lift( double value) {
+        final builtin = FfiConverterDouble32.lift(value);
+        return builtin;
+    }
+
+    static double lower( TimeIntervalSecFlt value) {
+        final builtin = value;
+        return FfiConverterDouble32.lower(builtin);
+    }
+
+    static LiftRetVal<TimeIntervalSecFlt> read( Uint8List buf) {
+        final builtin = FfiConverterDouble32.read(buf);
+        return LiftRetVal(builtin.value, builtin.bytesRead);
+    }
+
+    static int allocationSize(TimeIntervalSecFlt value) {
+        return FfiConverterDouble32.allocationSize(value);
+    }
+
+    static int write( TimeIntervalSecFlt value, Uint8List buf) {
+        return FfiConverterDouble32.write(value, buf);
+    }
+}typedef Url = Uri;
+
+class FfiConverterUrl {
+    static Url lift( RustBuffer value) {
+        final builtin = FfiConverterString.lift(value);
+        return Uri.parse(builtin);
+    }
+
+    static RustBuffer lower( Url value) {
+        final builtin = value.toString();
+        return FfiConverterString.lower(builtin);
+    }
+
+    static LiftRetVal<Url> read( Uint8List buf) {
+        final builtin = FfiConverterString.read(buf);
+        return LiftRetVal(Uri.parse(builtin.value), builtin.bytesRead);
+    }
+
+    static int allocationSize(Url value) {
+        return FfiConverterString.allocationSize(value.toString());
+    }
+
+    static int write( Url value, Uint8List buf) {
+        return FfiConverterString.write(value.toString(), buf);
+    }
+}class FfiConverterDouble32 {
+
+    static double lift(double value) => value;
+
+    static LiftRetVal<double> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat32(0), 4);
+    }
+
+    static double lower(double value) => value;
+
+    static int allocationSize([double value = 0]) {
+        return 4;
+    }
+
+    static int write(double value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setFloat32(0, value);
+        return FfiConverterDouble32.allocationSize();
+    }
+
+}class FfiConverterDouble64 {
+
+    static double lift(double value) => value;
+
+    static LiftRetVal<double> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat64(0), 8);
+    }
+
+    static double lower(double value) => value;
+
+    static int allocationSize([double value = 0]) {
+        return 8;
+    }
+
+    static int write(double value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setFloat64(0, value);
+        return FfiConverterDouble64.allocationSize();
+    }
+
+}class FfiConverterInt64 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt64(0), 8);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 8;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, value);
+        return FfiConverterInt64.allocationSize();
+    }
+
+}
+
+const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
+
+typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+Future<T> uniffiRustCallAsync<T, F>(
+    Pointer<Void> Function() rustFutureFunc,
+    void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc,
+    F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc,
+    void Function(Pointer<Void>) freeFunc,
+    T Function(F) liftFunc, [
+    UniffiRustCallStatusErrorHandler? errorHandler,
+]) async {
+    final rustFuture = rustFutureFunc();
+    final completer = Completer<int>();
+
+    late final NativeCallable<UniffiRustFutureContinuationCallback> callback;
+
+    void poll() {
+        pollFunc(
+            rustFuture,
+            callback.nativeFunction,
+            Pointer<Void>.fromAddress(0),
+        );
+    }
+    void onResponse(int _idx, int pollResult) {
+        if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+            completer.complete(pollResult);
+        } else {
+            poll();
+        }
+    }
+    callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener(onResponse);
+
+    try {
+        poll();
+        await completer.future;
+        callback.close();
+
+        final status = _uniffiAllocator<RustCallStatus>();
+        try {
+            final result = completeFunc(rustFuture, status);
+            checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+            return liftFunc(result);
+        } finally {
+            _uniffiAllocator.free(status);
+        }
+    } finally {
+        freeFunc(rustFuture);
+    }
+}
+
+class UniffiHandleMap<T> {
+    final Map<int, T> _map = {};
+    int _counter = 0;
+
+    int insert(T obj) {
+    final handle = _counter++;
+    _map[handle] = obj;
+    return handle;
+    }
+
+    T get(int handle) {
+    final obj = _map[handle];
+    if (obj == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    return obj;
+    }
+
+    void remove(int handle) {
+    if (_map.remove(handle) == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    }
+}
+
+CustomTypesDemo getCustomTypesDemo(CustomTypesDemo? demo,) {
+    return FfiConverterCustomTypesDemo.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_custom_types_fn_func_get_custom_types_demo(FfiConverterOptionalCustomTypesDemo.lower(demo), uniffiStatus), NullRustCallStatusErrorHandler()));
+}List<Url> getUrls(List<Url> urls,) {
+    return FfiConverterSequenceUrl.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_custom_types_fn_func_get_urls(FfiConverterSequenceUrl.lower(urls), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Handle nextHandle(Handle handle,) {
+    return FfiConverterHandle.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_custom_types_fn_func_next_handle(FfiConverterHandle.lower(handle), uniffiStatus), NullRustCallStatusErrorHandler()));
+}
+
+class _UniffiLib {
+    _UniffiLib._();
+
+    static final DynamicLibrary _dylib = _open();
+
+    static DynamicLibrary _open() {
+        if (Platform.isAndroid) return DynamicLibrary.open("libcustom_types.so");
+        if (Platform.isIOS) return DynamicLibrary.executable();
+        if (Platform.isLinux) return DynamicLibrary.open("libcustom_types.so");
+        if (Platform.isMacOS) return DynamicLibrary.open("libcustom_types.dylib");
+        if (Platform.isWindows) return DynamicLibrary.open("custom_types.dll");
+        throw UnsupportedError("Unsupported platform: \${Platform.operatingSystem}");
+    }
+
+    static final _UniffiLib instance = _UniffiLib._();
+
+    late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_custom_types_fn_func_get_custom_types_demo = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_custom_types_fn_func_get_custom_types_demo");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_custom_types_fn_func_get_urls = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_custom_types_fn_func_get_urls");late final int Function(int,Pointer<RustCallStatus>) uniffi_custom_types_fn_func_next_handle = _dylib.lookupFunction<
+        Int64 Function(Int64,Pointer<RustCallStatus>),
+        int Function(int,Pointer<RustCallStatus>)
+    >("uniffi_custom_types_fn_func_next_handle");late final RustBuffer Function(int,Pointer<RustCallStatus>) ffi_custom_types_rustbuffer_alloc = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("ffi_custom_types_rustbuffer_alloc");late final RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>) ffi_custom_types_rustbuffer_from_bytes = _dylib.lookupFunction<
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>),
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>)
+    >("ffi_custom_types_rustbuffer_from_bytes");late final void Function(RustBuffer,Pointer<RustCallStatus>) ffi_custom_types_rustbuffer_free = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("ffi_custom_types_rustbuffer_free");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) ffi_custom_types_rustbuffer_reserve = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("ffi_custom_types_rustbuffer_reserve");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_custom_types_rust_future_poll_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_custom_types_rust_future_poll_u8");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_cancel_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_cancel_u8");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_free_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_free_u8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_custom_types_rust_future_complete_u8 = _dylib.lookupFunction<
+        Uint8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_custom_types_rust_future_complete_u8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_custom_types_rust_future_poll_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_custom_types_rust_future_poll_i8");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_cancel_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_cancel_i8");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_free_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_free_i8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_custom_types_rust_future_complete_i8 = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_custom_types_rust_future_complete_i8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_custom_types_rust_future_poll_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_custom_types_rust_future_poll_u16");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_cancel_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_cancel_u16");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_free_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_free_u16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_custom_types_rust_future_complete_u16 = _dylib.lookupFunction<
+        Uint16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_custom_types_rust_future_complete_u16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_custom_types_rust_future_poll_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_custom_types_rust_future_poll_i16");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_cancel_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_cancel_i16");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_free_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_free_i16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_custom_types_rust_future_complete_i16 = _dylib.lookupFunction<
+        Int16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_custom_types_rust_future_complete_i16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_custom_types_rust_future_poll_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_custom_types_rust_future_poll_u32");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_cancel_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_cancel_u32");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_free_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_free_u32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_custom_types_rust_future_complete_u32 = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_custom_types_rust_future_complete_u32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_custom_types_rust_future_poll_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_custom_types_rust_future_poll_i32");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_cancel_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_cancel_i32");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_free_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_free_i32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_custom_types_rust_future_complete_i32 = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_custom_types_rust_future_complete_i32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_custom_types_rust_future_poll_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_custom_types_rust_future_poll_u64");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_cancel_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_cancel_u64");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_free_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_free_u64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_custom_types_rust_future_complete_u64 = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_custom_types_rust_future_complete_u64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_custom_types_rust_future_poll_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_custom_types_rust_future_poll_i64");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_cancel_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_cancel_i64");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_free_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_free_i64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_custom_types_rust_future_complete_i64 = _dylib.lookupFunction<
+        Int64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_custom_types_rust_future_complete_i64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_custom_types_rust_future_poll_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_custom_types_rust_future_poll_f32");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_cancel_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_cancel_f32");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_free_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_free_f32");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_custom_types_rust_future_complete_f32 = _dylib.lookupFunction<
+        Float Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_custom_types_rust_future_complete_f32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_custom_types_rust_future_poll_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_custom_types_rust_future_poll_f64");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_cancel_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_cancel_f64");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_free_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_free_f64");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_custom_types_rust_future_complete_f64 = _dylib.lookupFunction<
+        Double Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_custom_types_rust_future_complete_f64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_custom_types_rust_future_poll_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_custom_types_rust_future_poll_pointer");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_cancel_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_cancel_pointer");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_free_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_free_pointer");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_custom_types_rust_future_complete_pointer = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_custom_types_rust_future_complete_pointer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_custom_types_rust_future_poll_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_custom_types_rust_future_poll_rust_buffer");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_cancel_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_cancel_rust_buffer");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_free_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_free_rust_buffer");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_custom_types_rust_future_complete_rust_buffer = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_custom_types_rust_future_complete_rust_buffer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_custom_types_rust_future_poll_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_custom_types_rust_future_poll_void");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_cancel_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_cancel_void");late final void Function(Pointer<Void>,) ffi_custom_types_rust_future_free_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_custom_types_rust_future_free_void");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_custom_types_rust_future_complete_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_custom_types_rust_future_complete_void");late final int Function() uniffi_custom_types_checksum_func_get_custom_types_demo = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_custom_types_checksum_func_get_custom_types_demo");late final int Function() uniffi_custom_types_checksum_func_get_urls = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_custom_types_checksum_func_get_urls");late final int Function() uniffi_custom_types_checksum_func_next_handle = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_custom_types_checksum_func_next_handle");late final int Function() ffi_custom_types_uniffi_contract_version = _dylib.lookupFunction<
+        Uint32 Function(),
+        int Function()
+    >("ffi_custom_types_uniffi_contract_version");
+
+    static void _checkApiVersion() {
+        final bindingsVersion = 26;
+        final scaffoldingVersion = _UniffiLib.instance.ffi_custom_types_uniffi_contract_version();
+        if (bindingsVersion != scaffoldingVersion) {
+            throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version \$bindingsVersion, scaffolding version \$scaffoldingVersion");
+        }
+    }
+
+    static void _checkApiChecksums() {
+        if (_UniffiLib.instance.uniffi_custom_types_checksum_func_get_custom_types_demo() != 19030) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_custom_types_checksum_func_get_urls() != 16331) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_custom_types_checksum_func_next_handle() != 64952) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }
+    }
+}
+
+void initialize() {
+    _UniffiLib._open();
+}
+
+void ensureInitialized() {
+    _UniffiLib._checkApiVersion();
+    _UniffiLib._checkApiChecksums();
+}
@@ -0,0 +1 @@
+extension type const Handle(int value) {}
@@ -1,17 +1,37 @@
 import 'package:test/test.dart';
 import '../custom_types.dart';
+import '../handle.dart';
 
 void main() {
-  final api = Api.load();
-  
-  final demo = api.getCustomTypesDemo(null);
+  test('default demo', () {
+    final demo = getCustomTypesDemo(null);
 
-  assert(demo.url.toString() == 'http://example.com/');
-  
-  assert(demo.handle == 123);
+    expect(demo.url, Uri.parse('http://example.com/'));
+    expect(demo.handle, Handle(123));
+    expect(demo.timeIntervalMs, 456000);
+    expect(demo.timeIntervalSecDbl, 456.0);
+    expect(demo.timeIntervalSecFlt, 777.0);
+  });
 
-  demo.url = Uri.parse('http://new.example.com/');
-  demo.handle = 456;
-  
-  assert(demo == api.getCustomTypesDemo(demo));
+  test('round trip', () {
+    final demo = getCustomTypesDemo(CustomTypesDemo(
+      url: Uri.parse('http://new.example.com/'),
+      handle: Handle(456),
+      timeIntervalMs: 1000,
+      timeIntervalSecDbl: 1.5,
+      timeIntervalSecFlt: 2.5,
+    ));
+
+    expect(demo.url.host, 'new.example.com');
+    expect(demo.handle.value, 456);
+    expect(demo.timeIntervalMs, 1000);
+    expect(demo.timeIntervalSecDbl, 1.5);
+    expect(demo.timeIntervalSecFlt, 2.5);
+  });
+
+  test('custom arguments and return values', () {
+    expect(nextHandle(Handle(1)), Handle(2));
+    expect(getUrls([Uri.parse('https://a.example/'), Uri.parse('https://b.example/x')]),
+        [Uri.parse('https://a.example/'), Uri.parse('https://b.example/x')]);
+  });
 }
@@ -1,6 +1,11 @@
 use anyhow::Result;
 
-// #[test]
-// fn custom_types() -> Result<()> {
-//     uniffi_dart::testing::run_test("custom_types", "src/api.udl", None)
-// }
+#[test]
+fn custom_types() -> Result<()> {
+    uniffi_dart::testing::run_test("custom_types", "src/api.udl", None)
+}
+
+#[test]
+fn custom_types_golden() -> Result<()> {
+    uniffi_dart::testing::assert_golden("custom_types", "src/api.udl", None)
+}
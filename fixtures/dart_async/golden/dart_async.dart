@@ -0,0 +1,908 @@
+library dart_async;
+
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io" show Platform, File, Directory;
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+class MyRecord {
+    final String a;final int b;
+
+    MyRecord({required this.a,required this.b,});
+}
+
+class FfiConverterMyRecord {
+    static MyRecord lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMyRecord.read(data).value);
+    }
+
+    static LiftRetVal<MyRecord> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final a_lifted = FfiConverterString.read(Uint8List.view(buf.buffer, new_offset));
+        final a = a_lifted.value;
+        new_offset += a_lifted.bytesRead;final b_lifted = FfiConverterUInt32.read(Uint8List.view(buf.buffer, new_offset));
+        final b = b_lifted.value;
+        new_offset += b_lifted.bytesRead;
+        return LiftRetVal(MyRecord(
+            a: a,b: b,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( MyRecord value) {
+        final total_length = FfiConverterString.allocationSize(value.a) +FfiConverterUInt32.allocationSize(value.b) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( MyRecord value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterString.write(value.a, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterUInt32.write(value.b, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(MyRecord value) {
+        return FfiConverterString.allocationSize(value.a) +FfiConverterUInt32.allocationSize(value.b) + 0;
+    }
+}
+
+abstract class MyException implements Exception {
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterMyException {
+    static MyException lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterMyException.read(data).value);
+    }
+
+    static LiftRetVal<MyException> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4);
+        switch(index) {
+            case 1:
+                return FooMyException.read(subview).copyWithOffset(4);
+            default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( MyException value) {
+        return value.lower();
+    }
+
+    static int allocationSize(MyException value) {
+        return value.allocationSize();
+    }
+
+    static int write( MyException value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class FooMyException extends MyException {
+
+    FooMyException();
+
+    FooMyException._();
+
+    static LiftRetVal<FooMyException> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        return LiftRetVal(FooMyException._(), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        int new_offset = buf.offsetInBytes + 4;
+
+        return new_offset - buf.offsetInBytes;
+    }
+}
+
+class MyExceptionErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+        return FfiConverterMyException.lift(errorBuf);
+    }
+}
+
+class UniffiInternalError implements Exception {
+    static const int bufferOverflow = 0;
+    static const int incompleteData = 1;
+    static const int unexpectedOptionalTag = 2;
+    static const int unexpectedEnumCase = 3;
+    static const int unexpectedNullPointer = 4;
+    static const int unexpectedRustCallStatusCode = 5;
+    static const int unexpectedRustCallError = 6;
+    static const int unexpectedStaleHandle = 7;
+    static const int rustPanic = 8;
+
+    final int errorCode;
+    final String? panicMessage;
+
+    const UniffiInternalError(this.errorCode, this.panicMessage);
+
+    static UniffiInternalError panicked(String message) {
+    return UniffiInternalError(rustPanic, message);
+    }
+
+    @override
+    String toString() {
+    switch (errorCode) {
+        case bufferOverflow:
+        return "UniFfi::BufferOverflow";
+        case incompleteData:
+        return "UniFfi::IncompleteData";
+        case unexpectedOptionalTag:
+        return "UniFfi::UnexpectedOptionalTag";
+        case unexpectedEnumCase:
+        return "UniFfi::UnexpectedEnumCase";
+        case unexpectedNullPointer:
+        return "UniFfi::UnexpectedNullPointer";
+        case unexpectedRustCallStatusCode:
+        return "UniFfi::UnexpectedRustCallStatusCode";
+        case unexpectedRustCallError:
+        return "UniFfi::UnexpectedRustCallError";
+        case unexpectedStaleHandle:
+        return "UniFfi::UnexpectedStaleHandle";
+        case rustPanic:
+        return "UniFfi::rustPanic: \$\$panicMessage";
+        default:
+        return "UniFfi::UnknownError: \$\$errorCode";
+    }
+    }
+}
+
+const int CALL_SUCCESS = 0;
+const int CALL_ERROR = 1;
+const int CALL_UNEXPECTED_ERROR = 2;
+
+final class RustCallStatus extends Struct {
+    @Int8()
+    external int code;
+
+    external RustBuffer errorBuf;
+
+}
+
+void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
+
+    if (status.ref.code == CALL_SUCCESS) {
+    return;
+    } else if (status.ref.code == CALL_ERROR) {
+    throw errorHandler.lift(status.ref.errorBuf);
+    } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+    if (status.ref.errorBuf.len > 0) {
+        throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
+    } else {
+        throw UniffiInternalError.panicked("Rust panic");
+    }
+    } else {
+    throw UniffiInternalError.panicked("Unexpected RustCallStatus code: \${status.ref.code}");
+    }
+}
+
+#[doc=" Allocates the native memory the bindings pass to Rust for the duration of a call."]
+const Allocator _uniffiAllocator = calloc;
+
+T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+    final status = _uniffiAllocator<RustCallStatus>();
+    try {
+    final result = callback(status);
+    checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+    return result;
+    } finally {
+    _uniffiAllocator.free(status);
+    }
+}
+
+class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+    errorBuf.free();
+    return UniffiInternalError.panicked("Unexpected CALL_ERROR");
+    }
+}
+
+abstract class UniffiRustCallStatusErrorHandler {
+    Exception lift(RustBuffer errorBuf);
+}
+
+final class RustBuffer extends Struct {
+    @Uint64()
+    external int capacity;
+
+    @Uint64()
+    external int len;
+
+    external Pointer<Uint8> data;
+
+    static RustBuffer alloc(int size) {
+        return rustCall((status) => _UniffiLib.instance.ffi_dart_async_rustbuffer_alloc(size, status));
+    }
+
+    static RustBuffer fromBytes(ForeignBytes bytes) {
+        return rustCall((status) => _UniffiLib.instance.ffi_dart_async_rustbuffer_from_bytes(bytes, status));
+    }
+
+    void free() {
+        rustCall((status) => _UniffiLib.instance.ffi_dart_async_rustbuffer_free(this, status));
+    }
+
+    RustBuffer reserve(int additionalCapacity) {
+    return rustCall((status) => _UniffiLib.instance.ffi_dart_async_rustbuffer_reserve(this, additionalCapacity, status));
+    }
+
+    Uint8List asUint8List() {
+    final dataList = data.asTypedList(len);
+    final byteData = ByteData.sublistView(dataList);
+    return Uint8List.view(byteData.buffer);
+    }
+
+    @override
+    String toString() {
+    return "RustBuffer{capacity: \$capacity, len: \$len, data: \$data}";
+    }
+}
+
+RustBuffer toRustBuffer(Uint8List data) {
+    final length = data.length;
+
+    final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length);
+    final pointerList = frameData.asTypedList(length);
+    pointerList.setAll(0, data);
+
+    final bytes = _uniffiAllocator<ForeignBytes>();
+    bytes.ref.len = length;
+    bytes.ref.data = frameData;
+    try {
+        return RustBuffer.fromBytes(bytes.ref);
+    } finally {
+        _uniffiAllocator.free(frameData);
+        _uniffiAllocator.free(bytes);
+    }
+}
+
+T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+    try {
+        return lift(buf.asUint8List());
+    } finally {
+        buf.free();
+    }
+}
+
+final class ForeignBytes extends Struct {
+    @Int32()
+    external int len;
+    external Pointer<Uint8> data;
+
+    void free() {
+    _uniffiAllocator.free(data);
+    }
+}
+
+class LiftRetVal<T> {
+    final T value;
+    final int bytesRead;
+    const LiftRetVal(this.value, this.bytesRead);
+
+    LiftRetVal<T> copyWithOffset(int offset) {
+        return LiftRetVal(value, bytesRead + offset);
+    }
+}
+
+abstract class FfiConverter<D, F> {
+    const FfiConverter();
+
+    D lift(F value);
+    F lower(D value);
+    D read(ByteData buffer, int offset);
+    void write(D value, ByteData buffer, int offset);
+    int size(D value);
+}
+
+mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+    @override
+    T lift(T value) => value;
+
+    @override
+    T lower(T value) => value;
+}
+
+class FfiConverterBool {
+
+    static bool lift( int value) {
+        return value == 1;
+    }
+
+    static int lower( bool value) {
+        return value ? 1 :0;
+    }
+
+    static LiftRetVal<bool> read( Uint8List buf) {
+        return LiftRetVal(FfiConverterBool.lift(buf.first), 1);
+    }
+
+    static RustBuffer lowerIntoRustBuffer( bool value) {
+        return toRustBuffer(Uint8List.fromList([FfiConverterBool.lower(value)]));
+    }
+
+    static int allocationSize([bool value = false]) {
+        return 1;
+    }
+
+    static int write( bool value, Uint8List buf) {
+        buf.setAll(0, [value ? 1 : 0]);
+        return allocationSize();
+    }
+}class FfiConverterString {
+    static String lift( RustBuffer buf) {
+
+        return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
+    }
+
+    static RustBuffer lower( String value) {
+        return toRustBuffer(Utf8Encoder().convert(value));
+    }
+
+    static LiftRetVal<String> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+        return LiftRetVal(utf8.decoder.convert(buf, 4, end), end);
+    }
+
+    static int allocationSize([String value = ""]) {
+
+        return utf8.encoder.convert(value).length + 4;
+    }
+
+    static int write( String value, Uint8List buf) {
+
+        final list = utf8.encoder.convert(value);
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length);
+        buf.setAll(4, list);
+        return list.length + 4;
+    }
+}class FfiConverterUInt16 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint16(0), 2);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 2;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint16(0, value);
+        return FfiConverterUInt16.allocationSize();
+    }
+
+}class FfiConverterUInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, value);
+        return FfiConverterUInt32.allocationSize();
+    }
+
+}class FfiConverterUInt8 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint8(0), 1);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 1;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint8(0, value);
+        return FfiConverterUInt8.allocationSize();
+    }
+
+}
+
+const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
+
+typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+Future<T> uniffiRustCallAsync<T, F>(
+    Pointer<Void> Function() rustFutureFunc,
+    void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc,
+    F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc,
+    void Function(Pointer<Void>) freeFunc,
+    T Function(F) liftFunc, [
+    UniffiRustCallStatusErrorHandler? errorHandler,
+]) async {
+    final rustFuture = rustFutureFunc();
+    final completer = Completer<int>();
+
+    late final NativeCallable<UniffiRustFutureContinuationCallback> callback;
+
+    void poll() {
+        pollFunc(
+            rustFuture,
+            callback.nativeFunction,
+            Pointer<Void>.fromAddress(0),
+        );
+    }
+    void onResponse(int _idx, int pollResult) {
+        if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+            completer.complete(pollResult);
+        } else {
+            poll();
+        }
+    }
+    callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener(onResponse);
+
+    try {
+        poll();
+        await completer.future;
+        callback.close();
+
+        final status = _uniffiAllocator<RustCallStatus>();
+        try {
+            final result = completeFunc(rustFuture, status);
+            checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+            return liftFunc(result);
+        } finally {
+            _uniffiAllocator.free(status);
+        }
+    } finally {
+        freeFunc(rustFuture);
+    }
+}
+
+class UniffiHandleMap<T> {
+    final Map<int, T> _map = {};
+    int _counter = 0;
+
+    int insert(T obj) {
+    final handle = _counter++;
+    _map[handle] = obj;
+    return handle;
+    }
+
+    T get(int handle) {
+    final obj = _map[handle];
+    if (obj == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    return obj;
+    }
+
+    void remove(int handle) {
+    if (_map.remove(handle) == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    }
+}
+
+Future<bool> alwaysReady() {
+    return uniffiRustCallAsync(
+        () => _UniffiLib.instance.uniffi_dart_async_fn_func_always_ready(),
+        _UniffiLib.instance.ffi_dart_async_rust_future_poll_i8,
+        _UniffiLib.instance.ffi_dart_async_rust_future_complete_i8,
+        _UniffiLib.instance.ffi_dart_async_rust_future_free_i8,
+        FfiConverterBool.lift,
+        NullRustCallStatusErrorHandler(),
+    );
+}Future<void> brokenSleep(int ms,int failAfter,) {
+    return uniffiRustCallAsync(
+        () => _UniffiLib.instance.uniffi_dart_async_fn_func_broken_sleep(
+            ms,failAfter,
+        ),
+        _UniffiLib.instance.ffi_dart_async_rust_future_poll_void,
+        _UniffiLib.instance.ffi_dart_async_rust_future_complete_void,
+        _UniffiLib.instance.ffi_dart_async_rust_future_free_void,
+        (_) {},
+        NullRustCallStatusErrorHandler(),
+    );
+}Future<int> fallibleMe(bool doFail,) {
+    return uniffiRustCallAsync(
+        () => _UniffiLib.instance.uniffi_dart_async_fn_func_fallible_me(
+            FfiConverterBool.lower(doFail),
+        ),
+        _UniffiLib.instance.ffi_dart_async_rust_future_poll_u8,
+        _UniffiLib.instance.ffi_dart_async_rust_future_complete_u8,
+        _UniffiLib.instance.ffi_dart_async_rust_future_free_u8,
+        FfiConverterUInt8.lift,
+        MyExceptionErrorHandler(),
+    );
+}String greet(String who,) {
+    return FfiConverterString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_dart_async_fn_func_greet(FfiConverterString.lower(who), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Future<MyRecord> newMyRecord(String a,int b,) {
+    return uniffiRustCallAsync(
+        () => _UniffiLib.instance.uniffi_dart_async_fn_func_new_my_record(
+            FfiConverterString.lower(a),b,
+        ),
+        _UniffiLib.instance.ffi_dart_async_rust_future_poll_rust_buffer,
+        _UniffiLib.instance.ffi_dart_async_rust_future_complete_rust_buffer,
+        _UniffiLib.instance.ffi_dart_async_rust_future_free_rust_buffer,
+        FfiConverterMyRecord.lift,
+        NullRustCallStatusErrorHandler(),
+    );
+}Future<String> say() {
+    return uniffiRustCallAsync(
+        () => _UniffiLib.instance.uniffi_dart_async_fn_func_say(),
+        _UniffiLib.instance.ffi_dart_async_rust_future_poll_rust_buffer,
+        _UniffiLib.instance.ffi_dart_async_rust_future_complete_rust_buffer,
+        _UniffiLib.instance.ffi_dart_async_rust_future_free_rust_buffer,
+        FfiConverterString.lift,
+        NullRustCallStatusErrorHandler(),
+    );
+}Future<String> sayAfter(int ms,String who,) {
+    return uniffiRustCallAsync(
+        () => _UniffiLib.instance.uniffi_dart_async_fn_func_say_after(
+            ms,FfiConverterString.lower(who),
+        ),
+        _UniffiLib.instance.ffi_dart_async_rust_future_poll_rust_buffer,
+        _UniffiLib.instance.ffi_dart_async_rust_future_complete_rust_buffer,
+        _UniffiLib.instance.ffi_dart_async_rust_future_free_rust_buffer,
+        FfiConverterString.lift,
+        NullRustCallStatusErrorHandler(),
+    );
+}Future<String> sayAfterWithTokio(int ms,String who,) {
+    return uniffiRustCallAsync(
+        () => _UniffiLib.instance.uniffi_dart_async_fn_func_say_after_with_tokio(
+            ms,FfiConverterString.lower(who),
+        ),
+        _UniffiLib.instance.ffi_dart_async_rust_future_poll_rust_buffer,
+        _UniffiLib.instance.ffi_dart_async_rust_future_complete_rust_buffer,
+        _UniffiLib.instance.ffi_dart_async_rust_future_free_rust_buffer,
+        FfiConverterString.lift,
+        NullRustCallStatusErrorHandler(),
+    );
+}Future<bool> sleep(int ms,) {
+    return uniffiRustCallAsync(
+        () => _UniffiLib.instance.uniffi_dart_async_fn_func_sleep(
+            ms,
+        ),
+        _UniffiLib.instance.ffi_dart_async_rust_future_poll_i8,
+        _UniffiLib.instance.ffi_dart_async_rust_future_complete_i8,
+        _UniffiLib.instance.ffi_dart_async_rust_future_free_i8,
+        FfiConverterBool.lift,
+        NullRustCallStatusErrorHandler(),
+    );
+}Future<void> voidFunction() {
+    return uniffiRustCallAsync(
+        () => _UniffiLib.instance.uniffi_dart_async_fn_func_void_function(),
+        _UniffiLib.instance.ffi_dart_async_rust_future_poll_void,
+        _UniffiLib.instance.ffi_dart_async_rust_future_complete_void,
+        _UniffiLib.instance.ffi_dart_async_rust_future_free_void,
+        (_) {},
+        NullRustCallStatusErrorHandler(),
+    );
+}
+
+class _UniffiLib {
+    _UniffiLib._();
+
+    static final DynamicLibrary _dylib = _open();
+
+    static DynamicLibrary _open() {
+        if (Platform.isAndroid) return DynamicLibrary.open("libdart_async.so");
+        if (Platform.isIOS) return DynamicLibrary.executable();
+        if (Platform.isLinux) return DynamicLibrary.open("libdart_async.so");
+        if (Platform.isMacOS) return DynamicLibrary.open("libdart_async.dylib");
+        if (Platform.isWindows) return DynamicLibrary.open("dart_async.dll");
+        throw UnsupportedError("Unsupported platform: \${Platform.operatingSystem}");
+    }
+
+    static final _UniffiLib instance = _UniffiLib._();
+
+    late final Pointer<Void> Function() uniffi_dart_async_fn_func_always_ready = _dylib.lookupFunction<
+        Pointer<Void> Function(),
+        Pointer<Void> Function()
+    >("uniffi_dart_async_fn_func_always_ready");late final Pointer<Void> Function(int,int,) uniffi_dart_async_fn_func_broken_sleep = _dylib.lookupFunction<
+        Pointer<Void> Function(Uint16,Uint16,),
+        Pointer<Void> Function(int,int,)
+    >("uniffi_dart_async_fn_func_broken_sleep");late final Pointer<Void> Function(int,) uniffi_dart_async_fn_func_fallible_me = _dylib.lookupFunction<
+        Pointer<Void> Function(Int8,),
+        Pointer<Void> Function(int,)
+    >("uniffi_dart_async_fn_func_fallible_me");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_dart_async_fn_func_greet = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_dart_async_fn_func_greet");late final Pointer<Void> Function(RustBuffer,int,) uniffi_dart_async_fn_func_new_my_record = _dylib.lookupFunction<
+        Pointer<Void> Function(RustBuffer,Uint32,),
+        Pointer<Void> Function(RustBuffer,int,)
+    >("uniffi_dart_async_fn_func_new_my_record");late final Pointer<Void> Function() uniffi_dart_async_fn_func_say = _dylib.lookupFunction<
+        Pointer<Void> Function(),
+        Pointer<Void> Function()
+    >("uniffi_dart_async_fn_func_say");late final Pointer<Void> Function(int,RustBuffer,) uniffi_dart_async_fn_func_say_after = _dylib.lookupFunction<
+        Pointer<Void> Function(Uint16,RustBuffer,),
+        Pointer<Void> Function(int,RustBuffer,)
+    >("uniffi_dart_async_fn_func_say_after");late final Pointer<Void> Function(int,RustBuffer,) uniffi_dart_async_fn_func_say_after_with_tokio = _dylib.lookupFunction<
+        Pointer<Void> Function(Uint16,RustBuffer,),
+        Pointer<Void> Function(int,RustBuffer,)
+    >("uniffi_dart_async_fn_func_say_after_with_tokio");late final Pointer<Void> Function(int,) uniffi_dart_async_fn_func_sleep = _dylib.lookupFunction<
+        Pointer<Void> Function(Uint16,),
+        Pointer<Void> Function(int,)
+    >("uniffi_dart_async_fn_func_sleep");late final Pointer<Void> Function() uniffi_dart_async_fn_func_void_function = _dylib.lookupFunction<
+        Pointer<Void> Function(),
+        Pointer<Void> Function()
+    >("uniffi_dart_async_fn_func_void_function");late final RustBuffer Function(int,Pointer<RustCallStatus>) ffi_dart_async_rustbuffer_alloc = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("ffi_dart_async_rustbuffer_alloc");late final RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>) ffi_dart_async_rustbuffer_from_bytes = _dylib.lookupFunction<
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>),
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>)
+    >("ffi_dart_async_rustbuffer_from_bytes");late final void Function(RustBuffer,Pointer<RustCallStatus>) ffi_dart_async_rustbuffer_free = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("ffi_dart_async_rustbuffer_free");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) ffi_dart_async_rustbuffer_reserve = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("ffi_dart_async_rustbuffer_reserve");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dart_async_rust_future_poll_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dart_async_rust_future_poll_u8");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_cancel_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_cancel_u8");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_free_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_free_u8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dart_async_rust_future_complete_u8 = _dylib.lookupFunction<
+        Uint8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dart_async_rust_future_complete_u8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dart_async_rust_future_poll_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dart_async_rust_future_poll_i8");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_cancel_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_cancel_i8");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_free_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_free_i8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dart_async_rust_future_complete_i8 = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dart_async_rust_future_complete_i8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dart_async_rust_future_poll_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dart_async_rust_future_poll_u16");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_cancel_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_cancel_u16");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_free_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_free_u16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dart_async_rust_future_complete_u16 = _dylib.lookupFunction<
+        Uint16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dart_async_rust_future_complete_u16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dart_async_rust_future_poll_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dart_async_rust_future_poll_i16");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_cancel_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_cancel_i16");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_free_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_free_i16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dart_async_rust_future_complete_i16 = _dylib.lookupFunction<
+        Int16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dart_async_rust_future_complete_i16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dart_async_rust_future_poll_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dart_async_rust_future_poll_u32");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_cancel_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_cancel_u32");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_free_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_free_u32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dart_async_rust_future_complete_u32 = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dart_async_rust_future_complete_u32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dart_async_rust_future_poll_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dart_async_rust_future_poll_i32");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_cancel_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_cancel_i32");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_free_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_free_i32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dart_async_rust_future_complete_i32 = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dart_async_rust_future_complete_i32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dart_async_rust_future_poll_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dart_async_rust_future_poll_u64");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_cancel_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_cancel_u64");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_free_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_free_u64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dart_async_rust_future_complete_u64 = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dart_async_rust_future_complete_u64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dart_async_rust_future_poll_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dart_async_rust_future_poll_i64");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_cancel_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_cancel_i64");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_free_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_free_i64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dart_async_rust_future_complete_i64 = _dylib.lookupFunction<
+        Int64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dart_async_rust_future_complete_i64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dart_async_rust_future_poll_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dart_async_rust_future_poll_f32");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_cancel_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_cancel_f32");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_free_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_free_f32");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dart_async_rust_future_complete_f32 = _dylib.lookupFunction<
+        Float Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dart_async_rust_future_complete_f32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dart_async_rust_future_poll_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dart_async_rust_future_poll_f64");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_cancel_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_cancel_f64");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_free_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_free_f64");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dart_async_rust_future_complete_f64 = _dylib.lookupFunction<
+        Double Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dart_async_rust_future_complete_f64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dart_async_rust_future_poll_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dart_async_rust_future_poll_pointer");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_cancel_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_cancel_pointer");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_free_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_free_pointer");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dart_async_rust_future_complete_pointer = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dart_async_rust_future_complete_pointer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dart_async_rust_future_poll_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dart_async_rust_future_poll_rust_buffer");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_cancel_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_cancel_rust_buffer");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_free_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_free_rust_buffer");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dart_async_rust_future_complete_rust_buffer = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dart_async_rust_future_complete_rust_buffer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dart_async_rust_future_poll_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dart_async_rust_future_poll_void");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_cancel_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_cancel_void");late final void Function(Pointer<Void>,) ffi_dart_async_rust_future_free_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dart_async_rust_future_free_void");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dart_async_rust_future_complete_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dart_async_rust_future_complete_void");late final int Function() uniffi_dart_async_checksum_func_always_ready = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dart_async_checksum_func_always_ready");late final int Function() uniffi_dart_async_checksum_func_broken_sleep = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dart_async_checksum_func_broken_sleep");late final int Function() uniffi_dart_async_checksum_func_fallible_me = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dart_async_checksum_func_fallible_me");late final int Function() uniffi_dart_async_checksum_func_greet = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dart_async_checksum_func_greet");late final int Function() uniffi_dart_async_checksum_func_new_my_record = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dart_async_checksum_func_new_my_record");late final int Function() uniffi_dart_async_checksum_func_say = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dart_async_checksum_func_say");late final int Function() uniffi_dart_async_checksum_func_say_after = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dart_async_checksum_func_say_after");late final int Function() uniffi_dart_async_checksum_func_say_after_with_tokio = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dart_async_checksum_func_say_after_with_tokio");late final int Function() uniffi_dart_async_checksum_func_sleep = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dart_async_checksum_func_sleep");late final int Function() uniffi_dart_async_checksum_func_void_function = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dart_async_checksum_func_void_function");late final int Function() ffi_dart_async_uniffi_contract_version = _dylib.lookupFunction<
+        Uint32 Function(),
+        int Function()
+    >("ffi_dart_async_uniffi_contract_version");
+
+    static void _checkApiVersion() {
+        final bindingsVersion = 26;
+        final scaffoldingVersion = _UniffiLib.instance.ffi_dart_async_uniffi_contract_version();
+        if (bindingsVersion != scaffoldingVersion) {
+            throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version \$bindingsVersion, scaffolding version \$scaffoldingVersion");
+        }
+    }
+
+    static void _checkApiChecksums() {
+        if (_UniffiLib.instance.uniffi_dart_async_checksum_func_always_ready() != 26287) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dart_async_checksum_func_broken_sleep() != 27590) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dart_async_checksum_func_fallible_me() != 59636) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dart_async_checksum_func_greet() != 62036) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dart_async_checksum_func_new_my_record() != 19367) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dart_async_checksum_func_say() != 14792) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dart_async_checksum_func_say_after() != 34720) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dart_async_checksum_func_say_after_with_tokio() != 48053) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dart_async_checksum_func_sleep() != 53803) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dart_async_checksum_func_void_function() != 8682) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }
+    }
+}
+
+void initialize() {
+    _UniffiLib._open();
+}
+
+void ensureInitialized() {
+    _UniffiLib._checkApiVersion();
+    _UniffiLib._checkApiChecksums();
+}
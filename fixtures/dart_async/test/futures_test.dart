@@ -77,22 +77,13 @@ void main() {
 
   test('fallible_function_and_method', () async {
     final time1 = await measureTime(() async {
-      try {
-        fallibleMe(false);
-        expect(true, true);
-      } catch (exception) {
-        expect(false, true); // should never be reached
-      }
+      final result = await fallibleMe(false);
+      expect(result, 42);
     });
     expect(time1.inMilliseconds <= 100, true);
 
     final time2 = await measureTime(() async {
-      try {
-        fallibleMe(true);
-        expect(false, true); // should never be reached
-      } catch (exception) {
-        expect(true, true);
-      }
+      await expectLater(fallibleMe(true), throwsA(isA<FooMyException>()));
     });
     expect(time2.inMilliseconds <= 100, true);
   });
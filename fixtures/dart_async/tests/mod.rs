@@ -4,3 +4,8 @@ use anyhow::Result;
 fn dart_async() -> Result<()> {
     uniffi_dart::testing::run_test("dart_async", "src/api.udl", None)
 }
+
+#[test]
+fn dart_async_golden() -> Result<()> {
+    uniffi_dart::testing::assert_golden("dart_async", "src/api.udl", None)
+}
@@ -0,0 +1,1192 @@
+library default_values;
+
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io" show Platform, File, Directory;
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+class Options {
+    final String name;final int retries;final int mask;final int mode;final int offset;final bool verbose;final double ratio;final Level level;final String? label;final int? limit;final List<String> tags;
+
+    Options({required this.name,this.retries = 3,this.mask = 0xf,this.mode = 420,this.offset = -1,this.verbose = false,this.ratio = 0.5,this.level = Level.low,this.label = null,this.limit = 10,this.tags = const [],});
+}
+
+class FfiConverterOptions {
+    static Options lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptions.read(data).value);
+    }
+
+    static LiftRetVal<Options> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final name_lifted = FfiConverterString.read(Uint8List.view(buf.buffer, new_offset));
+        final name = name_lifted.value;
+        new_offset += name_lifted.bytesRead;final retries_lifted = FfiConverterUInt32.read(Uint8List.view(buf.buffer, new_offset));
+        final retries = retries_lifted.value;
+        new_offset += retries_lifted.bytesRead;final mask_lifted = FfiConverterUInt8.read(Uint8List.view(buf.buffer, new_offset));
+        final mask = mask_lifted.value;
+        new_offset += mask_lifted.bytesRead;final mode_lifted = FfiConverterUInt16.read(Uint8List.view(buf.buffer, new_offset));
+        final mode = mode_lifted.value;
+        new_offset += mode_lifted.bytesRead;final offset_lifted = FfiConverterInt8.read(Uint8List.view(buf.buffer, new_offset));
+        final offset = offset_lifted.value;
+        new_offset += offset_lifted.bytesRead;final verbose_lifted = FfiConverterBool.read(Uint8List.view(buf.buffer, new_offset));
+        final verbose = verbose_lifted.value;
+        new_offset += verbose_lifted.bytesRead;final ratio_lifted = FfiConverterDouble64.read(Uint8List.view(buf.buffer, new_offset));
+        final ratio = ratio_lifted.value;
+        new_offset += ratio_lifted.bytesRead;final level_lifted = FfiConverterLevel.read(Uint8List.view(buf.buffer, new_offset));
+        final level = level_lifted.value;
+        new_offset += level_lifted.bytesRead;final label_lifted = FfiConverterOptionalString.read(Uint8List.view(buf.buffer, new_offset));
+        final label = label_lifted.value;
+        new_offset += label_lifted.bytesRead;final limit_lifted = FfiConverterOptionalInt32.read(Uint8List.view(buf.buffer, new_offset));
+        final limit = limit_lifted.value;
+        new_offset += limit_lifted.bytesRead;final tags_lifted = FfiConverterSequenceString.read(Uint8List.view(buf.buffer, new_offset));
+        final tags = tags_lifted.value;
+        new_offset += tags_lifted.bytesRead;
+        return LiftRetVal(Options(
+            name: name,retries: retries,mask: mask,mode: mode,offset: offset,verbose: verbose,ratio: ratio,level: level,label: label,limit: limit,tags: tags,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( Options value) {
+        final total_length = FfiConverterString.allocationSize(value.name) +FfiConverterUInt32.allocationSize(value.retries) +FfiConverterUInt8.allocationSize(value.mask) +FfiConverterUInt16.allocationSize(value.mode) +FfiConverterInt8.allocationSize(value.offset) +FfiConverterBool.allocationSize(value.verbose) +FfiConverterDouble64.allocationSize(value.ratio) +FfiConverterLevel.allocationSize(value.level) +FfiConverterOptionalString.allocationSize(value.label) +FfiConverterOptionalInt32.allocationSize(value.limit) +FfiConverterSequenceString.allocationSize(value.tags) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( Options value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterString.write(value.name, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterUInt32.write(value.retries, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterUInt8.write(value.mask, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterUInt16.write(value.mode, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterInt8.write(value.offset, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterBool.write(value.verbose, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterDouble64.write(value.ratio, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterLevel.write(value.level, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterOptionalString.write(value.label, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterOptionalInt32.write(value.limit, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterSequenceString.write(value.tags, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Options value) {
+        return FfiConverterString.allocationSize(value.name) +FfiConverterUInt32.allocationSize(value.retries) +FfiConverterUInt8.allocationSize(value.mask) +FfiConverterUInt16.allocationSize(value.mode) +FfiConverterInt8.allocationSize(value.offset) +FfiConverterBool.allocationSize(value.verbose) +FfiConverterDouble64.allocationSize(value.ratio) +FfiConverterLevel.allocationSize(value.level) +FfiConverterOptionalString.allocationSize(value.label) +FfiConverterOptionalInt32.allocationSize(value.limit) +FfiConverterSequenceString.allocationSize(value.tags) + 0;
+    }
+}
+
+enum Level {
+    low,high,
+    ;
+}
+
+class FfiConverterLevel {
+    static Level lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterLevel.read(data).value);
+    }
+
+    static LiftRetVal<Level> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        switch(index) {
+            case 1:
+                return LiftRetVal(Level.low, 4);
+            case 2:
+                return LiftRetVal(Level.high, 4);
+            default:
+                throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( Level input) {
+        final buf = Uint8List(allocationSize(input));
+        write(input, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int allocationSize(Level value) {
+        return 4;
+    }
+
+    static int write( Level value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.index + 1);
+        return 4;
+    }
+}
+abstract class CounterInterface {
+    int add( {int amount = 1,});
+}
+
+final _CounterFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _CounterFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_default_values_fn_free_counter");
+
+class Counter implements CounterInterface, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    Counter._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_CounterFree, _ptr);
+        _CounterFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    Counter( {int start = 10,}) : _ptr = rustCall((uniffiStatus) =>
+        _UniffiLib.instance.uniffi_default_values_fn_constructor_counter_new(
+            start, uniffiStatus
+        ),
+        NullRustCallStatusErrorHandler()
+    ) {
+        _attachFinalizer();
+    }
+
+    factory Counter.lift(Pointer<Void> ptr) {
+        return Counter._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("Counter has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_default_values_fn_clone_counter(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _CounterFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_default_values_fn_free_counter(_ptr, status));
+    }
+
+    @override
+    int add( {int amount = 1,}) {
+        return rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_default_values_fn_method_counter_add(uniffiClonePointer(),
+        amount, uniffiStatus), NullRustCallStatusErrorHandler());
+    }
+}
+
+class FfiConverterCounter {
+    static Counter lift( Pointer<Void> value) {
+        return Counter.lift(value);
+    }
+
+    static Pointer<Void> lower( Counter value) {
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<Counter> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([Counter? value]) {
+        return 8;
+    }
+
+    static int write( Counter value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+class UniffiInternalError implements Exception {
+    static const int bufferOverflow = 0;
+    static const int incompleteData = 1;
+    static const int unexpectedOptionalTag = 2;
+    static const int unexpectedEnumCase = 3;
+    static const int unexpectedNullPointer = 4;
+    static const int unexpectedRustCallStatusCode = 5;
+    static const int unexpectedRustCallError = 6;
+    static const int unexpectedStaleHandle = 7;
+    static const int rustPanic = 8;
+
+    final int errorCode;
+    final String? panicMessage;
+
+    const UniffiInternalError(this.errorCode, this.panicMessage);
+
+    static UniffiInternalError panicked(String message) {
+    return UniffiInternalError(rustPanic, message);
+    }
+
+    @override
+    String toString() {
+    switch (errorCode) {
+        case bufferOverflow:
+        return "UniFfi::BufferOverflow";
+        case incompleteData:
+        return "UniFfi::IncompleteData";
+        case unexpectedOptionalTag:
+        return "UniFfi::UnexpectedOptionalTag";
+        case unexpectedEnumCase:
+        return "UniFfi::UnexpectedEnumCase";
+        case unexpectedNullPointer:
+        return "UniFfi::UnexpectedNullPointer";
+        case unexpectedRustCallStatusCode:
+        return "UniFfi::UnexpectedRustCallStatusCode";
+        case unexpectedRustCallError:
+        return "UniFfi::UnexpectedRustCallError";
+        case unexpectedStaleHandle:
+        return "UniFfi::UnexpectedStaleHandle";
+        case rustPanic:
+        return "UniFfi::rustPanic: \$\$panicMessage";
+        default:
+        return "UniFfi::UnknownError: \$\$errorCode";
+    }
+    }
+}
+
+const int CALL_SUCCESS = 0;
+const int CALL_ERROR = 1;
+const int CALL_UNEXPECTED_ERROR = 2;
+
+final class RustCallStatus extends Struct {
+    @Int8()
+    external int code;
+
+    external RustBuffer errorBuf;
+
+}
+
+void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
+
+    if (status.ref.code == CALL_SUCCESS) {
+    return;
+    } else if (status.ref.code == CALL_ERROR) {
+    throw errorHandler.lift(status.ref.errorBuf);
+    } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+    if (status.ref.errorBuf.len > 0) {
+        throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
+    } else {
+        throw UniffiInternalError.panicked("Rust panic");
+    }
+    } else {
+    throw UniffiInternalError.panicked("Unexpected RustCallStatus code: \${status.ref.code}");
+    }
+}
+
+#[doc=" Allocates the native memory the bindings pass to Rust for the duration of a call."]
+const Allocator _uniffiAllocator = calloc;
+
+T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+    final status = _uniffiAllocator<RustCallStatus>();
+    try {
+    final result = callback(status);
+    checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+    return result;
+    } finally {
+    _uniffiAllocator.free(status);
+    }
+}
+
+class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+    errorBuf.free();
+    return UniffiInternalError.panicked("Unexpected CALL_ERROR");
+    }
+}
+
+abstract class UniffiRustCallStatusErrorHandler {
+    Exception lift(RustBuffer errorBuf);
+}
+
+final class RustBuffer extends Struct {
+    @Uint64()
+    external int capacity;
+
+    @Uint64()
+    external int len;
+
+    external Pointer<Uint8> data;
+
+    static RustBuffer alloc(int size) {
+        return rustCall((status) => _UniffiLib.instance.ffi_default_values_rustbuffer_alloc(size, status));
+    }
+
+    static RustBuffer fromBytes(ForeignBytes bytes) {
+        return rustCall((status) => _UniffiLib.instance.ffi_default_values_rustbuffer_from_bytes(bytes, status));
+    }
+
+    void free() {
+        rustCall((status) => _UniffiLib.instance.ffi_default_values_rustbuffer_free(this, status));
+    }
+
+    RustBuffer reserve(int additionalCapacity) {
+    return rustCall((status) => _UniffiLib.instance.ffi_default_values_rustbuffer_reserve(this, additionalCapacity, status));
+    }
+
+    Uint8List asUint8List() {
+    final dataList = data.asTypedList(len);
+    final byteData = ByteData.sublistView(dataList);
+    return Uint8List.view(byteData.buffer);
+    }
+
+    @override
+    String toString() {
+    return "RustBuffer{capacity: \$capacity, len: \$len, data: \$data}";
+    }
+}
+
+RustBuffer toRustBuffer(Uint8List data) {
+    final length = data.length;
+
+    final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length);
+    final pointerList = frameData.asTypedList(length);
+    pointerList.setAll(0, data);
+
+    final bytes = _uniffiAllocator<ForeignBytes>();
+    bytes.ref.len = length;
+    bytes.ref.data = frameData;
+    try {
+        return RustBuffer.fromBytes(bytes.ref);
+    } finally {
+        _uniffiAllocator.free(frameData);
+        _uniffiAllocator.free(bytes);
+    }
+}
+
+T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+    try {
+        return lift(buf.asUint8List());
+    } finally {
+        buf.free();
+    }
+}
+
+final class ForeignBytes extends Struct {
+    @Int32()
+    external int len;
+    external Pointer<Uint8> data;
+
+    void free() {
+    _uniffiAllocator.free(data);
+    }
+}
+
+class LiftRetVal<T> {
+    final T value;
+    final int bytesRead;
+    const LiftRetVal(this.value, this.bytesRead);
+
+    LiftRetVal<T> copyWithOffset(int offset) {
+        return LiftRetVal(value, bytesRead + offset);
+    }
+}
+
+abstract class FfiConverter<D, F> {
+    const FfiConverter();
+
+    D lift(F value);
+    F lower(D value);
+    D read(ByteData buffer, int offset);
+    void write(D value, ByteData buffer, int offset);
+    int size(D value);
+}
+
+mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+    @override
+    T lift(T value) => value;
+
+    @override
+    T lower(T value) => value;
+}
+
+class FfiConverterBool {
+
+    static bool lift( int value) {
+        return value == 1;
+    }
+
+    static int lower( bool value) {
+        return value ? 1 :0;
+    }
+
+    static LiftRetVal<bool> read( Uint8List buf) {
+        return LiftRetVal(FfiConverterBool.lift(buf.first), 1);
+    }
+
+    static RustBuffer lowerIntoRustBuffer( bool value) {
+        return toRustBuffer(Uint8List.fromList([FfiConverterBool.lower(value)]));
+    }
+
+    static int allocationSize([bool value = false]) {
+        return 1;
+    }
+
+    static int write( bool value, Uint8List buf) {
+        buf.setAll(0, [value ? 1 : 0]);
+        return allocationSize();
+    }
+}class FfiConverterDouble32 {
+
+    static double lift(double value) => value;
+
+    static LiftRetVal<double> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat32(0), 4);
+    }
+
+    static double lower(double value) => value;
+
+    static int allocationSize([double value = 0]) {
+        return 4;
+    }
+
+    static int write(double value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setFloat32(0, value);
+        return FfiConverterDouble32.allocationSize();
+    }
+
+}class FfiConverterDouble64 {
+
+    static double lift(double value) => value;
+
+    static LiftRetVal<double> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat64(0), 8);
+    }
+
+    static double lower(double value) => value;
+
+    static int allocationSize([double value = 0]) {
+        return 8;
+    }
+
+    static int write(double value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setFloat64(0, value);
+        return FfiConverterDouble64.allocationSize();
+    }
+
+}class FfiConverterInt64 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt64(0), 8);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 8;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, value);
+        return FfiConverterInt64.allocationSize();
+    }
+
+}class FfiConverterInt8 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt8(0), 1);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 1;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt8(0, value);
+        return FfiConverterInt8.allocationSize();
+    }
+
+}class FfiConverterOptionalInt32 {
+
+    static int? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalInt32.read(data).value);
+    }
+
+    static LiftRetVal<int?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterInt32.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([int? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterInt32.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( int? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalInt32.allocationSize(value));
+        FfiConverterOptionalInt32.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( int? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterInt32.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalString {
+
+    static String? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalString.read(data).value);
+    }
+
+    static LiftRetVal<String?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterString.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([String? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterString.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( String? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalString.allocationSize(value));
+        FfiConverterOptionalString.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( String? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterString.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterSequenceInt64 {
+
+    static List<int> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceInt64.read(data).value);
+    }
+
+    static LiftRetVal<List<int>> read( Uint8List buf) {
+        List<int> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterInt64.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<int> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterInt64.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<int> value) {
+        return value.map((l) => FfiConverterInt64.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<int> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterSequenceString {
+
+    static List<String> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceString.read(data).value);
+    }
+
+    static LiftRetVal<List<String>> read( Uint8List buf) {
+        List<String> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterString.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<String> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterString.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<String> value) {
+        return value.map((l) => FfiConverterString.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<String> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterString {
+    static String lift( RustBuffer buf) {
+
+        return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
+    }
+
+    static RustBuffer lower( String value) {
+        return toRustBuffer(Utf8Encoder().convert(value));
+    }
+
+    static LiftRetVal<String> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+        return LiftRetVal(utf8.decoder.convert(buf, 4, end), end);
+    }
+
+    static int allocationSize([String value = ""]) {
+
+        return utf8.encoder.convert(value).length + 4;
+    }
+
+    static int write( String value, Uint8List buf) {
+
+        final list = utf8.encoder.convert(value);
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length);
+        buf.setAll(4, list);
+        return list.length + 4;
+    }
+}class FfiConverterUInt16 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint16(0), 2);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 2;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint16(0, value);
+        return FfiConverterUInt16.allocationSize();
+    }
+
+}class FfiConverterUInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, value);
+        return FfiConverterUInt32.allocationSize();
+    }
+
+}class FfiConverterUInt64 {
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0), 8);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 8;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, value);
+        return FfiConverterUInt64.allocationSize();
+    }
+}class FfiConverterUInt8 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint8(0), 1);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 1;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint8(0, value);
+        return FfiConverterUInt8.allocationSize();
+    }
+
+}class FfiConverterInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value);
+        return FfiConverterInt32.allocationSize();
+    }
+
+}
+
+const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
+
+typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+Future<T> uniffiRustCallAsync<T, F>(
+    Pointer<Void> Function() rustFutureFunc,
+    void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc,
+    F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc,
+    void Function(Pointer<Void>) freeFunc,
+    T Function(F) liftFunc, [
+    UniffiRustCallStatusErrorHandler? errorHandler,
+]) async {
+    final rustFuture = rustFutureFunc();
+    final completer = Completer<int>();
+
+    late final NativeCallable<UniffiRustFutureContinuationCallback> callback;
+
+    void poll() {
+        pollFunc(
+            rustFuture,
+            callback.nativeFunction,
+            Pointer<Void>.fromAddress(0),
+        );
+    }
+    void onResponse(int _idx, int pollResult) {
+        if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+            completer.complete(pollResult);
+        } else {
+            poll();
+        }
+    }
+    callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener(onResponse);
+
+    try {
+        poll();
+        await completer.future;
+        callback.close();
+
+        final status = _uniffiAllocator<RustCallStatus>();
+        try {
+            final result = completeFunc(rustFuture, status);
+            checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+            return liftFunc(result);
+        } finally {
+            _uniffiAllocator.free(status);
+        }
+    } finally {
+        freeFunc(rustFuture);
+    }
+}
+
+class UniffiHandleMap<T> {
+    final Map<int, T> _map = {};
+    int _counter = 0;
+
+    int insert(T obj) {
+    final handle = _counter++;
+    _map[handle] = obj;
+    return handle;
+    }
+
+    T get(int handle) {
+    final obj = _map[handle];
+    if (obj == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    return obj;
+    }
+
+    void remove(int handle) {
+    if (_map.remove(handle) == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    }
+}
+
+int count( {List<String> items = const [],}) {
+    return rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_default_values_fn_func_count(FfiConverterSequenceString.lower(items), uniffiStatus), NullRustCallStatusErrorHandler());
+}Options defaultOptions() {
+    return FfiConverterOptions.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_default_values_fn_func_default_options( uniffiStatus), NullRustCallStatusErrorHandler()));
+}String describe(Options options,) {
+    return FfiConverterString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_default_values_fn_func_describe(FfiConverterOptions.lower(options), uniffiStatus), NullRustCallStatusErrorHandler()));
+}String greet(String name, {String greeting = 'Hello',int times = 1,}) {
+    return FfiConverterString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_default_values_fn_func_greet(FfiConverterString.lower(name),FfiConverterString.lower(greeting),times, uniffiStatus), NullRustCallStatusErrorHandler()));
+}int largest( {int value = 0xffffffffffffffff,}) {
+    return FfiConverterUInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_default_values_fn_func_largest(FfiConverterUInt64.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Level level( {Level value = Level.high,}) {
+    return FfiConverterLevel.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_default_values_fn_func_level(FfiConverterLevel.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}String? nickname( {String? value = null,String? fallback = 'none',}) {
+    return FfiConverterOptionalString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_default_values_fn_func_nickname(FfiConverterOptionalString.lower(value),FfiConverterOptionalString.lower(fallback), uniffiStatus), NullRustCallStatusErrorHandler()));
+}List<int> numbers( {int negative = -42,int hex = 0xff,int octal = 493,int big = 0xffffffffffffffff,}) {
+    return FfiConverterSequenceInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_default_values_fn_func_numbers(negative,hex,octal,FfiConverterUInt64.lower(big), uniffiStatus), NullRustCallStatusErrorHandler()));
+}String prices( {double small = 1.5,double large = 0.25,}) {
+    return FfiConverterString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_default_values_fn_func_prices(small,large, uniffiStatus), NullRustCallStatusErrorHandler()));
+}String quote( {String text = 'It\'s \$5',}) {
+    return FfiConverterString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_default_values_fn_func_quote(FfiConverterString.lower(text), uniffiStatus), NullRustCallStatusErrorHandler()));
+}
+
+class _UniffiLib {
+    _UniffiLib._();
+
+    static final DynamicLibrary _dylib = _open();
+
+    static DynamicLibrary _open() {
+        if (Platform.isAndroid) return DynamicLibrary.open("libdefault_values.so");
+        if (Platform.isIOS) return DynamicLibrary.executable();
+        if (Platform.isLinux) return DynamicLibrary.open("libdefault_values.so");
+        if (Platform.isMacOS) return DynamicLibrary.open("libdefault_values.dylib");
+        if (Platform.isWindows) return DynamicLibrary.open("default_values.dll");
+        throw UnsupportedError("Unsupported platform: \${Platform.operatingSystem}");
+    }
+
+    static final _UniffiLib instance = _UniffiLib._();
+
+    late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_default_values_fn_clone_counter = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_default_values_fn_clone_counter");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_default_values_fn_free_counter = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_default_values_fn_free_counter");late final Pointer<Void> Function(int,Pointer<RustCallStatus>) uniffi_default_values_fn_constructor_counter_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Uint32,Pointer<RustCallStatus>),
+        Pointer<Void> Function(int,Pointer<RustCallStatus>)
+    >("uniffi_default_values_fn_constructor_counter_new");late final int Function(Pointer<Void>,int,Pointer<RustCallStatus>) uniffi_default_values_fn_method_counter_add = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Uint32,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,int,Pointer<RustCallStatus>)
+    >("uniffi_default_values_fn_method_counter_add");late final int Function(RustBuffer,Pointer<RustCallStatus>) uniffi_default_values_fn_func_count = _dylib.lookupFunction<
+        Uint32 Function(RustBuffer,Pointer<RustCallStatus>),
+        int Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_default_values_fn_func_count");late final RustBuffer Function(Pointer<RustCallStatus>) uniffi_default_values_fn_func_default_options = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<RustCallStatus>)
+    >("uniffi_default_values_fn_func_default_options");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_default_values_fn_func_describe = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_default_values_fn_func_describe");late final RustBuffer Function(RustBuffer,RustBuffer,int,Pointer<RustCallStatus>) uniffi_default_values_fn_func_greet = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,RustBuffer,Uint32,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,RustBuffer,int,Pointer<RustCallStatus>)
+    >("uniffi_default_values_fn_func_greet");late final int Function(int,Pointer<RustCallStatus>) uniffi_default_values_fn_func_largest = _dylib.lookupFunction<
+        Uint64 Function(Uint64,Pointer<RustCallStatus>),
+        int Function(int,Pointer<RustCallStatus>)
+    >("uniffi_default_values_fn_func_largest");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_default_values_fn_func_level = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_default_values_fn_func_level");late final RustBuffer Function(RustBuffer,RustBuffer,Pointer<RustCallStatus>) uniffi_default_values_fn_func_nickname = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_default_values_fn_func_nickname");late final RustBuffer Function(int,int,int,int,Pointer<RustCallStatus>) uniffi_default_values_fn_func_numbers = _dylib.lookupFunction<
+        RustBuffer Function(Int64,Uint32,Uint32,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,int,int,int,Pointer<RustCallStatus>)
+    >("uniffi_default_values_fn_func_numbers");late final RustBuffer Function(double,double,Pointer<RustCallStatus>) uniffi_default_values_fn_func_prices = _dylib.lookupFunction<
+        RustBuffer Function(Float,Double,Pointer<RustCallStatus>),
+        RustBuffer Function(double,double,Pointer<RustCallStatus>)
+    >("uniffi_default_values_fn_func_prices");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_default_values_fn_func_quote = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_default_values_fn_func_quote");late final RustBuffer Function(int,Pointer<RustCallStatus>) ffi_default_values_rustbuffer_alloc = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("ffi_default_values_rustbuffer_alloc");late final RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>) ffi_default_values_rustbuffer_from_bytes = _dylib.lookupFunction<
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>),
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>)
+    >("ffi_default_values_rustbuffer_from_bytes");late final void Function(RustBuffer,Pointer<RustCallStatus>) ffi_default_values_rustbuffer_free = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("ffi_default_values_rustbuffer_free");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) ffi_default_values_rustbuffer_reserve = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("ffi_default_values_rustbuffer_reserve");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_default_values_rust_future_poll_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_default_values_rust_future_poll_u8");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_cancel_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_cancel_u8");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_free_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_free_u8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_default_values_rust_future_complete_u8 = _dylib.lookupFunction<
+        Uint8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_default_values_rust_future_complete_u8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_default_values_rust_future_poll_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_default_values_rust_future_poll_i8");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_cancel_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_cancel_i8");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_free_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_free_i8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_default_values_rust_future_complete_i8 = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_default_values_rust_future_complete_i8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_default_values_rust_future_poll_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_default_values_rust_future_poll_u16");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_cancel_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_cancel_u16");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_free_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_free_u16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_default_values_rust_future_complete_u16 = _dylib.lookupFunction<
+        Uint16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_default_values_rust_future_complete_u16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_default_values_rust_future_poll_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_default_values_rust_future_poll_i16");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_cancel_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_cancel_i16");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_free_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_free_i16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_default_values_rust_future_complete_i16 = _dylib.lookupFunction<
+        Int16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_default_values_rust_future_complete_i16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_default_values_rust_future_poll_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_default_values_rust_future_poll_u32");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_cancel_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_cancel_u32");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_free_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_free_u32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_default_values_rust_future_complete_u32 = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_default_values_rust_future_complete_u32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_default_values_rust_future_poll_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_default_values_rust_future_poll_i32");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_cancel_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_cancel_i32");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_free_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_free_i32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_default_values_rust_future_complete_i32 = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_default_values_rust_future_complete_i32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_default_values_rust_future_poll_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_default_values_rust_future_poll_u64");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_cancel_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_cancel_u64");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_free_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_free_u64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_default_values_rust_future_complete_u64 = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_default_values_rust_future_complete_u64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_default_values_rust_future_poll_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_default_values_rust_future_poll_i64");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_cancel_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_cancel_i64");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_free_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_free_i64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_default_values_rust_future_complete_i64 = _dylib.lookupFunction<
+        Int64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_default_values_rust_future_complete_i64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_default_values_rust_future_poll_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_default_values_rust_future_poll_f32");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_cancel_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_cancel_f32");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_free_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_free_f32");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_default_values_rust_future_complete_f32 = _dylib.lookupFunction<
+        Float Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_default_values_rust_future_complete_f32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_default_values_rust_future_poll_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_default_values_rust_future_poll_f64");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_cancel_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_cancel_f64");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_free_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_free_f64");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_default_values_rust_future_complete_f64 = _dylib.lookupFunction<
+        Double Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_default_values_rust_future_complete_f64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_default_values_rust_future_poll_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_default_values_rust_future_poll_pointer");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_cancel_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_cancel_pointer");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_free_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_free_pointer");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_default_values_rust_future_complete_pointer = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_default_values_rust_future_complete_pointer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_default_values_rust_future_poll_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_default_values_rust_future_poll_rust_buffer");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_cancel_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_cancel_rust_buffer");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_free_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_free_rust_buffer");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_default_values_rust_future_complete_rust_buffer = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_default_values_rust_future_complete_rust_buffer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_default_values_rust_future_poll_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_default_values_rust_future_poll_void");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_cancel_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_cancel_void");late final void Function(Pointer<Void>,) ffi_default_values_rust_future_free_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_default_values_rust_future_free_void");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_default_values_rust_future_complete_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_default_values_rust_future_complete_void");late final int Function() uniffi_default_values_checksum_func_count = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_default_values_checksum_func_count");late final int Function() uniffi_default_values_checksum_func_default_options = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_default_values_checksum_func_default_options");late final int Function() uniffi_default_values_checksum_func_describe = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_default_values_checksum_func_describe");late final int Function() uniffi_default_values_checksum_func_greet = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_default_values_checksum_func_greet");late final int Function() uniffi_default_values_checksum_func_largest = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_default_values_checksum_func_largest");late final int Function() uniffi_default_values_checksum_func_level = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_default_values_checksum_func_level");late final int Function() uniffi_default_values_checksum_func_nickname = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_default_values_checksum_func_nickname");late final int Function() uniffi_default_values_checksum_func_numbers = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_default_values_checksum_func_numbers");late final int Function() uniffi_default_values_checksum_func_prices = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_default_values_checksum_func_prices");late final int Function() uniffi_default_values_checksum_func_quote = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_default_values_checksum_func_quote");late final int Function() uniffi_default_values_checksum_method_counter_add = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_default_values_checksum_method_counter_add");late final int Function() uniffi_default_values_checksum_constructor_counter_new = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_default_values_checksum_constructor_counter_new");late final int Function() ffi_default_values_uniffi_contract_version = _dylib.lookupFunction<
+        Uint32 Function(),
+        int Function()
+    >("ffi_default_values_uniffi_contract_version");late final Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>) uniffi_dart_finalizer_token_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>),
+        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>)
+    >("uniffi_dart_finalizer_token_new");
+    late final void Function(Pointer<Void>) uniffi_dart_finalizer_token_free = _dylib.lookupFunction<
+        Void Function(Pointer<Void>),
+        void Function(Pointer<Void>)
+    >("uniffi_dart_finalizer_token_free");
+    late final Pointer<NativeFinalizerFunction> uniffi_dart_finalizer_run = _dylib.lookup("uniffi_dart_finalizer_run");
+
+    static void _checkApiVersion() {
+        final bindingsVersion = 26;
+        final scaffoldingVersion = _UniffiLib.instance.ffi_default_values_uniffi_contract_version();
+        if (bindingsVersion != scaffoldingVersion) {
+            throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version \$bindingsVersion, scaffolding version \$scaffoldingVersion");
+        }
+    }
+
+    static void _checkApiChecksums() {
+        if (_UniffiLib.instance.uniffi_default_values_checksum_func_count() != 65442) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_default_values_checksum_func_default_options() != 51285) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_default_values_checksum_func_describe() != 9801) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_default_values_checksum_func_greet() != 46816) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_default_values_checksum_func_largest() != 59779) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_default_values_checksum_func_level() != 43008) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_default_values_checksum_func_nickname() != 1894) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_default_values_checksum_func_numbers() != 2642) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_default_values_checksum_func_prices() != 8489) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_default_values_checksum_func_quote() != 34126) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_default_values_checksum_method_counter_add() != 55296) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_default_values_checksum_constructor_counter_new() != 43926) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }
+    }
+}
+
+void initialize() {
+    _UniffiLib._open();
+}
+
+void ensureInitialized() {
+    _UniffiLib._checkApiVersion();
+    _UniffiLib._checkApiChecksums();
+}
@@ -0,0 +1,96 @@
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Level {
+    Low,
+    High,
+}
+
+#[derive(Debug)]
+pub struct Options {
+    name: String,
+    retries: u32,
+    mask: u8,
+    mode: u16,
+    offset: i8,
+    verbose: bool,
+    ratio: f64,
+    level: Level,
+    label: Option<String>,
+    limit: Option<i32>,
+    tags: Vec<String>,
+}
+
+pub fn greet(name: String, greeting: String, times: u32) -> String {
+    vec![format!("{greeting}, {name}!"); times as usize].join(" ")
+}
+
+pub fn describe(options: Options) -> String {
+    format!("{options:?}")
+}
+
+/// The options with every default of the UDL applied, to compare with Dart's.
+pub fn default_options() -> Options {
+    Options {
+        name: "default".to_string(),
+        retries: 3,
+        mask: 0x0f,
+        mode: 0o644,
+        offset: -1,
+        verbose: false,
+        ratio: 0.5,
+        level: Level::Low,
+        label: None,
+        limit: Some(10),
+        tags: vec![],
+    }
+}
+
+pub fn numbers(negative: i64, hex: u32, octal: u32, big: u64) -> Vec<i64> {
+    vec![negative, hex.into(), octal.into(), big as i64]
+}
+
+pub fn largest(value: u64) -> u64 {
+    value
+}
+
+pub fn prices(small: f32, large: f64) -> String {
+    format!("{small} {large}")
+}
+
+pub fn nickname(value: Option<String>, fallback: Option<String>) -> Option<String> {
+    value.or(fallback)
+}
+
+pub fn count(items: Vec<String>) -> u32 {
+    items.len() as u32
+}
+
+pub fn level(value: Level) -> Level {
+    value
+}
+
+pub fn quote(text: String) -> String {
+    text
+}
+
+pub struct Counter {
+    value: Mutex<u32>,
+}
+
+impl Counter {
+    pub fn new(start: u32) -> Self {
+        Self {
+            value: Mutex::new(start),
+        }
+    }
+
+    pub fn add(&self, amount: u32) -> u32 {
+        let mut value = self.value.lock().unwrap();
+        *value += amount;
+        *value
+    }
+}
+
+uniffi::include_scaffolding!("api");
+uniffi_dart::setup_finalizers!();
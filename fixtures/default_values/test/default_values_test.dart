@@ -0,0 +1,50 @@
+import 'package:test/test.dart';
+import '../default_values.dart';
+
+void main() {
+  test('function arguments', () {
+    expect(greet('Dart'), 'Hello, Dart!');
+    expect(greet('Dart', times: 2), 'Hello, Dart! Hello, Dart!');
+    expect(greet('Dart', greeting: 'Hi'), 'Hi, Dart!');
+    expect(prices(), '1.5 0.25');
+    expect(prices(large: 2.0), '1.5 2');
+    expect(nickname(), 'none');
+    expect(nickname(value: 'dash'), 'dash');
+    expect(nickname(fallback: null), null);
+    expect(count(), 0);
+    expect(count(items: ['a', 'b']), 2);
+    expect(level(), Level.high);
+    expect(quote(), r"It's $5");
+  });
+
+  test('integer radixes', () {
+    // u64::MAX arrives as the two's complement bit pattern of an i64
+    expect(numbers(), [-42, 255, 493, -1]);
+    expect(numbers(hex: 1), [-42, 1, 493, -1]);
+    // u64::MAX, rendered in hex since Dart rejects decimal literals above i64::MAX
+    expect(largest(), -1);
+  });
+
+  test('record fields', () {
+    final options = Options(name: 'default');
+    expect(options.retries, 3);
+    expect(options.mask, 15);
+    expect(options.mode, 420);
+    expect(options.offset, -1);
+    expect(options.verbose, false);
+    expect(options.ratio, 0.5);
+    expect(options.level, Level.low);
+    expect(options.label, null);
+    expect(options.limit, 10);
+    expect(options.tags, isEmpty);
+    expect(describe(options), describe(defaultOptions()));
+    expect(Options(name: 'other', retries: 5).retries, 5);
+  });
+
+  test('object constructor and method', () {
+    final counter = Counter();
+    expect(counter.add(), 11);
+    expect(counter.add(amount: 4), 15);
+    expect(Counter(start: 0).add(), 1);
+  });
+}
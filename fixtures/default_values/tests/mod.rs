@@ -0,0 +1,11 @@
+use anyhow::Result;
+
+#[test]
+fn default_values() -> Result<()> {
+    uniffi_dart::testing::run_test("default_values", "src/api.udl", None)
+}
+
+#[test]
+fn default_values_golden() -> Result<()> {
+    uniffi_dart::testing::assert_golden("default_values", "src/api.udl", None)
+}
@@ -0,0 +1,1269 @@
+library dispose;
+
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io" show Platform, File, Directory;
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+class ResourceJournalList {
+    final List<Resource> resources;
+
+    ResourceJournalList({required this.resources,});
+}
+
+class FfiConverterResourceJournalList {
+    static ResourceJournalList lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterResourceJournalList.read(data).value);
+    }
+
+    static LiftRetVal<ResourceJournalList> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final resources_lifted = FfiConverterSequenceResource.read(Uint8List.view(buf.buffer, new_offset));
+        final resources = resources_lifted.value;
+        new_offset += resources_lifted.bytesRead;
+        return LiftRetVal(ResourceJournalList(
+            resources: resources,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( ResourceJournalList value) {
+        final total_length = FfiConverterSequenceResource.allocationSize(value.resources) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( ResourceJournalList value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterSequenceResource.write(value.resources, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(ResourceJournalList value) {
+        return FfiConverterSequenceResource.allocationSize(value.resources) + 0;
+    }
+}class ResourceJournalMap {
+    final Map<int, Resource> resources;
+
+    ResourceJournalMap({required this.resources,});
+}
+
+class FfiConverterResourceJournalMap {
+    static ResourceJournalMap lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterResourceJournalMap.read(data).value);
+    }
+
+    static LiftRetVal<ResourceJournalMap> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final resources_lifted = FfiConverterMapInt32Resource.read(Uint8List.view(buf.buffer, new_offset));
+        final resources = resources_lifted.value;
+        new_offset += resources_lifted.bytesRead;
+        return LiftRetVal(ResourceJournalMap(
+            resources: resources,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( ResourceJournalMap value) {
+        final total_length = FfiConverterMapInt32Resource.allocationSize(value.resources) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( ResourceJournalMap value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterMapInt32Resource.write(value.resources, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(ResourceJournalMap value) {
+        return FfiConverterMapInt32Resource.allocationSize(value.resources) + 0;
+    }
+}class ResourceJournalMapList {
+    final Map<int, List<Resource>?> resources;
+
+    ResourceJournalMapList({required this.resources,});
+}
+
+class FfiConverterResourceJournalMapList {
+    static ResourceJournalMapList lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterResourceJournalMapList.read(data).value);
+    }
+
+    static LiftRetVal<ResourceJournalMapList> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final resources_lifted = FfiConverterMapInt32OptionalSequenceResource.read(Uint8List.view(buf.buffer, new_offset));
+        final resources = resources_lifted.value;
+        new_offset += resources_lifted.bytesRead;
+        return LiftRetVal(ResourceJournalMapList(
+            resources: resources,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( ResourceJournalMapList value) {
+        final total_length = FfiConverterMapInt32OptionalSequenceResource.allocationSize(value.resources) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( ResourceJournalMapList value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterMapInt32OptionalSequenceResource.write(value.resources, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(ResourceJournalMapList value) {
+        return FfiConverterMapInt32OptionalSequenceResource.allocationSize(value.resources) + 0;
+    }
+}
+
+abstract class MaybeResourceJournal {
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterMaybeResourceJournal {
+    static MaybeResourceJournal lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterMaybeResourceJournal.read(data).value);
+    }
+
+    static LiftRetVal<MaybeResourceJournal> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4);
+        switch(index) {
+            case 1:
+                return SomeMaybeResourceJournal.read(subview).copyWithOffset(4);
+            case 2:
+                return NoneMaybeResourceJournal.read(subview).copyWithOffset(4);
+            default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( MaybeResourceJournal value) {
+        return value.lower();
+    }
+
+    static int allocationSize(MaybeResourceJournal value) {
+        return value.allocationSize();
+    }
+
+    static int write( MaybeResourceJournal value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class SomeMaybeResourceJournal extends MaybeResourceJournal {
+    final ResourceJournalList resource;
+
+    SomeMaybeResourceJournal(this.resource,);
+
+    SomeMaybeResourceJournal._(this.resource,);
+
+    static LiftRetVal<SomeMaybeResourceJournal> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final resource_lifted = FfiConverterResourceJournalList.read(Uint8List.view(buf.buffer, new_offset));
+        final resource = resource_lifted.value;
+        new_offset += resource_lifted.bytesRead;
+        return LiftRetVal(SomeMaybeResourceJournal._(
+            resource,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterResourceJournalList.allocationSize(resource) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterResourceJournalList.write(resource, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class NoneMaybeResourceJournal extends MaybeResourceJournal {
+
+    NoneMaybeResourceJournal();
+
+    NoneMaybeResourceJournal._();
+
+    static LiftRetVal<NoneMaybeResourceJournal> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        return LiftRetVal(NoneMaybeResourceJournal._(), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 2);
+        int new_offset = buf.offsetInBytes + 4;
+
+        return new_offset - buf.offsetInBytes;
+    }
+}
+
+abstract class PanickingResourceInterface {}
+
+final _PanickingResourceFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _PanickingResourceFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_dispose_fn_free_panickingresource");
+
+class PanickingResource implements PanickingResourceInterface, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    PanickingResource._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_PanickingResourceFree, _ptr);
+        _PanickingResourceFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    PanickingResource() : _ptr = rustCall((uniffiStatus) =>
+        _UniffiLib.instance.uniffi_dispose_fn_constructor_panickingresource_new(
+             uniffiStatus
+        ),
+        NullRustCallStatusErrorHandler()
+    ) {
+        _attachFinalizer();
+    }
+
+    factory PanickingResource.lift(Pointer<Void> ptr) {
+        return PanickingResource._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("PanickingResource has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_dispose_fn_clone_panickingresource(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _PanickingResourceFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_dispose_fn_free_panickingresource(_ptr, status));
+    }
+}
+
+class FfiConverterPanickingResource {
+    static PanickingResource lift( Pointer<Void> value) {
+        return PanickingResource.lift(value);
+    }
+
+    static Pointer<Void> lower( PanickingResource value) {
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<PanickingResource> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([PanickingResource? value]) {
+        return 8;
+    }
+
+    static int write( PanickingResource value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+abstract class ResourceInterface {
+    bool isAlive();
+}
+
+final _ResourceFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _ResourceFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_dispose_fn_free_resource");
+
+class Resource implements ResourceInterface, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    Resource._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_ResourceFree, _ptr);
+        _ResourceFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    Resource() : _ptr = rustCall((uniffiStatus) =>
+        _UniffiLib.instance.uniffi_dispose_fn_constructor_resource_new(
+             uniffiStatus
+        ),
+        NullRustCallStatusErrorHandler()
+    ) {
+        _attachFinalizer();
+    }
+
+    factory Resource.lift(Pointer<Void> ptr) {
+        return Resource._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("Resource has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_dispose_fn_clone_resource(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _ResourceFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_dispose_fn_free_resource(_ptr, status));
+    }
+
+    @override
+    bool isAlive() {
+        return FfiConverterBool.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_dispose_fn_method_resource_is_alive(uniffiClonePointer(),
+         uniffiStatus), NullRustCallStatusErrorHandler()));
+    }
+}
+
+class FfiConverterResource {
+    static Resource lift( Pointer<Void> value) {
+        return Resource.lift(value);
+    }
+
+    static Pointer<Void> lower( Resource value) {
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<Resource> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([Resource? value]) {
+        return 8;
+    }
+
+    static int write( Resource value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+class UniffiInternalError implements Exception {
+    static const int bufferOverflow = 0;
+    static const int incompleteData = 1;
+    static const int unexpectedOptionalTag = 2;
+    static const int unexpectedEnumCase = 3;
+    static const int unexpectedNullPointer = 4;
+    static const int unexpectedRustCallStatusCode = 5;
+    static const int unexpectedRustCallError = 6;
+    static const int unexpectedStaleHandle = 7;
+    static const int rustPanic = 8;
+
+    final int errorCode;
+    final String? panicMessage;
+
+    const UniffiInternalError(this.errorCode, this.panicMessage);
+
+    static UniffiInternalError panicked(String message) {
+    return UniffiInternalError(rustPanic, message);
+    }
+
+    @override
+    String toString() {
+    switch (errorCode) {
+        case bufferOverflow:
+        return "UniFfi::BufferOverflow";
+        case incompleteData:
+        return "UniFfi::IncompleteData";
+        case unexpectedOptionalTag:
+        return "UniFfi::UnexpectedOptionalTag";
+        case unexpectedEnumCase:
+        return "UniFfi::UnexpectedEnumCase";
+        case unexpectedNullPointer:
+        return "UniFfi::UnexpectedNullPointer";
+        case unexpectedRustCallStatusCode:
+        return "UniFfi::UnexpectedRustCallStatusCode";
+        case unexpectedRustCallError:
+        return "UniFfi::UnexpectedRustCallError";
+        case unexpectedStaleHandle:
+        return "UniFfi::UnexpectedStaleHandle";
+        case rustPanic:
+        return "UniFfi::rustPanic: \$\$panicMessage";
+        default:
+        return "UniFfi::UnknownError: \$\$errorCode";
+    }
+    }
+}
+
+const int CALL_SUCCESS = 0;
+const int CALL_ERROR = 1;
+const int CALL_UNEXPECTED_ERROR = 2;
+
+final class RustCallStatus extends Struct {
+    @Int8()
+    external int code;
+
+    external RustBuffer errorBuf;
+
+}
+
+void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
+
+    if (status.ref.code == CALL_SUCCESS) {
+    return;
+    } else if (status.ref.code == CALL_ERROR) {
+    throw errorHandler.lift(status.ref.errorBuf);
+    } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+    if (status.ref.errorBuf.len > 0) {
+        throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
+    } else {
+        throw UniffiInternalError.panicked("Rust panic");
+    }
+    } else {
+    throw UniffiInternalError.panicked("Unexpected RustCallStatus code: \${status.ref.code}");
+    }
+}
+
+#[doc=" Allocates the native memory the bindings pass to Rust for the duration of a call."]
+const Allocator _uniffiAllocator = calloc;
+
+T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+    final status = _uniffiAllocator<RustCallStatus>();
+    try {
+    final result = callback(status);
+    checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+    return result;
+    } finally {
+    _uniffiAllocator.free(status);
+    }
+}
+
+class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+    errorBuf.free();
+    return UniffiInternalError.panicked("Unexpected CALL_ERROR");
+    }
+}
+
+abstract class UniffiRustCallStatusErrorHandler {
+    Exception lift(RustBuffer errorBuf);
+}
+
+final class RustBuffer extends Struct {
+    @Uint64()
+    external int capacity;
+
+    @Uint64()
+    external int len;
+
+    external Pointer<Uint8> data;
+
+    static RustBuffer alloc(int size) {
+        return rustCall((status) => _UniffiLib.instance.ffi_dispose_rustbuffer_alloc(size, status));
+    }
+
+    static RustBuffer fromBytes(ForeignBytes bytes) {
+        return rustCall((status) => _UniffiLib.instance.ffi_dispose_rustbuffer_from_bytes(bytes, status));
+    }
+
+    void free() {
+        rustCall((status) => _UniffiLib.instance.ffi_dispose_rustbuffer_free(this, status));
+    }
+
+    RustBuffer reserve(int additionalCapacity) {
+    return rustCall((status) => _UniffiLib.instance.ffi_dispose_rustbuffer_reserve(this, additionalCapacity, status));
+    }
+
+    Uint8List asUint8List() {
+    final dataList = data.asTypedList(len);
+    final byteData = ByteData.sublistView(dataList);
+    return Uint8List.view(byteData.buffer);
+    }
+
+    @override
+    String toString() {
+    return "RustBuffer{capacity: \$capacity, len: \$len, data: \$data}";
+    }
+}
+
+RustBuffer toRustBuffer(Uint8List data) {
+    final length = data.length;
+
+    final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length);
+    final pointerList = frameData.asTypedList(length);
+    pointerList.setAll(0, data);
+
+    final bytes = _uniffiAllocator<ForeignBytes>();
+    bytes.ref.len = length;
+    bytes.ref.data = frameData;
+    try {
+        return RustBuffer.fromBytes(bytes.ref);
+    } finally {
+        _uniffiAllocator.free(frameData);
+        _uniffiAllocator.free(bytes);
+    }
+}
+
+T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+    try {
+        return lift(buf.asUint8List());
+    } finally {
+        buf.free();
+    }
+}
+
+final class ForeignBytes extends Struct {
+    @Int32()
+    external int len;
+    external Pointer<Uint8> data;
+
+    void free() {
+    _uniffiAllocator.free(data);
+    }
+}
+
+class LiftRetVal<T> {
+    final T value;
+    final int bytesRead;
+    const LiftRetVal(this.value, this.bytesRead);
+
+    LiftRetVal<T> copyWithOffset(int offset) {
+        return LiftRetVal(value, bytesRead + offset);
+    }
+}
+
+abstract class FfiConverter<D, F> {
+    const FfiConverter();
+
+    D lift(F value);
+    F lower(D value);
+    D read(ByteData buffer, int offset);
+    void write(D value, ByteData buffer, int offset);
+    int size(D value);
+}
+
+mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+    @override
+    T lift(T value) => value;
+
+    @override
+    T lower(T value) => value;
+}
+
+class FfiConverterBool {
+
+    static bool lift( int value) {
+        return value == 1;
+    }
+
+    static int lower( bool value) {
+        return value ? 1 :0;
+    }
+
+    static LiftRetVal<bool> read( Uint8List buf) {
+        return LiftRetVal(FfiConverterBool.lift(buf.first), 1);
+    }
+
+    static RustBuffer lowerIntoRustBuffer( bool value) {
+        return toRustBuffer(Uint8List.fromList([FfiConverterBool.lower(value)]));
+    }
+
+    static int allocationSize([bool value = false]) {
+        return 1;
+    }
+
+    static int write( bool value, Uint8List buf) {
+        buf.setAll(0, [value ? 1 : 0]);
+        return allocationSize();
+    }
+}class FfiConverterInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value);
+        return FfiConverterInt32.allocationSize();
+    }
+
+}class FfiConverterMapInt32OptionalSequenceResource {
+
+    static Map<int, List<Resource>?> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMapInt32OptionalSequenceResource.read(data).value);
+    }
+
+    static LiftRetVal<Map<int, List<Resource>?>> read( Uint8List buf) {
+        final Map<int, List<Resource>?> res = <int, List<Resource>?>{};
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final key = FfiConverterInt32.read(Uint8List.view(buf.buffer, offset));
+            offset += key.bytesRead;
+            final value = FfiConverterOptionalSequenceResource.read(Uint8List.view(buf.buffer, offset));
+            offset += value.bytesRead;
+            res[key.value] = value.value;
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( Map<int, List<Resource>?> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (final entry in value.entries) {
+            offset += FfiConverterInt32.write(entry.key, Uint8List.view(buf.buffer, offset));
+            offset += FfiConverterOptionalSequenceResource.write(entry.value, Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Map<int, List<Resource>?> value) {
+        return value.entries
+            .map((e) => FfiConverterInt32.allocationSize(e.key) + FfiConverterOptionalSequenceResource.allocationSize(e.value))
+            .fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( Map<int, List<Resource>?> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterMapInt32Resource {
+
+    static Map<int, Resource> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMapInt32Resource.read(data).value);
+    }
+
+    static LiftRetVal<Map<int, Resource>> read( Uint8List buf) {
+        final Map<int, Resource> res = <int, Resource>{};
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final key = FfiConverterInt32.read(Uint8List.view(buf.buffer, offset));
+            offset += key.bytesRead;
+            final value = FfiConverterResource.read(Uint8List.view(buf.buffer, offset));
+            offset += value.bytesRead;
+            res[key.value] = value.value;
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( Map<int, Resource> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (final entry in value.entries) {
+            offset += FfiConverterInt32.write(entry.key, Uint8List.view(buf.buffer, offset));
+            offset += FfiConverterResource.write(entry.value, Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Map<int, Resource> value) {
+        return value.entries
+            .map((e) => FfiConverterInt32.allocationSize(e.key) + FfiConverterResource.allocationSize(e.value))
+            .fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( Map<int, Resource> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterSequenceResource {
+
+    static List<Resource> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceResource.read(data).value);
+    }
+
+    static LiftRetVal<List<Resource>> read( Uint8List buf) {
+        List<Resource> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterResource.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<Resource> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterResource.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<Resource> value) {
+        return value.map((l) => FfiConverterResource.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<Resource> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterString {
+    static String lift( RustBuffer buf) {
+
+        return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
+    }
+
+    static RustBuffer lower( String value) {
+        return toRustBuffer(Utf8Encoder().convert(value));
+    }
+
+    static LiftRetVal<String> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+        return LiftRetVal(utf8.decoder.convert(buf, 4, end), end);
+    }
+
+    static int allocationSize([String value = ""]) {
+
+        return utf8.encoder.convert(value).length + 4;
+    }
+
+    static int write( String value, Uint8List buf) {
+
+        final list = utf8.encoder.convert(value);
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length);
+        buf.setAll(4, list);
+        return list.length + 4;
+    }
+}class FfiConverterUInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, value);
+        return FfiConverterUInt32.allocationSize();
+    }
+
+}class FfiConverterOptionalSequenceResource {
+
+    static List<Resource>? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalSequenceResource.read(data).value);
+    }
+
+    static LiftRetVal<List<Resource>?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterSequenceResource.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([List<Resource>? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterSequenceResource.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( List<Resource>? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalSequenceResource.allocationSize(value));
+        FfiConverterOptionalSequenceResource.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( List<Resource>? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterSequenceResource.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}
+
+const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
+
+typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+Future<T> uniffiRustCallAsync<T, F>(
+    Pointer<Void> Function() rustFutureFunc,
+    void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc,
+    F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc,
+    void Function(Pointer<Void>) freeFunc,
+    T Function(F) liftFunc, [
+    UniffiRustCallStatusErrorHandler? errorHandler,
+]) async {
+    final rustFuture = rustFutureFunc();
+    final completer = Completer<int>();
+
+    late final NativeCallable<UniffiRustFutureContinuationCallback> callback;
+
+    void poll() {
+        pollFunc(
+            rustFuture,
+            callback.nativeFunction,
+            Pointer<Void>.fromAddress(0),
+        );
+    }
+    void onResponse(int _idx, int pollResult) {
+        if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+            completer.complete(pollResult);
+        } else {
+            poll();
+        }
+    }
+    callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener(onResponse);
+
+    try {
+        poll();
+        await completer.future;
+        callback.close();
+
+        final status = _uniffiAllocator<RustCallStatus>();
+        try {
+            final result = completeFunc(rustFuture, status);
+            checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+            return liftFunc(result);
+        } finally {
+            _uniffiAllocator.free(status);
+        }
+    } finally {
+        freeFunc(rustFuture);
+    }
+}
+
+class UniffiHandleMap<T> {
+    final Map<int, T> _map = {};
+    int _counter = 0;
+
+    int insert(T obj) {
+    final handle = _counter++;
+    _map[handle] = obj;
+    return handle;
+    }
+
+    T get(int handle) {
+    final obj = _map[handle];
+    if (obj == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    return obj;
+    }
+
+    void remove(int handle) {
+    if (_map.remove(handle) == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    }
+}
+
+int getDropPanicCount() {
+    return rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_dispose_fn_func_get_drop_panic_count( uniffiStatus), NullRustCallStatusErrorHandler());
+}int getLiveCount() {
+    return rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_dispose_fn_func_get_live_count( uniffiStatus), NullRustCallStatusErrorHandler());
+}MaybeResourceJournal getMaybeResourceJournal() {
+    return FfiConverterMaybeResourceJournal.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_dispose_fn_func_get_maybe_resource_journal( uniffiStatus), NullRustCallStatusErrorHandler()));
+}Resource getResource() {
+    return FfiConverterResource.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_dispose_fn_func_get_resource( uniffiStatus), NullRustCallStatusErrorHandler()));
+}ResourceJournalList getResourceJournalList() {
+    return FfiConverterResourceJournalList.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_dispose_fn_func_get_resource_journal_list( uniffiStatus), NullRustCallStatusErrorHandler()));
+}ResourceJournalMap getResourceJournalMap() {
+    return FfiConverterResourceJournalMap.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_dispose_fn_func_get_resource_journal_map( uniffiStatus), NullRustCallStatusErrorHandler()));
+}ResourceJournalMapList getResourceJournalMapList() {
+    return FfiConverterResourceJournalMapList.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_dispose_fn_func_get_resource_journal_map_list( uniffiStatus), NullRustCallStatusErrorHandler()));
+}int journalSize(ResourceJournalList journal,) {
+    return rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_dispose_fn_func_journal_size(FfiConverterResourceJournalList.lower(journal), uniffiStatus), NullRustCallStatusErrorHandler());
+}
+
+class _UniffiLib {
+    _UniffiLib._();
+
+    static final DynamicLibrary _dylib = _open();
+
+    static DynamicLibrary _open() {
+        if (Platform.isAndroid) return DynamicLibrary.open("libdispose.so");
+        if (Platform.isIOS) return DynamicLibrary.executable();
+        if (Platform.isLinux) return DynamicLibrary.open("libdispose.so");
+        if (Platform.isMacOS) return DynamicLibrary.open("libdispose.dylib");
+        if (Platform.isWindows) return DynamicLibrary.open("dispose.dll");
+        throw UnsupportedError("Unsupported platform: \${Platform.operatingSystem}");
+    }
+
+    static final _UniffiLib instance = _UniffiLib._();
+
+    late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_dispose_fn_clone_panickingresource = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_dispose_fn_clone_panickingresource");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_dispose_fn_free_panickingresource = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_dispose_fn_free_panickingresource");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_dispose_fn_constructor_panickingresource_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_dispose_fn_constructor_panickingresource_new");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_dispose_fn_clone_resource = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_dispose_fn_clone_resource");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_dispose_fn_free_resource = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_dispose_fn_free_resource");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_dispose_fn_constructor_resource_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_dispose_fn_constructor_resource_new");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_dispose_fn_method_resource_is_alive = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_dispose_fn_method_resource_is_alive");late final int Function(Pointer<RustCallStatus>) uniffi_dispose_fn_func_get_drop_panic_count = _dylib.lookupFunction<
+        Int32 Function(Pointer<RustCallStatus>),
+        int Function(Pointer<RustCallStatus>)
+    >("uniffi_dispose_fn_func_get_drop_panic_count");late final int Function(Pointer<RustCallStatus>) uniffi_dispose_fn_func_get_live_count = _dylib.lookupFunction<
+        Int32 Function(Pointer<RustCallStatus>),
+        int Function(Pointer<RustCallStatus>)
+    >("uniffi_dispose_fn_func_get_live_count");late final RustBuffer Function(Pointer<RustCallStatus>) uniffi_dispose_fn_func_get_maybe_resource_journal = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<RustCallStatus>)
+    >("uniffi_dispose_fn_func_get_maybe_resource_journal");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_dispose_fn_func_get_resource = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_dispose_fn_func_get_resource");late final RustBuffer Function(Pointer<RustCallStatus>) uniffi_dispose_fn_func_get_resource_journal_list = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<RustCallStatus>)
+    >("uniffi_dispose_fn_func_get_resource_journal_list");late final RustBuffer Function(Pointer<RustCallStatus>) uniffi_dispose_fn_func_get_resource_journal_map = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<RustCallStatus>)
+    >("uniffi_dispose_fn_func_get_resource_journal_map");late final RustBuffer Function(Pointer<RustCallStatus>) uniffi_dispose_fn_func_get_resource_journal_map_list = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<RustCallStatus>)
+    >("uniffi_dispose_fn_func_get_resource_journal_map_list");late final int Function(RustBuffer,Pointer<RustCallStatus>) uniffi_dispose_fn_func_journal_size = _dylib.lookupFunction<
+        Uint32 Function(RustBuffer,Pointer<RustCallStatus>),
+        int Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_dispose_fn_func_journal_size");late final RustBuffer Function(int,Pointer<RustCallStatus>) ffi_dispose_rustbuffer_alloc = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("ffi_dispose_rustbuffer_alloc");late final RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>) ffi_dispose_rustbuffer_from_bytes = _dylib.lookupFunction<
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>),
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>)
+    >("ffi_dispose_rustbuffer_from_bytes");late final void Function(RustBuffer,Pointer<RustCallStatus>) ffi_dispose_rustbuffer_free = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("ffi_dispose_rustbuffer_free");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) ffi_dispose_rustbuffer_reserve = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("ffi_dispose_rustbuffer_reserve");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dispose_rust_future_poll_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dispose_rust_future_poll_u8");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_cancel_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_cancel_u8");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_free_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_free_u8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dispose_rust_future_complete_u8 = _dylib.lookupFunction<
+        Uint8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dispose_rust_future_complete_u8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dispose_rust_future_poll_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dispose_rust_future_poll_i8");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_cancel_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_cancel_i8");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_free_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_free_i8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dispose_rust_future_complete_i8 = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dispose_rust_future_complete_i8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dispose_rust_future_poll_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dispose_rust_future_poll_u16");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_cancel_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_cancel_u16");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_free_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_free_u16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dispose_rust_future_complete_u16 = _dylib.lookupFunction<
+        Uint16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dispose_rust_future_complete_u16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dispose_rust_future_poll_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dispose_rust_future_poll_i16");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_cancel_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_cancel_i16");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_free_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_free_i16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dispose_rust_future_complete_i16 = _dylib.lookupFunction<
+        Int16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dispose_rust_future_complete_i16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dispose_rust_future_poll_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dispose_rust_future_poll_u32");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_cancel_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_cancel_u32");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_free_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_free_u32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dispose_rust_future_complete_u32 = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dispose_rust_future_complete_u32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dispose_rust_future_poll_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dispose_rust_future_poll_i32");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_cancel_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_cancel_i32");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_free_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_free_i32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dispose_rust_future_complete_i32 = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dispose_rust_future_complete_i32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dispose_rust_future_poll_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dispose_rust_future_poll_u64");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_cancel_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_cancel_u64");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_free_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_free_u64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dispose_rust_future_complete_u64 = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dispose_rust_future_complete_u64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dispose_rust_future_poll_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dispose_rust_future_poll_i64");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_cancel_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_cancel_i64");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_free_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_free_i64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dispose_rust_future_complete_i64 = _dylib.lookupFunction<
+        Int64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dispose_rust_future_complete_i64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dispose_rust_future_poll_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dispose_rust_future_poll_f32");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_cancel_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_cancel_f32");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_free_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_free_f32");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dispose_rust_future_complete_f32 = _dylib.lookupFunction<
+        Float Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dispose_rust_future_complete_f32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dispose_rust_future_poll_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dispose_rust_future_poll_f64");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_cancel_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_cancel_f64");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_free_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_free_f64");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dispose_rust_future_complete_f64 = _dylib.lookupFunction<
+        Double Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dispose_rust_future_complete_f64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dispose_rust_future_poll_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dispose_rust_future_poll_pointer");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_cancel_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_cancel_pointer");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_free_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_free_pointer");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dispose_rust_future_complete_pointer = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dispose_rust_future_complete_pointer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dispose_rust_future_poll_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dispose_rust_future_poll_rust_buffer");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_cancel_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_cancel_rust_buffer");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_free_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_free_rust_buffer");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dispose_rust_future_complete_rust_buffer = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dispose_rust_future_complete_rust_buffer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_dispose_rust_future_poll_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_dispose_rust_future_poll_void");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_cancel_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_cancel_void");late final void Function(Pointer<Void>,) ffi_dispose_rust_future_free_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_dispose_rust_future_free_void");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_dispose_rust_future_complete_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_dispose_rust_future_complete_void");late final int Function() uniffi_dispose_checksum_func_get_drop_panic_count = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dispose_checksum_func_get_drop_panic_count");late final int Function() uniffi_dispose_checksum_func_get_live_count = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dispose_checksum_func_get_live_count");late final int Function() uniffi_dispose_checksum_func_get_maybe_resource_journal = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dispose_checksum_func_get_maybe_resource_journal");late final int Function() uniffi_dispose_checksum_func_get_resource = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dispose_checksum_func_get_resource");late final int Function() uniffi_dispose_checksum_func_get_resource_journal_list = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dispose_checksum_func_get_resource_journal_list");late final int Function() uniffi_dispose_checksum_func_get_resource_journal_map = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dispose_checksum_func_get_resource_journal_map");late final int Function() uniffi_dispose_checksum_func_get_resource_journal_map_list = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dispose_checksum_func_get_resource_journal_map_list");late final int Function() uniffi_dispose_checksum_func_journal_size = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dispose_checksum_func_journal_size");late final int Function() uniffi_dispose_checksum_method_resource_is_alive = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dispose_checksum_method_resource_is_alive");late final int Function() uniffi_dispose_checksum_constructor_panickingresource_new = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dispose_checksum_constructor_panickingresource_new");late final int Function() uniffi_dispose_checksum_constructor_resource_new = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_dispose_checksum_constructor_resource_new");late final int Function() ffi_dispose_uniffi_contract_version = _dylib.lookupFunction<
+        Uint32 Function(),
+        int Function()
+    >("ffi_dispose_uniffi_contract_version");late final Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>) uniffi_dart_finalizer_token_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>),
+        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>)
+    >("uniffi_dart_finalizer_token_new");
+    late final void Function(Pointer<Void>) uniffi_dart_finalizer_token_free = _dylib.lookupFunction<
+        Void Function(Pointer<Void>),
+        void Function(Pointer<Void>)
+    >("uniffi_dart_finalizer_token_free");
+    late final Pointer<NativeFinalizerFunction> uniffi_dart_finalizer_run = _dylib.lookup("uniffi_dart_finalizer_run");
+
+    static void _checkApiVersion() {
+        final bindingsVersion = 26;
+        final scaffoldingVersion = _UniffiLib.instance.ffi_dispose_uniffi_contract_version();
+        if (bindingsVersion != scaffoldingVersion) {
+            throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version \$bindingsVersion, scaffolding version \$scaffoldingVersion");
+        }
+    }
+
+    static void _checkApiChecksums() {
+        if (_UniffiLib.instance.uniffi_dispose_checksum_func_get_drop_panic_count() != 20777) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dispose_checksum_func_get_live_count() != 49127) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dispose_checksum_func_get_maybe_resource_journal() != 38376) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dispose_checksum_func_get_resource() != 10292) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dispose_checksum_func_get_resource_journal_list() != 22748) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dispose_checksum_func_get_resource_journal_map() != 38924) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dispose_checksum_func_get_resource_journal_map_list() != 11965) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dispose_checksum_func_journal_size() != 11255) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dispose_checksum_method_resource_is_alive() != 30154) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dispose_checksum_constructor_panickingresource_new() != 16988) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_dispose_checksum_constructor_resource_new() != 24237) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }
+    }
+}
+
+void initialize() {
+    _UniffiLib._open();
+}
+
+void ensureInitialized() {
+    _UniffiLib._checkApiVersion();
+    _UniffiLib._checkApiChecksums();
+}
@@ -9,12 +9,17 @@ static LIVE_COUNT: Lazy<RwLock<i32>> = Lazy::new(|| RwLock::new(0));
 #[derive(Debug, Clone, uniffi::Object)]
 pub struct Resource {}
 
+#[uniffi::export]
 impl Resource {
     #[uniffi::constructor]
     pub fn new() -> Self {
         *LIVE_COUNT.write().unwrap() += 1;
         Resource {}
     }
+
+    pub fn is_alive(&self) -> bool {
+        true
+    }
 }
 
 impl Drop for Resource {
@@ -23,6 +28,42 @@ impl Drop for Resource {
     }
 }
 
+static DROP_PANIC_COUNT: Lazy<RwLock<i32>> = Lazy::new(|| RwLock::new(0));
+
+/// An object whose `Drop` panics, which must not take down the Dart side.
+#[derive(Debug, uniffi::Object)]
+pub struct PanickingResource {}
+
+#[uniffi::export]
+impl PanickingResource {
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        PanickingResource {}
+    }
+}
+
+impl Drop for PanickingResource {
+    fn drop(&mut self) {
+        *DROP_PANIC_COUNT.write().unwrap() += 1;
+        panic!("PanickingResource dropped");
+    }
+}
+
+#[uniffi::export]
+fn get_drop_panic_count() -> i32 {
+    *DROP_PANIC_COUNT.read().unwrap()
+}
+
+#[uniffi::export]
+fn get_live_count() -> i32 {
+    *LIVE_COUNT.read().unwrap()
+}
+
+#[uniffi::export]
+fn get_resource() -> Arc<Resource> {
+    Arc::new(Resource::new())
+}
+
 #[derive(Debug, Clone, uniffi::Record)]
 pub struct ResourceJournalList {
     resources: Vec<Arc<Resource>>,
@@ -44,16 +85,6 @@ pub enum MaybeResourceJournal {
     None,
 }
 
-#[uniffi::export]
-fn get_live_count() -> i32 {
-    *LIVE_COUNT.read().unwrap()
-}
-
-#[uniffi::export]
-fn get_resource() -> Arc<Resource> {
-    Arc::new(Resource::new())
-}
-
 #[uniffi::export]
 fn get_resource_journal_list() -> ResourceJournalList {
     ResourceJournalList {
@@ -82,4 +113,10 @@ fn get_maybe_resource_journal() -> MaybeResourceJournal {
     }
 }
 
+#[uniffi::export]
+fn journal_size(journal: ResourceJournalList) -> u32 {
+    journal.resources.len() as u32
+}
+
 uniffi::include_scaffolding!("api");
+uniffi_dart::setup_finalizers!();
@@ -1,41 +1,127 @@
+import 'dart:isolate';
+
 import 'package:test/test.dart';
 import '../dispose.dart';
 
+// Dart can't force a collection, so garbage is allocated until the finalizers have run
+Future<void> collectGarbage([bool Function()? done]) async {
+  done ??= () => getLiveCount() == 0;
+  for (var i = 0; i < 200 && !done(); i++) {
+    final garbage = List.generate(100000, (i) => [i]);
+    expect(garbage, isNotEmpty);
+    await Future.delayed(Duration(milliseconds: 10));
+  }
+}
+
 void main() {
-  final api = Api.load();
+  test('dispose drops the Rust object', () {
+    final resource = getResource();
+    expect(getLiveCount(), 1);
+    resource.dispose();
+    expect(getLiveCount(), 0);
+  });
+
+  test('constructed objects are dropped', () {
+    final resource = Resource();
+    expect(getLiveCount(), 1);
+    resource.dispose();
+    expect(getLiveCount(), 0);
+  });
+
+  test('dispose can be called twice', () {
+    final resource = Resource();
+    expect(resource.isDisposed, false);
+    resource.dispose();
+    expect(resource.isDisposed, true);
+    resource.dispose();
+    expect(resource.isDisposed, true);
+    expect(getLiveCount(), 0);
+  });
 
-  test('ObjectDecrementsLiveCount', () {
-    final resource = api.getResource();
-    expect(api.getLiveCount(), 1);
+  test('using a disposed object throws', () {
+    final resource = Resource();
+    expect(resource.isAlive(), true);
     resource.dispose();
-    expect(api.getLiveCount(), 0);
+    expect(() => resource.isAlive(), throwsStateError);
+    expect(() => resource.uniffiClonePointer(), throwsStateError);
+    expect(getLiveCount(), 0);
+  });
+
+  test('objects in a list are dropped', () {
+    final journal = getResourceJournalList();
+    expect(getLiveCount(), 2);
+    expect(journalSize(journal), 2);
+    expect(getLiveCount(), 2);
+    for (final resource in journal.resources) {
+      resource.dispose();
+    }
+    expect(getLiveCount(), 0);
+  });
+
+  test('objects in a map are dropped', () {
+    final journal = getResourceJournalMap();
+    expect(getLiveCount(), 2);
+    expect(journal.resources.keys, unorderedEquals([1, 2]));
+    for (final resource in journal.resources.values) {
+      resource.dispose();
+    }
+    expect(getLiveCount(), 0);
+  });
+
+  test('objects in optional lists in a map are dropped', () {
+    final journal = getResourceJournalMapList();
+    expect(getLiveCount(), 2);
+    expect(journal.resources[2], null);
+    for (final resource in journal.resources[1]!) {
+      resource.dispose();
+    }
+    expect(getLiveCount(), 0);
+  });
+
+  test('objects in an enum are dropped', () {
+    final journal = getMaybeResourceJournal();
+    expect(journal, isA<SomeMaybeResourceJournal>());
+    expect(getLiveCount(), 2);
+    for (final resource in (journal as SomeMaybeResourceJournal).resource.resources) {
+      resource.dispose();
+    }
+    expect(getLiveCount(), 0);
   });
 
-  test('MapDecrementsLiveCount', () {
-    final journal = api.getResourceJournalMap();
-    expect(api.getLiveCount(), 2);
-    journal.dispose();
-    expect(api.getLiveCount(), 0);
+  test('unreachable objects are dropped after GC', () async {
+    for (var i = 0; i < 100; i++) {
+      getResource();
+      Resource();
+    }
+    expect(getLiveCount(), greaterThan(0));
+    await collectGarbage();
+    expect(getLiveCount(), 0);
   });
 
-  test('ListDecrementsLiveCount', () {
-    final journal = api.getResourceJournalList();
-    expect(api.getLiveCount(), 2);
-    journal.dispose();
-    expect(api.getLiveCount(), 0);
+  test('objects of an exited isolate are dropped', () async {
+    final created = await Isolate.run(() {
+      final resources = List.generate(100, (_) => getResource());
+      return resources.length;
+    });
+    expect(created, 100);
+    await collectGarbage();
+    expect(getLiveCount(), 0);
   });
 
-  test('MapListDecrementsLiveCount', () {
-    final journal = api.getResourceJournalMapList();
-    expect(api.getLiveCount(), 2);
-    journal.dispose();
-    expect(api.getLiveCount(), 0);
+  test('a panic in Drop is thrown by dispose', () {
+    final before = getDropPanicCount();
+    final resource = PanickingResource();
+    expect(() => resource.dispose(), throwsA(isA<UniffiInternalError>()));
+    expect(getDropPanicCount(), before + 1);
+    expect(resource.isDisposed, true);
   });
 
-  test('EnumDecrementsLiveCount', () {
-    final maybeJournal = api.getMaybeResourceJournal();
-    expect(api.getLiveCount(), 2);
-    maybeJournal.dispose();
-    expect(api.getLiveCount(), 0);
+  test('a panic in Drop is ignored by the finalizer', () async {
+    final before = getDropPanicCount();
+    for (var i = 0; i < 10; i++) {
+      PanickingResource();
+    }
+    await collectGarbage(() => getDropPanicCount() == before + 10);
+    expect(getDropPanicCount(), before + 10);
   });
 }
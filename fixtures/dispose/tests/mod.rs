@@ -1,6 +1,11 @@
-// use anyhow::Result;
+use anyhow::Result;
 
-// #[test]
-// fn dispose() -> Result<()> {
-//     uniffi_dart::testing::run_test("dispose", "src/api.udl", None)
-// }
+#[test]
+fn dispose() -> Result<()> {
+    uniffi_dart::testing::run_test("dispose", "src/api.udl", None)
+}
+
+#[test]
+fn dispose_golden() -> Result<()> {
+    uniffi_dart::testing::assert_golden("dispose", "src/api.udl", None)
+}
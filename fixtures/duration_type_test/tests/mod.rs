@@ -4,3 +4,8 @@ use anyhow::Result;
 fn duration_type_test() -> Result<()> {
     uniffi_dart::testing::run_test("duration_type_test", "src/api.udl", None)
 }
+
+#[test]
+fn duration_type_test_golden() -> Result<()> {
+    uniffi_dart::testing::assert_golden("duration_type_test", "src/api.udl", None)
+}
@@ -0,0 +1,1849 @@
+library error_types;
+
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io" show Platform, File, Directory;
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+abstract class Exception_ implements Exception {
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterException_ {
+    static Exception_ lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterException_.read(data).value);
+    }
+
+    static LiftRetVal<Exception_> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4);
+        switch(index) {
+            case 1:
+                return OopsException.read(subview).copyWithOffset(4);
+            case 2:
+                return ValueException.read(subview).copyWithOffset(4);
+            case 3:
+                return IntValueException.read(subview).copyWithOffset(4);
+            case 4:
+                return FlatInnerException.read(subview).copyWithOffset(4);
+            case 5:
+                return InnerException.read(subview).copyWithOffset(4);
+            default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( Exception_ value) {
+        return value.lower();
+    }
+
+    static int allocationSize(Exception_ value) {
+        return value.allocationSize();
+    }
+
+    static int write( Exception_ value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class OopsException extends Exception_ {
+
+    OopsException();
+
+    OopsException._();
+
+    static LiftRetVal<OopsException> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        return LiftRetVal(OopsException._(), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        int new_offset = buf.offsetInBytes + 4;
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class ValueException extends Exception_ {
+    final String value;
+
+    ValueException(this.value,);
+
+    ValueException._(this.value,);
+
+    static LiftRetVal<ValueException> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final value_lifted = FfiConverterString.read(Uint8List.view(buf.buffer, new_offset));
+        final value = value_lifted.value;
+        new_offset += value_lifted.bytesRead;
+        return LiftRetVal(ValueException._(
+            value,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterString.allocationSize(value) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 2);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterString.write(value, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class IntValueException extends Exception_ {
+    final int value;
+
+    IntValueException(this.value,);
+
+    IntValueException._(this.value,);
+
+    static LiftRetVal<IntValueException> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final value_lifted = FfiConverterUInt16.read(Uint8List.view(buf.buffer, new_offset));
+        final value = value_lifted.value;
+        new_offset += value_lifted.bytesRead;
+        return LiftRetVal(IntValueException._(
+            value,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterUInt16.allocationSize(value) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 3);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterUInt16.write(value, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class FlatInnerException extends Exception_ {
+    final FlatInner error;
+
+    FlatInnerException(this.error,);
+
+    FlatInnerException._(this.error,);
+
+    static LiftRetVal<FlatInnerException> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final error_lifted = FfiConverterFlatInner.read(Uint8List.view(buf.buffer, new_offset));
+        final error = error_lifted.value;
+        new_offset += error_lifted.bytesRead;
+        return LiftRetVal(FlatInnerException._(
+            error,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterFlatInner.allocationSize(error) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 4);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterFlatInner.write(error, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class InnerException extends Exception_ {
+    final Inner error;
+
+    InnerException(this.error,);
+
+    InnerException._(this.error,);
+
+    static LiftRetVal<InnerException> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final error_lifted = FfiConverterInner.read(Uint8List.view(buf.buffer, new_offset));
+        final error = error_lifted.value;
+        new_offset += error_lifted.bytesRead;
+        return LiftRetVal(InnerException._(
+            error,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterInner.allocationSize(error) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 5);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterInner.write(error, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}
+
+class Exception_ErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+        return FfiConverterException_.lift(errorBuf);
+    }
+}enum FlatInner {
+    caseA,caseB,
+    ;
+}
+
+class FfiConverterFlatInner {
+    static FlatInner lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterFlatInner.read(data).value);
+    }
+
+    static LiftRetVal<FlatInner> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        switch(index) {
+            case 1:
+                return LiftRetVal(FlatInner.caseA, 4);
+            case 2:
+                return LiftRetVal(FlatInner.caseB, 4);
+            default:
+                throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( FlatInner input) {
+        final buf = Uint8List(allocationSize(input));
+        write(input, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int allocationSize(FlatInner value) {
+        return 4;
+    }
+
+    static int write( FlatInner value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.index + 1);
+        return 4;
+    }
+}abstract class Inner {
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterInner {
+    static Inner lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterInner.read(data).value);
+    }
+
+    static LiftRetVal<Inner> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4);
+        switch(index) {
+            case 1:
+                return CaseAInner.read(subview).copyWithOffset(4);
+            default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( Inner value) {
+        return value.lower();
+    }
+
+    static int allocationSize(Inner value) {
+        return value.allocationSize();
+    }
+
+    static int write( Inner value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class CaseAInner extends Inner {
+    final String ;
+
+    CaseAInner(this.,);
+
+    CaseAInner._(this.,);
+
+    static LiftRetVal<CaseAInner> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final _lifted = FfiConverterString.read(Uint8List.view(buf.buffer, new_offset));
+        final  = _lifted.value;
+        new_offset += _lifted.bytesRead;
+        return LiftRetVal(CaseAInner._(
+            ,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterString.allocationSize() + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterString.write(, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}
+
+abstract class TupleException implements Exception {
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterTupleException {
+    static TupleException lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterTupleException.read(data).value);
+    }
+
+    static LiftRetVal<TupleException> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4);
+        switch(index) {
+            case 1:
+                return OopsTupleException.read(subview).copyWithOffset(4);
+            case 2:
+                return ValueTupleException.read(subview).copyWithOffset(4);
+            default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( TupleException value) {
+        return value.lower();
+    }
+
+    static int allocationSize(TupleException value) {
+        return value.allocationSize();
+    }
+
+    static int write( TupleException value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class OopsTupleException extends TupleException {
+    final String ;
+
+    OopsTupleException(this.,);
+
+    OopsTupleException._(this.,);
+
+    static LiftRetVal<OopsTupleException> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final _lifted = FfiConverterString.read(Uint8List.view(buf.buffer, new_offset));
+        final  = _lifted.value;
+        new_offset += _lifted.bytesRead;
+        return LiftRetVal(OopsTupleException._(
+            ,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterString.allocationSize() + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterString.write(, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class ValueTupleException extends TupleException {
+    final int ;
+
+    ValueTupleException(this.,);
+
+    ValueTupleException._(this.,);
+
+    static LiftRetVal<ValueTupleException> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final _lifted = FfiConverterUInt16.read(Uint8List.view(buf.buffer, new_offset));
+        final  = _lifted.value;
+        new_offset += _lifted.bytesRead;
+        return LiftRetVal(ValueTupleException._(
+            ,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterUInt16.allocationSize() + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 2);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterUInt16.write(, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}
+
+class TupleExceptionErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+        return FfiConverterTupleException.lift(errorBuf);
+    }
+}
+abstract class ExceptionInterfaceInterface {
+    List<String> chain();
+    String? link(int index,);
+}
+
+final _ExceptionInterfaceFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _ExceptionInterfaceFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_error_types_fn_free_errorinterface");
+
+class ExceptionInterface implements Exception, ExceptionInterfaceInterface, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    ExceptionInterface._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_ExceptionInterfaceFree, _ptr);
+        _ExceptionInterfaceFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    factory ExceptionInterface.lift(Pointer<Void> ptr) {
+        return ExceptionInterface._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("ExceptionInterface has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_error_types_fn_clone_errorinterface(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _ExceptionInterfaceFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_error_types_fn_free_errorinterface(_ptr, status));
+    }
+
+    @override
+    List<String> chain() {
+        return FfiConverterSequenceString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_error_types_fn_method_errorinterface_chain(uniffiClonePointer(),
+         uniffiStatus), NullRustCallStatusErrorHandler()));
+    }@override
+    String? link(int index,) {
+        return FfiConverterOptionalString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_error_types_fn_method_errorinterface_link(uniffiClonePointer(),
+        FfiConverterUInt64.lower(index), uniffiStatus), NullRustCallStatusErrorHandler()));
+    }
+
+    @override
+    String toString() {
+        if (_disposed) {
+            return "<ExceptionInterface disposed>";
+        }
+        return FfiConverterString.lift(rustCall((status) => _UniffiLib.instance.uniffi_error_types_fn_method_errorinterface_uniffi_trait_display(uniffiClonePointer(), status)));
+    }
+}
+
+class FfiConverterExceptionInterface {
+    static ExceptionInterface lift( Pointer<Void> value) {
+        return ExceptionInterface.lift(value);
+    }
+
+    static Pointer<Void> lower( ExceptionInterface value) {
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<ExceptionInterface> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([ExceptionInterface? value]) {
+        return 8;
+    }
+
+    static int write( ExceptionInterface value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+class ExceptionInterfaceErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+        return liftFromRustBuffer(errorBuf, (buf) => FfiConverterExceptionInterface.read(buf).value);
+    }
+}abstract class ExceptionTrait implements Exception {
+    String msg();
+    void dispose();
+}
+
+final _ExceptionTraitImplFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _ExceptionTraitImplFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_error_types_fn_free_errortrait");
+
+class ExceptionTraitImpl implements ExceptionTrait, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    ExceptionTraitImpl._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_ExceptionTraitImplFree, _ptr);
+        _ExceptionTraitImplFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    factory ExceptionTraitImpl.lift(Pointer<Void> ptr) {
+        return ExceptionTraitImpl._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("ExceptionTraitImpl has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_error_types_fn_clone_errortrait(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _ExceptionTraitImplFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_error_types_fn_free_errortrait(_ptr, status));
+    }
+
+    @override
+    String msg() {
+        return FfiConverterString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_error_types_fn_method_errortrait_msg(uniffiClonePointer(),
+         uniffiStatus), NullRustCallStatusErrorHandler()));
+    }
+}
+
+class FfiConverterExceptionTrait {
+    static ExceptionTrait lift( Pointer<Void> value) {
+        return ExceptionTraitImpl.lift(value);
+    }
+
+    static Pointer<Void> lower( ExceptionTrait value) {
+        if (value is! ExceptionTraitImpl) {
+            throw ArgumentError.value(value, "value", "only Rust implementations of ExceptionTrait can be passed to Rust");
+        }
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<ExceptionTrait> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([ExceptionTrait? value]) {
+        return 8;
+    }
+
+    static int write( ExceptionTrait value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+class ExceptionTraitErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+        return liftFromRustBuffer(errorBuf, (buf) => FfiConverterExceptionTrait.read(buf).value);
+    }
+}abstract class ProcExceptionInterfaceInterface {
+    String message();
+}
+
+final _ProcExceptionInterfaceFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _ProcExceptionInterfaceFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_error_types_fn_free_procerrorinterface");
+
+class ProcExceptionInterface implements Exception, ProcExceptionInterfaceInterface, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    ProcExceptionInterface._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_ProcExceptionInterfaceFree, _ptr);
+        _ProcExceptionInterfaceFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    factory ProcExceptionInterface.lift(Pointer<Void> ptr) {
+        return ProcExceptionInterface._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("ProcExceptionInterface has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_error_types_fn_clone_procerrorinterface(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _ProcExceptionInterfaceFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_error_types_fn_free_procerrorinterface(_ptr, status));
+    }
+
+    @override
+    String message() {
+        return FfiConverterString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_error_types_fn_method_procerrorinterface_message(uniffiClonePointer(),
+         uniffiStatus), NullRustCallStatusErrorHandler()));
+    }
+
+    @override
+    String toString() {
+        if (_disposed) {
+            return "<ProcExceptionInterface disposed>";
+        }
+        return FfiConverterString.lift(rustCall((status) => _UniffiLib.instance.uniffi_error_types_fn_method_procerrorinterface_uniffi_trait_display(uniffiClonePointer(), status)));
+    }
+}
+
+class FfiConverterProcExceptionInterface {
+    static ProcExceptionInterface lift( Pointer<Void> value) {
+        return ProcExceptionInterface.lift(value);
+    }
+
+    static Pointer<Void> lower( ProcExceptionInterface value) {
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<ProcExceptionInterface> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([ProcExceptionInterface? value]) {
+        return 8;
+    }
+
+    static int write( ProcExceptionInterface value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+class ProcExceptionInterfaceErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+        return liftFromRustBuffer(errorBuf, (buf) => FfiConverterProcExceptionInterface.read(buf).value);
+    }
+}abstract class RichExceptionInterface {}
+
+final _RichExceptionFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _RichExceptionFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_error_types_fn_free_richerror");
+
+class RichException implements Exception, RichExceptionInterface, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    RichException._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_RichExceptionFree, _ptr);
+        _RichExceptionFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    factory RichException.lift(Pointer<Void> ptr) {
+        return RichException._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("RichException has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_error_types_fn_clone_richerror(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _RichExceptionFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_error_types_fn_free_richerror(_ptr, status));
+    }
+
+    @override
+    String toString() {
+        if (_disposed) {
+            return "<RichException disposed>";
+        }
+        return FfiConverterString.lift(rustCall((status) => _UniffiLib.instance.uniffi_error_types_fn_method_richerror_uniffi_trait_display(uniffiClonePointer(), status)));
+    }
+}
+
+class FfiConverterRichException {
+    static RichException lift( Pointer<Void> value) {
+        return RichException.lift(value);
+    }
+
+    static Pointer<Void> lower( RichException value) {
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<RichException> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([RichException? value]) {
+        return 8;
+    }
+
+    static int write( RichException value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+class RichExceptionErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+        return liftFromRustBuffer(errorBuf, (buf) => FfiConverterRichException.read(buf).value);
+    }
+}abstract class TestInterfaceInterface {
+    void oops();
+    Future<void> aoops();
+}
+
+final _TestInterfaceFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _TestInterfaceFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_error_types_fn_free_testinterface");
+
+class TestInterface implements TestInterfaceInterface, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    TestInterface._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_TestInterfaceFree, _ptr);
+        _TestInterfaceFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    TestInterface.fallibleNew() : _ptr = rustCall((uniffiStatus) =>
+        _UniffiLib.instance.uniffi_error_types_fn_constructor_testinterface_fallible_new(
+             uniffiStatus
+        ),
+        ExceptionInterfaceErrorHandler()
+    ) {
+        _attachFinalizer();
+    }TestInterface() : _ptr = rustCall((uniffiStatus) =>
+        _UniffiLib.instance.uniffi_error_types_fn_constructor_testinterface_new(
+             uniffiStatus
+        ),
+        NullRustCallStatusErrorHandler()
+    ) {
+        _attachFinalizer();
+    }
+
+    factory TestInterface.lift(Pointer<Void> ptr) {
+        return TestInterface._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("TestInterface has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_error_types_fn_clone_testinterface(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _TestInterfaceFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_error_types_fn_free_testinterface(_ptr, status));
+    }
+
+    @override
+    void oops() {
+        rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_error_types_fn_method_testinterface_oops(uniffiClonePointer(),
+         uniffiStatus), ExceptionInterfaceErrorHandler());
+    }@override
+    Future<void> aoops() {
+        return uniffiRustCallAsync(
+            () => _UniffiLib.instance.uniffi_error_types_fn_method_testinterface_aoops(
+                uniffiClonePointer(),
+            ),
+            _UniffiLib.instance.ffi_error_types_rust_future_poll_void,
+            _UniffiLib.instance.ffi_error_types_rust_future_complete_void,
+            _UniffiLib.instance.ffi_error_types_rust_future_free_void,
+            (_) {},
+            ExceptionInterfaceErrorHandler(),
+        );
+    }
+}
+
+class FfiConverterTestInterface {
+    static TestInterface lift( Pointer<Void> value) {
+        return TestInterface.lift(value);
+    }
+
+    static Pointer<Void> lower( TestInterface value) {
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<TestInterface> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([TestInterface? value]) {
+        return 8;
+    }
+
+    static int write( TestInterface value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+class UniffiInternalError implements Exception {
+    static const int bufferOverflow = 0;
+    static const int incompleteData = 1;
+    static const int unexpectedOptionalTag = 2;
+    static const int unexpectedEnumCase = 3;
+    static const int unexpectedNullPointer = 4;
+    static const int unexpectedRustCallStatusCode = 5;
+    static const int unexpectedRustCallError = 6;
+    static const int unexpectedStaleHandle = 7;
+    static const int rustPanic = 8;
+
+    final int errorCode;
+    final String? panicMessage;
+
+    const UniffiInternalError(this.errorCode, this.panicMessage);
+
+    static UniffiInternalError panicked(String message) {
+    return UniffiInternalError(rustPanic, message);
+    }
+
+    @override
+    String toString() {
+    switch (errorCode) {
+        case bufferOverflow:
+        return "UniFfi::BufferOverflow";
+        case incompleteData:
+        return "UniFfi::IncompleteData";
+        case unexpectedOptionalTag:
+        return "UniFfi::UnexpectedOptionalTag";
+        case unexpectedEnumCase:
+        return "UniFfi::UnexpectedEnumCase";
+        case unexpectedNullPointer:
+        return "UniFfi::UnexpectedNullPointer";
+        case unexpectedRustCallStatusCode:
+        return "UniFfi::UnexpectedRustCallStatusCode";
+        case unexpectedRustCallError:
+        return "UniFfi::UnexpectedRustCallError";
+        case unexpectedStaleHandle:
+        return "UniFfi::UnexpectedStaleHandle";
+        case rustPanic:
+        return "UniFfi::rustPanic: \$\$panicMessage";
+        default:
+        return "UniFfi::UnknownError: \$\$errorCode";
+    }
+    }
+}
+
+const int CALL_SUCCESS = 0;
+const int CALL_ERROR = 1;
+const int CALL_UNEXPECTED_ERROR = 2;
+
+final class RustCallStatus extends Struct {
+    @Int8()
+    external int code;
+
+    external RustBuffer errorBuf;
+
+}
+
+void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
+
+    if (status.ref.code == CALL_SUCCESS) {
+    return;
+    } else if (status.ref.code == CALL_ERROR) {
+    throw errorHandler.lift(status.ref.errorBuf);
+    } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+    if (status.ref.errorBuf.len > 0) {
+        throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
+    } else {
+        throw UniffiInternalError.panicked("Rust panic");
+    }
+    } else {
+    throw UniffiInternalError.panicked("Unexpected RustCallStatus code: \${status.ref.code}");
+    }
+}
+
+#[doc=" Allocates the native memory the bindings pass to Rust for the duration of a call."]
+const Allocator _uniffiAllocator = calloc;
+
+T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+    final status = _uniffiAllocator<RustCallStatus>();
+    try {
+    final result = callback(status);
+    checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+    return result;
+    } finally {
+    _uniffiAllocator.free(status);
+    }
+}
+
+class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+    errorBuf.free();
+    return UniffiInternalError.panicked("Unexpected CALL_ERROR");
+    }
+}
+
+abstract class UniffiRustCallStatusErrorHandler {
+    Exception lift(RustBuffer errorBuf);
+}
+
+final class RustBuffer extends Struct {
+    @Uint64()
+    external int capacity;
+
+    @Uint64()
+    external int len;
+
+    external Pointer<Uint8> data;
+
+    static RustBuffer alloc(int size) {
+        return rustCall((status) => _UniffiLib.instance.ffi_error_types_rustbuffer_alloc(size, status));
+    }
+
+    static RustBuffer fromBytes(ForeignBytes bytes) {
+        return rustCall((status) => _UniffiLib.instance.ffi_error_types_rustbuffer_from_bytes(bytes, status));
+    }
+
+    void free() {
+        rustCall((status) => _UniffiLib.instance.ffi_error_types_rustbuffer_free(this, status));
+    }
+
+    RustBuffer reserve(int additionalCapacity) {
+    return rustCall((status) => _UniffiLib.instance.ffi_error_types_rustbuffer_reserve(this, additionalCapacity, status));
+    }
+
+    Uint8List asUint8List() {
+    final dataList = data.asTypedList(len);
+    final byteData = ByteData.sublistView(dataList);
+    return Uint8List.view(byteData.buffer);
+    }
+
+    @override
+    String toString() {
+    return "RustBuffer{capacity: \$capacity, len: \$len, data: \$data}";
+    }
+}
+
+RustBuffer toRustBuffer(Uint8List data) {
+    final length = data.length;
+
+    final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length);
+    final pointerList = frameData.asTypedList(length);
+    pointerList.setAll(0, data);
+
+    final bytes = _uniffiAllocator<ForeignBytes>();
+    bytes.ref.len = length;
+    bytes.ref.data = frameData;
+    try {
+        return RustBuffer.fromBytes(bytes.ref);
+    } finally {
+        _uniffiAllocator.free(frameData);
+        _uniffiAllocator.free(bytes);
+    }
+}
+
+T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+    try {
+        return lift(buf.asUint8List());
+    } finally {
+        buf.free();
+    }
+}
+
+final class ForeignBytes extends Struct {
+    @Int32()
+    external int len;
+    external Pointer<Uint8> data;
+
+    void free() {
+    _uniffiAllocator.free(data);
+    }
+}
+
+class LiftRetVal<T> {
+    final T value;
+    final int bytesRead;
+    const LiftRetVal(this.value, this.bytesRead);
+
+    LiftRetVal<T> copyWithOffset(int offset) {
+        return LiftRetVal(value, bytesRead + offset);
+    }
+}
+
+abstract class FfiConverter<D, F> {
+    const FfiConverter();
+
+    D lift(F value);
+    F lower(D value);
+    D read(ByteData buffer, int offset);
+    void write(D value, ByteData buffer, int offset);
+    int size(D value);
+}
+
+mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+    @override
+    T lift(T value) => value;
+
+    @override
+    T lower(T value) => value;
+}
+
+class FfiConverterOptionalString {
+
+    static String? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalString.read(data).value);
+    }
+
+    static LiftRetVal<String?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterString.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([String? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterString.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( String? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalString.allocationSize(value));
+        FfiConverterOptionalString.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( String? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterString.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalTupleError {
+
+    static TupleException? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalTupleError.read(data).value);
+    }
+
+    static LiftRetVal<TupleException?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterTupleException.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([TupleException? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterTupleException.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( TupleException? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalTupleError.allocationSize(value));
+        FfiConverterOptionalTupleError.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( TupleException? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterTupleException.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterSequenceString {
+
+    static List<String> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceString.read(data).value);
+    }
+
+    static LiftRetVal<List<String>> read( Uint8List buf) {
+        List<String> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterString.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<String> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterString.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<String> value) {
+        return value.map((l) => FfiConverterString.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<String> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterString {
+    static String lift( RustBuffer buf) {
+
+        return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
+    }
+
+    static RustBuffer lower( String value) {
+        return toRustBuffer(Utf8Encoder().convert(value));
+    }
+
+    static LiftRetVal<String> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+        return LiftRetVal(utf8.decoder.convert(buf, 4, end), end);
+    }
+
+    static int allocationSize([String value = ""]) {
+
+        return utf8.encoder.convert(value).length + 4;
+    }
+
+    static int write( String value, Uint8List buf) {
+
+        final list = utf8.encoder.convert(value);
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length);
+        buf.setAll(4, list);
+        return list.length + 4;
+    }
+}class FfiConverterUInt16 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint16(0), 2);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 2;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint16(0, value);
+        return FfiConverterUInt16.allocationSize();
+    }
+
+}class FfiConverterUInt64 {
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0), 8);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 8;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, value);
+        return FfiConverterUInt64.allocationSize();
+    }
+}
+
+const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
+
+typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+Future<T> uniffiRustCallAsync<T, F>(
+    Pointer<Void> Function() rustFutureFunc,
+    void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc,
+    F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc,
+    void Function(Pointer<Void>) freeFunc,
+    T Function(F) liftFunc, [
+    UniffiRustCallStatusErrorHandler? errorHandler,
+]) async {
+    final rustFuture = rustFutureFunc();
+    final completer = Completer<int>();
+
+    late final NativeCallable<UniffiRustFutureContinuationCallback> callback;
+
+    void poll() {
+        pollFunc(
+            rustFuture,
+            callback.nativeFunction,
+            Pointer<Void>.fromAddress(0),
+        );
+    }
+    void onResponse(int _idx, int pollResult) {
+        if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+            completer.complete(pollResult);
+        } else {
+            poll();
+        }
+    }
+    callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener(onResponse);
+
+    try {
+        poll();
+        await completer.future;
+        callback.close();
+
+        final status = _uniffiAllocator<RustCallStatus>();
+        try {
+            final result = completeFunc(rustFuture, status);
+            checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+            return liftFunc(result);
+        } finally {
+            _uniffiAllocator.free(status);
+        }
+    } finally {
+        freeFunc(rustFuture);
+    }
+}
+
+class UniffiHandleMap<T> {
+    final Map<int, T> _map = {};
+    int _counter = 0;
+
+    int insert(T obj) {
+    final handle = _counter++;
+    _map[handle] = obj;
+    return handle;
+    }
+
+    T get(int handle) {
+    final obj = _map[handle];
+    if (obj == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    return obj;
+    }
+
+    void remove(int handle) {
+    if (_map.remove(handle) == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    }
+}
+
+ExceptionInterface getError(String message,) {
+    return FfiConverterExceptionInterface.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_error_types_fn_func_get_error(FfiConverterString.lower(message), uniffiStatus), NullRustCallStatusErrorHandler()));
+}void oops() {
+    rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_error_types_fn_func_oops( uniffiStatus), ExceptionInterfaceErrorHandler());
+}void oopsNowrap() {
+    rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_error_types_fn_func_oops_nowrap( uniffiStatus), ExceptionInterfaceErrorHandler());
+}void throwRich(String message,) {
+    rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_error_types_fn_func_throw_rich(FfiConverterString.lower(message), uniffiStatus), RichExceptionErrorHandler());
+}Future<void> aoops() {
+    return uniffiRustCallAsync(
+        () => _UniffiLib.instance.uniffi_error_types_fn_func_aoops(),
+        _UniffiLib.instance.ffi_error_types_rust_future_poll_void,
+        _UniffiLib.instance.ffi_error_types_rust_future_complete_void,
+        _UniffiLib.instance.ffi_error_types_rust_future_free_void,
+        (_) {},
+        ExceptionInterfaceErrorHandler(),
+    );
+}TupleException getTuple( {TupleException? t = null,}) {
+    return FfiConverterTupleException.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_error_types_fn_func_get_tuple(FfiConverterOptionalTupleError.lower(t), uniffiStatus), NullRustCallStatusErrorHandler()));
+}void oopsEnum(int i,) {
+    rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_error_types_fn_func_oops_enum(i, uniffiStatus), Exception_ErrorHandler());
+}void oopsTuple(int i,) {
+    rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_error_types_fn_func_oops_tuple(i, uniffiStatus), TupleExceptionErrorHandler());
+}ProcExceptionInterface returnProcError(String e,) {
+    return FfiConverterProcExceptionInterface.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_error_types_fn_func_return_proc_error(FfiConverterString.lower(e), uniffiStatus), NullRustCallStatusErrorHandler()));
+}void throwProcError(String e,) {
+    rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_error_types_fn_func_throw_proc_error(FfiConverterString.lower(e), uniffiStatus), ProcExceptionInterfaceErrorHandler());
+}void toops() {
+    rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_error_types_fn_func_toops( uniffiStatus), ExceptionTraitErrorHandler());
+}
+
+class _UniffiLib {
+    _UniffiLib._();
+
+    static final DynamicLibrary _dylib = _open();
+
+    static DynamicLibrary _open() {
+        if (Platform.isAndroid) return DynamicLibrary.open("liberror_types.so");
+        if (Platform.isIOS) return DynamicLibrary.executable();
+        if (Platform.isLinux) return DynamicLibrary.open("liberror_types.so");
+        if (Platform.isMacOS) return DynamicLibrary.open("liberror_types.dylib");
+        if (Platform.isWindows) return DynamicLibrary.open("error_types.dll");
+        throw UnsupportedError("Unsupported platform: \${Platform.operatingSystem}");
+    }
+
+    static final _UniffiLib instance = _UniffiLib._();
+
+    late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_clone_errorinterface = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_clone_errorinterface");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_free_errorinterface = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_free_errorinterface");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_method_errorinterface_chain = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_method_errorinterface_chain");late final RustBuffer Function(Pointer<Void>,int,Pointer<RustCallStatus>) uniffi_error_types_fn_method_errorinterface_link = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,int,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_method_errorinterface_link");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_method_errorinterface_uniffi_trait_debug = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_method_errorinterface_uniffi_trait_debug");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_method_errorinterface_uniffi_trait_display = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_method_errorinterface_uniffi_trait_display");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_clone_richerror = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_clone_richerror");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_free_richerror = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_free_richerror");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_method_richerror_uniffi_trait_debug = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_method_richerror_uniffi_trait_debug");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_method_richerror_uniffi_trait_display = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_method_richerror_uniffi_trait_display");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_clone_testinterface = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_clone_testinterface");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_free_testinterface = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_free_testinterface");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_error_types_fn_constructor_testinterface_fallible_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_constructor_testinterface_fallible_new");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_error_types_fn_constructor_testinterface_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_constructor_testinterface_new");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_method_testinterface_oops = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_method_testinterface_oops");late final Pointer<Void> Function(Pointer<Void>,) uniffi_error_types_fn_method_testinterface_aoops = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,),
+        Pointer<Void> Function(Pointer<Void>,)
+    >("uniffi_error_types_fn_method_testinterface_aoops");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_clone_errortrait = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_clone_errortrait");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_free_errortrait = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_free_errortrait");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_method_errortrait_msg = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_method_errortrait_msg");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_clone_procerrorinterface = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_clone_procerrorinterface");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_free_procerrorinterface = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_free_procerrorinterface");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_method_procerrorinterface_message = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_method_procerrorinterface_message");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_method_procerrorinterface_uniffi_trait_debug = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_method_procerrorinterface_uniffi_trait_debug");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_error_types_fn_method_procerrorinterface_uniffi_trait_display = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_method_procerrorinterface_uniffi_trait_display");late final Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>) uniffi_error_types_fn_func_get_error = _dylib.lookupFunction<
+        Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>),
+        Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_func_get_error");late final void Function(Pointer<RustCallStatus>) uniffi_error_types_fn_func_oops = _dylib.lookupFunction<
+        Void Function(Pointer<RustCallStatus>),
+        void Function(Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_func_oops");late final void Function(Pointer<RustCallStatus>) uniffi_error_types_fn_func_oops_nowrap = _dylib.lookupFunction<
+        Void Function(Pointer<RustCallStatus>),
+        void Function(Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_func_oops_nowrap");late final void Function(RustBuffer,Pointer<RustCallStatus>) uniffi_error_types_fn_func_throw_rich = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_func_throw_rich");late final Pointer<Void> Function() uniffi_error_types_fn_func_aoops = _dylib.lookupFunction<
+        Pointer<Void> Function(),
+        Pointer<Void> Function()
+    >("uniffi_error_types_fn_func_aoops");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_error_types_fn_func_get_tuple = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_func_get_tuple");late final void Function(int,Pointer<RustCallStatus>) uniffi_error_types_fn_func_oops_enum = _dylib.lookupFunction<
+        Void Function(Uint16,Pointer<RustCallStatus>),
+        void Function(int,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_func_oops_enum");late final void Function(int,Pointer<RustCallStatus>) uniffi_error_types_fn_func_oops_tuple = _dylib.lookupFunction<
+        Void Function(Uint16,Pointer<RustCallStatus>),
+        void Function(int,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_func_oops_tuple");late final Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>) uniffi_error_types_fn_func_return_proc_error = _dylib.lookupFunction<
+        Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>),
+        Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_func_return_proc_error");late final void Function(RustBuffer,Pointer<RustCallStatus>) uniffi_error_types_fn_func_throw_proc_error = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_func_throw_proc_error");late final void Function(Pointer<RustCallStatus>) uniffi_error_types_fn_func_toops = _dylib.lookupFunction<
+        Void Function(Pointer<RustCallStatus>),
+        void Function(Pointer<RustCallStatus>)
+    >("uniffi_error_types_fn_func_toops");late final RustBuffer Function(int,Pointer<RustCallStatus>) ffi_error_types_rustbuffer_alloc = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("ffi_error_types_rustbuffer_alloc");late final RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>) ffi_error_types_rustbuffer_from_bytes = _dylib.lookupFunction<
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>),
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>)
+    >("ffi_error_types_rustbuffer_from_bytes");late final void Function(RustBuffer,Pointer<RustCallStatus>) ffi_error_types_rustbuffer_free = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("ffi_error_types_rustbuffer_free");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) ffi_error_types_rustbuffer_reserve = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("ffi_error_types_rustbuffer_reserve");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_error_types_rust_future_poll_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_error_types_rust_future_poll_u8");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_cancel_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_cancel_u8");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_free_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_free_u8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_error_types_rust_future_complete_u8 = _dylib.lookupFunction<
+        Uint8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_error_types_rust_future_complete_u8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_error_types_rust_future_poll_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_error_types_rust_future_poll_i8");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_cancel_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_cancel_i8");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_free_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_free_i8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_error_types_rust_future_complete_i8 = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_error_types_rust_future_complete_i8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_error_types_rust_future_poll_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_error_types_rust_future_poll_u16");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_cancel_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_cancel_u16");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_free_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_free_u16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_error_types_rust_future_complete_u16 = _dylib.lookupFunction<
+        Uint16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_error_types_rust_future_complete_u16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_error_types_rust_future_poll_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_error_types_rust_future_poll_i16");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_cancel_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_cancel_i16");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_free_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_free_i16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_error_types_rust_future_complete_i16 = _dylib.lookupFunction<
+        Int16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_error_types_rust_future_complete_i16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_error_types_rust_future_poll_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_error_types_rust_future_poll_u32");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_cancel_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_cancel_u32");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_free_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_free_u32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_error_types_rust_future_complete_u32 = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_error_types_rust_future_complete_u32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_error_types_rust_future_poll_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_error_types_rust_future_poll_i32");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_cancel_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_cancel_i32");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_free_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_free_i32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_error_types_rust_future_complete_i32 = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_error_types_rust_future_complete_i32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_error_types_rust_future_poll_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_error_types_rust_future_poll_u64");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_cancel_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_cancel_u64");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_free_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_free_u64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_error_types_rust_future_complete_u64 = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_error_types_rust_future_complete_u64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_error_types_rust_future_poll_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_error_types_rust_future_poll_i64");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_cancel_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_cancel_i64");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_free_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_free_i64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_error_types_rust_future_complete_i64 = _dylib.lookupFunction<
+        Int64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_error_types_rust_future_complete_i64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_error_types_rust_future_poll_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_error_types_rust_future_poll_f32");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_cancel_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_cancel_f32");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_free_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_free_f32");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_error_types_rust_future_complete_f32 = _dylib.lookupFunction<
+        Float Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_error_types_rust_future_complete_f32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_error_types_rust_future_poll_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_error_types_rust_future_poll_f64");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_cancel_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_cancel_f64");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_free_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_free_f64");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_error_types_rust_future_complete_f64 = _dylib.lookupFunction<
+        Double Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_error_types_rust_future_complete_f64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_error_types_rust_future_poll_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_error_types_rust_future_poll_pointer");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_cancel_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_cancel_pointer");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_free_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_free_pointer");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_error_types_rust_future_complete_pointer = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_error_types_rust_future_complete_pointer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_error_types_rust_future_poll_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_error_types_rust_future_poll_rust_buffer");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_cancel_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_cancel_rust_buffer");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_free_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_free_rust_buffer");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_error_types_rust_future_complete_rust_buffer = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_error_types_rust_future_complete_rust_buffer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_error_types_rust_future_poll_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_error_types_rust_future_poll_void");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_cancel_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_cancel_void");late final void Function(Pointer<Void>,) ffi_error_types_rust_future_free_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_error_types_rust_future_free_void");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_error_types_rust_future_complete_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_error_types_rust_future_complete_void");late final int Function() uniffi_error_types_checksum_func_get_error = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_func_get_error");late final int Function() uniffi_error_types_checksum_func_oops = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_func_oops");late final int Function() uniffi_error_types_checksum_func_oops_nowrap = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_func_oops_nowrap");late final int Function() uniffi_error_types_checksum_func_throw_rich = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_func_throw_rich");late final int Function() uniffi_error_types_checksum_func_aoops = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_func_aoops");late final int Function() uniffi_error_types_checksum_func_get_tuple = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_func_get_tuple");late final int Function() uniffi_error_types_checksum_func_oops_enum = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_func_oops_enum");late final int Function() uniffi_error_types_checksum_func_oops_tuple = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_func_oops_tuple");late final int Function() uniffi_error_types_checksum_func_return_proc_error = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_func_return_proc_error");late final int Function() uniffi_error_types_checksum_func_throw_proc_error = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_func_throw_proc_error");late final int Function() uniffi_error_types_checksum_func_toops = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_func_toops");late final int Function() uniffi_error_types_checksum_method_errorinterface_chain = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_method_errorinterface_chain");late final int Function() uniffi_error_types_checksum_method_errorinterface_link = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_method_errorinterface_link");late final int Function() uniffi_error_types_checksum_method_testinterface_oops = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_method_testinterface_oops");late final int Function() uniffi_error_types_checksum_method_testinterface_aoops = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_method_testinterface_aoops");late final int Function() uniffi_error_types_checksum_method_errortrait_msg = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_method_errortrait_msg");late final int Function() uniffi_error_types_checksum_method_procerrorinterface_message = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_method_procerrorinterface_message");late final int Function() uniffi_error_types_checksum_constructor_testinterface_fallible_new = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_constructor_testinterface_fallible_new");late final int Function() uniffi_error_types_checksum_constructor_testinterface_new = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_error_types_checksum_constructor_testinterface_new");late final int Function() ffi_error_types_uniffi_contract_version = _dylib.lookupFunction<
+        Uint32 Function(),
+        int Function()
+    >("ffi_error_types_uniffi_contract_version");late final Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>) uniffi_dart_finalizer_token_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>),
+        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>)
+    >("uniffi_dart_finalizer_token_new");
+    late final void Function(Pointer<Void>) uniffi_dart_finalizer_token_free = _dylib.lookupFunction<
+        Void Function(Pointer<Void>),
+        void Function(Pointer<Void>)
+    >("uniffi_dart_finalizer_token_free");
+    late final Pointer<NativeFinalizerFunction> uniffi_dart_finalizer_run = _dylib.lookup("uniffi_dart_finalizer_run");
+
+    static void _checkApiVersion() {
+        final bindingsVersion = 26;
+        final scaffoldingVersion = _UniffiLib.instance.ffi_error_types_uniffi_contract_version();
+        if (bindingsVersion != scaffoldingVersion) {
+            throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version \$bindingsVersion, scaffolding version \$scaffoldingVersion");
+        }
+    }
+
+    static void _checkApiChecksums() {
+        if (_UniffiLib.instance.uniffi_error_types_checksum_func_get_error() != 60068) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_error_types_checksum_func_oops() != 3341) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_error_types_checksum_func_oops_nowrap() != 34388) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_error_types_checksum_func_throw_rich() != 33432) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_error_types_checksum_func_aoops() != 51001) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_error_types_checksum_func_get_tuple() != 54564) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_error_types_checksum_func_oops_enum() != 30060) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_error_types_checksum_func_oops_tuple() != 56282) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_error_types_checksum_func_return_proc_error() != 8897) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_error_types_checksum_func_throw_proc_error() != 24935) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_error_types_checksum_func_toops() != 24535) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_error_types_checksum_method_errorinterface_chain() != 10009) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_error_types_checksum_method_errorinterface_link() != 17770) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_error_types_checksum_method_testinterface_oops() != 47663) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_error_types_checksum_method_testinterface_aoops() != 36554) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_error_types_checksum_method_errortrait_msg() != 58274) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_error_types_checksum_method_procerrorinterface_message() != 20882) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_error_types_checksum_constructor_testinterface_fallible_new() != 10902) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_error_types_checksum_constructor_testinterface_new() != 35630) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }
+    }
+}
+
+void initialize() {
+    _UniffiLib._open();
+}
+
+void ensureInitialized() {
+    _UniffiLib._checkApiVersion();
+    _UniffiLib._checkApiChecksums();
+}
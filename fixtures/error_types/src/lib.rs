@@ -243,3 +243,4 @@ fn get_tuple(t: Option<TupleError>) -> TupleError {
 }
 
 uniffi::include_scaffolding!("api");
+uniffi_dart::setup_finalizers!();
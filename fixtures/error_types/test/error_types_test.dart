@@ -7,7 +7,7 @@ void main() {
       try {
         oops();
         fail('Must have failed');
-      } on ErrorInterface catch (e) {
+      } on ExceptionInterface catch (e) {
         expect(e.toString(),
             'because uniffi told me so\n\nCaused by:\n    oops');
         expect(e.chain().length, 2);
@@ -19,7 +19,7 @@ void main() {
       try {
         oopsNowrap();
         fail('Must have failed');
-      } on ErrorInterface catch (e) {
+      } on ExceptionInterface catch (e) {
         expect(e.toString(),
             'because uniffi told me so\n\nCaused by:\n    oops');
         expect(e.chain().length, 2);
@@ -31,7 +31,7 @@ void main() {
       try {
         toops();
         fail('Must have failed');
-      } on ErrorTrait catch (e) {
+      } on ExceptionTrait catch (e) {
         expect(e.msg(), 'trait-oops');
       }
     });
@@ -46,106 +46,81 @@ void main() {
       try {
         throwRich('oh no');
         fail('Must have failed');
-      } on RichError catch (e) {
+      } on RichException catch (e) {
         expect(e.toString(), 'RichError: "oh no"');
       }
     });
 
+    test('Constructor and method errors', () {
+      expect(() => TestInterface.fallibleNew(),
+          throwsA(isA<ExceptionInterface>()));
+      expect(() => TestInterface().oops(), throwsA(isA<ExceptionInterface>()));
+    });
+
+    test('Proc-macro error interface', () {
+      expect(() => throwProcError('eek'),
+          throwsA(isA<ProcExceptionInterface>()));
+      expect(returnProcError('eek').toString(), 'ProcErrorInterface(eek)');
+    });
+
     group('Enum Error Tests', () {
       test('Oops variant', () {
-        expect(() => oops_enum(0), throwsA(isA<Error>()));
-        try {
-          oops_enum(0);
-        } catch (e) {
-          expect(e.toString(), 'uniffi.error_types.Exception\$Oops: ');
-        }
+        expect(() => oopsEnum(0), throwsA(isA<OopsException>()));
       });
 
       test('Value variant', () {
-        expect(() => oops_enum(1), throwsA(isA<Error>()));
-        try {
-          oops_enum(1);
-        } catch (e) {
-          expect(e.toString(),
-              'uniffi.error_types.Exception\$Value: value=value');
-        }
+        expect(
+            () => oopsEnum(1),
+            throwsA(isA<ValueException>()
+                .having((e) => e.value, 'value', 'value')));
       });
 
       test('IntValue variant', () {
-        expect(() => oops_enum(2), throwsA(isA<Error>()));
-        try {
-          oops_enum(2);
-        } catch (e) {
-          expect(e.toString(), 'uniffi.error_types.Exception\$IntValue: value=2');
-        }
+        expect(
+            () => oopsEnum(2),
+            throwsA(
+                isA<IntValueException>().having((e) => e.value, 'value', 2)));
       });
 
-      test('FlatInnerError variant with CaseA', () {
-        expect(() => oops_enum(3), throwsA(isA<Error.FlatInnerError>()));
-        try {
-          oops_enum(3);
-        } catch (e) {
-          expect(
-              e.toString(),
-              'uniffi.error_types.Exception\$FlatInnerException: error=uniffi.error_types.FlatInner\$CaseA: inner');
-        }
+      test('FlatInnerError variant', () {
+        expect(() => oopsEnum(3), throwsA(isA<FlatInnerException>()));
+        expect(() => oopsEnum(4), throwsA(isA<FlatInnerException>()));
       });
 
-      test('FlatInnerError variant with CaseB', () {
-        expect(() => oops_enum(4), throwsA(isA<Error.FlatInnerError>()));
-        try {
-          oops_enum(4);
-        } catch (e) {
-          expect(
-              e.toString(),
-              'uniffi.error_types.Exception\$FlatInnerException: error=uniffi.error_types.FlatInner\$CaseB: NonUniffiTypeValue: value');
-        }
+      test('InnerError variant', () {
+        expect(() => oopsEnum(5), throwsA(isA<InnerException>()));
       });
 
-      test('InnerError variant', () {
-        expect(() => oops_enum(5), throwsA(isA<Error.InnerError>()));
-        try {
-          oops_enum(5);
-        } catch (e) {
-          expect(e.toString(),
-              'uniffi.error_types.Exception\$InnerException: error=uniffi.error_types.Inner\$CaseA: v1=inner');
-        }
+      test('Panics surface as internal errors', () {
+        expect(() => oopsEnum(6), throwsA(isA<UniffiInternalError>()));
       });
     });
 
     group('Tuple Error Tests', () {
       test('TupleError Oops variant', () {
-        expect(() => oops_tuple(0), throwsA(isA<TupleError>()));
-        try {
-          oops_tuple(0);
-        } catch (e) {
-          expect(e.toString(), "'oops'");
-          expect(e.toString(), equals("TupleError.Oops('oops')"));
-        }
+        expect(() => oopsTuple(0), throwsA(isA<OopsTupleException>()));
       });
 
       test('TupleError Value variant', () {
-        expect(() => oops_tuple(1), throwsA(isA<TupleError>()));
-        try {
-          oops_tuple(1);
-        } catch (e) {
-          expect(e.toString(), '1');
-          expect(e.toString(), equals("TupleError.Value(1)"));
-        }
+        expect(() => oopsTuple(1), throwsA(isA<ValueTupleException>()));
+      });
+
+      test('Panics surface as internal errors', () {
+        expect(() => oopsTuple(2), throwsA(isA<UniffiInternalError>()));
       });
 
       test('Get tuple with default', () {
-        final tuple = getTuple(null);
-        expect(tuple.toString(), "TupleError.Oops('oops')");
-        expect(getTuple(tuple), tuple);
-      }
+        final tuple = getTuple();
+        expect(tuple, isA<OopsTupleException>());
+        expect(getTuple(t: tuple), isA<OopsTupleException>());
+      });
     });
 
     test('Async throw error', () async {
       try {
         await aoops();
         fail('Must have failed');
-      } on ErrorInterface catch (e) {
+      } on ExceptionInterface catch (e) {
         expect(e.toString(), 'async-oops');
       }
     });
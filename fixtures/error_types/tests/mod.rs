@@ -4,3 +4,8 @@ use anyhow::Result;
 fn error_types() -> Result<()> {
     uniffi_dart::testing::run_test("error_types", "src/api.udl", None)
 }
+
+#[test]
+fn error_types_golden() -> Result<()> {
+    uniffi_dart::testing::assert_golden("error_types", "src/api.udl", None)
+}
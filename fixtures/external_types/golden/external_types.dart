@@ -0,0 +1,848 @@
+library external_types;
+
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io" show Platform, File, Directory;
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+import "map_types.dart" as map_types;import "trait_interfaces.dart" as trait_interfaces;import "trait_methods.dart" as trait_methods;
+
+class Journey {
+    final trait_methods.ProcTraitMethods guide;final map_types.Point start;final List<map_types.Direction> steps;
+
+    Journey({required this.guide,required this.start,required this.steps,});
+}
+
+class FfiConverterJourney {
+    static Journey lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterJourney.read(data).value);
+    }
+
+    static LiftRetVal<Journey> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final guide_lifted = FfiConverterExternalProcTraitMethods.read(Uint8List.view(buf.buffer, new_offset));
+        final guide = guide_lifted.value;
+        new_offset += guide_lifted.bytesRead;final start_lifted = FfiConverterExternalPoint.read(Uint8List.view(buf.buffer, new_offset));
+        final start = start_lifted.value;
+        new_offset += start_lifted.bytesRead;final steps_lifted = FfiConverterSequenceExternalDirection.read(Uint8List.view(buf.buffer, new_offset));
+        final steps = steps_lifted.value;
+        new_offset += steps_lifted.bytesRead;
+        return LiftRetVal(Journey(
+            guide: guide,start: start,steps: steps,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( Journey value) {
+        final total_length = FfiConverterExternalProcTraitMethods.allocationSize(value.guide) +FfiConverterExternalPoint.allocationSize(value.start) +FfiConverterSequenceExternalDirection.allocationSize(value.steps) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( Journey value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterExternalProcTraitMethods.write(value.guide, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterExternalPoint.write(value.start, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterSequenceExternalDirection.write(value.steps, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Journey value) {
+        return FfiConverterExternalProcTraitMethods.allocationSize(value.guide) +FfiConverterExternalPoint.allocationSize(value.start) +FfiConverterSequenceExternalDirection.allocationSize(value.steps) + 0;
+    }
+}
+
+class UniffiInternalError implements Exception {
+    static const int bufferOverflow = 0;
+    static const int incompleteData = 1;
+    static const int unexpectedOptionalTag = 2;
+    static const int unexpectedEnumCase = 3;
+    static const int unexpectedNullPointer = 4;
+    static const int unexpectedRustCallStatusCode = 5;
+    static const int unexpectedRustCallError = 6;
+    static const int unexpectedStaleHandle = 7;
+    static const int rustPanic = 8;
+
+    final int errorCode;
+    final String? panicMessage;
+
+    const UniffiInternalError(this.errorCode, this.panicMessage);
+
+    static UniffiInternalError panicked(String message) {
+    return UniffiInternalError(rustPanic, message);
+    }
+
+    @override
+    String toString() {
+    switch (errorCode) {
+        case bufferOverflow:
+        return "UniFfi::BufferOverflow";
+        case incompleteData:
+        return "UniFfi::IncompleteData";
+        case unexpectedOptionalTag:
+        return "UniFfi::UnexpectedOptionalTag";
+        case unexpectedEnumCase:
+        return "UniFfi::UnexpectedEnumCase";
+        case unexpectedNullPointer:
+        return "UniFfi::UnexpectedNullPointer";
+        case unexpectedRustCallStatusCode:
+        return "UniFfi::UnexpectedRustCallStatusCode";
+        case unexpectedRustCallError:
+        return "UniFfi::UnexpectedRustCallError";
+        case unexpectedStaleHandle:
+        return "UniFfi::UnexpectedStaleHandle";
+        case rustPanic:
+        return "UniFfi::rustPanic: \$\$panicMessage";
+        default:
+        return "UniFfi::UnknownError: \$\$errorCode";
+    }
+    }
+}
+
+const int CALL_SUCCESS = 0;
+const int CALL_ERROR = 1;
+const int CALL_UNEXPECTED_ERROR = 2;
+
+final class RustCallStatus extends Struct {
+    @Int8()
+    external int code;
+
+    external RustBuffer errorBuf;
+
+}
+
+void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
+
+    if (status.ref.code == CALL_SUCCESS) {
+    return;
+    } else if (status.ref.code == CALL_ERROR) {
+    throw errorHandler.lift(status.ref.errorBuf);
+    } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+    if (status.ref.errorBuf.len > 0) {
+        throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
+    } else {
+        throw UniffiInternalError.panicked("Rust panic");
+    }
+    } else {
+    throw UniffiInternalError.panicked("Unexpected RustCallStatus code: \${status.ref.code}");
+    }
+}
+
+#[doc=" Allocates the native memory the bindings pass to Rust for the duration of a call."]
+const Allocator _uniffiAllocator = calloc;
+
+T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+    final status = _uniffiAllocator<RustCallStatus>();
+    try {
+    final result = callback(status);
+    checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+    return result;
+    } finally {
+    _uniffiAllocator.free(status);
+    }
+}
+
+class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+    errorBuf.free();
+    return UniffiInternalError.panicked("Unexpected CALL_ERROR");
+    }
+}
+
+abstract class UniffiRustCallStatusErrorHandler {
+    Exception lift(RustBuffer errorBuf);
+}
+
+final class RustBuffer extends Struct {
+    @Uint64()
+    external int capacity;
+
+    @Uint64()
+    external int len;
+
+    external Pointer<Uint8> data;
+
+    static RustBuffer alloc(int size) {
+        return rustCall((status) => _UniffiLib.instance.ffi_external_types_rustbuffer_alloc(size, status));
+    }
+
+    static RustBuffer fromBytes(ForeignBytes bytes) {
+        return rustCall((status) => _UniffiLib.instance.ffi_external_types_rustbuffer_from_bytes(bytes, status));
+    }
+
+    void free() {
+        rustCall((status) => _UniffiLib.instance.ffi_external_types_rustbuffer_free(this, status));
+    }
+
+    RustBuffer reserve(int additionalCapacity) {
+    return rustCall((status) => _UniffiLib.instance.ffi_external_types_rustbuffer_reserve(this, additionalCapacity, status));
+    }
+
+    Uint8List asUint8List() {
+    final dataList = data.asTypedList(len);
+    final byteData = ByteData.sublistView(dataList);
+    return Uint8List.view(byteData.buffer);
+    }
+
+    @override
+    String toString() {
+    return "RustBuffer{capacity: \$capacity, len: \$len, data: \$data}";
+    }
+}
+
+RustBuffer toRustBuffer(Uint8List data) {
+    final length = data.length;
+
+    final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length);
+    final pointerList = frameData.asTypedList(length);
+    pointerList.setAll(0, data);
+
+    final bytes = _uniffiAllocator<ForeignBytes>();
+    bytes.ref.len = length;
+    bytes.ref.data = frameData;
+    try {
+        return RustBuffer.fromBytes(bytes.ref);
+    } finally {
+        _uniffiAllocator.free(frameData);
+        _uniffiAllocator.free(bytes);
+    }
+}
+
+T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+    try {
+        return lift(buf.asUint8List());
+    } finally {
+        buf.free();
+    }
+}
+
+final class ForeignBytes extends Struct {
+    @Int32()
+    external int len;
+    external Pointer<Uint8> data;
+
+    void free() {
+    _uniffiAllocator.free(data);
+    }
+}
+
+class LiftRetVal<T> {
+    final T value;
+    final int bytesRead;
+    const LiftRetVal(this.value, this.bytesRead);
+
+    LiftRetVal<T> copyWithOffset(int offset) {
+        return LiftRetVal(value, bytesRead + offset);
+    }
+}
+
+abstract class FfiConverter<D, F> {
+    const FfiConverter();
+
+    D lift(F value);
+    F lower(D value);
+    D read(ByteData buffer, int offset);
+    void write(D value, ByteData buffer, int offset);
+    int size(D value);
+}
+
+mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+    @override
+    T lift(T value) => value;
+
+    @override
+    T lower(T value) => value;
+}
+
+class FfiConverterExternalDirection {
+    static map_types.Direction lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterExternalDirection.read(data).value);
+    }
+
+    static RustBuffer lower( map_types.Direction value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static LiftRetVal<map_types.Direction> read( Uint8List buf) {
+        final lifted = map_types.FfiConverterDirection.read(buf);
+        return LiftRetVal(lifted.value, lifted.bytesRead);
+    }
+
+    static int allocationSize(map_types.Direction value) {
+        return map_types.FfiConverterDirection.allocationSize(value);
+    }
+
+    static int write( map_types.Direction value, Uint8List buf) {
+        return map_types.FfiConverterDirection.write(value, buf);
+    }
+}class FfiConverterExternalPlugin {
+    static trait_interfaces.Plugin lift( Pointer<Void> value) {
+        return trait_interfaces.FfiConverterPlugin.lift(value);
+    }
+
+    static Pointer<Void> lower( trait_interfaces.Plugin value) {
+        return trait_interfaces.FfiConverterPlugin.lower(value);
+    }
+
+    static LiftRetVal<trait_interfaces.Plugin> read( Uint8List buf) {
+        return trait_interfaces.FfiConverterPlugin.read(buf);
+    }
+
+    static int allocationSize([trait_interfaces.Plugin? value]) {
+        return 8;
+    }
+
+    static int write( trait_interfaces.Plugin value, Uint8List buf) {
+        return trait_interfaces.FfiConverterPlugin.write(value, buf);
+    }
+}class FfiConverterExternalPoint {
+    static map_types.Point lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterExternalPoint.read(data).value);
+    }
+
+    static RustBuffer lower( map_types.Point value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static LiftRetVal<map_types.Point> read( Uint8List buf) {
+        final lifted = map_types.FfiConverterPoint.read(buf);
+        return LiftRetVal(lifted.value, lifted.bytesRead);
+    }
+
+    static int allocationSize(map_types.Point value) {
+        return map_types.FfiConverterPoint.allocationSize(value);
+    }
+
+    static int write( map_types.Point value, Uint8List buf) {
+        return map_types.FfiConverterPoint.write(value, buf);
+    }
+}class FfiConverterExternalProcTraitMethods {
+    static trait_methods.ProcTraitMethods lift( Pointer<Void> value) {
+        return trait_methods.FfiConverterProcTraitMethods.lift(value);
+    }
+
+    static Pointer<Void> lower( trait_methods.ProcTraitMethods value) {
+        return trait_methods.FfiConverterProcTraitMethods.lower(value);
+    }
+
+    static LiftRetVal<trait_methods.ProcTraitMethods> read( Uint8List buf) {
+        return trait_methods.FfiConverterProcTraitMethods.read(buf);
+    }
+
+    static int allocationSize([trait_methods.ProcTraitMethods? value]) {
+        return 8;
+    }
+
+    static int write( trait_methods.ProcTraitMethods value, Uint8List buf) {
+        return trait_methods.FfiConverterProcTraitMethods.write(value, buf);
+    }
+}class FfiConverterInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value);
+        return FfiConverterInt32.allocationSize();
+    }
+
+}class FfiConverterOptionalExternalDirection {
+
+    static map_types.Direction? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalExternalDirection.read(data).value);
+    }
+
+    static LiftRetVal<map_types.Direction?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterExternalDirection.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([map_types.Direction? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterExternalDirection.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( map_types.Direction? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalExternalDirection.allocationSize(value));
+        FfiConverterOptionalExternalDirection.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( map_types.Direction? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterExternalDirection.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterSequenceExternalDirection {
+
+    static List<map_types.Direction> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceExternalDirection.read(data).value);
+    }
+
+    static LiftRetVal<List<map_types.Direction>> read( Uint8List buf) {
+        List<map_types.Direction> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterExternalDirection.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<map_types.Direction> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterExternalDirection.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<map_types.Direction> value) {
+        return value.map((l) => FfiConverterExternalDirection.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<map_types.Direction> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterString {
+    static String lift( RustBuffer buf) {
+
+        return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
+    }
+
+    static RustBuffer lower( String value) {
+        return toRustBuffer(Utf8Encoder().convert(value));
+    }
+
+    static LiftRetVal<String> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+        return LiftRetVal(utf8.decoder.convert(buf, 4, end), end);
+    }
+
+    static int allocationSize([String value = ""]) {
+
+        return utf8.encoder.convert(value).length + 4;
+    }
+
+    static int write( String value, Uint8List buf) {
+
+        final list = utf8.encoder.convert(value);
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length);
+        buf.setAll(4, list);
+        return list.length + 4;
+    }
+}
+
+const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
+
+typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+Future<T> uniffiRustCallAsync<T, F>(
+    Pointer<Void> Function() rustFutureFunc,
+    void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc,
+    F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc,
+    void Function(Pointer<Void>) freeFunc,
+    T Function(F) liftFunc, [
+    UniffiRustCallStatusErrorHandler? errorHandler,
+]) async {
+    final rustFuture = rustFutureFunc();
+    final completer = Completer<int>();
+
+    late final NativeCallable<UniffiRustFutureContinuationCallback> callback;
+
+    void poll() {
+        pollFunc(
+            rustFuture,
+            callback.nativeFunction,
+            Pointer<Void>.fromAddress(0),
+        );
+    }
+    void onResponse(int _idx, int pollResult) {
+        if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+            completer.complete(pollResult);
+        } else {
+            poll();
+        }
+    }
+    callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener(onResponse);
+
+    try {
+        poll();
+        await completer.future;
+        callback.close();
+
+        final status = _uniffiAllocator<RustCallStatus>();
+        try {
+            final result = completeFunc(rustFuture, status);
+            checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+            return liftFunc(result);
+        } finally {
+            _uniffiAllocator.free(status);
+        }
+    } finally {
+        freeFunc(rustFuture);
+    }
+}
+
+class UniffiHandleMap<T> {
+    final Map<int, T> _map = {};
+    int _counter = 0;
+
+    int insert(T obj) {
+    final handle = _counter++;
+    _map[handle] = obj;
+    return handle;
+    }
+
+    T get(int handle) {
+    final obj = _map[handle];
+    if (obj == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    return obj;
+    }
+
+    void remove(int handle) {
+    if (_map.remove(handle) == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    }
+}
+
+int applyPerStep(trait_interfaces.Plugin plugin,Journey journey,int input,) {
+    return rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_external_types_fn_func_apply_per_step(FfiConverterExternalPlugin.lower(plugin),FfiConverterJourney.lower(journey),input, uniffiStatus), NullRustCallStatusErrorHandler());
+}Future<map_types.Point> asyncDestination(Journey journey,) {
+    return uniffiRustCallAsync(
+        () => _UniffiLib.instance.uniffi_external_types_fn_func_async_destination(
+            FfiConverterJourney.lower(journey),
+        ),
+        _UniffiLib.instance.ffi_external_types_rust_future_poll_rust_buffer,
+        _UniffiLib.instance.ffi_external_types_rust_future_complete_rust_buffer,
+        _UniffiLib.instance.ffi_external_types_rust_future_free_rust_buffer,
+        FfiConverterExternalPoint.lift,
+        NullRustCallStatusErrorHandler(),
+    );
+}map_types.Point destination(Journey journey,) {
+    return FfiConverterExternalPoint.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_external_types_fn_func_destination(FfiConverterJourney.lower(journey), uniffiStatus), NullRustCallStatusErrorHandler()));
+}trait_methods.ProcTraitMethods journeyGuide(Journey journey,) {
+    return FfiConverterExternalProcTraitMethods.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_external_types_fn_func_journey_guide(FfiConverterJourney.lower(journey), uniffiStatus), NullRustCallStatusErrorHandler()));
+}map_types.Direction? optionalDirection(map_types.Direction? direction,) {
+    return FfiConverterOptionalExternalDirection.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_external_types_fn_func_optional_direction(FfiConverterOptionalExternalDirection.lower(direction), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Journey startJourney(trait_methods.ProcTraitMethods guide,map_types.Point start,) {
+    return FfiConverterJourney.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_external_types_fn_func_start_journey(FfiConverterExternalProcTraitMethods.lower(guide),FfiConverterExternalPoint.lower(start), uniffiStatus), NullRustCallStatusErrorHandler()));
+}trait_interfaces.Plugin stepPlugin() {
+    return FfiConverterExternalPlugin.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_external_types_fn_func_step_plugin( uniffiStatus), NullRustCallStatusErrorHandler()));
+}Journey walk(Journey journey,map_types.Direction direction,) {
+    return FfiConverterJourney.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_external_types_fn_func_walk(FfiConverterJourney.lower(journey),FfiConverterExternalDirection.lower(direction), uniffiStatus), NullRustCallStatusErrorHandler()));
+}
+
+class _UniffiLib {
+    _UniffiLib._();
+
+    static final DynamicLibrary _dylib = _open();
+
+    static DynamicLibrary _open() {
+        if (Platform.isAndroid) return DynamicLibrary.open("libexternal_types.so");
+        if (Platform.isIOS) return DynamicLibrary.executable();
+        if (Platform.isLinux) return DynamicLibrary.open("libexternal_types.so");
+        if (Platform.isMacOS) return DynamicLibrary.open("libexternal_types.dylib");
+        if (Platform.isWindows) return DynamicLibrary.open("external_types.dll");
+        throw UnsupportedError("Unsupported platform: \${Platform.operatingSystem}");
+    }
+
+    static final _UniffiLib instance = _UniffiLib._();
+
+    late final int Function(Pointer<Void>,RustBuffer,int,Pointer<RustCallStatus>) uniffi_external_types_fn_func_apply_per_step = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,RustBuffer,Int32,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,RustBuffer,int,Pointer<RustCallStatus>)
+    >("uniffi_external_types_fn_func_apply_per_step");late final Pointer<Void> Function(RustBuffer,) uniffi_external_types_fn_func_async_destination = _dylib.lookupFunction<
+        Pointer<Void> Function(RustBuffer,),
+        Pointer<Void> Function(RustBuffer,)
+    >("uniffi_external_types_fn_func_async_destination");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_external_types_fn_func_destination = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_external_types_fn_func_destination");late final Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>) uniffi_external_types_fn_func_journey_guide = _dylib.lookupFunction<
+        Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>),
+        Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_external_types_fn_func_journey_guide");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_external_types_fn_func_optional_direction = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_external_types_fn_func_optional_direction");late final RustBuffer Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>) uniffi_external_types_fn_func_start_journey = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_external_types_fn_func_start_journey");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_external_types_fn_func_step_plugin = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_external_types_fn_func_step_plugin");late final RustBuffer Function(RustBuffer,RustBuffer,Pointer<RustCallStatus>) uniffi_external_types_fn_func_walk = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_external_types_fn_func_walk");late final RustBuffer Function(int,Pointer<RustCallStatus>) ffi_external_types_rustbuffer_alloc = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("ffi_external_types_rustbuffer_alloc");late final RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>) ffi_external_types_rustbuffer_from_bytes = _dylib.lookupFunction<
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>),
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>)
+    >("ffi_external_types_rustbuffer_from_bytes");late final void Function(RustBuffer,Pointer<RustCallStatus>) ffi_external_types_rustbuffer_free = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("ffi_external_types_rustbuffer_free");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) ffi_external_types_rustbuffer_reserve = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("ffi_external_types_rustbuffer_reserve");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_external_types_rust_future_poll_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_external_types_rust_future_poll_u8");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_cancel_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_cancel_u8");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_free_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_free_u8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_external_types_rust_future_complete_u8 = _dylib.lookupFunction<
+        Uint8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_external_types_rust_future_complete_u8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_external_types_rust_future_poll_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_external_types_rust_future_poll_i8");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_cancel_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_cancel_i8");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_free_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_free_i8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_external_types_rust_future_complete_i8 = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_external_types_rust_future_complete_i8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_external_types_rust_future_poll_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_external_types_rust_future_poll_u16");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_cancel_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_cancel_u16");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_free_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_free_u16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_external_types_rust_future_complete_u16 = _dylib.lookupFunction<
+        Uint16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_external_types_rust_future_complete_u16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_external_types_rust_future_poll_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_external_types_rust_future_poll_i16");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_cancel_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_cancel_i16");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_free_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_free_i16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_external_types_rust_future_complete_i16 = _dylib.lookupFunction<
+        Int16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_external_types_rust_future_complete_i16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_external_types_rust_future_poll_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_external_types_rust_future_poll_u32");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_cancel_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_cancel_u32");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_free_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_free_u32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_external_types_rust_future_complete_u32 = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_external_types_rust_future_complete_u32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_external_types_rust_future_poll_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_external_types_rust_future_poll_i32");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_cancel_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_cancel_i32");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_free_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_free_i32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_external_types_rust_future_complete_i32 = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_external_types_rust_future_complete_i32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_external_types_rust_future_poll_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_external_types_rust_future_poll_u64");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_cancel_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_cancel_u64");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_free_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_free_u64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_external_types_rust_future_complete_u64 = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_external_types_rust_future_complete_u64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_external_types_rust_future_poll_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_external_types_rust_future_poll_i64");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_cancel_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_cancel_i64");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_free_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_free_i64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_external_types_rust_future_complete_i64 = _dylib.lookupFunction<
+        Int64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_external_types_rust_future_complete_i64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_external_types_rust_future_poll_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_external_types_rust_future_poll_f32");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_cancel_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_cancel_f32");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_free_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_free_f32");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_external_types_rust_future_complete_f32 = _dylib.lookupFunction<
+        Float Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_external_types_rust_future_complete_f32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_external_types_rust_future_poll_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_external_types_rust_future_poll_f64");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_cancel_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_cancel_f64");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_free_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_free_f64");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_external_types_rust_future_complete_f64 = _dylib.lookupFunction<
+        Double Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_external_types_rust_future_complete_f64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_external_types_rust_future_poll_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_external_types_rust_future_poll_pointer");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_cancel_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_cancel_pointer");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_free_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_free_pointer");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_external_types_rust_future_complete_pointer = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_external_types_rust_future_complete_pointer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_external_types_rust_future_poll_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_external_types_rust_future_poll_rust_buffer");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_cancel_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_cancel_rust_buffer");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_free_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_free_rust_buffer");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_external_types_rust_future_complete_rust_buffer = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_external_types_rust_future_complete_rust_buffer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_external_types_rust_future_poll_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_external_types_rust_future_poll_void");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_cancel_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_cancel_void");late final void Function(Pointer<Void>,) ffi_external_types_rust_future_free_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_external_types_rust_future_free_void");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_external_types_rust_future_complete_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_external_types_rust_future_complete_void");late final int Function() uniffi_external_types_checksum_func_apply_per_step = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_external_types_checksum_func_apply_per_step");late final int Function() uniffi_external_types_checksum_func_async_destination = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_external_types_checksum_func_async_destination");late final int Function() uniffi_external_types_checksum_func_destination = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_external_types_checksum_func_destination");late final int Function() uniffi_external_types_checksum_func_journey_guide = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_external_types_checksum_func_journey_guide");late final int Function() uniffi_external_types_checksum_func_optional_direction = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_external_types_checksum_func_optional_direction");late final int Function() uniffi_external_types_checksum_func_start_journey = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_external_types_checksum_func_start_journey");late final int Function() uniffi_external_types_checksum_func_step_plugin = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_external_types_checksum_func_step_plugin");late final int Function() uniffi_external_types_checksum_func_walk = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_external_types_checksum_func_walk");late final int Function() ffi_external_types_uniffi_contract_version = _dylib.lookupFunction<
+        Uint32 Function(),
+        int Function()
+    >("ffi_external_types_uniffi_contract_version");
+
+    static void _checkApiVersion() {
+        final bindingsVersion = 26;
+        final scaffoldingVersion = _UniffiLib.instance.ffi_external_types_uniffi_contract_version();
+        if (bindingsVersion != scaffoldingVersion) {
+            throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version \$bindingsVersion, scaffolding version \$scaffoldingVersion");
+        }
+    }
+
+    static void _checkApiChecksums() {
+        if (_UniffiLib.instance.uniffi_external_types_checksum_func_apply_per_step() != 53293) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_external_types_checksum_func_async_destination() != 41755) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_external_types_checksum_func_destination() != 46703) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_external_types_checksum_func_journey_guide() != 50057) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_external_types_checksum_func_optional_direction() != 54310) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_external_types_checksum_func_start_journey() != 37067) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_external_types_checksum_func_step_plugin() != 33075) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_external_types_checksum_func_walk() != 37238) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }
+    }
+}
+
+void initialize() {
+    _UniffiLib._open();
+}
+
+void ensureInitialized() {
+    _UniffiLib._checkApiVersion();
+    _UniffiLib._checkApiChecksums();
+}
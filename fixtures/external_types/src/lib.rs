@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use map_types::{Direction, Point};
+use trait_interfaces::Plugin;
+use trait_methods::ProcTraitMethods;
+
+#[derive(uniffi::Record)]
+pub struct Journey {
+    guide: Arc<ProcTraitMethods>,
+    start: Point,
+    steps: Vec<Direction>,
+}
+
+#[uniffi::export]
+pub fn start_journey(guide: Arc<ProcTraitMethods>, start: Point) -> Journey {
+    Journey {
+        guide,
+        start,
+        steps: vec![],
+    }
+}
+
+#[uniffi::export]
+pub fn walk(journey: Journey, direction: Direction) -> Journey {
+    let mut steps = journey.steps;
+    steps.push(direction);
+    Journey { steps, ..journey }
+}
+
+#[uniffi::export]
+pub fn destination(journey: Journey) -> Point {
+    journey
+        .steps
+        .into_iter()
+        .fold(journey.start, |point, direction| {
+            map_types::move_all(HashMap::from([(direction, point)]))
+                .remove(&direction)
+                .unwrap()
+        })
+}
+
+#[uniffi::export]
+pub fn journey_guide(journey: Journey) -> Arc<ProcTraitMethods> {
+    journey.guide
+}
+
+#[uniffi::export]
+pub fn step_plugin() -> Arc<dyn Plugin> {
+    trait_interfaces::make_adder(1)
+}
+
+/// Applies an external trait interface once per step of the journey.
+#[uniffi::export]
+pub fn apply_per_step(plugin: Arc<dyn Plugin>, journey: Journey, input: i32) -> i32 {
+    journey
+        .steps
+        .iter()
+        .fold(input, |value, _| plugin.apply(value))
+}
+
+#[uniffi::export]
+pub fn optional_direction(direction: Option<Direction>) -> Option<Direction> {
+    direction
+}
+
+#[uniffi::export]
+pub async fn async_destination(journey: Journey) -> Point {
+    destination(journey)
+}
+
+uniffi::setup_scaffolding!();
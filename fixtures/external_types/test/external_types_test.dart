@@ -0,0 +1,52 @@
+import 'package:test/test.dart';
+import '../external_types.dart';
+import '../map_types.dart';
+import '../trait_interfaces.dart';
+import '../trait_methods.dart';
+
+void main() {
+  test('records holding external types', () {
+    var journey = startJourney(ProcTraitMethods("atlas"), Point(x: 0, y: 0));
+    expect(journey.start.x, 0);
+    expect(journey.steps, isEmpty);
+
+    journey = walk(journey, Direction.north);
+    journey = walk(journey, Direction.east);
+    expect(journey.steps, [Direction.north, Direction.east]);
+    expect(journey.guide, ProcTraitMethods("atlas"));
+  });
+
+  test('external records and enums as arguments and return values', () {
+    var journey = startJourney(ProcTraitMethods("gaia"), Point(x: 2, y: 3));
+    journey = walk(journey, Direction.south);
+    journey = walk(journey, Direction.west);
+    final point = destination(journey);
+    expect(point.x, 1);
+    expect(point.y, 2);
+  });
+
+  test('external objects', () {
+    final journey = startJourney(ProcTraitMethods("terra"), Point(x: 0, y: 0));
+    expect(journeyGuide(journey), ProcTraitMethods("terra"));
+  });
+
+  test('external trait interfaces', () {
+    final plugin = stepPlugin();
+    expect(plugin, isA<Plugin>());
+    expect(plugin.name(), 'add 1');
+    final journey = walk(walk(startJourney(ProcTraitMethods("iris"), Point(x: 0, y: 0)), Direction.north), Direction.east);
+    expect(applyPerStep(plugin, journey, 10), 12);
+    expect(applyPerStep(makeDoubler(), journey, 3), 12);
+  });
+
+  test('optional external types', () {
+    expect(optionalDirection(Direction.north), Direction.north);
+    expect(optionalDirection(null), null);
+  });
+
+  test('async external types', () async {
+    final journey = walk(startJourney(ProcTraitMethods("luna"), Point(x: 0, y: 0)), Direction.north);
+    final point = await asyncDestination(journey);
+    expect(point.y, 1);
+  });
+}
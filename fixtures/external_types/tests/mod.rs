@@ -0,0 +1,11 @@
+use anyhow::Result;
+
+#[test]
+fn external_types() -> Result<()> {
+    uniffi_dart::testing::run_library_test("external_types", None)
+}
+
+#[test]
+fn external_types_golden() -> Result<()> {
+    uniffi_dart::testing::assert_library_golden("external_types", None)
+}
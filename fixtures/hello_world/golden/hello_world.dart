@@ -0,0 +1,865 @@
+library hello_world;
+
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io" show Platform, File, Directory;
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+class WorldState {
+    final int inhabitants;final String? name;
+
+    WorldState({required this.inhabitants,required this.name,});
+}
+
+class FfiConverterWorldState {
+    static WorldState lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterWorldState.read(data).value);
+    }
+
+    static LiftRetVal<WorldState> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final inhabitants_lifted = FfiConverterUInt8.read(Uint8List.view(buf.buffer, new_offset));
+        final inhabitants = inhabitants_lifted.value;
+        new_offset += inhabitants_lifted.bytesRead;final name_lifted = FfiConverterOptionalString.read(Uint8List.view(buf.buffer, new_offset));
+        final name = name_lifted.value;
+        new_offset += name_lifted.bytesRead;
+        return LiftRetVal(WorldState(
+            inhabitants: inhabitants,name: name,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( WorldState value) {
+        final total_length = FfiConverterUInt8.allocationSize(value.inhabitants) +FfiConverterOptionalString.allocationSize(value.name) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( WorldState value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterUInt8.write(value.inhabitants, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterOptionalString.write(value.name, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(WorldState value) {
+        return FfiConverterUInt8.allocationSize(value.inhabitants) +FfiConverterOptionalString.allocationSize(value.name) + 0;
+    }
+}
+
+abstract class WorldInterface {
+    World incInhabitants();
+    bool isThere();
+    String? name();
+    String? prefixedName(String? inp,);
+    World setName(String? inp,);
+    WorldState state();
+}
+
+final _WorldFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _WorldFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_hello_world_fn_free_world");
+
+class World implements WorldInterface, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    World._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_WorldFree, _ptr);
+        _WorldFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    factory World.lift(Pointer<Void> ptr) {
+        return World._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("World has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_hello_world_fn_clone_world(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _WorldFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_hello_world_fn_free_world(_ptr, status));
+    }
+
+    @override
+    World incInhabitants() {
+        return FfiConverterWorld.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_hello_world_fn_method_world_inc_inhabitants(uniffiClonePointer(),
+         uniffiStatus), NullRustCallStatusErrorHandler()));
+    }@override
+    bool isThere() {
+        return FfiConverterBool.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_hello_world_fn_method_world_is_there(uniffiClonePointer(),
+         uniffiStatus), NullRustCallStatusErrorHandler()));
+    }@override
+    String? name() {
+        return FfiConverterOptionalString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_hello_world_fn_method_world_name(uniffiClonePointer(),
+         uniffiStatus), NullRustCallStatusErrorHandler()));
+    }@override
+    String? prefixedName(String? inp,) {
+        return FfiConverterOptionalString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_hello_world_fn_method_world_prefixed_name(uniffiClonePointer(),
+        FfiConverterOptionalString.lower(inp), uniffiStatus), NullRustCallStatusErrorHandler()));
+    }@override
+    World setName(String? inp,) {
+        return FfiConverterWorld.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_hello_world_fn_method_world_set_name(uniffiClonePointer(),
+        FfiConverterOptionalString.lower(inp), uniffiStatus), NullRustCallStatusErrorHandler()));
+    }@override
+    WorldState state() {
+        return FfiConverterWorldState.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_hello_world_fn_method_world_state(uniffiClonePointer(),
+         uniffiStatus), NullRustCallStatusErrorHandler()));
+    }
+}
+
+class FfiConverterWorld {
+    static World lift( Pointer<Void> value) {
+        return World.lift(value);
+    }
+
+    static Pointer<Void> lower( World value) {
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<World> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([World? value]) {
+        return 8;
+    }
+
+    static int write( World value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+class UniffiInternalError implements Exception {
+    static const int bufferOverflow = 0;
+    static const int incompleteData = 1;
+    static const int unexpectedOptionalTag = 2;
+    static const int unexpectedEnumCase = 3;
+    static const int unexpectedNullPointer = 4;
+    static const int unexpectedRustCallStatusCode = 5;
+    static const int unexpectedRustCallError = 6;
+    static const int unexpectedStaleHandle = 7;
+    static const int rustPanic = 8;
+
+    final int errorCode;
+    final String? panicMessage;
+
+    const UniffiInternalError(this.errorCode, this.panicMessage);
+
+    static UniffiInternalError panicked(String message) {
+    return UniffiInternalError(rustPanic, message);
+    }
+
+    @override
+    String toString() {
+    switch (errorCode) {
+        case bufferOverflow:
+        return "UniFfi::BufferOverflow";
+        case incompleteData:
+        return "UniFfi::IncompleteData";
+        case unexpectedOptionalTag:
+        return "UniFfi::UnexpectedOptionalTag";
+        case unexpectedEnumCase:
+        return "UniFfi::UnexpectedEnumCase";
+        case unexpectedNullPointer:
+        return "UniFfi::UnexpectedNullPointer";
+        case unexpectedRustCallStatusCode:
+        return "UniFfi::UnexpectedRustCallStatusCode";
+        case unexpectedRustCallError:
+        return "UniFfi::UnexpectedRustCallError";
+        case unexpectedStaleHandle:
+        return "UniFfi::UnexpectedStaleHandle";
+        case rustPanic:
+        return "UniFfi::rustPanic: \$\$panicMessage";
+        default:
+        return "UniFfi::UnknownError: \$\$errorCode";
+    }
+    }
+}
+
+const int CALL_SUCCESS = 0;
+const int CALL_ERROR = 1;
+const int CALL_UNEXPECTED_ERROR = 2;
+
+final class RustCallStatus extends Struct {
+    @Int8()
+    external int code;
+
+    external RustBuffer errorBuf;
+
+}
+
+void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
+
+    if (status.ref.code == CALL_SUCCESS) {
+    return;
+    } else if (status.ref.code == CALL_ERROR) {
+    throw errorHandler.lift(status.ref.errorBuf);
+    } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+    if (status.ref.errorBuf.len > 0) {
+        throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
+    } else {
+        throw UniffiInternalError.panicked("Rust panic");
+    }
+    } else {
+    throw UniffiInternalError.panicked("Unexpected RustCallStatus code: \${status.ref.code}");
+    }
+}
+
+#[doc=" Allocates the native memory the bindings pass to Rust for the duration of a call."]
+const Allocator _uniffiAllocator = calloc;
+
+T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+    final status = _uniffiAllocator<RustCallStatus>();
+    try {
+    final result = callback(status);
+    checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+    return result;
+    } finally {
+    _uniffiAllocator.free(status);
+    }
+}
+
+class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+    errorBuf.free();
+    return UniffiInternalError.panicked("Unexpected CALL_ERROR");
+    }
+}
+
+abstract class UniffiRustCallStatusErrorHandler {
+    Exception lift(RustBuffer errorBuf);
+}
+
+final class RustBuffer extends Struct {
+    @Uint64()
+    external int capacity;
+
+    @Uint64()
+    external int len;
+
+    external Pointer<Uint8> data;
+
+    static RustBuffer alloc(int size) {
+        return rustCall((status) => _UniffiLib.instance.ffi_hello_world_rustbuffer_alloc(size, status));
+    }
+
+    static RustBuffer fromBytes(ForeignBytes bytes) {
+        return rustCall((status) => _UniffiLib.instance.ffi_hello_world_rustbuffer_from_bytes(bytes, status));
+    }
+
+    void free() {
+        rustCall((status) => _UniffiLib.instance.ffi_hello_world_rustbuffer_free(this, status));
+    }
+
+    RustBuffer reserve(int additionalCapacity) {
+    return rustCall((status) => _UniffiLib.instance.ffi_hello_world_rustbuffer_reserve(this, additionalCapacity, status));
+    }
+
+    Uint8List asUint8List() {
+    final dataList = data.asTypedList(len);
+    final byteData = ByteData.sublistView(dataList);
+    return Uint8List.view(byteData.buffer);
+    }
+
+    @override
+    String toString() {
+    return "RustBuffer{capacity: \$capacity, len: \$len, data: \$data}";
+    }
+}
+
+RustBuffer toRustBuffer(Uint8List data) {
+    final length = data.length;
+
+    final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length);
+    final pointerList = frameData.asTypedList(length);
+    pointerList.setAll(0, data);
+
+    final bytes = _uniffiAllocator<ForeignBytes>();
+    bytes.ref.len = length;
+    bytes.ref.data = frameData;
+    try {
+        return RustBuffer.fromBytes(bytes.ref);
+    } finally {
+        _uniffiAllocator.free(frameData);
+        _uniffiAllocator.free(bytes);
+    }
+}
+
+T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+    try {
+        return lift(buf.asUint8List());
+    } finally {
+        buf.free();
+    }
+}
+
+final class ForeignBytes extends Struct {
+    @Int32()
+    external int len;
+    external Pointer<Uint8> data;
+
+    void free() {
+    _uniffiAllocator.free(data);
+    }
+}
+
+class LiftRetVal<T> {
+    final T value;
+    final int bytesRead;
+    const LiftRetVal(this.value, this.bytesRead);
+
+    LiftRetVal<T> copyWithOffset(int offset) {
+        return LiftRetVal(value, bytesRead + offset);
+    }
+}
+
+abstract class FfiConverter<D, F> {
+    const FfiConverter();
+
+    D lift(F value);
+    F lower(D value);
+    D read(ByteData buffer, int offset);
+    void write(D value, ByteData buffer, int offset);
+    int size(D value);
+}
+
+mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+    @override
+    T lift(T value) => value;
+
+    @override
+    T lower(T value) => value;
+}
+
+class FfiConverterBool {
+
+    static bool lift( int value) {
+        return value == 1;
+    }
+
+    static int lower( bool value) {
+        return value ? 1 :0;
+    }
+
+    static LiftRetVal<bool> read( Uint8List buf) {
+        return LiftRetVal(FfiConverterBool.lift(buf.first), 1);
+    }
+
+    static RustBuffer lowerIntoRustBuffer( bool value) {
+        return toRustBuffer(Uint8List.fromList([FfiConverterBool.lower(value)]));
+    }
+
+    static int allocationSize([bool value = false]) {
+        return 1;
+    }
+
+    static int write( bool value, Uint8List buf) {
+        buf.setAll(0, [value ? 1 : 0]);
+        return allocationSize();
+    }
+}class FfiConverterOptionalString {
+
+    static String? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalString.read(data).value);
+    }
+
+    static LiftRetVal<String?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterString.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([String? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterString.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( String? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalString.allocationSize(value));
+        FfiConverterOptionalString.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( String? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterString.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterString {
+    static String lift( RustBuffer buf) {
+
+        return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
+    }
+
+    static RustBuffer lower( String value) {
+        return toRustBuffer(Utf8Encoder().convert(value));
+    }
+
+    static LiftRetVal<String> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+        return LiftRetVal(utf8.decoder.convert(buf, 4, end), end);
+    }
+
+    static int allocationSize([String value = ""]) {
+
+        return utf8.encoder.convert(value).length + 4;
+    }
+
+    static int write( String value, Uint8List buf) {
+
+        final list = utf8.encoder.convert(value);
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length);
+        buf.setAll(4, list);
+        return list.length + 4;
+    }
+}class FfiConverterUInt8 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint8(0), 1);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 1;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint8(0, value);
+        return FfiConverterUInt8.allocationSize();
+    }
+
+}
+
+const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
+
+typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+Future<T> uniffiRustCallAsync<T, F>(
+    Pointer<Void> Function() rustFutureFunc,
+    void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc,
+    F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc,
+    void Function(Pointer<Void>) freeFunc,
+    T Function(F) liftFunc, [
+    UniffiRustCallStatusErrorHandler? errorHandler,
+]) async {
+    final rustFuture = rustFutureFunc();
+    final completer = Completer<int>();
+
+    late final NativeCallable<UniffiRustFutureContinuationCallback> callback;
+
+    void poll() {
+        pollFunc(
+            rustFuture,
+            callback.nativeFunction,
+            Pointer<Void>.fromAddress(0),
+        );
+    }
+    void onResponse(int _idx, int pollResult) {
+        if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+            completer.complete(pollResult);
+        } else {
+            poll();
+        }
+    }
+    callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener(onResponse);
+
+    try {
+        poll();
+        await completer.future;
+        callback.close();
+
+        final status = _uniffiAllocator<RustCallStatus>();
+        try {
+            final result = completeFunc(rustFuture, status);
+            checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+            return liftFunc(result);
+        } finally {
+            _uniffiAllocator.free(status);
+        }
+    } finally {
+        freeFunc(rustFuture);
+    }
+}
+
+class UniffiHandleMap<T> {
+    final Map<int, T> _map = {};
+    int _counter = 0;
+
+    int insert(T obj) {
+    final handle = _counter++;
+    _map[handle] = obj;
+    return handle;
+    }
+
+    T get(int handle) {
+    final obj = _map[handle];
+    if (obj == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    return obj;
+    }
+
+    void remove(int handle) {
+    if (_map.remove(handle) == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    }
+}
+
+String hello(String input,) {
+    return FfiConverterString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_hello_world_fn_func_hello(FfiConverterString.lower(input), uniffiStatus), NullRustCallStatusErrorHandler()));
+}String helloWorld() {
+    return FfiConverterString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_hello_world_fn_func_hello_world( uniffiStatus), NullRustCallStatusErrorHandler()));
+}World newWorld() {
+    return FfiConverterWorld.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_hello_world_fn_func_new_world( uniffiStatus), NullRustCallStatusErrorHandler()));
+}World newWorldWithName(String name,) {
+    return FfiConverterWorld.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_hello_world_fn_func_new_world_with_name(FfiConverterString.lower(name), uniffiStatus), NullRustCallStatusErrorHandler()));
+}
+
+class _UniffiLib {
+    _UniffiLib._();
+
+    static final DynamicLibrary _dylib = _open();
+
+    static DynamicLibrary _open() {
+        if (Platform.isAndroid) return DynamicLibrary.open("libhello_world.so");
+        if (Platform.isIOS) return DynamicLibrary.executable();
+        if (Platform.isLinux) return DynamicLibrary.open("libhello_world.so");
+        if (Platform.isMacOS) return DynamicLibrary.open("libhello_world.dylib");
+        if (Platform.isWindows) return DynamicLibrary.open("hello_world.dll");
+        throw UnsupportedError("Unsupported platform: \${Platform.operatingSystem}");
+    }
+
+    static final _UniffiLib instance = _UniffiLib._();
+
+    late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_hello_world_fn_clone_world = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_hello_world_fn_clone_world");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_hello_world_fn_free_world = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_hello_world_fn_free_world");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_hello_world_fn_method_world_inc_inhabitants = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_hello_world_fn_method_world_inc_inhabitants");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_hello_world_fn_method_world_is_there = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_hello_world_fn_method_world_is_there");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_hello_world_fn_method_world_name = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_hello_world_fn_method_world_name");late final RustBuffer Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>) uniffi_hello_world_fn_method_world_prefixed_name = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_hello_world_fn_method_world_prefixed_name");late final Pointer<Void> Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>) uniffi_hello_world_fn_method_world_set_name = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_hello_world_fn_method_world_set_name");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_hello_world_fn_method_world_state = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_hello_world_fn_method_world_state");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_hello_world_fn_func_hello = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_hello_world_fn_func_hello");late final RustBuffer Function(Pointer<RustCallStatus>) uniffi_hello_world_fn_func_hello_world = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<RustCallStatus>)
+    >("uniffi_hello_world_fn_func_hello_world");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_hello_world_fn_func_new_world = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_hello_world_fn_func_new_world");late final Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>) uniffi_hello_world_fn_func_new_world_with_name = _dylib.lookupFunction<
+        Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>),
+        Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_hello_world_fn_func_new_world_with_name");late final RustBuffer Function(int,Pointer<RustCallStatus>) ffi_hello_world_rustbuffer_alloc = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("ffi_hello_world_rustbuffer_alloc");late final RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>) ffi_hello_world_rustbuffer_from_bytes = _dylib.lookupFunction<
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>),
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>)
+    >("ffi_hello_world_rustbuffer_from_bytes");late final void Function(RustBuffer,Pointer<RustCallStatus>) ffi_hello_world_rustbuffer_free = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("ffi_hello_world_rustbuffer_free");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) ffi_hello_world_rustbuffer_reserve = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("ffi_hello_world_rustbuffer_reserve");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_hello_world_rust_future_poll_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_hello_world_rust_future_poll_u8");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_cancel_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_cancel_u8");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_free_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_free_u8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_hello_world_rust_future_complete_u8 = _dylib.lookupFunction<
+        Uint8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_hello_world_rust_future_complete_u8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_hello_world_rust_future_poll_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_hello_world_rust_future_poll_i8");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_cancel_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_cancel_i8");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_free_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_free_i8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_hello_world_rust_future_complete_i8 = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_hello_world_rust_future_complete_i8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_hello_world_rust_future_poll_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_hello_world_rust_future_poll_u16");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_cancel_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_cancel_u16");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_free_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_free_u16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_hello_world_rust_future_complete_u16 = _dylib.lookupFunction<
+        Uint16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_hello_world_rust_future_complete_u16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_hello_world_rust_future_poll_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_hello_world_rust_future_poll_i16");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_cancel_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_cancel_i16");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_free_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_free_i16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_hello_world_rust_future_complete_i16 = _dylib.lookupFunction<
+        Int16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_hello_world_rust_future_complete_i16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_hello_world_rust_future_poll_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_hello_world_rust_future_poll_u32");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_cancel_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_cancel_u32");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_free_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_free_u32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_hello_world_rust_future_complete_u32 = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_hello_world_rust_future_complete_u32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_hello_world_rust_future_poll_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_hello_world_rust_future_poll_i32");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_cancel_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_cancel_i32");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_free_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_free_i32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_hello_world_rust_future_complete_i32 = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_hello_world_rust_future_complete_i32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_hello_world_rust_future_poll_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_hello_world_rust_future_poll_u64");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_cancel_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_cancel_u64");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_free_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_free_u64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_hello_world_rust_future_complete_u64 = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_hello_world_rust_future_complete_u64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_hello_world_rust_future_poll_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_hello_world_rust_future_poll_i64");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_cancel_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_cancel_i64");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_free_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_free_i64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_hello_world_rust_future_complete_i64 = _dylib.lookupFunction<
+        Int64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_hello_world_rust_future_complete_i64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_hello_world_rust_future_poll_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_hello_world_rust_future_poll_f32");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_cancel_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_cancel_f32");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_free_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_free_f32");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_hello_world_rust_future_complete_f32 = _dylib.lookupFunction<
+        Float Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_hello_world_rust_future_complete_f32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_hello_world_rust_future_poll_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_hello_world_rust_future_poll_f64");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_cancel_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_cancel_f64");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_free_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_free_f64");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_hello_world_rust_future_complete_f64 = _dylib.lookupFunction<
+        Double Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_hello_world_rust_future_complete_f64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_hello_world_rust_future_poll_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_hello_world_rust_future_poll_pointer");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_cancel_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_cancel_pointer");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_free_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_free_pointer");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_hello_world_rust_future_complete_pointer = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_hello_world_rust_future_complete_pointer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_hello_world_rust_future_poll_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_hello_world_rust_future_poll_rust_buffer");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_cancel_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_cancel_rust_buffer");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_free_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_free_rust_buffer");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_hello_world_rust_future_complete_rust_buffer = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_hello_world_rust_future_complete_rust_buffer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_hello_world_rust_future_poll_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_hello_world_rust_future_poll_void");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_cancel_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_cancel_void");late final void Function(Pointer<Void>,) ffi_hello_world_rust_future_free_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_hello_world_rust_future_free_void");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_hello_world_rust_future_complete_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_hello_world_rust_future_complete_void");late final int Function() uniffi_hello_world_checksum_func_hello = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_hello_world_checksum_func_hello");late final int Function() uniffi_hello_world_checksum_func_hello_world = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_hello_world_checksum_func_hello_world");late final int Function() uniffi_hello_world_checksum_func_new_world = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_hello_world_checksum_func_new_world");late final int Function() uniffi_hello_world_checksum_func_new_world_with_name = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_hello_world_checksum_func_new_world_with_name");late final int Function() uniffi_hello_world_checksum_method_world_inc_inhabitants = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_hello_world_checksum_method_world_inc_inhabitants");late final int Function() uniffi_hello_world_checksum_method_world_is_there = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_hello_world_checksum_method_world_is_there");late final int Function() uniffi_hello_world_checksum_method_world_name = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_hello_world_checksum_method_world_name");late final int Function() uniffi_hello_world_checksum_method_world_prefixed_name = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_hello_world_checksum_method_world_prefixed_name");late final int Function() uniffi_hello_world_checksum_method_world_set_name = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_hello_world_checksum_method_world_set_name");late final int Function() uniffi_hello_world_checksum_method_world_state = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_hello_world_checksum_method_world_state");late final int Function() ffi_hello_world_uniffi_contract_version = _dylib.lookupFunction<
+        Uint32 Function(),
+        int Function()
+    >("ffi_hello_world_uniffi_contract_version");late final Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>) uniffi_dart_finalizer_token_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>),
+        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>)
+    >("uniffi_dart_finalizer_token_new");
+    late final void Function(Pointer<Void>) uniffi_dart_finalizer_token_free = _dylib.lookupFunction<
+        Void Function(Pointer<Void>),
+        void Function(Pointer<Void>)
+    >("uniffi_dart_finalizer_token_free");
+    late final Pointer<NativeFinalizerFunction> uniffi_dart_finalizer_run = _dylib.lookup("uniffi_dart_finalizer_run");
+
+    static void _checkApiVersion() {
+        final bindingsVersion = 26;
+        final scaffoldingVersion = _UniffiLib.instance.ffi_hello_world_uniffi_contract_version();
+        if (bindingsVersion != scaffoldingVersion) {
+            throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version \$bindingsVersion, scaffolding version \$scaffoldingVersion");
+        }
+    }
+
+    static void _checkApiChecksums() {
+        if (_UniffiLib.instance.uniffi_hello_world_checksum_func_hello() != 59859) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_hello_world_checksum_func_hello_world() != 64773) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_hello_world_checksum_func_new_world() != 7174) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_hello_world_checksum_func_new_world_with_name() != 26270) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_hello_world_checksum_method_world_inc_inhabitants() != 24860) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_hello_world_checksum_method_world_is_there() != 25296) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_hello_world_checksum_method_world_name() != 23263) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_hello_world_checksum_method_world_prefixed_name() != 33696) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_hello_world_checksum_method_world_set_name() != 7167) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_hello_world_checksum_method_world_state() != 16315) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }
+    }
+}
+
+void initialize() {
+    _UniffiLib._open();
+}
+
+void ensureInitialized() {
+    _UniffiLib._checkApiVersion();
+    _UniffiLib._checkApiChecksums();
+}
@@ -80,3 +80,4 @@ pub fn hello(input: String) -> String {
 }
 
 uniffi::include_scaffolding!("api");
+uniffi_dart::setup_finalizers!();
@@ -4,3 +4,8 @@ use anyhow::Result;
 fn hello_world() -> Result<()> {
     uniffi_dart::testing::run_test("hello_world", "src/api.udl", None)
 }
+
+#[test]
+fn hello_world_golden() -> Result<()> {
+    uniffi_dart::testing::assert_golden("hello_world", "src/api.udl", None)
+}
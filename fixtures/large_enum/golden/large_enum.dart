@@ -0,0 +1,1632 @@
+library large_enum;
+
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io" show Platform, File, Directory;
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+class MapEntry_ {
+    final Value key;final Value value;
+
+    MapEntry_({required this.key,required this.value,});
+}
+
+class FfiConverterMapEntry {
+    static MapEntry_ lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMapEntry.read(data).value);
+    }
+
+    static LiftRetVal<MapEntry_> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final key_lifted = FfiConverterValue.read(Uint8List.view(buf.buffer, new_offset));
+        final key = key_lifted.value;
+        new_offset += key_lifted.bytesRead;final value_lifted = FfiConverterValue.read(Uint8List.view(buf.buffer, new_offset));
+        final value = value_lifted.value;
+        new_offset += value_lifted.bytesRead;
+        return LiftRetVal(MapEntry_(
+            key: key,value: value,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( MapEntry_ value) {
+        final total_length = FfiConverterValue.allocationSize(value.key) +FfiConverterValue.allocationSize(value.value) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( MapEntry_ value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterValue.write(value.key, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterValue.write(value.value, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(MapEntry_ value) {
+        return FfiConverterValue.allocationSize(value.key) +FfiConverterValue.allocationSize(value.value) + 0;
+    }
+}
+
+enum FlatEnum {
+    one,two,three,four,
+    ;
+}
+
+class FfiConverterFlatEnum {
+    static FlatEnum lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterFlatEnum.read(data).value);
+    }
+
+    static LiftRetVal<FlatEnum> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        switch(index) {
+            case 1:
+                return LiftRetVal(FlatEnum.one, 4);
+            case 2:
+                return LiftRetVal(FlatEnum.two, 4);
+            case 3:
+                return LiftRetVal(FlatEnum.three, 4);
+            case 4:
+                return LiftRetVal(FlatEnum.four, 4);
+            default:
+                throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( FlatEnum input) {
+        final buf = Uint8List(allocationSize(input));
+        write(input, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int allocationSize(FlatEnum value) {
+        return 4;
+    }
+
+    static int write( FlatEnum value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.index + 1);
+        return 4;
+    }
+}abstract class Value {
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterValue {
+    static Value lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterValue.read(data).value);
+    }
+
+    static LiftRetVal<Value> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4);
+        switch(index) {
+            case 1:
+                return StringValue.read(subview).copyWithOffset(4);
+            case 2:
+                return BoolValue.read(subview).copyWithOffset(4);
+            case 3:
+                return U8Value.read(subview).copyWithOffset(4);
+            case 4:
+                return U16Value.read(subview).copyWithOffset(4);
+            case 5:
+                return U32Value.read(subview).copyWithOffset(4);
+            case 6:
+                return U64Value.read(subview).copyWithOffset(4);
+            case 7:
+                return I8Value.read(subview).copyWithOffset(4);
+            case 8:
+                return I16Value.read(subview).copyWithOffset(4);
+            case 9:
+                return I32Value.read(subview).copyWithOffset(4);
+            case 10:
+                return I64Value.read(subview).copyWithOffset(4);
+            case 11:
+                return F32Value.read(subview).copyWithOffset(4);
+            case 12:
+                return F64Value.read(subview).copyWithOffset(4);
+            case 13:
+                return PublicKeyValue.read(subview).copyWithOffset(4);
+            default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( Value value) {
+        return value.lower();
+    }
+
+    static int allocationSize(Value value) {
+        return value.allocationSize();
+    }
+
+    static int write( Value value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class StringValue extends Value {
+    final String value;
+
+    StringValue(this.value,);
+
+    StringValue._(this.value,);
+
+    static LiftRetVal<StringValue> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final value_lifted = FfiConverterString.read(Uint8List.view(buf.buffer, new_offset));
+        final value = value_lifted.value;
+        new_offset += value_lifted.bytesRead;
+        return LiftRetVal(StringValue._(
+            value,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterString.allocationSize(value) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterString.write(value, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class BoolValue extends Value {
+    final bool value;
+
+    BoolValue(this.value,);
+
+    BoolValue._(this.value,);
+
+    static LiftRetVal<BoolValue> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final value_lifted = FfiConverterBool.read(Uint8List.view(buf.buffer, new_offset));
+        final value = value_lifted.value;
+        new_offset += value_lifted.bytesRead;
+        return LiftRetVal(BoolValue._(
+            value,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterBool.allocationSize(value) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 2);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterBool.write(value, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class U8Value extends Value {
+    final int value;
+
+    U8Value(this.value,);
+
+    U8Value._(this.value,);
+
+    static LiftRetVal<U8Value> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final value_lifted = FfiConverterUInt8.read(Uint8List.view(buf.buffer, new_offset));
+        final value = value_lifted.value;
+        new_offset += value_lifted.bytesRead;
+        return LiftRetVal(U8Value._(
+            value,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterUInt8.allocationSize(value) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 3);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterUInt8.write(value, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class U16Value extends Value {
+    final int value;
+
+    U16Value(this.value,);
+
+    U16Value._(this.value,);
+
+    static LiftRetVal<U16Value> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final value_lifted = FfiConverterUInt16.read(Uint8List.view(buf.buffer, new_offset));
+        final value = value_lifted.value;
+        new_offset += value_lifted.bytesRead;
+        return LiftRetVal(U16Value._(
+            value,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterUInt16.allocationSize(value) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 4);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterUInt16.write(value, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class U32Value extends Value {
+    final int value;
+
+    U32Value(this.value,);
+
+    U32Value._(this.value,);
+
+    static LiftRetVal<U32Value> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final value_lifted = FfiConverterUInt32.read(Uint8List.view(buf.buffer, new_offset));
+        final value = value_lifted.value;
+        new_offset += value_lifted.bytesRead;
+        return LiftRetVal(U32Value._(
+            value,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterUInt32.allocationSize(value) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 5);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterUInt32.write(value, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class U64Value extends Value {
+    final int value;
+
+    U64Value(this.value,);
+
+    U64Value._(this.value,);
+
+    static LiftRetVal<U64Value> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final value_lifted = FfiConverterUInt64.read(Uint8List.view(buf.buffer, new_offset));
+        final value = value_lifted.value;
+        new_offset += value_lifted.bytesRead;
+        return LiftRetVal(U64Value._(
+            value,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterUInt64.allocationSize(value) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 6);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterUInt64.write(value, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class I8Value extends Value {
+    final int value;
+
+    I8Value(this.value,);
+
+    I8Value._(this.value,);
+
+    static LiftRetVal<I8Value> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final value_lifted = FfiConverterInt8.read(Uint8List.view(buf.buffer, new_offset));
+        final value = value_lifted.value;
+        new_offset += value_lifted.bytesRead;
+        return LiftRetVal(I8Value._(
+            value,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterInt8.allocationSize(value) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 7);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterInt8.write(value, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class I16Value extends Value {
+    final int value;
+
+    I16Value(this.value,);
+
+    I16Value._(this.value,);
+
+    static LiftRetVal<I16Value> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final value_lifted = FfiConverterInt16.read(Uint8List.view(buf.buffer, new_offset));
+        final value = value_lifted.value;
+        new_offset += value_lifted.bytesRead;
+        return LiftRetVal(I16Value._(
+            value,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterInt16.allocationSize(value) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 8);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterInt16.write(value, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class I32Value extends Value {
+    final int value;
+
+    I32Value(this.value,);
+
+    I32Value._(this.value,);
+
+    static LiftRetVal<I32Value> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final value_lifted = FfiConverterInt32.read(Uint8List.view(buf.buffer, new_offset));
+        final value = value_lifted.value;
+        new_offset += value_lifted.bytesRead;
+        return LiftRetVal(I32Value._(
+            value,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterInt32.allocationSize(value) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 9);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterInt32.write(value, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class I64Value extends Value {
+    final int value;
+
+    I64Value(this.value,);
+
+    I64Value._(this.value,);
+
+    static LiftRetVal<I64Value> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final value_lifted = FfiConverterInt64.read(Uint8List.view(buf.buffer, new_offset));
+        final value = value_lifted.value;
+        new_offset += value_lifted.bytesRead;
+        return LiftRetVal(I64Value._(
+            value,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterInt64.allocationSize(value) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 10);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterInt64.write(value, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class F32Value extends Value {
+    final double value;
+
+    F32Value(this.value,);
+
+    F32Value._(this.value,);
+
+    static LiftRetVal<F32Value> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final value_lifted = FfiConverterDouble32.read(Uint8List.view(buf.buffer, new_offset));
+        final value = value_lifted.value;
+        new_offset += value_lifted.bytesRead;
+        return LiftRetVal(F32Value._(
+            value,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterDouble32.allocationSize(value) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 11);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterDouble32.write(value, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class F64Value extends Value {
+    final double value;
+
+    F64Value(this.value,);
+
+    F64Value._(this.value,);
+
+    static LiftRetVal<F64Value> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final value_lifted = FfiConverterDouble64.read(Uint8List.view(buf.buffer, new_offset));
+        final value = value_lifted.value;
+        new_offset += value_lifted.bytesRead;
+        return LiftRetVal(F64Value._(
+            value,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterDouble64.allocationSize(value) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 12);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterDouble64.write(value, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class PublicKeyValue extends Value {
+    final List<int> value;
+
+    PublicKeyValue(this.value,);
+
+    PublicKeyValue._(this.value,);
+
+    static LiftRetVal<PublicKeyValue> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final value_lifted = FfiConverterSequenceInt32.read(Uint8List.view(buf.buffer, new_offset));
+        final value = value_lifted.value;
+        new_offset += value_lifted.bytesRead;
+        return LiftRetVal(PublicKeyValue._(
+            value,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterSequenceInt32.allocationSize(value) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 13);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterSequenceInt32.write(value, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}
+
+class UniffiInternalError implements Exception {
+    static const int bufferOverflow = 0;
+    static const int incompleteData = 1;
+    static const int unexpectedOptionalTag = 2;
+    static const int unexpectedEnumCase = 3;
+    static const int unexpectedNullPointer = 4;
+    static const int unexpectedRustCallStatusCode = 5;
+    static const int unexpectedRustCallError = 6;
+    static const int unexpectedStaleHandle = 7;
+    static const int rustPanic = 8;
+
+    final int errorCode;
+    final String? panicMessage;
+
+    const UniffiInternalError(this.errorCode, this.panicMessage);
+
+    static UniffiInternalError panicked(String message) {
+    return UniffiInternalError(rustPanic, message);
+    }
+
+    @override
+    String toString() {
+    switch (errorCode) {
+        case bufferOverflow:
+        return "UniFfi::BufferOverflow";
+        case incompleteData:
+        return "UniFfi::IncompleteData";
+        case unexpectedOptionalTag:
+        return "UniFfi::UnexpectedOptionalTag";
+        case unexpectedEnumCase:
+        return "UniFfi::UnexpectedEnumCase";
+        case unexpectedNullPointer:
+        return "UniFfi::UnexpectedNullPointer";
+        case unexpectedRustCallStatusCode:
+        return "UniFfi::UnexpectedRustCallStatusCode";
+        case unexpectedRustCallError:
+        return "UniFfi::UnexpectedRustCallError";
+        case unexpectedStaleHandle:
+        return "UniFfi::UnexpectedStaleHandle";
+        case rustPanic:
+        return "UniFfi::rustPanic: \$\$panicMessage";
+        default:
+        return "UniFfi::UnknownError: \$\$errorCode";
+    }
+    }
+}
+
+const int CALL_SUCCESS = 0;
+const int CALL_ERROR = 1;
+const int CALL_UNEXPECTED_ERROR = 2;
+
+final class RustCallStatus extends Struct {
+    @Int8()
+    external int code;
+
+    external RustBuffer errorBuf;
+
+}
+
+void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
+
+    if (status.ref.code == CALL_SUCCESS) {
+    return;
+    } else if (status.ref.code == CALL_ERROR) {
+    throw errorHandler.lift(status.ref.errorBuf);
+    } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+    if (status.ref.errorBuf.len > 0) {
+        throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
+    } else {
+        throw UniffiInternalError.panicked("Rust panic");
+    }
+    } else {
+    throw UniffiInternalError.panicked("Unexpected RustCallStatus code: \${status.ref.code}");
+    }
+}
+
+#[doc=" Allocates the native memory the bindings pass to Rust for the duration of a call."]
+const Allocator _uniffiAllocator = calloc;
+
+T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+    final status = _uniffiAllocator<RustCallStatus>();
+    try {
+    final result = callback(status);
+    checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+    return result;
+    } finally {
+    _uniffiAllocator.free(status);
+    }
+}
+
+class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+    errorBuf.free();
+    return UniffiInternalError.panicked("Unexpected CALL_ERROR");
+    }
+}
+
+abstract class UniffiRustCallStatusErrorHandler {
+    Exception lift(RustBuffer errorBuf);
+}
+
+final class RustBuffer extends Struct {
+    @Uint64()
+    external int capacity;
+
+    @Uint64()
+    external int len;
+
+    external Pointer<Uint8> data;
+
+    static RustBuffer alloc(int size) {
+        return rustCall((status) => _UniffiLib.instance.ffi_large_enum_rustbuffer_alloc(size, status));
+    }
+
+    static RustBuffer fromBytes(ForeignBytes bytes) {
+        return rustCall((status) => _UniffiLib.instance.ffi_large_enum_rustbuffer_from_bytes(bytes, status));
+    }
+
+    void free() {
+        rustCall((status) => _UniffiLib.instance.ffi_large_enum_rustbuffer_free(this, status));
+    }
+
+    RustBuffer reserve(int additionalCapacity) {
+    return rustCall((status) => _UniffiLib.instance.ffi_large_enum_rustbuffer_reserve(this, additionalCapacity, status));
+    }
+
+    Uint8List asUint8List() {
+    final dataList = data.asTypedList(len);
+    final byteData = ByteData.sublistView(dataList);
+    return Uint8List.view(byteData.buffer);
+    }
+
+    @override
+    String toString() {
+    return "RustBuffer{capacity: \$capacity, len: \$len, data: \$data}";
+    }
+}
+
+RustBuffer toRustBuffer(Uint8List data) {
+    final length = data.length;
+
+    final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length);
+    final pointerList = frameData.asTypedList(length);
+    pointerList.setAll(0, data);
+
+    final bytes = _uniffiAllocator<ForeignBytes>();
+    bytes.ref.len = length;
+    bytes.ref.data = frameData;
+    try {
+        return RustBuffer.fromBytes(bytes.ref);
+    } finally {
+        _uniffiAllocator.free(frameData);
+        _uniffiAllocator.free(bytes);
+    }
+}
+
+T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+    try {
+        return lift(buf.asUint8List());
+    } finally {
+        buf.free();
+    }
+}
+
+final class ForeignBytes extends Struct {
+    @Int32()
+    external int len;
+    external Pointer<Uint8> data;
+
+    void free() {
+    _uniffiAllocator.free(data);
+    }
+}
+
+class LiftRetVal<T> {
+    final T value;
+    final int bytesRead;
+    const LiftRetVal(this.value, this.bytesRead);
+
+    LiftRetVal<T> copyWithOffset(int offset) {
+        return LiftRetVal(value, bytesRead + offset);
+    }
+}
+
+abstract class FfiConverter<D, F> {
+    const FfiConverter();
+
+    D lift(F value);
+    F lower(D value);
+    D read(ByteData buffer, int offset);
+    void write(D value, ByteData buffer, int offset);
+    int size(D value);
+}
+
+mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+    @override
+    T lift(T value) => value;
+
+    @override
+    T lower(T value) => value;
+}
+
+class FfiConverterBool {
+
+    static bool lift( int value) {
+        return value == 1;
+    }
+
+    static int lower( bool value) {
+        return value ? 1 :0;
+    }
+
+    static LiftRetVal<bool> read( Uint8List buf) {
+        return LiftRetVal(FfiConverterBool.lift(buf.first), 1);
+    }
+
+    static RustBuffer lowerIntoRustBuffer( bool value) {
+        return toRustBuffer(Uint8List.fromList([FfiConverterBool.lower(value)]));
+    }
+
+    static int allocationSize([bool value = false]) {
+        return 1;
+    }
+
+    static int write( bool value, Uint8List buf) {
+        buf.setAll(0, [value ? 1 : 0]);
+        return allocationSize();
+    }
+}class FfiConverterDouble32 {
+
+    static double lift(double value) => value;
+
+    static LiftRetVal<double> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat32(0), 4);
+    }
+
+    static double lower(double value) => value;
+
+    static int allocationSize([double value = 0]) {
+        return 4;
+    }
+
+    static int write(double value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setFloat32(0, value);
+        return FfiConverterDouble32.allocationSize();
+    }
+
+}class FfiConverterDouble64 {
+
+    static double lift(double value) => value;
+
+    static LiftRetVal<double> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat64(0), 8);
+    }
+
+    static double lower(double value) => value;
+
+    static int allocationSize([double value = 0]) {
+        return 8;
+    }
+
+    static int write(double value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setFloat64(0, value);
+        return FfiConverterDouble64.allocationSize();
+    }
+
+}class FfiConverterInt16 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt16(0), 2);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 2;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt16(0, value);
+        return FfiConverterInt16.allocationSize();
+    }
+
+}class FfiConverterInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value);
+        return FfiConverterInt32.allocationSize();
+    }
+
+}class FfiConverterInt64 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt64(0), 8);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 8;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, value);
+        return FfiConverterInt64.allocationSize();
+    }
+
+}class FfiConverterInt8 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt8(0), 1);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 1;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt8(0, value);
+        return FfiConverterInt8.allocationSize();
+    }
+
+}class FfiConverterSequenceInt32 {
+
+    static List<int> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceInt32.read(data).value);
+    }
+
+    static LiftRetVal<List<int>> read( Uint8List buf) {
+        List<int> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterInt32.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<int> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterInt32.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<int> value) {
+        return value.map((l) => FfiConverterInt32.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<int> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterString {
+    static String lift( RustBuffer buf) {
+
+        return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
+    }
+
+    static RustBuffer lower( String value) {
+        return toRustBuffer(Utf8Encoder().convert(value));
+    }
+
+    static LiftRetVal<String> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+        return LiftRetVal(utf8.decoder.convert(buf, 4, end), end);
+    }
+
+    static int allocationSize([String value = ""]) {
+
+        return utf8.encoder.convert(value).length + 4;
+    }
+
+    static int write( String value, Uint8List buf) {
+
+        final list = utf8.encoder.convert(value);
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length);
+        buf.setAll(4, list);
+        return list.length + 4;
+    }
+}class FfiConverterUInt16 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint16(0), 2);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 2;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint16(0, value);
+        return FfiConverterUInt16.allocationSize();
+    }
+
+}class FfiConverterUInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, value);
+        return FfiConverterUInt32.allocationSize();
+    }
+
+}class FfiConverterUInt64 {
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0), 8);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 8;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, value);
+        return FfiConverterUInt64.allocationSize();
+    }
+}class FfiConverterUInt8 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint8(0), 1);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 1;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint8(0, value);
+        return FfiConverterUInt8.allocationSize();
+    }
+
+}
+
+const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
+
+typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+Future<T> uniffiRustCallAsync<T, F>(
+    Pointer<Void> Function() rustFutureFunc,
+    void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc,
+    F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc,
+    void Function(Pointer<Void>) freeFunc,
+    T Function(F) liftFunc, [
+    UniffiRustCallStatusErrorHandler? errorHandler,
+]) async {
+    final rustFuture = rustFutureFunc();
+    final completer = Completer<int>();
+
+    late final NativeCallable<UniffiRustFutureContinuationCallback> callback;
+
+    void poll() {
+        pollFunc(
+            rustFuture,
+            callback.nativeFunction,
+            Pointer<Void>.fromAddress(0),
+        );
+    }
+    void onResponse(int _idx, int pollResult) {
+        if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+            completer.complete(pollResult);
+        } else {
+            poll();
+        }
+    }
+    callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener(onResponse);
+
+    try {
+        poll();
+        await completer.future;
+        callback.close();
+
+        final status = _uniffiAllocator<RustCallStatus>();
+        try {
+            final result = completeFunc(rustFuture, status);
+            checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+            return liftFunc(result);
+        } finally {
+            _uniffiAllocator.free(status);
+        }
+    } finally {
+        freeFunc(rustFuture);
+    }
+}
+
+class UniffiHandleMap<T> {
+    final Map<int, T> _map = {};
+    int _counter = 0;
+
+    int insert(T obj) {
+    final handle = _counter++;
+    _map[handle] = obj;
+    return handle;
+    }
+
+    T get(int handle) {
+    final obj = _map[handle];
+    if (obj == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    return obj;
+    }
+
+    void remove(int handle) {
+    if (_map.remove(handle) == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    }
+}
+
+Value newBoolValue(bool value,) {
+    return FfiConverterValue.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_new_bool_value(FfiConverterBool.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Value newF32Value(double value,) {
+    return FfiConverterValue.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_new_f32_value(value, uniffiStatus), NullRustCallStatusErrorHandler()));
+}Value newF64Value(double value,) {
+    return FfiConverterValue.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_new_f64_value(value, uniffiStatus), NullRustCallStatusErrorHandler()));
+}FlatEnum newFlatFour() {
+    return FfiConverterFlatEnum.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_new_flat_four( uniffiStatus), NullRustCallStatusErrorHandler()));
+}FlatEnum newFlatOne() {
+    return FfiConverterFlatEnum.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_new_flat_one( uniffiStatus), NullRustCallStatusErrorHandler()));
+}FlatEnum newFlatThree() {
+    return FfiConverterFlatEnum.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_new_flat_three( uniffiStatus), NullRustCallStatusErrorHandler()));
+}FlatEnum newFlatTwo() {
+    return FfiConverterFlatEnum.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_new_flat_two( uniffiStatus), NullRustCallStatusErrorHandler()));
+}Value newI16Value(int value,) {
+    return FfiConverterValue.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_new_i16_value(value, uniffiStatus), NullRustCallStatusErrorHandler()));
+}Value newI32Value(int value,) {
+    return FfiConverterValue.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_new_i32_value(value, uniffiStatus), NullRustCallStatusErrorHandler()));
+}Value newI64Value(int value,) {
+    return FfiConverterValue.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_new_i64_value(value, uniffiStatus), NullRustCallStatusErrorHandler()));
+}Value newI8Value(int value,) {
+    return FfiConverterValue.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_new_i8_value(value, uniffiStatus), NullRustCallStatusErrorHandler()));
+}Value newPublicKeyValue(List<int> value,) {
+    return FfiConverterValue.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_new_public_key_value(FfiConverterSequenceInt32.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Value newPublicKeyValueWithoutArgument() {
+    return FfiConverterValue.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_new_public_key_value_without_argument( uniffiStatus), NullRustCallStatusErrorHandler()));
+}Value newStringValue(String value,) {
+    return FfiConverterValue.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_new_string_value(FfiConverterString.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Value newU16Value(int value,) {
+    return FfiConverterValue.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_new_u16_value(value, uniffiStatus), NullRustCallStatusErrorHandler()));
+}Value newU32Value(int value,) {
+    return FfiConverterValue.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_new_u32_value(value, uniffiStatus), NullRustCallStatusErrorHandler()));
+}Value newU64Value(int value,) {
+    return FfiConverterValue.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_new_u64_value(FfiConverterUInt64.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Value newU8Value(int value,) {
+    return FfiConverterValue.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_new_u8_value(value, uniffiStatus), NullRustCallStatusErrorHandler()));
+}String takeFlatEnum(FlatEnum flat,) {
+    return FfiConverterString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_take_flat_enum(FfiConverterFlatEnum.lower(flat), uniffiStatus), NullRustCallStatusErrorHandler()));
+}String takeValue(Value value,) {
+    return FfiConverterString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_large_enum_fn_func_take_value(FfiConverterValue.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}
+
+class _UniffiLib {
+    _UniffiLib._();
+
+    static final DynamicLibrary _dylib = _open();
+
+    static DynamicLibrary _open() {
+        if (Platform.isAndroid) return DynamicLibrary.open("liblarge_enum.so");
+        if (Platform.isIOS) return DynamicLibrary.executable();
+        if (Platform.isLinux) return DynamicLibrary.open("liblarge_enum.so");
+        if (Platform.isMacOS) return DynamicLibrary.open("liblarge_enum.dylib");
+        if (Platform.isWindows) return DynamicLibrary.open("large_enum.dll");
+        throw UnsupportedError("Unsupported platform: \${Platform.operatingSystem}");
+    }
+
+    static final _UniffiLib instance = _UniffiLib._();
+
+    late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_large_enum_fn_func_new_bool_value = _dylib.lookupFunction<
+        RustBuffer Function(Int8,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_new_bool_value");late final RustBuffer Function(double,Pointer<RustCallStatus>) uniffi_large_enum_fn_func_new_f32_value = _dylib.lookupFunction<
+        RustBuffer Function(Float,Pointer<RustCallStatus>),
+        RustBuffer Function(double,Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_new_f32_value");late final RustBuffer Function(double,Pointer<RustCallStatus>) uniffi_large_enum_fn_func_new_f64_value = _dylib.lookupFunction<
+        RustBuffer Function(Double,Pointer<RustCallStatus>),
+        RustBuffer Function(double,Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_new_f64_value");late final RustBuffer Function(Pointer<RustCallStatus>) uniffi_large_enum_fn_func_new_flat_four = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_new_flat_four");late final RustBuffer Function(Pointer<RustCallStatus>) uniffi_large_enum_fn_func_new_flat_one = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_new_flat_one");late final RustBuffer Function(Pointer<RustCallStatus>) uniffi_large_enum_fn_func_new_flat_three = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_new_flat_three");late final RustBuffer Function(Pointer<RustCallStatus>) uniffi_large_enum_fn_func_new_flat_two = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_new_flat_two");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_large_enum_fn_func_new_i16_value = _dylib.lookupFunction<
+        RustBuffer Function(Int16,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_new_i16_value");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_large_enum_fn_func_new_i32_value = _dylib.lookupFunction<
+        RustBuffer Function(Int32,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_new_i32_value");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_large_enum_fn_func_new_i64_value = _dylib.lookupFunction<
+        RustBuffer Function(Int64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_new_i64_value");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_large_enum_fn_func_new_i8_value = _dylib.lookupFunction<
+        RustBuffer Function(Int8,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_new_i8_value");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_large_enum_fn_func_new_public_key_value = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_new_public_key_value");late final RustBuffer Function(Pointer<RustCallStatus>) uniffi_large_enum_fn_func_new_public_key_value_without_argument = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_new_public_key_value_without_argument");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_large_enum_fn_func_new_string_value = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_new_string_value");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_large_enum_fn_func_new_u16_value = _dylib.lookupFunction<
+        RustBuffer Function(Uint16,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_new_u16_value");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_large_enum_fn_func_new_u32_value = _dylib.lookupFunction<
+        RustBuffer Function(Uint32,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_new_u32_value");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_large_enum_fn_func_new_u64_value = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_new_u64_value");late final RustBuffer Function(int,Pointer<RustCallStatus>) uniffi_large_enum_fn_func_new_u8_value = _dylib.lookupFunction<
+        RustBuffer Function(Uint8,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_new_u8_value");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_large_enum_fn_func_take_flat_enum = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_take_flat_enum");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_large_enum_fn_func_take_value = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_large_enum_fn_func_take_value");late final RustBuffer Function(int,Pointer<RustCallStatus>) ffi_large_enum_rustbuffer_alloc = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("ffi_large_enum_rustbuffer_alloc");late final RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>) ffi_large_enum_rustbuffer_from_bytes = _dylib.lookupFunction<
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>),
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>)
+    >("ffi_large_enum_rustbuffer_from_bytes");late final void Function(RustBuffer,Pointer<RustCallStatus>) ffi_large_enum_rustbuffer_free = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("ffi_large_enum_rustbuffer_free");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) ffi_large_enum_rustbuffer_reserve = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("ffi_large_enum_rustbuffer_reserve");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_large_enum_rust_future_poll_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_large_enum_rust_future_poll_u8");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_cancel_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_cancel_u8");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_free_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_free_u8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_large_enum_rust_future_complete_u8 = _dylib.lookupFunction<
+        Uint8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_large_enum_rust_future_complete_u8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_large_enum_rust_future_poll_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_large_enum_rust_future_poll_i8");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_cancel_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_cancel_i8");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_free_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_free_i8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_large_enum_rust_future_complete_i8 = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_large_enum_rust_future_complete_i8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_large_enum_rust_future_poll_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_large_enum_rust_future_poll_u16");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_cancel_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_cancel_u16");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_free_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_free_u16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_large_enum_rust_future_complete_u16 = _dylib.lookupFunction<
+        Uint16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_large_enum_rust_future_complete_u16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_large_enum_rust_future_poll_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_large_enum_rust_future_poll_i16");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_cancel_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_cancel_i16");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_free_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_free_i16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_large_enum_rust_future_complete_i16 = _dylib.lookupFunction<
+        Int16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_large_enum_rust_future_complete_i16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_large_enum_rust_future_poll_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_large_enum_rust_future_poll_u32");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_cancel_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_cancel_u32");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_free_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_free_u32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_large_enum_rust_future_complete_u32 = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_large_enum_rust_future_complete_u32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_large_enum_rust_future_poll_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_large_enum_rust_future_poll_i32");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_cancel_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_cancel_i32");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_free_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_free_i32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_large_enum_rust_future_complete_i32 = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_large_enum_rust_future_complete_i32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_large_enum_rust_future_poll_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_large_enum_rust_future_poll_u64");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_cancel_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_cancel_u64");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_free_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_free_u64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_large_enum_rust_future_complete_u64 = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_large_enum_rust_future_complete_u64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_large_enum_rust_future_poll_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_large_enum_rust_future_poll_i64");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_cancel_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_cancel_i64");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_free_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_free_i64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_large_enum_rust_future_complete_i64 = _dylib.lookupFunction<
+        Int64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_large_enum_rust_future_complete_i64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_large_enum_rust_future_poll_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_large_enum_rust_future_poll_f32");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_cancel_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_cancel_f32");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_free_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_free_f32");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_large_enum_rust_future_complete_f32 = _dylib.lookupFunction<
+        Float Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_large_enum_rust_future_complete_f32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_large_enum_rust_future_poll_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_large_enum_rust_future_poll_f64");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_cancel_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_cancel_f64");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_free_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_free_f64");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_large_enum_rust_future_complete_f64 = _dylib.lookupFunction<
+        Double Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_large_enum_rust_future_complete_f64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_large_enum_rust_future_poll_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_large_enum_rust_future_poll_pointer");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_cancel_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_cancel_pointer");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_free_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_free_pointer");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_large_enum_rust_future_complete_pointer = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_large_enum_rust_future_complete_pointer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_large_enum_rust_future_poll_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_large_enum_rust_future_poll_rust_buffer");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_cancel_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_cancel_rust_buffer");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_free_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_free_rust_buffer");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_large_enum_rust_future_complete_rust_buffer = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_large_enum_rust_future_complete_rust_buffer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_large_enum_rust_future_poll_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_large_enum_rust_future_poll_void");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_cancel_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_cancel_void");late final void Function(Pointer<Void>,) ffi_large_enum_rust_future_free_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_large_enum_rust_future_free_void");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_large_enum_rust_future_complete_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_large_enum_rust_future_complete_void");late final int Function() uniffi_large_enum_checksum_func_new_bool_value = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_new_bool_value");late final int Function() uniffi_large_enum_checksum_func_new_f32_value = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_new_f32_value");late final int Function() uniffi_large_enum_checksum_func_new_f64_value = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_new_f64_value");late final int Function() uniffi_large_enum_checksum_func_new_flat_four = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_new_flat_four");late final int Function() uniffi_large_enum_checksum_func_new_flat_one = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_new_flat_one");late final int Function() uniffi_large_enum_checksum_func_new_flat_three = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_new_flat_three");late final int Function() uniffi_large_enum_checksum_func_new_flat_two = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_new_flat_two");late final int Function() uniffi_large_enum_checksum_func_new_i16_value = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_new_i16_value");late final int Function() uniffi_large_enum_checksum_func_new_i32_value = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_new_i32_value");late final int Function() uniffi_large_enum_checksum_func_new_i64_value = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_new_i64_value");late final int Function() uniffi_large_enum_checksum_func_new_i8_value = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_new_i8_value");late final int Function() uniffi_large_enum_checksum_func_new_public_key_value = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_new_public_key_value");late final int Function() uniffi_large_enum_checksum_func_new_public_key_value_without_argument = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_new_public_key_value_without_argument");late final int Function() uniffi_large_enum_checksum_func_new_string_value = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_new_string_value");late final int Function() uniffi_large_enum_checksum_func_new_u16_value = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_new_u16_value");late final int Function() uniffi_large_enum_checksum_func_new_u32_value = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_new_u32_value");late final int Function() uniffi_large_enum_checksum_func_new_u64_value = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_new_u64_value");late final int Function() uniffi_large_enum_checksum_func_new_u8_value = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_new_u8_value");late final int Function() uniffi_large_enum_checksum_func_take_flat_enum = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_take_flat_enum");late final int Function() uniffi_large_enum_checksum_func_take_value = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_large_enum_checksum_func_take_value");late final int Function() ffi_large_enum_uniffi_contract_version = _dylib.lookupFunction<
+        Uint32 Function(),
+        int Function()
+    >("ffi_large_enum_uniffi_contract_version");
+
+    static void _checkApiVersion() {
+        final bindingsVersion = 26;
+        final scaffoldingVersion = _UniffiLib.instance.ffi_large_enum_uniffi_contract_version();
+        if (bindingsVersion != scaffoldingVersion) {
+            throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version \$bindingsVersion, scaffolding version \$scaffoldingVersion");
+        }
+    }
+
+    static void _checkApiChecksums() {
+        if (_UniffiLib.instance.uniffi_large_enum_checksum_func_new_bool_value() != 39128) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_new_f32_value() != 22337) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_new_f64_value() != 30739) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_new_flat_four() != 41651) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_new_flat_one() != 33094) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_new_flat_three() != 5161) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_new_flat_two() != 10106) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_new_i16_value() != 11489) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_new_i32_value() != 60009) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_new_i64_value() != 953) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_new_i8_value() != 27831) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_new_public_key_value() != 40815) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_new_public_key_value_without_argument() != 21914) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_new_string_value() != 34499) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_new_u16_value() != 37961) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_new_u32_value() != 7696) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_new_u64_value() != 32786) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_new_u8_value() != 33215) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_take_flat_enum() != 58721) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_large_enum_checksum_func_take_value() != 14868) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }
+    }
+}
+
+void initialize() {
+    _UniffiLib._open();
+}
+
+void ensureInitialized() {
+    _UniffiLib._checkApiVersion();
+    _UniffiLib._checkApiChecksums();
+}
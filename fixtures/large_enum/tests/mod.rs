@@ -4,3 +4,8 @@ use anyhow::Result;
 fn large_enum() -> Result<()> {
     uniffi_dart::testing::run_test("large_enum", "src/api.udl", None)
 }
+
+#[test]
+fn large_enum_golden() -> Result<()> {
+    uniffi_dart::testing::assert_golden("large_enum", "src/api.udl", None)
+}
@@ -0,0 +1,1132 @@
+library leak_check;
+
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io" show Platform, File, Directory;
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+class Reading {
+    final String label;final List<int> values;final String? note;
+
+    Reading({required this.label,required this.values,required this.note,});
+}
+
+class FfiConverterReading {
+    static Reading lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterReading.read(data).value);
+    }
+
+    static LiftRetVal<Reading> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final label_lifted = FfiConverterString.read(Uint8List.view(buf.buffer, new_offset));
+        final label = label_lifted.value;
+        new_offset += label_lifted.bytesRead;final values_lifted = FfiConverterSequenceInt32.read(Uint8List.view(buf.buffer, new_offset));
+        final values = values_lifted.value;
+        new_offset += values_lifted.bytesRead;final note_lifted = FfiConverterOptionalString.read(Uint8List.view(buf.buffer, new_offset));
+        final note = note_lifted.value;
+        new_offset += note_lifted.bytesRead;
+        return LiftRetVal(Reading(
+            label: label,values: values,note: note,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( Reading value) {
+        final total_length = FfiConverterString.allocationSize(value.label) +FfiConverterSequenceInt32.allocationSize(value.values) +FfiConverterOptionalString.allocationSize(value.note) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( Reading value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterString.write(value.label, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterSequenceInt32.write(value.values, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterOptionalString.write(value.note, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Reading value) {
+        return FfiConverterString.allocationSize(value.label) +FfiConverterSequenceInt32.allocationSize(value.values) +FfiConverterOptionalString.allocationSize(value.note) + 0;
+    }
+}
+
+abstract class LeakException implements Exception {
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterLeakException {
+    static LeakException lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterLeakException.read(data).value);
+    }
+
+    static LiftRetVal<LeakException> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4);
+        switch(index) {
+            case 1:
+                return FailedLeakException.read(subview).copyWithOffset(4);
+            default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( LeakException value) {
+        return value.lower();
+    }
+
+    static int allocationSize(LeakException value) {
+        return value.allocationSize();
+    }
+
+    static int write( LeakException value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class FailedLeakException extends LeakException {
+    final String message;
+
+    FailedLeakException(this.message,);
+
+    FailedLeakException._(this.message,);
+
+    static LiftRetVal<FailedLeakException> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final message_lifted = FfiConverterString.read(Uint8List.view(buf.buffer, new_offset));
+        final message = message_lifted.value;
+        new_offset += message_lifted.bytesRead;
+        return LiftRetVal(FailedLeakException._(
+            message,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterString.allocationSize(message) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterString.write(message, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}
+
+class LeakExceptionErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+        return FfiConverterLeakException.lift(errorBuf);
+    }
+}abstract class Shape {
+    RustBuffer lower();
+    int allocationSize();
+    int write( Uint8List buf);
+}
+
+class FfiConverterShape {
+    static Shape lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterShape.read(data).value);
+    }
+
+    static LiftRetVal<Shape> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4);
+        switch(index) {
+            case 1:
+                return CircleShape.read(subview).copyWithOffset(4);
+            case 2:
+                return PolygonShape.read(subview).copyWithOffset(4);
+            default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( Shape value) {
+        return value.lower();
+    }
+
+    static int allocationSize(Shape value) {
+        return value.allocationSize();
+    }
+
+    static int write( Shape value, Uint8List buf) {
+        return value.write(buf);
+    }
+}
+
+class CircleShape extends Shape {
+    final double radius;
+
+    CircleShape(this.radius,);
+
+    CircleShape._(this.radius,);
+
+    static LiftRetVal<CircleShape> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final radius_lifted = FfiConverterDouble64.read(Uint8List.view(buf.buffer, new_offset));
+        final radius = radius_lifted.value;
+        new_offset += radius_lifted.bytesRead;
+        return LiftRetVal(CircleShape._(
+            radius,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterDouble64.allocationSize(radius) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterDouble64.write(radius, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}class PolygonShape extends Shape {
+    final String name;final List<int> corners;
+
+    PolygonShape({ required this.name,required this.corners, });
+
+    PolygonShape._(this.name,this.corners,);
+
+    static LiftRetVal<PolygonShape> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final name_lifted = FfiConverterString.read(Uint8List.view(buf.buffer, new_offset));
+        final name = name_lifted.value;
+        new_offset += name_lifted.bytesRead;final corners_lifted = FfiConverterSequenceUInt32.read(Uint8List.view(buf.buffer, new_offset));
+        final corners = corners_lifted.value;
+        new_offset += corners_lifted.bytesRead;
+        return LiftRetVal(PolygonShape._(
+            name,corners,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    @override
+    RustBuffer lower() {
+        final buf = Uint8List(allocationSize());
+        write(buf);
+        return toRustBuffer(buf);
+    }
+
+    @override
+    int allocationSize() {
+        return FfiConverterString.allocationSize(name) +FfiConverterSequenceUInt32.allocationSize(corners) + 4;
+    }
+
+    @override
+    int write( Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 2);
+        int new_offset = buf.offsetInBytes + 4;
+
+        new_offset += FfiConverterString.write(name, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterSequenceUInt32.write(corners, Uint8List.view(buf.buffer, new_offset));
+
+        return new_offset - buf.offsetInBytes;
+    }
+}
+
+class UniffiInternalError implements Exception {
+    static const int bufferOverflow = 0;
+    static const int incompleteData = 1;
+    static const int unexpectedOptionalTag = 2;
+    static const int unexpectedEnumCase = 3;
+    static const int unexpectedNullPointer = 4;
+    static const int unexpectedRustCallStatusCode = 5;
+    static const int unexpectedRustCallError = 6;
+    static const int unexpectedStaleHandle = 7;
+    static const int rustPanic = 8;
+
+    final int errorCode;
+    final String? panicMessage;
+
+    const UniffiInternalError(this.errorCode, this.panicMessage);
+
+    static UniffiInternalError panicked(String message) {
+    return UniffiInternalError(rustPanic, message);
+    }
+
+    @override
+    String toString() {
+    switch (errorCode) {
+        case bufferOverflow:
+        return "UniFfi::BufferOverflow";
+        case incompleteData:
+        return "UniFfi::IncompleteData";
+        case unexpectedOptionalTag:
+        return "UniFfi::UnexpectedOptionalTag";
+        case unexpectedEnumCase:
+        return "UniFfi::UnexpectedEnumCase";
+        case unexpectedNullPointer:
+        return "UniFfi::UnexpectedNullPointer";
+        case unexpectedRustCallStatusCode:
+        return "UniFfi::UnexpectedRustCallStatusCode";
+        case unexpectedRustCallError:
+        return "UniFfi::UnexpectedRustCallError";
+        case unexpectedStaleHandle:
+        return "UniFfi::UnexpectedStaleHandle";
+        case rustPanic:
+        return "UniFfi::rustPanic: \$\$panicMessage";
+        default:
+        return "UniFfi::UnknownError: \$\$errorCode";
+    }
+    }
+}
+
+const int CALL_SUCCESS = 0;
+const int CALL_ERROR = 1;
+const int CALL_UNEXPECTED_ERROR = 2;
+
+final class RustCallStatus extends Struct {
+    @Int8()
+    external int code;
+
+    external RustBuffer errorBuf;
+
+}
+
+void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
+
+    if (status.ref.code == CALL_SUCCESS) {
+    return;
+    } else if (status.ref.code == CALL_ERROR) {
+    throw errorHandler.lift(status.ref.errorBuf);
+    } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+    if (status.ref.errorBuf.len > 0) {
+        throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
+    } else {
+        throw UniffiInternalError.panicked("Rust panic");
+    }
+    } else {
+    throw UniffiInternalError.panicked("Unexpected RustCallStatus code: \${status.ref.code}");
+    }
+}
+
+#[doc=" Allocates the native memory the bindings pass to Rust for the duration of a call."]
+Allocator _uniffiAllocator = calloc;
+
+#[doc=" Replaces the allocator of the native memory passed to Rust, e.g. by one that"]
+#[doc=" counts what is still allocated."]
+set uniffiAllocator(Allocator allocator) => _uniffiAllocator = allocator;
+
+T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+    final status = _uniffiAllocator<RustCallStatus>();
+    try {
+    final result = callback(status);
+    checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+    return result;
+    } finally {
+    _uniffiAllocator.free(status);
+    }
+}
+
+class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+    errorBuf.free();
+    return UniffiInternalError.panicked("Unexpected CALL_ERROR");
+    }
+}
+
+abstract class UniffiRustCallStatusErrorHandler {
+    Exception lift(RustBuffer errorBuf);
+}
+
+final class RustBuffer extends Struct {
+    @Uint64()
+    external int capacity;
+
+    @Uint64()
+    external int len;
+
+    external Pointer<Uint8> data;
+
+    static RustBuffer alloc(int size) {
+        return rustCall((status) => _UniffiLib.instance.ffi_leak_check_rustbuffer_alloc(size, status));
+    }
+
+    static RustBuffer fromBytes(ForeignBytes bytes) {
+        return rustCall((status) => _UniffiLib.instance.ffi_leak_check_rustbuffer_from_bytes(bytes, status));
+    }
+
+    void free() {
+        rustCall((status) => _UniffiLib.instance.ffi_leak_check_rustbuffer_free(this, status));
+    }
+
+    RustBuffer reserve(int additionalCapacity) {
+    return rustCall((status) => _UniffiLib.instance.ffi_leak_check_rustbuffer_reserve(this, additionalCapacity, status));
+    }
+
+    Uint8List asUint8List() {
+    final dataList = data.asTypedList(len);
+    final byteData = ByteData.sublistView(dataList);
+    return Uint8List.view(byteData.buffer);
+    }
+
+    @override
+    String toString() {
+    return "RustBuffer{capacity: \$capacity, len: \$len, data: \$data}";
+    }
+}
+
+RustBuffer toRustBuffer(Uint8List data) {
+    final length = data.length;
+
+    final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length);
+    final pointerList = frameData.asTypedList(length);
+    pointerList.setAll(0, data);
+
+    final bytes = _uniffiAllocator<ForeignBytes>();
+    bytes.ref.len = length;
+    bytes.ref.data = frameData;
+    try {
+        return RustBuffer.fromBytes(bytes.ref);
+    } finally {
+        _uniffiAllocator.free(frameData);
+        _uniffiAllocator.free(bytes);
+    }
+}
+
+T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+    try {
+        return lift(buf.asUint8List());
+    } finally {
+        buf.free();
+    }
+}
+
+final class ForeignBytes extends Struct {
+    @Int32()
+    external int len;
+    external Pointer<Uint8> data;
+
+    void free() {
+    _uniffiAllocator.free(data);
+    }
+}
+
+class LiftRetVal<T> {
+    final T value;
+    final int bytesRead;
+    const LiftRetVal(this.value, this.bytesRead);
+
+    LiftRetVal<T> copyWithOffset(int offset) {
+        return LiftRetVal(value, bytesRead + offset);
+    }
+}
+
+abstract class FfiConverter<D, F> {
+    const FfiConverter();
+
+    D lift(F value);
+    F lower(D value);
+    D read(ByteData buffer, int offset);
+    void write(D value, ByteData buffer, int offset);
+    int size(D value);
+}
+
+mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+    @override
+    T lift(T value) => value;
+
+    @override
+    T lower(T value) => value;
+}
+
+class FfiConverterBytes {
+    static Uint8List lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterBytes.read(data).value);
+    }
+
+    static RustBuffer lower( Uint8List value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static LiftRetVal<Uint8List> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+
+        return LiftRetVal(buf.sublist(4, end), end);
+    }
+
+    static int allocationSize([Uint8List? value]) {
+        return (value?.length ?? 0) + 4;
+    }
+
+    static int write( Uint8List value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        buf.setAll(4, value);
+        return value.length + 4;
+    }
+}class FfiConverterDouble64 {
+
+    static double lift(double value) => value;
+
+    static LiftRetVal<double> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat64(0), 8);
+    }
+
+    static double lower(double value) => value;
+
+    static int allocationSize([double value = 0]) {
+        return 8;
+    }
+
+    static int write(double value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setFloat64(0, value);
+        return FfiConverterDouble64.allocationSize();
+    }
+
+}class FfiConverterInt64 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt64(0), 8);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 8;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, value);
+        return FfiConverterInt64.allocationSize();
+    }
+
+}class FfiConverterMapStringString {
+
+    static Map<String, String> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMapStringString.read(data).value);
+    }
+
+    static LiftRetVal<Map<String, String>> read( Uint8List buf) {
+        final Map<String, String> res = <String, String>{};
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final key = FfiConverterString.read(Uint8List.view(buf.buffer, offset));
+            offset += key.bytesRead;
+            final value = FfiConverterString.read(Uint8List.view(buf.buffer, offset));
+            offset += value.bytesRead;
+            res[key.value] = value.value;
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( Map<String, String> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (final entry in value.entries) {
+            offset += FfiConverterString.write(entry.key, Uint8List.view(buf.buffer, offset));
+            offset += FfiConverterString.write(entry.value, Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Map<String, String> value) {
+        return value.entries
+            .map((e) => FfiConverterString.allocationSize(e.key) + FfiConverterString.allocationSize(e.value))
+            .fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( Map<String, String> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterOptionalString {
+
+    static String? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalString.read(data).value);
+    }
+
+    static LiftRetVal<String?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterString.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([String? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterString.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( String? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalString.allocationSize(value));
+        FfiConverterOptionalString.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( String? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterString.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterSequenceInt32 {
+
+    static List<int> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceInt32.read(data).value);
+    }
+
+    static LiftRetVal<List<int>> read( Uint8List buf) {
+        List<int> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterInt32.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<int> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterInt32.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<int> value) {
+        return value.map((l) => FfiConverterInt32.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<int> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterSequenceUInt32 {
+
+    static List<int> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceUInt32.read(data).value);
+    }
+
+    static LiftRetVal<List<int>> read( Uint8List buf) {
+        List<int> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterUInt32.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<int> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterUInt32.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<int> value) {
+        return value.map((l) => FfiConverterUInt32.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<int> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterString {
+    static String lift( RustBuffer buf) {
+
+        return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
+    }
+
+    static RustBuffer lower( String value) {
+        return toRustBuffer(Utf8Encoder().convert(value));
+    }
+
+    static LiftRetVal<String> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+        return LiftRetVal(utf8.decoder.convert(buf, 4, end), end);
+    }
+
+    static int allocationSize([String value = ""]) {
+
+        return utf8.encoder.convert(value).length + 4;
+    }
+
+    static int write( String value, Uint8List buf) {
+
+        final list = utf8.encoder.convert(value);
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length);
+        buf.setAll(4, list);
+        return list.length + 4;
+    }
+}class FfiConverterUInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, value);
+        return FfiConverterUInt32.allocationSize();
+    }
+
+}class FfiConverterInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value);
+        return FfiConverterInt32.allocationSize();
+    }
+
+}
+
+const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
+
+typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+Future<T> uniffiRustCallAsync<T, F>(
+    Pointer<Void> Function() rustFutureFunc,
+    void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc,
+    F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc,
+    void Function(Pointer<Void>) freeFunc,
+    T Function(F) liftFunc, [
+    UniffiRustCallStatusErrorHandler? errorHandler,
+]) async {
+    final rustFuture = rustFutureFunc();
+    final completer = Completer<int>();
+
+    late final NativeCallable<UniffiRustFutureContinuationCallback> callback;
+
+    void poll() {
+        pollFunc(
+            rustFuture,
+            callback.nativeFunction,
+            Pointer<Void>.fromAddress(0),
+        );
+    }
+    void onResponse(int _idx, int pollResult) {
+        if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+            completer.complete(pollResult);
+        } else {
+            poll();
+        }
+    }
+    callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener(onResponse);
+
+    try {
+        poll();
+        await completer.future;
+        callback.close();
+
+        final status = _uniffiAllocator<RustCallStatus>();
+        try {
+            final result = completeFunc(rustFuture, status);
+            checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+            return liftFunc(result);
+        } finally {
+            _uniffiAllocator.free(status);
+        }
+    } finally {
+        freeFunc(rustFuture);
+    }
+}
+
+class UniffiHandleMap<T> {
+    final Map<int, T> _map = {};
+    int _counter = 0;
+
+    int insert(T obj) {
+    final handle = _counter++;
+    _map[handle] = obj;
+    return handle;
+    }
+
+    T get(int handle) {
+    final obj = _map[handle];
+    if (obj == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    return obj;
+    }
+
+    void remove(int handle) {
+    if (_map.remove(handle) == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    }
+}
+
+Uint8List echoBytes(Uint8List value,) {
+    return FfiConverterBytes.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_leak_check_fn_func_echo_bytes(FfiConverterBytes.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Map<String, String> echoMap(Map<String, String> value,) {
+    return FfiConverterMapStringString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_leak_check_fn_func_echo_map(FfiConverterMapStringString.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}String? echoOptional(String? value,) {
+    return FfiConverterOptionalString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_leak_check_fn_func_echo_optional(FfiConverterOptionalString.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Reading echoReading(Reading value,) {
+    return FfiConverterReading.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_leak_check_fn_func_echo_reading(FfiConverterReading.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Shape echoShape(Shape value,) {
+    return FfiConverterShape.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_leak_check_fn_func_echo_shape(FfiConverterShape.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}String echoString(String value,) {
+    return FfiConverterString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_leak_check_fn_func_echo_string(FfiConverterString.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Future<String> echoStringAsync(String value,) {
+    return uniffiRustCallAsync(
+        () => _UniffiLib.instance.uniffi_leak_check_fn_func_echo_string_async(
+            FfiConverterString.lower(value),
+        ),
+        _UniffiLib.instance.ffi_leak_check_rust_future_poll_rust_buffer,
+        _UniffiLib.instance.ffi_leak_check_rust_future_complete_rust_buffer,
+        _UniffiLib.instance.ffi_leak_check_rust_future_free_rust_buffer,
+        FfiConverterString.lift,
+        NullRustCallStatusErrorHandler(),
+    );
+}void fail(String message,) {
+    rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_leak_check_fn_func_fail(FfiConverterString.lower(message), uniffiStatus), LeakExceptionErrorHandler());
+}int liveAllocations() {
+    return rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_leak_check_fn_func_live_allocations( uniffiStatus), NullRustCallStatusErrorHandler());
+}
+
+class _UniffiLib {
+    _UniffiLib._();
+
+    static final DynamicLibrary _dylib = _open();
+
+    static DynamicLibrary _open() {
+        if (Platform.isAndroid) return DynamicLibrary.open("libleak_check.so");
+        if (Platform.isIOS) return DynamicLibrary.executable();
+        if (Platform.isLinux) return DynamicLibrary.open("libleak_check.so");
+        if (Platform.isMacOS) return DynamicLibrary.open("libleak_check.dylib");
+        if (Platform.isWindows) return DynamicLibrary.open("leak_check.dll");
+        throw UnsupportedError("Unsupported platform: \${Platform.operatingSystem}");
+    }
+
+    static final _UniffiLib instance = _UniffiLib._();
+
+    late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_leak_check_fn_func_echo_bytes = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_leak_check_fn_func_echo_bytes");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_leak_check_fn_func_echo_map = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_leak_check_fn_func_echo_map");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_leak_check_fn_func_echo_optional = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_leak_check_fn_func_echo_optional");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_leak_check_fn_func_echo_reading = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_leak_check_fn_func_echo_reading");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_leak_check_fn_func_echo_shape = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_leak_check_fn_func_echo_shape");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_leak_check_fn_func_echo_string = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_leak_check_fn_func_echo_string");late final Pointer<Void> Function(RustBuffer,) uniffi_leak_check_fn_func_echo_string_async = _dylib.lookupFunction<
+        Pointer<Void> Function(RustBuffer,),
+        Pointer<Void> Function(RustBuffer,)
+    >("uniffi_leak_check_fn_func_echo_string_async");late final void Function(RustBuffer,Pointer<RustCallStatus>) uniffi_leak_check_fn_func_fail = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_leak_check_fn_func_fail");late final int Function(Pointer<RustCallStatus>) uniffi_leak_check_fn_func_live_allocations = _dylib.lookupFunction<
+        Int64 Function(Pointer<RustCallStatus>),
+        int Function(Pointer<RustCallStatus>)
+    >("uniffi_leak_check_fn_func_live_allocations");late final RustBuffer Function(int,Pointer<RustCallStatus>) ffi_leak_check_rustbuffer_alloc = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("ffi_leak_check_rustbuffer_alloc");late final RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>) ffi_leak_check_rustbuffer_from_bytes = _dylib.lookupFunction<
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>),
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>)
+    >("ffi_leak_check_rustbuffer_from_bytes");late final void Function(RustBuffer,Pointer<RustCallStatus>) ffi_leak_check_rustbuffer_free = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("ffi_leak_check_rustbuffer_free");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) ffi_leak_check_rustbuffer_reserve = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("ffi_leak_check_rustbuffer_reserve");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_leak_check_rust_future_poll_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_leak_check_rust_future_poll_u8");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_cancel_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_cancel_u8");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_free_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_free_u8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_leak_check_rust_future_complete_u8 = _dylib.lookupFunction<
+        Uint8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_leak_check_rust_future_complete_u8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_leak_check_rust_future_poll_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_leak_check_rust_future_poll_i8");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_cancel_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_cancel_i8");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_free_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_free_i8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_leak_check_rust_future_complete_i8 = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_leak_check_rust_future_complete_i8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_leak_check_rust_future_poll_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_leak_check_rust_future_poll_u16");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_cancel_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_cancel_u16");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_free_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_free_u16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_leak_check_rust_future_complete_u16 = _dylib.lookupFunction<
+        Uint16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_leak_check_rust_future_complete_u16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_leak_check_rust_future_poll_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_leak_check_rust_future_poll_i16");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_cancel_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_cancel_i16");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_free_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_free_i16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_leak_check_rust_future_complete_i16 = _dylib.lookupFunction<
+        Int16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_leak_check_rust_future_complete_i16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_leak_check_rust_future_poll_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_leak_check_rust_future_poll_u32");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_cancel_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_cancel_u32");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_free_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_free_u32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_leak_check_rust_future_complete_u32 = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_leak_check_rust_future_complete_u32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_leak_check_rust_future_poll_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_leak_check_rust_future_poll_i32");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_cancel_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_cancel_i32");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_free_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_free_i32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_leak_check_rust_future_complete_i32 = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_leak_check_rust_future_complete_i32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_leak_check_rust_future_poll_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_leak_check_rust_future_poll_u64");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_cancel_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_cancel_u64");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_free_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_free_u64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_leak_check_rust_future_complete_u64 = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_leak_check_rust_future_complete_u64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_leak_check_rust_future_poll_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_leak_check_rust_future_poll_i64");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_cancel_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_cancel_i64");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_free_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_free_i64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_leak_check_rust_future_complete_i64 = _dylib.lookupFunction<
+        Int64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_leak_check_rust_future_complete_i64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_leak_check_rust_future_poll_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_leak_check_rust_future_poll_f32");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_cancel_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_cancel_f32");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_free_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_free_f32");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_leak_check_rust_future_complete_f32 = _dylib.lookupFunction<
+        Float Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_leak_check_rust_future_complete_f32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_leak_check_rust_future_poll_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_leak_check_rust_future_poll_f64");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_cancel_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_cancel_f64");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_free_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_free_f64");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_leak_check_rust_future_complete_f64 = _dylib.lookupFunction<
+        Double Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_leak_check_rust_future_complete_f64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_leak_check_rust_future_poll_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_leak_check_rust_future_poll_pointer");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_cancel_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_cancel_pointer");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_free_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_free_pointer");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_leak_check_rust_future_complete_pointer = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_leak_check_rust_future_complete_pointer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_leak_check_rust_future_poll_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_leak_check_rust_future_poll_rust_buffer");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_cancel_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_cancel_rust_buffer");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_free_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_free_rust_buffer");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_leak_check_rust_future_complete_rust_buffer = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_leak_check_rust_future_complete_rust_buffer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_leak_check_rust_future_poll_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_leak_check_rust_future_poll_void");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_cancel_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_cancel_void");late final void Function(Pointer<Void>,) ffi_leak_check_rust_future_free_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_leak_check_rust_future_free_void");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_leak_check_rust_future_complete_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_leak_check_rust_future_complete_void");late final int Function() uniffi_leak_check_checksum_func_echo_bytes = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_leak_check_checksum_func_echo_bytes");late final int Function() uniffi_leak_check_checksum_func_echo_map = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_leak_check_checksum_func_echo_map");late final int Function() uniffi_leak_check_checksum_func_echo_optional = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_leak_check_checksum_func_echo_optional");late final int Function() uniffi_leak_check_checksum_func_echo_reading = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_leak_check_checksum_func_echo_reading");late final int Function() uniffi_leak_check_checksum_func_echo_shape = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_leak_check_checksum_func_echo_shape");late final int Function() uniffi_leak_check_checksum_func_echo_string = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_leak_check_checksum_func_echo_string");late final int Function() uniffi_leak_check_checksum_func_echo_string_async = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_leak_check_checksum_func_echo_string_async");late final int Function() uniffi_leak_check_checksum_func_fail = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_leak_check_checksum_func_fail");late final int Function() uniffi_leak_check_checksum_func_live_allocations = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_leak_check_checksum_func_live_allocations");late final int Function() ffi_leak_check_uniffi_contract_version = _dylib.lookupFunction<
+        Uint32 Function(),
+        int Function()
+    >("ffi_leak_check_uniffi_contract_version");
+
+    static void _checkApiVersion() {
+        final bindingsVersion = 26;
+        final scaffoldingVersion = _UniffiLib.instance.ffi_leak_check_uniffi_contract_version();
+        if (bindingsVersion != scaffoldingVersion) {
+            throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version \$bindingsVersion, scaffolding version \$scaffoldingVersion");
+        }
+    }
+
+    static void _checkApiChecksums() {
+        if (_UniffiLib.instance.uniffi_leak_check_checksum_func_echo_bytes() != 36881) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_leak_check_checksum_func_echo_map() != 63484) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_leak_check_checksum_func_echo_optional() != 36862) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_leak_check_checksum_func_echo_reading() != 6788) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_leak_check_checksum_func_echo_shape() != 39851) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_leak_check_checksum_func_echo_string() != 9979) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_leak_check_checksum_func_echo_string_async() != 26992) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_leak_check_checksum_func_fail() != 37658) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_leak_check_checksum_func_live_allocations() != 41581) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }
+    }
+}
+
+void initialize() {
+    _UniffiLib._open();
+}
+
+void ensureInitialized() {
+    _UniffiLib._checkApiVersion();
+    _UniffiLib._checkApiChecksums();
+}
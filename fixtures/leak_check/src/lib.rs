@@ -0,0 +1,90 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+
+// Counts the allocations that are alive, so that the Dart side can check that every buffer it
+// gets from Rust is given back.
+struct CountingAllocator;
+
+static LIVE_ALLOCATIONS: AtomicI64 = AtomicI64::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        LIVE_ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE_ALLOCATIONS.fetch_sub(1, Ordering::SeqCst);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[uniffi::export]
+pub fn live_allocations() -> i64 {
+    LIVE_ALLOCATIONS.load(Ordering::SeqCst)
+}
+
+#[derive(uniffi::Record)]
+pub struct Reading {
+    label: String,
+    values: Vec<i32>,
+    note: Option<String>,
+}
+
+#[derive(uniffi::Enum)]
+pub enum Shape {
+    Circle { radius: f64 },
+    Polygon { name: String, corners: Vec<u32> },
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum LeakError {
+    #[error("{message}")]
+    Failed { message: String },
+}
+
+#[uniffi::export]
+pub fn echo_string(value: String) -> String {
+    value
+}
+
+#[uniffi::export]
+pub fn echo_bytes(value: Vec<u8>) -> Vec<u8> {
+    value
+}
+
+#[uniffi::export]
+pub fn echo_reading(value: Reading) -> Reading {
+    value
+}
+
+#[uniffi::export]
+pub fn echo_shape(value: Shape) -> Shape {
+    value
+}
+
+#[uniffi::export]
+pub fn echo_map(value: HashMap<String, String>) -> HashMap<String, String> {
+    value
+}
+
+#[uniffi::export]
+pub fn echo_optional(value: Option<String>) -> Option<String> {
+    value
+}
+
+#[uniffi::export]
+pub fn fail(message: String) -> Result<(), LeakError> {
+    Err(LeakError::Failed { message })
+}
+
+#[uniffi::export]
+pub async fn echo_string_async(value: String) -> String {
+    value
+}
+
+uniffi::setup_scaffolding!();
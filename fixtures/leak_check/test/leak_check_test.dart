@@ -0,0 +1,56 @@
+import 'dart:ffi';
+import 'dart:typed_data';
+
+import 'package:ffi/ffi.dart';
+import 'package:test/test.dart';
+import '../leak_check.dart';
+
+// Counts the native memory the bindings allocate on the Dart side, which Rust's allocator
+// doesn't see
+class CountingAllocator implements Allocator {
+  int live = 0;
+
+  @override
+  Pointer<T> allocate<T extends NativeType>(int byteCount, {int? alignment}) {
+    live++;
+    return calloc.allocate(byteCount, alignment: alignment);
+  }
+
+  @override
+  void free(Pointer<NativeType> pointer) {
+    live--;
+    calloc.free(pointer);
+  }
+}
+
+Future<void> roundTrip() async {
+  echoString("a string that is long enough to need its own allocation");
+  echoBytes(Uint8List.fromList(List.generate(64, (i) => i)));
+  echoReading(Reading(label: "reading", values: [1, 2, 3], note: "note"));
+  echoShape(PolygonShape(name: "triangle", corners: [0, 1, 2]));
+  echoShape(CircleShape(1.5));
+  echoMap({"key": "value", "other": "entry"});
+  echoOptional("some");
+  echoOptional(null);
+  expect(() => fail("boom"), throwsA(isA<LeakException>()));
+  await echoStringAsync("async");
+}
+
+void main() {
+  test('calls keep the native allocation count flat', () async {
+    final dartAllocator = CountingAllocator();
+    uniffiAllocator = dartAllocator;
+    // Warm up, so that one-off allocations (e.g. lazily created statics) don't count
+    for (var i = 0; i < 10; i++) {
+      await roundTrip();
+    }
+    final before = liveAllocations();
+    final dartBefore = dartAllocator.live;
+    for (var i = 0; i < 1000; i++) {
+      await roundTrip();
+      expect(dartAllocator.live, dartBefore);
+    }
+    // A leaked buffer per call would show up as thousands of allocations
+    expect(liveAllocations() - before, lessThan(10));
+  });
+}
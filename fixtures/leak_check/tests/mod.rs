@@ -0,0 +1,11 @@
+use anyhow::Result;
+
+#[test]
+fn leak_check() -> Result<()> {
+    uniffi_dart::testing::run_library_test("leak_check", None)
+}
+
+#[test]
+fn leak_check_golden() -> Result<()> {
+    uniffi_dart::testing::assert_library_golden("leak_check", None)
+}
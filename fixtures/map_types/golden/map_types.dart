@@ -0,0 +1,1367 @@
+library map_types;
+
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io" show Platform, File, Directory;
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+class Point {
+    final int x;final int y;
+
+    Point({required this.x,required this.y,});
+}
+
+class FfiConverterPoint {
+    static Point lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterPoint.read(data).value);
+    }
+
+    static LiftRetVal<Point> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final x_lifted = FfiConverterInt32.read(Uint8List.view(buf.buffer, new_offset));
+        final x = x_lifted.value;
+        new_offset += x_lifted.bytesRead;final y_lifted = FfiConverterInt32.read(Uint8List.view(buf.buffer, new_offset));
+        final y = y_lifted.value;
+        new_offset += y_lifted.bytesRead;
+        return LiftRetVal(Point(
+            x: x,y: y,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( Point value) {
+        final total_length = FfiConverterInt32.allocationSize(value.x) +FfiConverterInt32.allocationSize(value.y) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( Point value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterInt32.write(value.x, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterInt32.write(value.y, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Point value) {
+        return FfiConverterInt32.allocationSize(value.x) +FfiConverterInt32.allocationSize(value.y) + 0;
+    }
+}
+
+enum Direction {
+    north,east,south,west,
+    ;
+}
+
+class FfiConverterDirection {
+    static Direction lift( RustBuffer buffer) {
+        return liftFromRustBuffer(buffer, (data) => FfiConverterDirection.read(data).value);
+    }
+
+    static LiftRetVal<Direction> read( Uint8List buf) {
+        final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        switch(index) {
+            case 1:
+                return LiftRetVal(Direction.north, 4);
+            case 2:
+                return LiftRetVal(Direction.east, 4);
+            case 3:
+                return LiftRetVal(Direction.south, 4);
+            case 4:
+                return LiftRetVal(Direction.west, 4);
+            default:
+                throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+        }
+    }
+
+    static RustBuffer lower( Direction input) {
+        final buf = Uint8List(allocationSize(input));
+        write(input, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int allocationSize(Direction value) {
+        return 4;
+    }
+
+    static int write( Direction value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.index + 1);
+        return 4;
+    }
+}
+abstract class ItemInterface {
+    String name();
+}
+
+final _ItemFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _ItemFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_map_types_fn_free_item");
+
+class Item implements ItemInterface, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    Item._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_ItemFree, _ptr);
+        _ItemFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    Item(String name,) : _ptr = rustCall((uniffiStatus) =>
+        _UniffiLib.instance.uniffi_map_types_fn_constructor_item_new(
+            FfiConverterString.lower(name), uniffiStatus
+        ),
+        NullRustCallStatusErrorHandler()
+    ) {
+        _attachFinalizer();
+    }
+
+    factory Item.lift(Pointer<Void> ptr) {
+        return Item._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("Item has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_map_types_fn_clone_item(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _ItemFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_map_types_fn_free_item(_ptr, status));
+    }
+
+    @override
+    String name() {
+        return FfiConverterString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_map_types_fn_method_item_name(uniffiClonePointer(),
+         uniffiStatus), NullRustCallStatusErrorHandler()));
+    }
+}
+
+class FfiConverterItem {
+    static Item lift( Pointer<Void> value) {
+        return Item.lift(value);
+    }
+
+    static Pointer<Void> lower( Item value) {
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<Item> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([Item? value]) {
+        return 8;
+    }
+
+    static int write( Item value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+class UniffiInternalError implements Exception {
+    static const int bufferOverflow = 0;
+    static const int incompleteData = 1;
+    static const int unexpectedOptionalTag = 2;
+    static const int unexpectedEnumCase = 3;
+    static const int unexpectedNullPointer = 4;
+    static const int unexpectedRustCallStatusCode = 5;
+    static const int unexpectedRustCallError = 6;
+    static const int unexpectedStaleHandle = 7;
+    static const int rustPanic = 8;
+
+    final int errorCode;
+    final String? panicMessage;
+
+    const UniffiInternalError(this.errorCode, this.panicMessage);
+
+    static UniffiInternalError panicked(String message) {
+    return UniffiInternalError(rustPanic, message);
+    }
+
+    @override
+    String toString() {
+    switch (errorCode) {
+        case bufferOverflow:
+        return "UniFfi::BufferOverflow";
+        case incompleteData:
+        return "UniFfi::IncompleteData";
+        case unexpectedOptionalTag:
+        return "UniFfi::UnexpectedOptionalTag";
+        case unexpectedEnumCase:
+        return "UniFfi::UnexpectedEnumCase";
+        case unexpectedNullPointer:
+        return "UniFfi::UnexpectedNullPointer";
+        case unexpectedRustCallStatusCode:
+        return "UniFfi::UnexpectedRustCallStatusCode";
+        case unexpectedRustCallError:
+        return "UniFfi::UnexpectedRustCallError";
+        case unexpectedStaleHandle:
+        return "UniFfi::UnexpectedStaleHandle";
+        case rustPanic:
+        return "UniFfi::rustPanic: \$\$panicMessage";
+        default:
+        return "UniFfi::UnknownError: \$\$errorCode";
+    }
+    }
+}
+
+const int CALL_SUCCESS = 0;
+const int CALL_ERROR = 1;
+const int CALL_UNEXPECTED_ERROR = 2;
+
+final class RustCallStatus extends Struct {
+    @Int8()
+    external int code;
+
+    external RustBuffer errorBuf;
+
+}
+
+void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
+
+    if (status.ref.code == CALL_SUCCESS) {
+    return;
+    } else if (status.ref.code == CALL_ERROR) {
+    throw errorHandler.lift(status.ref.errorBuf);
+    } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+    if (status.ref.errorBuf.len > 0) {
+        throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
+    } else {
+        throw UniffiInternalError.panicked("Rust panic");
+    }
+    } else {
+    throw UniffiInternalError.panicked("Unexpected RustCallStatus code: \${status.ref.code}");
+    }
+}
+
+#[doc=" Allocates the native memory the bindings pass to Rust for the duration of a call."]
+const Allocator _uniffiAllocator = calloc;
+
+T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+    final status = _uniffiAllocator<RustCallStatus>();
+    try {
+    final result = callback(status);
+    checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+    return result;
+    } finally {
+    _uniffiAllocator.free(status);
+    }
+}
+
+class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+    errorBuf.free();
+    return UniffiInternalError.panicked("Unexpected CALL_ERROR");
+    }
+}
+
+abstract class UniffiRustCallStatusErrorHandler {
+    Exception lift(RustBuffer errorBuf);
+}
+
+final class RustBuffer extends Struct {
+    @Uint64()
+    external int capacity;
+
+    @Uint64()
+    external int len;
+
+    external Pointer<Uint8> data;
+
+    static RustBuffer alloc(int size) {
+        return rustCall((status) => _UniffiLib.instance.ffi_map_types_rustbuffer_alloc(size, status));
+    }
+
+    static RustBuffer fromBytes(ForeignBytes bytes) {
+        return rustCall((status) => _UniffiLib.instance.ffi_map_types_rustbuffer_from_bytes(bytes, status));
+    }
+
+    void free() {
+        rustCall((status) => _UniffiLib.instance.ffi_map_types_rustbuffer_free(this, status));
+    }
+
+    RustBuffer reserve(int additionalCapacity) {
+    return rustCall((status) => _UniffiLib.instance.ffi_map_types_rustbuffer_reserve(this, additionalCapacity, status));
+    }
+
+    Uint8List asUint8List() {
+    final dataList = data.asTypedList(len);
+    final byteData = ByteData.sublistView(dataList);
+    return Uint8List.view(byteData.buffer);
+    }
+
+    @override
+    String toString() {
+    return "RustBuffer{capacity: \$capacity, len: \$len, data: \$data}";
+    }
+}
+
+RustBuffer toRustBuffer(Uint8List data) {
+    final length = data.length;
+
+    final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length);
+    final pointerList = frameData.asTypedList(length);
+    pointerList.setAll(0, data);
+
+    final bytes = _uniffiAllocator<ForeignBytes>();
+    bytes.ref.len = length;
+    bytes.ref.data = frameData;
+    try {
+        return RustBuffer.fromBytes(bytes.ref);
+    } finally {
+        _uniffiAllocator.free(frameData);
+        _uniffiAllocator.free(bytes);
+    }
+}
+
+T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+    try {
+        return lift(buf.asUint8List());
+    } finally {
+        buf.free();
+    }
+}
+
+final class ForeignBytes extends Struct {
+    @Int32()
+    external int len;
+    external Pointer<Uint8> data;
+
+    void free() {
+    _uniffiAllocator.free(data);
+    }
+}
+
+class LiftRetVal<T> {
+    final T value;
+    final int bytesRead;
+    const LiftRetVal(this.value, this.bytesRead);
+
+    LiftRetVal<T> copyWithOffset(int offset) {
+        return LiftRetVal(value, bytesRead + offset);
+    }
+}
+
+abstract class FfiConverter<D, F> {
+    const FfiConverter();
+
+    D lift(F value);
+    F lower(D value);
+    D read(ByteData buffer, int offset);
+    void write(D value, ByteData buffer, int offset);
+    int size(D value);
+}
+
+mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+    @override
+    T lift(T value) => value;
+
+    @override
+    T lower(T value) => value;
+}
+
+class FfiConverterInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value);
+        return FfiConverterInt32.allocationSize();
+    }
+
+}class FfiConverterMapDirectionPoint {
+
+    static Map<Direction, Point> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMapDirectionPoint.read(data).value);
+    }
+
+    static LiftRetVal<Map<Direction, Point>> read( Uint8List buf) {
+        final Map<Direction, Point> res = <Direction, Point>{};
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final key = FfiConverterDirection.read(Uint8List.view(buf.buffer, offset));
+            offset += key.bytesRead;
+            final value = FfiConverterPoint.read(Uint8List.view(buf.buffer, offset));
+            offset += value.bytesRead;
+            res[key.value] = value.value;
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( Map<Direction, Point> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (final entry in value.entries) {
+            offset += FfiConverterDirection.write(entry.key, Uint8List.view(buf.buffer, offset));
+            offset += FfiConverterPoint.write(entry.value, Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Map<Direction, Point> value) {
+        return value.entries
+            .map((e) => FfiConverterDirection.allocationSize(e.key) + FfiConverterPoint.allocationSize(e.value))
+            .fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( Map<Direction, Point> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterMapStringInt32 {
+
+    static Map<String, int> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMapStringInt32.read(data).value);
+    }
+
+    static LiftRetVal<Map<String, int>> read( Uint8List buf) {
+        final Map<String, int> res = <String, int>{};
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final key = FfiConverterString.read(Uint8List.view(buf.buffer, offset));
+            offset += key.bytesRead;
+            final value = FfiConverterInt32.read(Uint8List.view(buf.buffer, offset));
+            offset += value.bytesRead;
+            res[key.value] = value.value;
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( Map<String, int> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (final entry in value.entries) {
+            offset += FfiConverterString.write(entry.key, Uint8List.view(buf.buffer, offset));
+            offset += FfiConverterInt32.write(entry.value, Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Map<String, int> value) {
+        return value.entries
+            .map((e) => FfiConverterString.allocationSize(e.key) + FfiConverterInt32.allocationSize(e.value))
+            .fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( Map<String, int> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterMapStringItem {
+
+    static Map<String, Item> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMapStringItem.read(data).value);
+    }
+
+    static LiftRetVal<Map<String, Item>> read( Uint8List buf) {
+        final Map<String, Item> res = <String, Item>{};
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final key = FfiConverterString.read(Uint8List.view(buf.buffer, offset));
+            offset += key.bytesRead;
+            final value = FfiConverterItem.read(Uint8List.view(buf.buffer, offset));
+            offset += value.bytesRead;
+            res[key.value] = value.value;
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( Map<String, Item> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (final entry in value.entries) {
+            offset += FfiConverterString.write(entry.key, Uint8List.view(buf.buffer, offset));
+            offset += FfiConverterItem.write(entry.value, Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Map<String, Item> value) {
+        return value.entries
+            .map((e) => FfiConverterString.allocationSize(e.key) + FfiConverterItem.allocationSize(e.value))
+            .fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( Map<String, Item> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterMapStringMapStringInt32 {
+
+    static Map<String, Map<String, int>> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMapStringMapStringInt32.read(data).value);
+    }
+
+    static LiftRetVal<Map<String, Map<String, int>>> read( Uint8List buf) {
+        final Map<String, Map<String, int>> res = <String, Map<String, int>>{};
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final key = FfiConverterString.read(Uint8List.view(buf.buffer, offset));
+            offset += key.bytesRead;
+            final value = FfiConverterMapStringInt32.read(Uint8List.view(buf.buffer, offset));
+            offset += value.bytesRead;
+            res[key.value] = value.value;
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( Map<String, Map<String, int>> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (final entry in value.entries) {
+            offset += FfiConverterString.write(entry.key, Uint8List.view(buf.buffer, offset));
+            offset += FfiConverterMapStringInt32.write(entry.value, Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Map<String, Map<String, int>> value) {
+        return value.entries
+            .map((e) => FfiConverterString.allocationSize(e.key) + FfiConverterMapStringInt32.allocationSize(e.value))
+            .fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( Map<String, Map<String, int>> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterMapStringOptionalString {
+
+    static Map<String, String?> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMapStringOptionalString.read(data).value);
+    }
+
+    static LiftRetVal<Map<String, String?>> read( Uint8List buf) {
+        final Map<String, String?> res = <String, String?>{};
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final key = FfiConverterString.read(Uint8List.view(buf.buffer, offset));
+            offset += key.bytesRead;
+            final value = FfiConverterOptionalString.read(Uint8List.view(buf.buffer, offset));
+            offset += value.bytesRead;
+            res[key.value] = value.value;
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( Map<String, String?> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (final entry in value.entries) {
+            offset += FfiConverterString.write(entry.key, Uint8List.view(buf.buffer, offset));
+            offset += FfiConverterOptionalString.write(entry.value, Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Map<String, String?> value) {
+        return value.entries
+            .map((e) => FfiConverterString.allocationSize(e.key) + FfiConverterOptionalString.allocationSize(e.value))
+            .fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( Map<String, String?> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterMapStringSequenceInt32 {
+
+    static Map<String, List<int>> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMapStringSequenceInt32.read(data).value);
+    }
+
+    static LiftRetVal<Map<String, List<int>>> read( Uint8List buf) {
+        final Map<String, List<int>> res = <String, List<int>>{};
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final key = FfiConverterString.read(Uint8List.view(buf.buffer, offset));
+            offset += key.bytesRead;
+            final value = FfiConverterSequenceInt32.read(Uint8List.view(buf.buffer, offset));
+            offset += value.bytesRead;
+            res[key.value] = value.value;
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( Map<String, List<int>> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (final entry in value.entries) {
+            offset += FfiConverterString.write(entry.key, Uint8List.view(buf.buffer, offset));
+            offset += FfiConverterSequenceInt32.write(entry.value, Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Map<String, List<int>> value) {
+        return value.entries
+            .map((e) => FfiConverterString.allocationSize(e.key) + FfiConverterSequenceInt32.allocationSize(e.value))
+            .fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( Map<String, List<int>> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterMapStringString {
+
+    static Map<String, String> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMapStringString.read(data).value);
+    }
+
+    static LiftRetVal<Map<String, String>> read( Uint8List buf) {
+        final Map<String, String> res = <String, String>{};
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final key = FfiConverterString.read(Uint8List.view(buf.buffer, offset));
+            offset += key.bytesRead;
+            final value = FfiConverterString.read(Uint8List.view(buf.buffer, offset));
+            offset += value.bytesRead;
+            res[key.value] = value.value;
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( Map<String, String> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (final entry in value.entries) {
+            offset += FfiConverterString.write(entry.key, Uint8List.view(buf.buffer, offset));
+            offset += FfiConverterString.write(entry.value, Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Map<String, String> value) {
+        return value.entries
+            .map((e) => FfiConverterString.allocationSize(e.key) + FfiConverterString.allocationSize(e.value))
+            .fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( Map<String, String> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterMapStringUInt64 {
+
+    static Map<String, int> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMapStringUInt64.read(data).value);
+    }
+
+    static LiftRetVal<Map<String, int>> read( Uint8List buf) {
+        final Map<String, int> res = <String, int>{};
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final key = FfiConverterString.read(Uint8List.view(buf.buffer, offset));
+            offset += key.bytesRead;
+            final value = FfiConverterUInt64.read(Uint8List.view(buf.buffer, offset));
+            offset += value.bytesRead;
+            res[key.value] = value.value;
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( Map<String, int> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (final entry in value.entries) {
+            offset += FfiConverterString.write(entry.key, Uint8List.view(buf.buffer, offset));
+            offset += FfiConverterUInt64.write(entry.value, Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Map<String, int> value) {
+        return value.entries
+            .map((e) => FfiConverterString.allocationSize(e.key) + FfiConverterUInt64.allocationSize(e.value))
+            .fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( Map<String, int> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterMapUInt32UInt64 {
+
+    static Map<int, int> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMapUInt32UInt64.read(data).value);
+    }
+
+    static LiftRetVal<Map<int, int>> read( Uint8List buf) {
+        final Map<int, int> res = <int, int>{};
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final key = FfiConverterUInt32.read(Uint8List.view(buf.buffer, offset));
+            offset += key.bytesRead;
+            final value = FfiConverterUInt64.read(Uint8List.view(buf.buffer, offset));
+            offset += value.bytesRead;
+            res[key.value] = value.value;
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( Map<int, int> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (final entry in value.entries) {
+            offset += FfiConverterUInt32.write(entry.key, Uint8List.view(buf.buffer, offset));
+            offset += FfiConverterUInt64.write(entry.value, Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Map<int, int> value) {
+        return value.entries
+            .map((e) => FfiConverterUInt32.allocationSize(e.key) + FfiConverterUInt64.allocationSize(e.value))
+            .fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( Map<int, int> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterOptionalString {
+
+    static String? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalString.read(data).value);
+    }
+
+    static LiftRetVal<String?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterString.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([String? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterString.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( String? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalString.allocationSize(value));
+        FfiConverterOptionalString.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( String? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterString.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterSequenceInt32 {
+
+    static List<int> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceInt32.read(data).value);
+    }
+
+    static LiftRetVal<List<int>> read( Uint8List buf) {
+        List<int> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterInt32.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<int> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterInt32.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<int> value) {
+        return value.map((l) => FfiConverterInt32.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<int> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterSequenceString {
+
+    static List<String> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceString.read(data).value);
+    }
+
+    static LiftRetVal<List<String>> read( Uint8List buf) {
+        List<String> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterString.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<String> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterString.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<String> value) {
+        return value.map((l) => FfiConverterString.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<String> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterString {
+    static String lift( RustBuffer buf) {
+
+        return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
+    }
+
+    static RustBuffer lower( String value) {
+        return toRustBuffer(Utf8Encoder().convert(value));
+    }
+
+    static LiftRetVal<String> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+        return LiftRetVal(utf8.decoder.convert(buf, 4, end), end);
+    }
+
+    static int allocationSize([String value = ""]) {
+
+        return utf8.encoder.convert(value).length + 4;
+    }
+
+    static int write( String value, Uint8List buf) {
+
+        final list = utf8.encoder.convert(value);
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length);
+        buf.setAll(4, list);
+        return list.length + 4;
+    }
+}class FfiConverterUInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, value);
+        return FfiConverterUInt32.allocationSize();
+    }
+
+}class FfiConverterUInt64 {
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0), 8);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 8;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, value);
+        return FfiConverterUInt64.allocationSize();
+    }
+}
+
+const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
+
+typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+Future<T> uniffiRustCallAsync<T, F>(
+    Pointer<Void> Function() rustFutureFunc,
+    void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc,
+    F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc,
+    void Function(Pointer<Void>) freeFunc,
+    T Function(F) liftFunc, [
+    UniffiRustCallStatusErrorHandler? errorHandler,
+]) async {
+    final rustFuture = rustFutureFunc();
+    final completer = Completer<int>();
+
+    late final NativeCallable<UniffiRustFutureContinuationCallback> callback;
+
+    void poll() {
+        pollFunc(
+            rustFuture,
+            callback.nativeFunction,
+            Pointer<Void>.fromAddress(0),
+        );
+    }
+    void onResponse(int _idx, int pollResult) {
+        if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+            completer.complete(pollResult);
+        } else {
+            poll();
+        }
+    }
+    callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener(onResponse);
+
+    try {
+        poll();
+        await completer.future;
+        callback.close();
+
+        final status = _uniffiAllocator<RustCallStatus>();
+        try {
+            final result = completeFunc(rustFuture, status);
+            checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+            return liftFunc(result);
+        } finally {
+            _uniffiAllocator.free(status);
+        }
+    } finally {
+        freeFunc(rustFuture);
+    }
+}
+
+class UniffiHandleMap<T> {
+    final Map<int, T> _map = {};
+    int _counter = 0;
+
+    int insert(T obj) {
+    final handle = _counter++;
+    _map[handle] = obj;
+    return handle;
+    }
+
+    T get(int handle) {
+    final obj = _map[handle];
+    if (obj == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    return obj;
+    }
+
+    void remove(int handle) {
+    if (_map.remove(handle) == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    }
+}
+
+int countEntries(Map<String, String?> dict,) {
+    return rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_map_types_fn_func_count_entries(FfiConverterMapStringOptionalString.lower(dict), uniffiStatus), NullRustCallStatusErrorHandler());
+}Map<String, int> echoDict(Map<String, int> dict,) {
+    return FfiConverterMapStringUInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_map_types_fn_func_echo_dict(FfiConverterMapStringUInt64.lower(dict), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Map<String, Map<String, int>> echoNested(Map<String, Map<String, int>> dict,) {
+    return FfiConverterMapStringMapStringInt32.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_map_types_fn_func_echo_nested(FfiConverterMapStringMapStringInt32.lower(dict), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Map<String, String?> echoOptionalValues(Map<String, String?> dict,) {
+    return FfiConverterMapStringOptionalString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_map_types_fn_func_echo_optional_values(FfiConverterMapStringOptionalString.lower(dict), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Map<String, List<int>> echoSequenceValues(Map<String, List<int>> dict,) {
+    return FfiConverterMapStringSequenceInt32.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_map_types_fn_func_echo_sequence_values(FfiConverterMapStringSequenceInt32.lower(dict), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Map<String, int> getDict(String key,int value,) {
+    return FfiConverterMapStringUInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_map_types_fn_func_get_dict(FfiConverterString.lower(key),FfiConverterUInt64.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Map<int, int> getIntDict(int key,int value,) {
+    return FfiConverterMapUInt32UInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_map_types_fn_func_get_int_dict(key,FfiConverterUInt64.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Map<String, String> itemNames(Map<String, Item> items,) {
+    return FfiConverterMapStringString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_map_types_fn_func_item_names(FfiConverterMapStringItem.lower(items), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Map<String, Item> itemsByName(List<String> names,) {
+    return FfiConverterMapStringItem.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_map_types_fn_func_items_by_name(FfiConverterSequenceString.lower(names), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Map<Direction, Point> moveAll(Map<Direction, Point> points,) {
+    return FfiConverterMapDirectionPoint.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_map_types_fn_func_move_all(FfiConverterMapDirectionPoint.lower(points), uniffiStatus), NullRustCallStatusErrorHandler()));
+}
+
+class _UniffiLib {
+    _UniffiLib._();
+
+    static final DynamicLibrary _dylib = _open();
+
+    static DynamicLibrary _open() {
+        if (Platform.isAndroid) return DynamicLibrary.open("libmap_types.so");
+        if (Platform.isIOS) return DynamicLibrary.executable();
+        if (Platform.isLinux) return DynamicLibrary.open("libmap_types.so");
+        if (Platform.isMacOS) return DynamicLibrary.open("libmap_types.dylib");
+        if (Platform.isWindows) return DynamicLibrary.open("map_types.dll");
+        throw UnsupportedError("Unsupported platform: \${Platform.operatingSystem}");
+    }
+
+    static final _UniffiLib instance = _UniffiLib._();
+
+    late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_map_types_fn_clone_item = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_map_types_fn_clone_item");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_map_types_fn_free_item = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_map_types_fn_free_item");late final Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>) uniffi_map_types_fn_constructor_item_new = _dylib.lookupFunction<
+        Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>),
+        Pointer<Void> Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_map_types_fn_constructor_item_new");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_map_types_fn_method_item_name = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_map_types_fn_method_item_name");late final int Function(RustBuffer,Pointer<RustCallStatus>) uniffi_map_types_fn_func_count_entries = _dylib.lookupFunction<
+        Uint32 Function(RustBuffer,Pointer<RustCallStatus>),
+        int Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_map_types_fn_func_count_entries");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_map_types_fn_func_echo_dict = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_map_types_fn_func_echo_dict");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_map_types_fn_func_echo_nested = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_map_types_fn_func_echo_nested");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_map_types_fn_func_echo_optional_values = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_map_types_fn_func_echo_optional_values");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_map_types_fn_func_echo_sequence_values = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_map_types_fn_func_echo_sequence_values");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) uniffi_map_types_fn_func_get_dict = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("uniffi_map_types_fn_func_get_dict");late final RustBuffer Function(int,int,Pointer<RustCallStatus>) uniffi_map_types_fn_func_get_int_dict = _dylib.lookupFunction<
+        RustBuffer Function(Uint32,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,int,Pointer<RustCallStatus>)
+    >("uniffi_map_types_fn_func_get_int_dict");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_map_types_fn_func_item_names = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_map_types_fn_func_item_names");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_map_types_fn_func_items_by_name = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_map_types_fn_func_items_by_name");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_map_types_fn_func_move_all = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_map_types_fn_func_move_all");late final RustBuffer Function(int,Pointer<RustCallStatus>) ffi_map_types_rustbuffer_alloc = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("ffi_map_types_rustbuffer_alloc");late final RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>) ffi_map_types_rustbuffer_from_bytes = _dylib.lookupFunction<
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>),
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>)
+    >("ffi_map_types_rustbuffer_from_bytes");late final void Function(RustBuffer,Pointer<RustCallStatus>) ffi_map_types_rustbuffer_free = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("ffi_map_types_rustbuffer_free");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) ffi_map_types_rustbuffer_reserve = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("ffi_map_types_rustbuffer_reserve");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_map_types_rust_future_poll_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_map_types_rust_future_poll_u8");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_cancel_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_cancel_u8");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_free_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_free_u8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_map_types_rust_future_complete_u8 = _dylib.lookupFunction<
+        Uint8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_map_types_rust_future_complete_u8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_map_types_rust_future_poll_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_map_types_rust_future_poll_i8");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_cancel_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_cancel_i8");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_free_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_free_i8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_map_types_rust_future_complete_i8 = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_map_types_rust_future_complete_i8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_map_types_rust_future_poll_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_map_types_rust_future_poll_u16");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_cancel_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_cancel_u16");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_free_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_free_u16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_map_types_rust_future_complete_u16 = _dylib.lookupFunction<
+        Uint16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_map_types_rust_future_complete_u16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_map_types_rust_future_poll_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_map_types_rust_future_poll_i16");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_cancel_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_cancel_i16");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_free_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_free_i16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_map_types_rust_future_complete_i16 = _dylib.lookupFunction<
+        Int16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_map_types_rust_future_complete_i16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_map_types_rust_future_poll_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_map_types_rust_future_poll_u32");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_cancel_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_cancel_u32");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_free_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_free_u32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_map_types_rust_future_complete_u32 = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_map_types_rust_future_complete_u32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_map_types_rust_future_poll_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_map_types_rust_future_poll_i32");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_cancel_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_cancel_i32");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_free_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_free_i32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_map_types_rust_future_complete_i32 = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_map_types_rust_future_complete_i32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_map_types_rust_future_poll_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_map_types_rust_future_poll_u64");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_cancel_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_cancel_u64");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_free_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_free_u64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_map_types_rust_future_complete_u64 = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_map_types_rust_future_complete_u64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_map_types_rust_future_poll_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_map_types_rust_future_poll_i64");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_cancel_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_cancel_i64");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_free_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_free_i64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_map_types_rust_future_complete_i64 = _dylib.lookupFunction<
+        Int64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_map_types_rust_future_complete_i64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_map_types_rust_future_poll_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_map_types_rust_future_poll_f32");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_cancel_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_cancel_f32");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_free_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_free_f32");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_map_types_rust_future_complete_f32 = _dylib.lookupFunction<
+        Float Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_map_types_rust_future_complete_f32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_map_types_rust_future_poll_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_map_types_rust_future_poll_f64");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_cancel_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_cancel_f64");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_free_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_free_f64");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_map_types_rust_future_complete_f64 = _dylib.lookupFunction<
+        Double Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_map_types_rust_future_complete_f64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_map_types_rust_future_poll_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_map_types_rust_future_poll_pointer");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_cancel_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_cancel_pointer");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_free_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_free_pointer");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_map_types_rust_future_complete_pointer = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_map_types_rust_future_complete_pointer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_map_types_rust_future_poll_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_map_types_rust_future_poll_rust_buffer");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_cancel_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_cancel_rust_buffer");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_free_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_free_rust_buffer");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_map_types_rust_future_complete_rust_buffer = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_map_types_rust_future_complete_rust_buffer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_map_types_rust_future_poll_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_map_types_rust_future_poll_void");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_cancel_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_cancel_void");late final void Function(Pointer<Void>,) ffi_map_types_rust_future_free_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_map_types_rust_future_free_void");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_map_types_rust_future_complete_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_map_types_rust_future_complete_void");late final int Function() uniffi_map_types_checksum_func_count_entries = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_map_types_checksum_func_count_entries");late final int Function() uniffi_map_types_checksum_func_echo_dict = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_map_types_checksum_func_echo_dict");late final int Function() uniffi_map_types_checksum_func_echo_nested = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_map_types_checksum_func_echo_nested");late final int Function() uniffi_map_types_checksum_func_echo_optional_values = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_map_types_checksum_func_echo_optional_values");late final int Function() uniffi_map_types_checksum_func_echo_sequence_values = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_map_types_checksum_func_echo_sequence_values");late final int Function() uniffi_map_types_checksum_func_get_dict = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_map_types_checksum_func_get_dict");late final int Function() uniffi_map_types_checksum_func_get_int_dict = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_map_types_checksum_func_get_int_dict");late final int Function() uniffi_map_types_checksum_func_item_names = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_map_types_checksum_func_item_names");late final int Function() uniffi_map_types_checksum_func_items_by_name = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_map_types_checksum_func_items_by_name");late final int Function() uniffi_map_types_checksum_func_move_all = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_map_types_checksum_func_move_all");late final int Function() uniffi_map_types_checksum_method_item_name = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_map_types_checksum_method_item_name");late final int Function() uniffi_map_types_checksum_constructor_item_new = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_map_types_checksum_constructor_item_new");late final int Function() ffi_map_types_uniffi_contract_version = _dylib.lookupFunction<
+        Uint32 Function(),
+        int Function()
+    >("ffi_map_types_uniffi_contract_version");late final Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>) uniffi_dart_finalizer_token_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>),
+        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>)
+    >("uniffi_dart_finalizer_token_new");
+    late final void Function(Pointer<Void>) uniffi_dart_finalizer_token_free = _dylib.lookupFunction<
+        Void Function(Pointer<Void>),
+        void Function(Pointer<Void>)
+    >("uniffi_dart_finalizer_token_free");
+    late final Pointer<NativeFinalizerFunction> uniffi_dart_finalizer_run = _dylib.lookup("uniffi_dart_finalizer_run");
+
+    static void _checkApiVersion() {
+        final bindingsVersion = 26;
+        final scaffoldingVersion = _UniffiLib.instance.ffi_map_types_uniffi_contract_version();
+        if (bindingsVersion != scaffoldingVersion) {
+            throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version \$bindingsVersion, scaffolding version \$scaffoldingVersion");
+        }
+    }
+
+    static void _checkApiChecksums() {
+        if (_UniffiLib.instance.uniffi_map_types_checksum_func_count_entries() != 62691) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_map_types_checksum_func_echo_dict() != 49523) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_map_types_checksum_func_echo_nested() != 19639) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_map_types_checksum_func_echo_optional_values() != 41884) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_map_types_checksum_func_echo_sequence_values() != 22415) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_map_types_checksum_func_get_dict() != 8336) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_map_types_checksum_func_get_int_dict() != 36869) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_map_types_checksum_func_item_names() != 4800) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_map_types_checksum_func_items_by_name() != 11675) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_map_types_checksum_func_move_all() != 57343) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_map_types_checksum_method_item_name() != 40832) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_map_types_checksum_constructor_item_new() != 60154) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }
+    }
+}
+
+void initialize() {
+    _UniffiLib._open();
+}
+
+void ensureInitialized() {
+    _UniffiLib._checkApiVersion();
+    _UniffiLib._checkApiChecksums();
+}
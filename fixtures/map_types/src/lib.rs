@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(uniffi::Enum, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[derive(uniffi::Record, Clone)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(uniffi::Object)]
+pub struct Item {
+    name: String,
+}
+
+#[uniffi::export]
+impl Item {
+    #[uniffi::constructor]
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+#[uniffi::export]
+pub fn get_dict(key: String, value: u64) -> HashMap<String, u64> {
+    HashMap::from([(key, value)])
+}
+
+#[uniffi::export]
+pub fn get_int_dict(key: u32, value: u64) -> HashMap<u32, u64> {
+    HashMap::from([(key, value)])
+}
+
+#[uniffi::export]
+pub fn echo_dict(dict: HashMap<String, u64>) -> HashMap<String, u64> {
+    dict
+}
+
+#[uniffi::export]
+pub fn count_entries(dict: HashMap<String, Option<String>>) -> u32 {
+    dict.values().filter(|v| v.is_some()).count() as u32
+}
+
+#[uniffi::export]
+pub fn echo_optional_values(dict: HashMap<String, Option<String>>) -> HashMap<String, Option<String>> {
+    dict
+}
+
+#[uniffi::export]
+pub fn echo_sequence_values(dict: HashMap<String, Vec<i32>>) -> HashMap<String, Vec<i32>> {
+    dict
+}
+
+#[uniffi::export]
+pub fn echo_nested(dict: HashMap<String, HashMap<String, i32>>) -> HashMap<String, HashMap<String, i32>> {
+    dict
+}
+
+#[uniffi::export]
+pub fn move_all(points: HashMap<Direction, Point>) -> HashMap<Direction, Point> {
+    points
+        .into_iter()
+        .map(|(direction, Point { x, y })| {
+            let point = match direction {
+                Direction::North => Point { x, y: y + 1 },
+                Direction::East => Point { x: x + 1, y },
+                Direction::South => Point { x, y: y - 1 },
+                Direction::West => Point { x: x - 1, y },
+            };
+            (direction, point)
+        })
+        .collect()
+}
+
+#[uniffi::export]
+pub fn items_by_name(names: Vec<String>) -> HashMap<String, Arc<Item>> {
+    names
+        .into_iter()
+        .map(|name| (name.clone(), Arc::new(Item { name })))
+        .collect()
+}
+
+/// The names of the items, keyed like the map they came in, to check that each value arrived.
+#[uniffi::export]
+pub fn item_names(items: HashMap<String, Arc<Item>>) -> HashMap<String, String> {
+    items
+        .into_iter()
+        .map(|(key, item)| (key, item.name()))
+        .collect()
+}
+
+uniffi::include_scaffolding!("api");
+uniffi_dart::setup_finalizers!();
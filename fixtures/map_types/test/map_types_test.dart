@@ -0,0 +1,59 @@
+import 'package:test/test.dart';
+import '../map_types.dart';
+
+void main() {
+  test('string keys', () {
+    expect(getDict('answer', 42), {'answer': 42});
+  });
+
+  test('integer keys', () {
+    expect(getIntDict(7, 42), {7: 42});
+  });
+
+  test('round trip', () {
+    expect(echoDict({}), isEmpty);
+    expect(echoDict({'a': 1, 'b': 2, 'c': 3}), {'a': 1, 'b': 2, 'c': 3});
+  });
+
+  test('optional values', () {
+    final dict = {'some': 'value', 'none': null};
+    expect(countEntries(dict), 1);
+    expect(echoOptionalValues(dict), dict);
+  });
+
+  test('sequence values', () {
+    final dict = {
+      'empty': <int>[],
+      'full': [1, 2, 3],
+    };
+    expect(echoSequenceValues(dict), dict);
+  });
+
+  test('nested maps', () {
+    final dict = {
+      'outer': {'inner': 1, 'other': -1},
+      'empty': <String, int>{},
+    };
+    expect(echoNested(dict), dict);
+  });
+
+  test('enum keys and record values', () {
+    final moved = moveAll({
+      Direction.north: Point(x: 0, y: 0),
+      Direction.west: Point(x: 3, y: 3),
+    });
+    expect(moved.length, 2);
+    expect(moved[Direction.north]!.y, 1);
+    expect(moved[Direction.west]!.x, 2);
+    expect(moved[Direction.west]!.y, 3);
+  });
+
+  test('object values', () {
+    final items = itemsByName(['apple', 'pear']);
+    expect(items.keys, unorderedEquals(['apple', 'pear']));
+    expect(items['apple']!.name(), 'apple');
+    expect(items['pear']!.name(), 'pear');
+    expect(itemNames({'first': Item('one'), 'second': items['pear']!}),
+        {'first': 'one', 'second': 'pear'});
+  });
+}
@@ -0,0 +1,11 @@
+use anyhow::Result;
+
+#[test]
+fn map_types() -> Result<()> {
+    uniffi_dart::testing::run_test("map_types", "src/api.udl", None)
+}
+
+#[test]
+fn map_types_golden() -> Result<()> {
+    uniffi_dart::testing::assert_golden("map_types", "src/api.udl", None)
+}
@@ -0,0 +1,1038 @@
+library primitive_conformance;
+
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io" show Platform, File, Directory;
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+class Boundaries {
+    final int i8Min;final int u8Max;final int i16Min;final int u16Max;final int i32Min;final int u32Max;final int i64Min;final int i64Max;final int u64Max;final double f32Nan;final double f32NegZero;final double f32Max;final double f64Nan;final double f64NegZero;final double f64NegInfinity;final double f64MinPositive;
+
+    Boundaries({required this.i8Min,required this.u8Max,required this.i16Min,required this.u16Max,required this.i32Min,required this.u32Max,required this.i64Min,required this.i64Max,required this.u64Max,required this.f32Nan,required this.f32NegZero,required this.f32Max,required this.f64Nan,required this.f64NegZero,required this.f64NegInfinity,required this.f64MinPositive,});
+}
+
+class FfiConverterBoundaries {
+    static Boundaries lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterBoundaries.read(data).value);
+    }
+
+    static LiftRetVal<Boundaries> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final i8Min_lifted = FfiConverterInt8.read(Uint8List.view(buf.buffer, new_offset));
+        final i8Min = i8Min_lifted.value;
+        new_offset += i8Min_lifted.bytesRead;final u8Max_lifted = FfiConverterUInt8.read(Uint8List.view(buf.buffer, new_offset));
+        final u8Max = u8Max_lifted.value;
+        new_offset += u8Max_lifted.bytesRead;final i16Min_lifted = FfiConverterInt16.read(Uint8List.view(buf.buffer, new_offset));
+        final i16Min = i16Min_lifted.value;
+        new_offset += i16Min_lifted.bytesRead;final u16Max_lifted = FfiConverterUInt16.read(Uint8List.view(buf.buffer, new_offset));
+        final u16Max = u16Max_lifted.value;
+        new_offset += u16Max_lifted.bytesRead;final i32Min_lifted = FfiConverterInt32.read(Uint8List.view(buf.buffer, new_offset));
+        final i32Min = i32Min_lifted.value;
+        new_offset += i32Min_lifted.bytesRead;final u32Max_lifted = FfiConverterUInt32.read(Uint8List.view(buf.buffer, new_offset));
+        final u32Max = u32Max_lifted.value;
+        new_offset += u32Max_lifted.bytesRead;final i64Min_lifted = FfiConverterInt64.read(Uint8List.view(buf.buffer, new_offset));
+        final i64Min = i64Min_lifted.value;
+        new_offset += i64Min_lifted.bytesRead;final i64Max_lifted = FfiConverterInt64.read(Uint8List.view(buf.buffer, new_offset));
+        final i64Max = i64Max_lifted.value;
+        new_offset += i64Max_lifted.bytesRead;final u64Max_lifted = FfiConverterUInt64.read(Uint8List.view(buf.buffer, new_offset));
+        final u64Max = u64Max_lifted.value;
+        new_offset += u64Max_lifted.bytesRead;final f32Nan_lifted = FfiConverterDouble32.read(Uint8List.view(buf.buffer, new_offset));
+        final f32Nan = f32Nan_lifted.value;
+        new_offset += f32Nan_lifted.bytesRead;final f32NegZero_lifted = FfiConverterDouble32.read(Uint8List.view(buf.buffer, new_offset));
+        final f32NegZero = f32NegZero_lifted.value;
+        new_offset += f32NegZero_lifted.bytesRead;final f32Max_lifted = FfiConverterDouble32.read(Uint8List.view(buf.buffer, new_offset));
+        final f32Max = f32Max_lifted.value;
+        new_offset += f32Max_lifted.bytesRead;final f64Nan_lifted = FfiConverterDouble64.read(Uint8List.view(buf.buffer, new_offset));
+        final f64Nan = f64Nan_lifted.value;
+        new_offset += f64Nan_lifted.bytesRead;final f64NegZero_lifted = FfiConverterDouble64.read(Uint8List.view(buf.buffer, new_offset));
+        final f64NegZero = f64NegZero_lifted.value;
+        new_offset += f64NegZero_lifted.bytesRead;final f64NegInfinity_lifted = FfiConverterDouble64.read(Uint8List.view(buf.buffer, new_offset));
+        final f64NegInfinity = f64NegInfinity_lifted.value;
+        new_offset += f64NegInfinity_lifted.bytesRead;final f64MinPositive_lifted = FfiConverterDouble64.read(Uint8List.view(buf.buffer, new_offset));
+        final f64MinPositive = f64MinPositive_lifted.value;
+        new_offset += f64MinPositive_lifted.bytesRead;
+        return LiftRetVal(Boundaries(
+            i8Min: i8Min,u8Max: u8Max,i16Min: i16Min,u16Max: u16Max,i32Min: i32Min,u32Max: u32Max,i64Min: i64Min,i64Max: i64Max,u64Max: u64Max,f32Nan: f32Nan,f32NegZero: f32NegZero,f32Max: f32Max,f64Nan: f64Nan,f64NegZero: f64NegZero,f64NegInfinity: f64NegInfinity,f64MinPositive: f64MinPositive,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( Boundaries value) {
+        final total_length = FfiConverterInt8.allocationSize(value.i8Min) +FfiConverterUInt8.allocationSize(value.u8Max) +FfiConverterInt16.allocationSize(value.i16Min) +FfiConverterUInt16.allocationSize(value.u16Max) +FfiConverterInt32.allocationSize(value.i32Min) +FfiConverterUInt32.allocationSize(value.u32Max) +FfiConverterInt64.allocationSize(value.i64Min) +FfiConverterInt64.allocationSize(value.i64Max) +FfiConverterUInt64.allocationSize(value.u64Max) +FfiConverterDouble32.allocationSize(value.f32Nan) +FfiConverterDouble32.allocationSize(value.f32NegZero) +FfiConverterDouble32.allocationSize(value.f32Max) +FfiConverterDouble64.allocationSize(value.f64Nan) +FfiConverterDouble64.allocationSize(value.f64NegZero) +FfiConverterDouble64.allocationSize(value.f64NegInfinity) +FfiConverterDouble64.allocationSize(value.f64MinPositive) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( Boundaries value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterInt8.write(value.i8Min, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterUInt8.write(value.u8Max, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterInt16.write(value.i16Min, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterUInt16.write(value.u16Max, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterInt32.write(value.i32Min, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterUInt32.write(value.u32Max, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterInt64.write(value.i64Min, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterInt64.write(value.i64Max, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterUInt64.write(value.u64Max, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterDouble32.write(value.f32Nan, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterDouble32.write(value.f32NegZero, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterDouble32.write(value.f32Max, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterDouble64.write(value.f64Nan, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterDouble64.write(value.f64NegZero, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterDouble64.write(value.f64NegInfinity, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterDouble64.write(value.f64MinPositive, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Boundaries value) {
+        return FfiConverterInt8.allocationSize(value.i8Min) +FfiConverterUInt8.allocationSize(value.u8Max) +FfiConverterInt16.allocationSize(value.i16Min) +FfiConverterUInt16.allocationSize(value.u16Max) +FfiConverterInt32.allocationSize(value.i32Min) +FfiConverterUInt32.allocationSize(value.u32Max) +FfiConverterInt64.allocationSize(value.i64Min) +FfiConverterInt64.allocationSize(value.i64Max) +FfiConverterUInt64.allocationSize(value.u64Max) +FfiConverterDouble32.allocationSize(value.f32Nan) +FfiConverterDouble32.allocationSize(value.f32NegZero) +FfiConverterDouble32.allocationSize(value.f32Max) +FfiConverterDouble64.allocationSize(value.f64Nan) +FfiConverterDouble64.allocationSize(value.f64NegZero) +FfiConverterDouble64.allocationSize(value.f64NegInfinity) +FfiConverterDouble64.allocationSize(value.f64MinPositive) + 0;
+    }
+}
+
+class UniffiInternalError implements Exception {
+    static const int bufferOverflow = 0;
+    static const int incompleteData = 1;
+    static const int unexpectedOptionalTag = 2;
+    static const int unexpectedEnumCase = 3;
+    static const int unexpectedNullPointer = 4;
+    static const int unexpectedRustCallStatusCode = 5;
+    static const int unexpectedRustCallError = 6;
+    static const int unexpectedStaleHandle = 7;
+    static const int rustPanic = 8;
+
+    final int errorCode;
+    final String? panicMessage;
+
+    const UniffiInternalError(this.errorCode, this.panicMessage);
+
+    static UniffiInternalError panicked(String message) {
+    return UniffiInternalError(rustPanic, message);
+    }
+
+    @override
+    String toString() {
+    switch (errorCode) {
+        case bufferOverflow:
+        return "UniFfi::BufferOverflow";
+        case incompleteData:
+        return "UniFfi::IncompleteData";
+        case unexpectedOptionalTag:
+        return "UniFfi::UnexpectedOptionalTag";
+        case unexpectedEnumCase:
+        return "UniFfi::UnexpectedEnumCase";
+        case unexpectedNullPointer:
+        return "UniFfi::UnexpectedNullPointer";
+        case unexpectedRustCallStatusCode:
+        return "UniFfi::UnexpectedRustCallStatusCode";
+        case unexpectedRustCallError:
+        return "UniFfi::UnexpectedRustCallError";
+        case unexpectedStaleHandle:
+        return "UniFfi::UnexpectedStaleHandle";
+        case rustPanic:
+        return "UniFfi::rustPanic: \$\$panicMessage";
+        default:
+        return "UniFfi::UnknownError: \$\$errorCode";
+    }
+    }
+}
+
+const int CALL_SUCCESS = 0;
+const int CALL_ERROR = 1;
+const int CALL_UNEXPECTED_ERROR = 2;
+
+final class RustCallStatus extends Struct {
+    @Int8()
+    external int code;
+
+    external RustBuffer errorBuf;
+
+}
+
+void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
+
+    if (status.ref.code == CALL_SUCCESS) {
+    return;
+    } else if (status.ref.code == CALL_ERROR) {
+    throw errorHandler.lift(status.ref.errorBuf);
+    } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+    if (status.ref.errorBuf.len > 0) {
+        throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
+    } else {
+        throw UniffiInternalError.panicked("Rust panic");
+    }
+    } else {
+    throw UniffiInternalError.panicked("Unexpected RustCallStatus code: \${status.ref.code}");
+    }
+}
+
+#[doc=" Allocates the native memory the bindings pass to Rust for the duration of a call."]
+const Allocator _uniffiAllocator = calloc;
+
+T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+    final status = _uniffiAllocator<RustCallStatus>();
+    try {
+    final result = callback(status);
+    checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+    return result;
+    } finally {
+    _uniffiAllocator.free(status);
+    }
+}
+
+class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+    errorBuf.free();
+    return UniffiInternalError.panicked("Unexpected CALL_ERROR");
+    }
+}
+
+abstract class UniffiRustCallStatusErrorHandler {
+    Exception lift(RustBuffer errorBuf);
+}
+
+final class RustBuffer extends Struct {
+    @Uint64()
+    external int capacity;
+
+    @Uint64()
+    external int len;
+
+    external Pointer<Uint8> data;
+
+    static RustBuffer alloc(int size) {
+        return rustCall((status) => _UniffiLib.instance.ffi_primitive_conformance_rustbuffer_alloc(size, status));
+    }
+
+    static RustBuffer fromBytes(ForeignBytes bytes) {
+        return rustCall((status) => _UniffiLib.instance.ffi_primitive_conformance_rustbuffer_from_bytes(bytes, status));
+    }
+
+    void free() {
+        rustCall((status) => _UniffiLib.instance.ffi_primitive_conformance_rustbuffer_free(this, status));
+    }
+
+    RustBuffer reserve(int additionalCapacity) {
+    return rustCall((status) => _UniffiLib.instance.ffi_primitive_conformance_rustbuffer_reserve(this, additionalCapacity, status));
+    }
+
+    Uint8List asUint8List() {
+    final dataList = data.asTypedList(len);
+    final byteData = ByteData.sublistView(dataList);
+    return Uint8List.view(byteData.buffer);
+    }
+
+    @override
+    String toString() {
+    return "RustBuffer{capacity: \$capacity, len: \$len, data: \$data}";
+    }
+}
+
+RustBuffer toRustBuffer(Uint8List data) {
+    final length = data.length;
+
+    final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length);
+    final pointerList = frameData.asTypedList(length);
+    pointerList.setAll(0, data);
+
+    final bytes = _uniffiAllocator<ForeignBytes>();
+    bytes.ref.len = length;
+    bytes.ref.data = frameData;
+    try {
+        return RustBuffer.fromBytes(bytes.ref);
+    } finally {
+        _uniffiAllocator.free(frameData);
+        _uniffiAllocator.free(bytes);
+    }
+}
+
+T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+    try {
+        return lift(buf.asUint8List());
+    } finally {
+        buf.free();
+    }
+}
+
+final class ForeignBytes extends Struct {
+    @Int32()
+    external int len;
+    external Pointer<Uint8> data;
+
+    void free() {
+    _uniffiAllocator.free(data);
+    }
+}
+
+class LiftRetVal<T> {
+    final T value;
+    final int bytesRead;
+    const LiftRetVal(this.value, this.bytesRead);
+
+    LiftRetVal<T> copyWithOffset(int offset) {
+        return LiftRetVal(value, bytesRead + offset);
+    }
+}
+
+abstract class FfiConverter<D, F> {
+    const FfiConverter();
+
+    D lift(F value);
+    F lower(D value);
+    D read(ByteData buffer, int offset);
+    void write(D value, ByteData buffer, int offset);
+    int size(D value);
+}
+
+mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+    @override
+    T lift(T value) => value;
+
+    @override
+    T lower(T value) => value;
+}
+
+class FfiConverterBool {
+
+    static bool lift( int value) {
+        return value == 1;
+    }
+
+    static int lower( bool value) {
+        return value ? 1 :0;
+    }
+
+    static LiftRetVal<bool> read( Uint8List buf) {
+        return LiftRetVal(FfiConverterBool.lift(buf.first), 1);
+    }
+
+    static RustBuffer lowerIntoRustBuffer( bool value) {
+        return toRustBuffer(Uint8List.fromList([FfiConverterBool.lower(value)]));
+    }
+
+    static int allocationSize([bool value = false]) {
+        return 1;
+    }
+
+    static int write( bool value, Uint8List buf) {
+        buf.setAll(0, [value ? 1 : 0]);
+        return allocationSize();
+    }
+}class FfiConverterDouble32 {
+
+    static double lift(double value) => value;
+
+    static LiftRetVal<double> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat32(0), 4);
+    }
+
+    static double lower(double value) => value;
+
+    static int allocationSize([double value = 0]) {
+        return 4;
+    }
+
+    static int write(double value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setFloat32(0, value);
+        return FfiConverterDouble32.allocationSize();
+    }
+
+}class FfiConverterDouble64 {
+
+    static double lift(double value) => value;
+
+    static LiftRetVal<double> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat64(0), 8);
+    }
+
+    static double lower(double value) => value;
+
+    static int allocationSize([double value = 0]) {
+        return 8;
+    }
+
+    static int write(double value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setFloat64(0, value);
+        return FfiConverterDouble64.allocationSize();
+    }
+
+}class FfiConverterInt16 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt16(0), 2);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 2;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt16(0, value);
+        return FfiConverterInt16.allocationSize();
+    }
+
+}class FfiConverterInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value);
+        return FfiConverterInt32.allocationSize();
+    }
+
+}class FfiConverterInt64 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt64(0), 8);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 8;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, value);
+        return FfiConverterInt64.allocationSize();
+    }
+
+}class FfiConverterInt8 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt8(0), 1);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 1;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt8(0, value);
+        return FfiConverterInt8.allocationSize();
+    }
+
+}class FfiConverterSequenceDouble32 {
+
+    static List<double> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceDouble32.read(data).value);
+    }
+
+    static LiftRetVal<List<double>> read( Uint8List buf) {
+        List<double> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterDouble32.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<double> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterDouble32.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<double> value) {
+        return value.map((l) => FfiConverterDouble32.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<double> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterSequenceDouble64 {
+
+    static List<double> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceDouble64.read(data).value);
+    }
+
+    static LiftRetVal<List<double>> read( Uint8List buf) {
+        List<double> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterDouble64.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<double> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterDouble64.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<double> value) {
+        return value.map((l) => FfiConverterDouble64.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<double> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterSequenceInt64 {
+
+    static List<int> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceInt64.read(data).value);
+    }
+
+    static LiftRetVal<List<int>> read( Uint8List buf) {
+        List<int> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterInt64.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<int> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterInt64.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<int> value) {
+        return value.map((l) => FfiConverterInt64.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<int> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterSequenceUInt64 {
+
+    static List<int> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceUInt64.read(data).value);
+    }
+
+    static LiftRetVal<List<int>> read( Uint8List buf) {
+        List<int> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterUInt64.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<int> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterUInt64.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<int> value) {
+        return value.map((l) => FfiConverterUInt64.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<int> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterString {
+    static String lift( RustBuffer buf) {
+
+        return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
+    }
+
+    static RustBuffer lower( String value) {
+        return toRustBuffer(Utf8Encoder().convert(value));
+    }
+
+    static LiftRetVal<String> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+        return LiftRetVal(utf8.decoder.convert(buf, 4, end), end);
+    }
+
+    static int allocationSize([String value = ""]) {
+
+        return utf8.encoder.convert(value).length + 4;
+    }
+
+    static int write( String value, Uint8List buf) {
+
+        final list = utf8.encoder.convert(value);
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length);
+        buf.setAll(4, list);
+        return list.length + 4;
+    }
+}class FfiConverterUInt16 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint16(0), 2);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 2;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint16(0, value);
+        return FfiConverterUInt16.allocationSize();
+    }
+
+}class FfiConverterUInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, value);
+        return FfiConverterUInt32.allocationSize();
+    }
+
+}class FfiConverterUInt64 {
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0), 8);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 8;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, value);
+        return FfiConverterUInt64.allocationSize();
+    }
+}class FfiConverterUInt8 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint8(0), 1);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 1;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint8(0, value);
+        return FfiConverterUInt8.allocationSize();
+    }
+
+}
+
+const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
+
+typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+Future<T> uniffiRustCallAsync<T, F>(
+    Pointer<Void> Function() rustFutureFunc,
+    void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc,
+    F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc,
+    void Function(Pointer<Void>) freeFunc,
+    T Function(F) liftFunc, [
+    UniffiRustCallStatusErrorHandler? errorHandler,
+]) async {
+    final rustFuture = rustFutureFunc();
+    final completer = Completer<int>();
+
+    late final NativeCallable<UniffiRustFutureContinuationCallback> callback;
+
+    void poll() {
+        pollFunc(
+            rustFuture,
+            callback.nativeFunction,
+            Pointer<Void>.fromAddress(0),
+        );
+    }
+    void onResponse(int _idx, int pollResult) {
+        if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+            completer.complete(pollResult);
+        } else {
+            poll();
+        }
+    }
+    callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener(onResponse);
+
+    try {
+        poll();
+        await completer.future;
+        callback.close();
+
+        final status = _uniffiAllocator<RustCallStatus>();
+        try {
+            final result = completeFunc(rustFuture, status);
+            checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+            return liftFunc(result);
+        } finally {
+            _uniffiAllocator.free(status);
+        }
+    } finally {
+        freeFunc(rustFuture);
+    }
+}
+
+class UniffiHandleMap<T> {
+    final Map<int, T> _map = {};
+    int _counter = 0;
+
+    int insert(T obj) {
+    final handle = _counter++;
+    _map[handle] = obj;
+    return handle;
+    }
+
+    T get(int handle) {
+    final obj = _map[handle];
+    if (obj == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    return obj;
+    }
+
+    void remove(int handle) {
+    if (_map.remove(handle) == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    }
+}
+
+Boundaries boundaries() {
+    return FfiConverterBoundaries.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_primitive_conformance_fn_func_boundaries( uniffiStatus), NullRustCallStatusErrorHandler()));
+}Boundaries echoBoundaries(Boundaries value,) {
+    return FfiConverterBoundaries.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_primitive_conformance_fn_func_echo_boundaries(FfiConverterBoundaries.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}List<double> echoF32s(List<double> values,) {
+    return FfiConverterSequenceDouble32.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_primitive_conformance_fn_func_echo_f32s(FfiConverterSequenceDouble32.lower(values), uniffiStatus), NullRustCallStatusErrorHandler()));
+}List<double> echoF64s(List<double> values,) {
+    return FfiConverterSequenceDouble64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_primitive_conformance_fn_func_echo_f64s(FfiConverterSequenceDouble64.lower(values), uniffiStatus), NullRustCallStatusErrorHandler()));
+}List<int> echoI64s(List<int> values,) {
+    return FfiConverterSequenceInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_primitive_conformance_fn_func_echo_i64s(FfiConverterSequenceInt64.lower(values), uniffiStatus), NullRustCallStatusErrorHandler()));
+}List<int> echoU64s(List<int> values,) {
+    return FfiConverterSequenceUInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_primitive_conformance_fn_func_echo_u64s(FfiConverterSequenceUInt64.lower(values), uniffiStatus), NullRustCallStatusErrorHandler()));
+}List<int> f64Bits(List<double> values,) {
+    return FfiConverterSequenceUInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_primitive_conformance_fn_func_f64_bits(FfiConverterSequenceDouble64.lower(values), uniffiStatus), NullRustCallStatusErrorHandler()));
+}bool isBoundaries(Boundaries value,) {
+    return FfiConverterBool.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_primitive_conformance_fn_func_is_boundaries(FfiConverterBoundaries.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}
+
+class _UniffiLib {
+    _UniffiLib._();
+
+    static final DynamicLibrary _dylib = _open();
+
+    static DynamicLibrary _open() {
+        if (Platform.isAndroid) return DynamicLibrary.open("libprimitive_conformance.so");
+        if (Platform.isIOS) return DynamicLibrary.executable();
+        if (Platform.isLinux) return DynamicLibrary.open("libprimitive_conformance.so");
+        if (Platform.isMacOS) return DynamicLibrary.open("libprimitive_conformance.dylib");
+        if (Platform.isWindows) return DynamicLibrary.open("primitive_conformance.dll");
+        throw UnsupportedError("Unsupported platform: \${Platform.operatingSystem}");
+    }
+
+    static final _UniffiLib instance = _UniffiLib._();
+
+    late final RustBuffer Function(Pointer<RustCallStatus>) uniffi_primitive_conformance_fn_func_boundaries = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<RustCallStatus>)
+    >("uniffi_primitive_conformance_fn_func_boundaries");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_primitive_conformance_fn_func_echo_boundaries = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_primitive_conformance_fn_func_echo_boundaries");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_primitive_conformance_fn_func_echo_f32s = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_primitive_conformance_fn_func_echo_f32s");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_primitive_conformance_fn_func_echo_f64s = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_primitive_conformance_fn_func_echo_f64s");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_primitive_conformance_fn_func_echo_i64s = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_primitive_conformance_fn_func_echo_i64s");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_primitive_conformance_fn_func_echo_u64s = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_primitive_conformance_fn_func_echo_u64s");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_primitive_conformance_fn_func_f64_bits = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_primitive_conformance_fn_func_f64_bits");late final int Function(RustBuffer,Pointer<RustCallStatus>) uniffi_primitive_conformance_fn_func_is_boundaries = _dylib.lookupFunction<
+        Int8 Function(RustBuffer,Pointer<RustCallStatus>),
+        int Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_primitive_conformance_fn_func_is_boundaries");late final RustBuffer Function(int,Pointer<RustCallStatus>) ffi_primitive_conformance_rustbuffer_alloc = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("ffi_primitive_conformance_rustbuffer_alloc");late final RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>) ffi_primitive_conformance_rustbuffer_from_bytes = _dylib.lookupFunction<
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>),
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>)
+    >("ffi_primitive_conformance_rustbuffer_from_bytes");late final void Function(RustBuffer,Pointer<RustCallStatus>) ffi_primitive_conformance_rustbuffer_free = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("ffi_primitive_conformance_rustbuffer_free");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) ffi_primitive_conformance_rustbuffer_reserve = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("ffi_primitive_conformance_rustbuffer_reserve");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_primitive_conformance_rust_future_poll_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_poll_u8");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_cancel_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_cancel_u8");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_free_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_free_u8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_primitive_conformance_rust_future_complete_u8 = _dylib.lookupFunction<
+        Uint8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_primitive_conformance_rust_future_complete_u8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_primitive_conformance_rust_future_poll_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_poll_i8");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_cancel_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_cancel_i8");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_free_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_free_i8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_primitive_conformance_rust_future_complete_i8 = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_primitive_conformance_rust_future_complete_i8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_primitive_conformance_rust_future_poll_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_poll_u16");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_cancel_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_cancel_u16");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_free_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_free_u16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_primitive_conformance_rust_future_complete_u16 = _dylib.lookupFunction<
+        Uint16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_primitive_conformance_rust_future_complete_u16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_primitive_conformance_rust_future_poll_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_poll_i16");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_cancel_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_cancel_i16");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_free_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_free_i16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_primitive_conformance_rust_future_complete_i16 = _dylib.lookupFunction<
+        Int16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_primitive_conformance_rust_future_complete_i16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_primitive_conformance_rust_future_poll_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_poll_u32");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_cancel_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_cancel_u32");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_free_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_free_u32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_primitive_conformance_rust_future_complete_u32 = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_primitive_conformance_rust_future_complete_u32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_primitive_conformance_rust_future_poll_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_poll_i32");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_cancel_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_cancel_i32");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_free_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_free_i32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_primitive_conformance_rust_future_complete_i32 = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_primitive_conformance_rust_future_complete_i32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_primitive_conformance_rust_future_poll_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_poll_u64");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_cancel_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_cancel_u64");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_free_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_free_u64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_primitive_conformance_rust_future_complete_u64 = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_primitive_conformance_rust_future_complete_u64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_primitive_conformance_rust_future_poll_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_poll_i64");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_cancel_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_cancel_i64");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_free_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_free_i64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_primitive_conformance_rust_future_complete_i64 = _dylib.lookupFunction<
+        Int64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_primitive_conformance_rust_future_complete_i64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_primitive_conformance_rust_future_poll_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_poll_f32");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_cancel_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_cancel_f32");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_free_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_free_f32");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_primitive_conformance_rust_future_complete_f32 = _dylib.lookupFunction<
+        Float Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_primitive_conformance_rust_future_complete_f32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_primitive_conformance_rust_future_poll_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_poll_f64");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_cancel_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_cancel_f64");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_free_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_free_f64");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_primitive_conformance_rust_future_complete_f64 = _dylib.lookupFunction<
+        Double Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_primitive_conformance_rust_future_complete_f64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_primitive_conformance_rust_future_poll_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_poll_pointer");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_cancel_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_cancel_pointer");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_free_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_free_pointer");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_primitive_conformance_rust_future_complete_pointer = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_primitive_conformance_rust_future_complete_pointer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_primitive_conformance_rust_future_poll_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_poll_rust_buffer");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_cancel_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_cancel_rust_buffer");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_free_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_free_rust_buffer");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_primitive_conformance_rust_future_complete_rust_buffer = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_primitive_conformance_rust_future_complete_rust_buffer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_primitive_conformance_rust_future_poll_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_poll_void");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_cancel_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_cancel_void");late final void Function(Pointer<Void>,) ffi_primitive_conformance_rust_future_free_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_primitive_conformance_rust_future_free_void");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_primitive_conformance_rust_future_complete_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_primitive_conformance_rust_future_complete_void");late final int Function() uniffi_primitive_conformance_checksum_func_boundaries = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_primitive_conformance_checksum_func_boundaries");late final int Function() uniffi_primitive_conformance_checksum_func_echo_boundaries = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_primitive_conformance_checksum_func_echo_boundaries");late final int Function() uniffi_primitive_conformance_checksum_func_echo_f32s = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_primitive_conformance_checksum_func_echo_f32s");late final int Function() uniffi_primitive_conformance_checksum_func_echo_f64s = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_primitive_conformance_checksum_func_echo_f64s");late final int Function() uniffi_primitive_conformance_checksum_func_echo_i64s = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_primitive_conformance_checksum_func_echo_i64s");late final int Function() uniffi_primitive_conformance_checksum_func_echo_u64s = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_primitive_conformance_checksum_func_echo_u64s");late final int Function() uniffi_primitive_conformance_checksum_func_f64_bits = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_primitive_conformance_checksum_func_f64_bits");late final int Function() uniffi_primitive_conformance_checksum_func_is_boundaries = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_primitive_conformance_checksum_func_is_boundaries");late final int Function() ffi_primitive_conformance_uniffi_contract_version = _dylib.lookupFunction<
+        Uint32 Function(),
+        int Function()
+    >("ffi_primitive_conformance_uniffi_contract_version");
+
+    static void _checkApiVersion() {
+        final bindingsVersion = 26;
+        final scaffoldingVersion = _UniffiLib.instance.ffi_primitive_conformance_uniffi_contract_version();
+        if (bindingsVersion != scaffoldingVersion) {
+            throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version \$bindingsVersion, scaffolding version \$scaffoldingVersion");
+        }
+    }
+
+    static void _checkApiChecksums() {
+        if (_UniffiLib.instance.uniffi_primitive_conformance_checksum_func_boundaries() != 59154) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_primitive_conformance_checksum_func_echo_boundaries() != 56268) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_primitive_conformance_checksum_func_echo_f32s() != 368) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_primitive_conformance_checksum_func_echo_f64s() != 32675) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_primitive_conformance_checksum_func_echo_i64s() != 61205) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_primitive_conformance_checksum_func_echo_u64s() != 30849) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_primitive_conformance_checksum_func_f64_bits() != 52053) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_primitive_conformance_checksum_func_is_boundaries() != 61531) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }
+    }
+}
+
+void initialize() {
+    _UniffiLib._open();
+}
+
+void ensureInitialized() {
+    _UniffiLib._checkApiVersion();
+    _UniffiLib._checkApiChecksums();
+}
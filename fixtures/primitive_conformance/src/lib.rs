@@ -0,0 +1,97 @@
+/// Boundary values of every primitive, which must survive a trip through a `RustBuffer` exactly.
+#[derive(uniffi::Record)]
+pub struct Boundaries {
+    i8_min: i8,
+    u8_max: u8,
+    i16_min: i16,
+    u16_max: u16,
+    i32_min: i32,
+    u32_max: u32,
+    i64_min: i64,
+    i64_max: i64,
+    u64_max: u64,
+    f32_nan: f32,
+    f32_neg_zero: f32,
+    f32_max: f32,
+    f64_nan: f64,
+    f64_neg_zero: f64,
+    f64_neg_infinity: f64,
+    f64_min_positive: f64,
+}
+
+#[uniffi::export]
+pub fn boundaries() -> Boundaries {
+    Boundaries {
+        i8_min: i8::MIN,
+        u8_max: u8::MAX,
+        i16_min: i16::MIN,
+        u16_max: u16::MAX,
+        i32_min: i32::MIN,
+        u32_max: u32::MAX,
+        i64_min: i64::MIN,
+        i64_max: i64::MAX,
+        u64_max: u64::MAX,
+        f32_nan: f32::NAN,
+        f32_neg_zero: -0.0,
+        f32_max: f32::MAX,
+        f64_nan: f64::NAN,
+        f64_neg_zero: -0.0,
+        f64_neg_infinity: f64::NEG_INFINITY,
+        f64_min_positive: f64::MIN_POSITIVE,
+    }
+}
+
+/// Whether `value` holds exactly the values of `boundaries()`, comparing floats bit for bit.
+#[uniffi::export]
+pub fn is_boundaries(value: Boundaries) -> bool {
+    let expected = boundaries();
+    value.i8_min == expected.i8_min
+        && value.u8_max == expected.u8_max
+        && value.i16_min == expected.i16_min
+        && value.u16_max == expected.u16_max
+        && value.i32_min == expected.i32_min
+        && value.u32_max == expected.u32_max
+        && value.i64_min == expected.i64_min
+        && value.i64_max == expected.i64_max
+        && value.u64_max == expected.u64_max
+        && value.f32_nan.is_nan()
+        && value.f32_neg_zero.to_bits() == expected.f32_neg_zero.to_bits()
+        && value.f32_max == expected.f32_max
+        && value.f64_nan.is_nan()
+        && value.f64_neg_zero.to_bits() == expected.f64_neg_zero.to_bits()
+        && value.f64_neg_infinity == expected.f64_neg_infinity
+        && value.f64_min_positive == expected.f64_min_positive
+}
+
+#[uniffi::export]
+pub fn echo_boundaries(value: Boundaries) -> Boundaries {
+    value
+}
+
+#[uniffi::export]
+pub fn echo_f32s(values: Vec<f32>) -> Vec<f32> {
+    values
+}
+
+#[uniffi::export]
+pub fn echo_f64s(values: Vec<f64>) -> Vec<f64> {
+    values
+}
+
+#[uniffi::export]
+pub fn echo_i64s(values: Vec<i64>) -> Vec<i64> {
+    values
+}
+
+#[uniffi::export]
+pub fn echo_u64s(values: Vec<u64>) -> Vec<u64> {
+    values
+}
+
+/// The raw bits of the floats as Rust received them.
+#[uniffi::export]
+pub fn f64_bits(values: Vec<f64>) -> Vec<u64> {
+    values.into_iter().map(f64::to_bits).collect()
+}
+
+uniffi::setup_scaffolding!();
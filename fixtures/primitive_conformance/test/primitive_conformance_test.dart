@@ -0,0 +1,75 @@
+import 'package:test/test.dart';
+import '../primitive_conformance.dart';
+
+// Dart has no unsigned 64-bit integers, so u64::MAX arrives as its two's complement bit pattern
+const u64Max = 0xFFFFFFFFFFFFFFFF;
+const i64Min = -0x7FFFFFFFFFFFFFFF - 1;
+const i64Max = 0x7FFFFFFFFFFFFFFF;
+
+Boundaries dartBoundaries() => Boundaries(
+      i8Min: -128,
+      u8Max: 255,
+      i16Min: -32768,
+      u16Max: 65535,
+      i32Min: -2147483648,
+      u32Max: 4294967295,
+      i64Min: i64Min,
+      i64Max: i64Max,
+      u64Max: u64Max,
+      f32Nan: double.nan,
+      f32NegZero: -0.0,
+      f32Max: 3.4028234663852886e38,
+      f64Nan: double.nan,
+      f64NegZero: -0.0,
+      f64NegInfinity: double.negativeInfinity,
+      f64MinPositive: 2.2250738585072014e-308,
+    );
+
+void main() {
+  test('boundaries from Rust', () {
+    final value = boundaries();
+    expect(value.i8Min, -128);
+    expect(value.u8Max, 255);
+    expect(value.i16Min, -32768);
+    expect(value.u16Max, 65535);
+    expect(value.i32Min, -2147483648);
+    expect(value.u32Max, 4294967295);
+    expect(value.i64Min, i64Min);
+    expect(value.i64Max, i64Max);
+    expect(value.u64Max, u64Max);
+    expect(value.f32Nan.isNaN, true);
+    expect(value.f32NegZero, 0.0);
+    expect(value.f32NegZero.isNegative, true);
+    expect(value.f32Max, 3.4028234663852886e38);
+    expect(value.f64Nan.isNaN, true);
+    expect(value.f64NegZero.isNegative, true);
+    expect(value.f64NegInfinity, double.negativeInfinity);
+    expect(value.f64MinPositive, 2.2250738585072014e-308);
+  });
+
+  test('boundaries to Rust', () {
+    expect(isBoundaries(dartBoundaries()), true);
+    expect(isBoundaries(boundaries()), true);
+    expect(isBoundaries(echoBoundaries(dartBoundaries())), true);
+  });
+
+  test('sequences', () {
+    final f64s = echoF64s([double.nan, -0.0, double.infinity, 1.5]);
+    expect(f64s[0].isNaN, true);
+    expect(f64s[1].isNegative, true);
+    expect(f64s.sublist(2), [double.infinity, 1.5]);
+
+    final f32s = echoF32s([double.nan, -0.0, 0.25]);
+    expect(f32s[0].isNaN, true);
+    expect(f32s[1].isNegative, true);
+    expect(f32s[2], 0.25);
+
+    expect(echoI64s([i64Min, -1, 0, i64Max]), [i64Min, -1, 0, i64Max]);
+    expect(echoU64s([0, 1, u64Max]), [0, 1, u64Max]);
+  });
+
+  test('floats arrive bit for bit', () {
+    // 1.0 is 0x3FF0000000000000, which would be 0x000000000000F03F with the bytes swapped
+    expect(f64Bits([1.0, -0.0]), [0x3FF0000000000000, i64Min]);
+  });
+}
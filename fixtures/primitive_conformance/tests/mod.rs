@@ -0,0 +1,11 @@
+use anyhow::Result;
+
+#[test]
+fn primitive_conformance() -> Result<()> {
+    uniffi_dart::testing::run_library_test("primitive_conformance", None)
+}
+
+#[test]
+fn primitive_conformance_golden() -> Result<()> {
+    uniffi_dart::testing::assert_library_golden("primitive_conformance", None)
+}
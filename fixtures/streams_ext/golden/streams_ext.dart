@@ -0,0 +1,1412 @@
+library streams_ext;
+
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io" show Platform, File, Directory;
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+abstract class AsyncTimerStreamStreamExtInterface {
+    Future<int?> next();
+}
+
+final _AsyncTimerStreamStreamExtFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _AsyncTimerStreamStreamExtFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_streams_ext_fn_free_asynctimerstreamstreamext");
+
+class AsyncTimerStreamStreamExt implements AsyncTimerStreamStreamExtInterface, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    AsyncTimerStreamStreamExt._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_AsyncTimerStreamStreamExtFree, _ptr);
+        _AsyncTimerStreamStreamExtFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    AsyncTimerStreamStreamExt() : _ptr = rustCall((uniffiStatus) =>
+        _UniffiLib.instance.uniffi_streams_ext_fn_constructor_asynctimerstreamstreamext_new(
+             uniffiStatus
+        ),
+        NullRustCallStatusErrorHandler()
+    ) {
+        _attachFinalizer();
+    }
+
+    factory AsyncTimerStreamStreamExt.lift(Pointer<Void> ptr) {
+        return AsyncTimerStreamStreamExt._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("AsyncTimerStreamStreamExt has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_streams_ext_fn_clone_asynctimerstreamstreamext(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _AsyncTimerStreamStreamExtFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_streams_ext_fn_free_asynctimerstreamstreamext(_ptr, status));
+    }
+
+    @override
+    Future<int?> next() {
+        return uniffiRustCallAsync(
+            () => _UniffiLib.instance.uniffi_streams_ext_fn_method_asynctimerstreamstreamext_next(
+                uniffiClonePointer(),
+            ),
+            _UniffiLib.instance.ffi_streams_ext_rust_future_poll_rust_buffer,
+            _UniffiLib.instance.ffi_streams_ext_rust_future_complete_rust_buffer,
+            _UniffiLib.instance.ffi_streams_ext_rust_future_free_rust_buffer,
+            FfiConverterOptionalUInt64.lift,
+            NullRustCallStatusErrorHandler(),
+        );
+    }
+}
+
+class FfiConverterAsyncTimerStreamStreamExt {
+    static AsyncTimerStreamStreamExt lift( Pointer<Void> value) {
+        return AsyncTimerStreamStreamExt.lift(value);
+    }
+
+    static Pointer<Void> lower( AsyncTimerStreamStreamExt value) {
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<AsyncTimerStreamStreamExt> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([AsyncTimerStreamStreamExt? value]) {
+        return 8;
+    }
+
+    static int write( AsyncTimerStreamStreamExt value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+asyncTimerStream() async* {
+    final asyncTimerStream = createStreamAsyncTimerStream();
+    try {
+        while (true) {
+            final value = await asyncTimerStream.next();
+            if (value == null) {
+                break;
+            }
+            yield value;
+        }
+    } catch (e) {
+
+        rethrow;
+    }
+
+}
+
+abstract class CombinedStreamsStreamExtInterface {
+    Future<String?> next();
+}
+
+final _CombinedStreamsStreamExtFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _CombinedStreamsStreamExtFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_streams_ext_fn_free_combinedstreamsstreamext");
+
+class CombinedStreamsStreamExt implements CombinedStreamsStreamExtInterface, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    CombinedStreamsStreamExt._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_CombinedStreamsStreamExtFree, _ptr);
+        _CombinedStreamsStreamExtFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    CombinedStreamsStreamExt() : _ptr = rustCall((uniffiStatus) =>
+        _UniffiLib.instance.uniffi_streams_ext_fn_constructor_combinedstreamsstreamext_new(
+             uniffiStatus
+        ),
+        NullRustCallStatusErrorHandler()
+    ) {
+        _attachFinalizer();
+    }
+
+    factory CombinedStreamsStreamExt.lift(Pointer<Void> ptr) {
+        return CombinedStreamsStreamExt._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("CombinedStreamsStreamExt has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_streams_ext_fn_clone_combinedstreamsstreamext(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _CombinedStreamsStreamExtFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_streams_ext_fn_free_combinedstreamsstreamext(_ptr, status));
+    }
+
+    @override
+    Future<String?> next() {
+        return uniffiRustCallAsync(
+            () => _UniffiLib.instance.uniffi_streams_ext_fn_method_combinedstreamsstreamext_next(
+                uniffiClonePointer(),
+            ),
+            _UniffiLib.instance.ffi_streams_ext_rust_future_poll_rust_buffer,
+            _UniffiLib.instance.ffi_streams_ext_rust_future_complete_rust_buffer,
+            _UniffiLib.instance.ffi_streams_ext_rust_future_free_rust_buffer,
+            FfiConverterOptionalString.lift,
+            NullRustCallStatusErrorHandler(),
+        );
+    }
+}
+
+class FfiConverterCombinedStreamsStreamExt {
+    static CombinedStreamsStreamExt lift( Pointer<Void> value) {
+        return CombinedStreamsStreamExt.lift(value);
+    }
+
+    static Pointer<Void> lower( CombinedStreamsStreamExt value) {
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<CombinedStreamsStreamExt> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([CombinedStreamsStreamExt? value]) {
+        return 8;
+    }
+
+    static int write( CombinedStreamsStreamExt value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+combinedStreams() async* {
+    final combinedStreams = createStreamCombinedStreams();
+    try {
+        while (true) {
+            final value = await combinedStreams.next();
+            if (value == null) {
+                break;
+            }
+            yield value;
+        }
+    } catch (e) {
+
+        rethrow;
+    }
+
+}
+
+abstract class CountStreamStreamExtInterface {
+    Future<int?> next();
+}
+
+final _CountStreamStreamExtFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _CountStreamStreamExtFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_streams_ext_fn_free_countstreamstreamext");
+
+class CountStreamStreamExt implements CountStreamStreamExtInterface, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    CountStreamStreamExt._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_CountStreamStreamExtFree, _ptr);
+        _CountStreamStreamExtFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    CountStreamStreamExt() : _ptr = rustCall((uniffiStatus) =>
+        _UniffiLib.instance.uniffi_streams_ext_fn_constructor_countstreamstreamext_new(
+             uniffiStatus
+        ),
+        NullRustCallStatusErrorHandler()
+    ) {
+        _attachFinalizer();
+    }
+
+    factory CountStreamStreamExt.lift(Pointer<Void> ptr) {
+        return CountStreamStreamExt._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("CountStreamStreamExt has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_streams_ext_fn_clone_countstreamstreamext(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _CountStreamStreamExtFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_streams_ext_fn_free_countstreamstreamext(_ptr, status));
+    }
+
+    @override
+    Future<int?> next() {
+        return uniffiRustCallAsync(
+            () => _UniffiLib.instance.uniffi_streams_ext_fn_method_countstreamstreamext_next(
+                uniffiClonePointer(),
+            ),
+            _UniffiLib.instance.ffi_streams_ext_rust_future_poll_rust_buffer,
+            _UniffiLib.instance.ffi_streams_ext_rust_future_complete_rust_buffer,
+            _UniffiLib.instance.ffi_streams_ext_rust_future_free_rust_buffer,
+            FfiConverterOptionalInt32.lift,
+            NullRustCallStatusErrorHandler(),
+        );
+    }
+}
+
+class FfiConverterCountStreamStreamExt {
+    static CountStreamStreamExt lift( Pointer<Void> value) {
+        return CountStreamStreamExt.lift(value);
+    }
+
+    static Pointer<Void> lower( CountStreamStreamExt value) {
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<CountStreamStreamExt> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([CountStreamStreamExt? value]) {
+        return 8;
+    }
+
+    static int write( CountStreamStreamExt value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+countStream() async* {
+    final countStream = createStreamCountStream();
+    try {
+        while (true) {
+            final value = await countStream.next();
+            if (value == null) {
+                break;
+            }
+            yield value;
+        }
+    } catch (e) {
+
+        rethrow;
+    }
+
+}
+
+abstract class FibonacciStreamStreamExtInterface {
+    Future<int?> next();
+}
+
+final _FibonacciStreamStreamExtFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _FibonacciStreamStreamExtFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_streams_ext_fn_free_fibonaccistreamstreamext");
+
+class FibonacciStreamStreamExt implements FibonacciStreamStreamExtInterface, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    FibonacciStreamStreamExt._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_FibonacciStreamStreamExtFree, _ptr);
+        _FibonacciStreamStreamExtFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    FibonacciStreamStreamExt() : _ptr = rustCall((uniffiStatus) =>
+        _UniffiLib.instance.uniffi_streams_ext_fn_constructor_fibonaccistreamstreamext_new(
+             uniffiStatus
+        ),
+        NullRustCallStatusErrorHandler()
+    ) {
+        _attachFinalizer();
+    }
+
+    factory FibonacciStreamStreamExt.lift(Pointer<Void> ptr) {
+        return FibonacciStreamStreamExt._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("FibonacciStreamStreamExt has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_streams_ext_fn_clone_fibonaccistreamstreamext(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _FibonacciStreamStreamExtFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_streams_ext_fn_free_fibonaccistreamstreamext(_ptr, status));
+    }
+
+    @override
+    Future<int?> next() {
+        return uniffiRustCallAsync(
+            () => _UniffiLib.instance.uniffi_streams_ext_fn_method_fibonaccistreamstreamext_next(
+                uniffiClonePointer(),
+            ),
+            _UniffiLib.instance.ffi_streams_ext_rust_future_poll_rust_buffer,
+            _UniffiLib.instance.ffi_streams_ext_rust_future_complete_rust_buffer,
+            _UniffiLib.instance.ffi_streams_ext_rust_future_free_rust_buffer,
+            FfiConverterOptionalUInt64.lift,
+            NullRustCallStatusErrorHandler(),
+        );
+    }
+}
+
+class FfiConverterFibonacciStreamStreamExt {
+    static FibonacciStreamStreamExt lift( Pointer<Void> value) {
+        return FibonacciStreamStreamExt.lift(value);
+    }
+
+    static Pointer<Void> lower( FibonacciStreamStreamExt value) {
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<FibonacciStreamStreamExt> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([FibonacciStreamStreamExt? value]) {
+        return 8;
+    }
+
+    static int write( FibonacciStreamStreamExt value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+fibonacciStream() async* {
+    final fibonacciStream = createStreamFibonacciStream();
+    try {
+        while (true) {
+            final value = await fibonacciStream.next();
+            if (value == null) {
+                break;
+            }
+            yield value;
+        }
+    } catch (e) {
+
+        rethrow;
+    }
+
+}
+
+abstract class SimpleStreamStreamExtInterface {
+    Future<int?> next();
+}
+
+final _SimpleStreamStreamExtFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _SimpleStreamStreamExtFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_streams_ext_fn_free_simplestreamstreamext");
+
+class SimpleStreamStreamExt implements SimpleStreamStreamExtInterface, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    SimpleStreamStreamExt._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_SimpleStreamStreamExtFree, _ptr);
+        _SimpleStreamStreamExtFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    SimpleStreamStreamExt() : _ptr = rustCall((uniffiStatus) =>
+        _UniffiLib.instance.uniffi_streams_ext_fn_constructor_simplestreamstreamext_new(
+             uniffiStatus
+        ),
+        NullRustCallStatusErrorHandler()
+    ) {
+        _attachFinalizer();
+    }
+
+    factory SimpleStreamStreamExt.lift(Pointer<Void> ptr) {
+        return SimpleStreamStreamExt._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("SimpleStreamStreamExt has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_streams_ext_fn_clone_simplestreamstreamext(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _SimpleStreamStreamExtFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_streams_ext_fn_free_simplestreamstreamext(_ptr, status));
+    }
+
+    @override
+    Future<int?> next() {
+        return uniffiRustCallAsync(
+            () => _UniffiLib.instance.uniffi_streams_ext_fn_method_simplestreamstreamext_next(
+                uniffiClonePointer(),
+            ),
+            _UniffiLib.instance.ffi_streams_ext_rust_future_poll_rust_buffer,
+            _UniffiLib.instance.ffi_streams_ext_rust_future_complete_rust_buffer,
+            _UniffiLib.instance.ffi_streams_ext_rust_future_free_rust_buffer,
+            FfiConverterOptionalInt32.lift,
+            NullRustCallStatusErrorHandler(),
+        );
+    }
+}
+
+class FfiConverterSimpleStreamStreamExt {
+    static SimpleStreamStreamExt lift( Pointer<Void> value) {
+        return SimpleStreamStreamExt.lift(value);
+    }
+
+    static Pointer<Void> lower( SimpleStreamStreamExt value) {
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<SimpleStreamStreamExt> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([SimpleStreamStreamExt? value]) {
+        return 8;
+    }
+
+    static int write( SimpleStreamStreamExt value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+simpleStream() async* {
+    final simpleStream = createStreamSimpleStream();
+    try {
+        while (true) {
+            final value = await simpleStream.next();
+            if (value == null) {
+                break;
+            }
+            yield value;
+        }
+    } catch (e) {
+
+        rethrow;
+    }
+
+}
+
+class UniffiInternalError implements Exception {
+    static const int bufferOverflow = 0;
+    static const int incompleteData = 1;
+    static const int unexpectedOptionalTag = 2;
+    static const int unexpectedEnumCase = 3;
+    static const int unexpectedNullPointer = 4;
+    static const int unexpectedRustCallStatusCode = 5;
+    static const int unexpectedRustCallError = 6;
+    static const int unexpectedStaleHandle = 7;
+    static const int rustPanic = 8;
+
+    final int errorCode;
+    final String? panicMessage;
+
+    const UniffiInternalError(this.errorCode, this.panicMessage);
+
+    static UniffiInternalError panicked(String message) {
+    return UniffiInternalError(rustPanic, message);
+    }
+
+    @override
+    String toString() {
+    switch (errorCode) {
+        case bufferOverflow:
+        return "UniFfi::BufferOverflow";
+        case incompleteData:
+        return "UniFfi::IncompleteData";
+        case unexpectedOptionalTag:
+        return "UniFfi::UnexpectedOptionalTag";
+        case unexpectedEnumCase:
+        return "UniFfi::UnexpectedEnumCase";
+        case unexpectedNullPointer:
+        return "UniFfi::UnexpectedNullPointer";
+        case unexpectedRustCallStatusCode:
+        return "UniFfi::UnexpectedRustCallStatusCode";
+        case unexpectedRustCallError:
+        return "UniFfi::UnexpectedRustCallError";
+        case unexpectedStaleHandle:
+        return "UniFfi::UnexpectedStaleHandle";
+        case rustPanic:
+        return "UniFfi::rustPanic: \$\$panicMessage";
+        default:
+        return "UniFfi::UnknownError: \$\$errorCode";
+    }
+    }
+}
+
+const int CALL_SUCCESS = 0;
+const int CALL_ERROR = 1;
+const int CALL_UNEXPECTED_ERROR = 2;
+
+final class RustCallStatus extends Struct {
+    @Int8()
+    external int code;
+
+    external RustBuffer errorBuf;
+
+}
+
+void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
+
+    if (status.ref.code == CALL_SUCCESS) {
+    return;
+    } else if (status.ref.code == CALL_ERROR) {
+    throw errorHandler.lift(status.ref.errorBuf);
+    } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+    if (status.ref.errorBuf.len > 0) {
+        throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
+    } else {
+        throw UniffiInternalError.panicked("Rust panic");
+    }
+    } else {
+    throw UniffiInternalError.panicked("Unexpected RustCallStatus code: \${status.ref.code}");
+    }
+}
+
+#[doc=" Allocates the native memory the bindings pass to Rust for the duration of a call."]
+const Allocator _uniffiAllocator = calloc;
+
+T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+    final status = _uniffiAllocator<RustCallStatus>();
+    try {
+    final result = callback(status);
+    checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+    return result;
+    } finally {
+    _uniffiAllocator.free(status);
+    }
+}
+
+class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+    errorBuf.free();
+    return UniffiInternalError.panicked("Unexpected CALL_ERROR");
+    }
+}
+
+abstract class UniffiRustCallStatusErrorHandler {
+    Exception lift(RustBuffer errorBuf);
+}
+
+final class RustBuffer extends Struct {
+    @Uint64()
+    external int capacity;
+
+    @Uint64()
+    external int len;
+
+    external Pointer<Uint8> data;
+
+    static RustBuffer alloc(int size) {
+        return rustCall((status) => _UniffiLib.instance.ffi_streams_ext_rustbuffer_alloc(size, status));
+    }
+
+    static RustBuffer fromBytes(ForeignBytes bytes) {
+        return rustCall((status) => _UniffiLib.instance.ffi_streams_ext_rustbuffer_from_bytes(bytes, status));
+    }
+
+    void free() {
+        rustCall((status) => _UniffiLib.instance.ffi_streams_ext_rustbuffer_free(this, status));
+    }
+
+    RustBuffer reserve(int additionalCapacity) {
+    return rustCall((status) => _UniffiLib.instance.ffi_streams_ext_rustbuffer_reserve(this, additionalCapacity, status));
+    }
+
+    Uint8List asUint8List() {
+    final dataList = data.asTypedList(len);
+    final byteData = ByteData.sublistView(dataList);
+    return Uint8List.view(byteData.buffer);
+    }
+
+    @override
+    String toString() {
+    return "RustBuffer{capacity: \$capacity, len: \$len, data: \$data}";
+    }
+}
+
+RustBuffer toRustBuffer(Uint8List data) {
+    final length = data.length;
+
+    final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length);
+    final pointerList = frameData.asTypedList(length);
+    pointerList.setAll(0, data);
+
+    final bytes = _uniffiAllocator<ForeignBytes>();
+    bytes.ref.len = length;
+    bytes.ref.data = frameData;
+    try {
+        return RustBuffer.fromBytes(bytes.ref);
+    } finally {
+        _uniffiAllocator.free(frameData);
+        _uniffiAllocator.free(bytes);
+    }
+}
+
+T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+    try {
+        return lift(buf.asUint8List());
+    } finally {
+        buf.free();
+    }
+}
+
+final class ForeignBytes extends Struct {
+    @Int32()
+    external int len;
+    external Pointer<Uint8> data;
+
+    void free() {
+    _uniffiAllocator.free(data);
+    }
+}
+
+class LiftRetVal<T> {
+    final T value;
+    final int bytesRead;
+    const LiftRetVal(this.value, this.bytesRead);
+
+    LiftRetVal<T> copyWithOffset(int offset) {
+        return LiftRetVal(value, bytesRead + offset);
+    }
+}
+
+abstract class FfiConverter<D, F> {
+    const FfiConverter();
+
+    D lift(F value);
+    F lower(D value);
+    D read(ByteData buffer, int offset);
+    void write(D value, ByteData buffer, int offset);
+    int size(D value);
+}
+
+mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+    @override
+    T lift(T value) => value;
+
+    @override
+    T lower(T value) => value;
+}
+
+class FfiConverterInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value);
+        return FfiConverterInt32.allocationSize();
+    }
+
+}class FfiConverterOptionalInt32 {
+
+    static int? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalInt32.read(data).value);
+    }
+
+    static LiftRetVal<int?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterInt32.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([int? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterInt32.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( int? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalInt32.allocationSize(value));
+        FfiConverterOptionalInt32.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( int? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterInt32.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalString {
+
+    static String? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalString.read(data).value);
+    }
+
+    static LiftRetVal<String?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterString.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([String? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterString.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( String? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalString.allocationSize(value));
+        FfiConverterOptionalString.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( String? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterString.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterOptionalUInt64 {
+
+    static int? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalUInt64.read(data).value);
+    }
+
+    static LiftRetVal<int?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterUInt64.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([int? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterUInt64.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( int? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalUInt64.allocationSize(value));
+        FfiConverterOptionalUInt64.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( int? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterUInt64.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterString {
+    static String lift( RustBuffer buf) {
+
+        return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
+    }
+
+    static RustBuffer lower( String value) {
+        return toRustBuffer(Utf8Encoder().convert(value));
+    }
+
+    static LiftRetVal<String> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+        return LiftRetVal(utf8.decoder.convert(buf, 4, end), end);
+    }
+
+    static int allocationSize([String value = ""]) {
+
+        return utf8.encoder.convert(value).length + 4;
+    }
+
+    static int write( String value, Uint8List buf) {
+
+        final list = utf8.encoder.convert(value);
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length);
+        buf.setAll(4, list);
+        return list.length + 4;
+    }
+}class FfiConverterUInt64 {
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0), 8);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 8;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, value);
+        return FfiConverterUInt64.allocationSize();
+    }
+}
+
+const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
+
+typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+Future<T> uniffiRustCallAsync<T, F>(
+    Pointer<Void> Function() rustFutureFunc,
+    void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc,
+    F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc,
+    void Function(Pointer<Void>) freeFunc,
+    T Function(F) liftFunc, [
+    UniffiRustCallStatusErrorHandler? errorHandler,
+]) async {
+    final rustFuture = rustFutureFunc();
+    final completer = Completer<int>();
+
+    late final NativeCallable<UniffiRustFutureContinuationCallback> callback;
+
+    void poll() {
+        pollFunc(
+            rustFuture,
+            callback.nativeFunction,
+            Pointer<Void>.fromAddress(0),
+        );
+    }
+    void onResponse(int _idx, int pollResult) {
+        if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+            completer.complete(pollResult);
+        } else {
+            poll();
+        }
+    }
+    callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener(onResponse);
+
+    try {
+        poll();
+        await completer.future;
+        callback.close();
+
+        final status = _uniffiAllocator<RustCallStatus>();
+        try {
+            final result = completeFunc(rustFuture, status);
+            checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+            return liftFunc(result);
+        } finally {
+            _uniffiAllocator.free(status);
+        }
+    } finally {
+        freeFunc(rustFuture);
+    }
+}
+
+class UniffiHandleMap<T> {
+    final Map<int, T> _map = {};
+    int _counter = 0;
+
+    int insert(T obj) {
+    final handle = _counter++;
+    _map[handle] = obj;
+    return handle;
+    }
+
+    T get(int handle) {
+    final obj = _map[handle];
+    if (obj == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    return obj;
+    }
+
+    void remove(int handle) {
+    if (_map.remove(handle) == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    }
+}
+
+AsyncTimerStreamStreamExt createStreamAsyncTimerStream() {
+    return FfiConverterAsyncTimerStreamStreamExt.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_streams_ext_fn_func_create_stream_async_timer_stream( uniffiStatus), NullRustCallStatusErrorHandler()));
+}CombinedStreamsStreamExt createStreamCombinedStreams() {
+    return FfiConverterCombinedStreamsStreamExt.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_streams_ext_fn_func_create_stream_combined_streams( uniffiStatus), NullRustCallStatusErrorHandler()));
+}CountStreamStreamExt createStreamCountStream() {
+    return FfiConverterCountStreamStreamExt.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_streams_ext_fn_func_create_stream_count_stream( uniffiStatus), NullRustCallStatusErrorHandler()));
+}FibonacciStreamStreamExt createStreamFibonacciStream() {
+    return FfiConverterFibonacciStreamStreamExt.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_streams_ext_fn_func_create_stream_fibonacci_stream( uniffiStatus), NullRustCallStatusErrorHandler()));
+}SimpleStreamStreamExt createStreamSimpleStream() {
+    return FfiConverterSimpleStreamStreamExt.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_streams_ext_fn_func_create_stream_simple_stream( uniffiStatus), NullRustCallStatusErrorHandler()));
+}
+
+class _UniffiLib {
+    _UniffiLib._();
+
+    static final DynamicLibrary _dylib = _open();
+
+    static DynamicLibrary _open() {
+        if (Platform.isAndroid) return DynamicLibrary.open("libstreams_ext.so");
+        if (Platform.isIOS) return DynamicLibrary.executable();
+        if (Platform.isLinux) return DynamicLibrary.open("libstreams_ext.so");
+        if (Platform.isMacOS) return DynamicLibrary.open("libstreams_ext.dylib");
+        if (Platform.isWindows) return DynamicLibrary.open("streams_ext.dll");
+        throw UnsupportedError("Unsupported platform: \${Platform.operatingSystem}");
+    }
+
+    static final _UniffiLib instance = _UniffiLib._();
+
+    late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_streams_ext_fn_clone_asynctimerstreamstreamext = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_clone_asynctimerstreamstreamext");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_streams_ext_fn_free_asynctimerstreamstreamext = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_free_asynctimerstreamstreamext");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_streams_ext_fn_constructor_asynctimerstreamstreamext_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_constructor_asynctimerstreamstreamext_new");late final Pointer<Void> Function(Pointer<Void>,) uniffi_streams_ext_fn_method_asynctimerstreamstreamext_next = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,),
+        Pointer<Void> Function(Pointer<Void>,)
+    >("uniffi_streams_ext_fn_method_asynctimerstreamstreamext_next");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_streams_ext_fn_clone_combinedstreamsstreamext = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_clone_combinedstreamsstreamext");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_streams_ext_fn_free_combinedstreamsstreamext = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_free_combinedstreamsstreamext");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_streams_ext_fn_constructor_combinedstreamsstreamext_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_constructor_combinedstreamsstreamext_new");late final Pointer<Void> Function(Pointer<Void>,) uniffi_streams_ext_fn_method_combinedstreamsstreamext_next = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,),
+        Pointer<Void> Function(Pointer<Void>,)
+    >("uniffi_streams_ext_fn_method_combinedstreamsstreamext_next");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_streams_ext_fn_clone_countstreamstreamext = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_clone_countstreamstreamext");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_streams_ext_fn_free_countstreamstreamext = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_free_countstreamstreamext");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_streams_ext_fn_constructor_countstreamstreamext_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_constructor_countstreamstreamext_new");late final Pointer<Void> Function(Pointer<Void>,) uniffi_streams_ext_fn_method_countstreamstreamext_next = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,),
+        Pointer<Void> Function(Pointer<Void>,)
+    >("uniffi_streams_ext_fn_method_countstreamstreamext_next");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_streams_ext_fn_clone_fibonaccistreamstreamext = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_clone_fibonaccistreamstreamext");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_streams_ext_fn_free_fibonaccistreamstreamext = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_free_fibonaccistreamstreamext");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_streams_ext_fn_constructor_fibonaccistreamstreamext_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_constructor_fibonaccistreamstreamext_new");late final Pointer<Void> Function(Pointer<Void>,) uniffi_streams_ext_fn_method_fibonaccistreamstreamext_next = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,),
+        Pointer<Void> Function(Pointer<Void>,)
+    >("uniffi_streams_ext_fn_method_fibonaccistreamstreamext_next");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_streams_ext_fn_clone_simplestreamstreamext = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_clone_simplestreamstreamext");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_streams_ext_fn_free_simplestreamstreamext = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_free_simplestreamstreamext");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_streams_ext_fn_constructor_simplestreamstreamext_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_constructor_simplestreamstreamext_new");late final Pointer<Void> Function(Pointer<Void>,) uniffi_streams_ext_fn_method_simplestreamstreamext_next = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,),
+        Pointer<Void> Function(Pointer<Void>,)
+    >("uniffi_streams_ext_fn_method_simplestreamstreamext_next");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_streams_ext_fn_func_create_stream_async_timer_stream = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_func_create_stream_async_timer_stream");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_streams_ext_fn_func_create_stream_combined_streams = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_func_create_stream_combined_streams");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_streams_ext_fn_func_create_stream_count_stream = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_func_create_stream_count_stream");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_streams_ext_fn_func_create_stream_fibonacci_stream = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_func_create_stream_fibonacci_stream");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_streams_ext_fn_func_create_stream_simple_stream = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_streams_ext_fn_func_create_stream_simple_stream");late final RustBuffer Function(int,Pointer<RustCallStatus>) ffi_streams_ext_rustbuffer_alloc = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("ffi_streams_ext_rustbuffer_alloc");late final RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>) ffi_streams_ext_rustbuffer_from_bytes = _dylib.lookupFunction<
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>),
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>)
+    >("ffi_streams_ext_rustbuffer_from_bytes");late final void Function(RustBuffer,Pointer<RustCallStatus>) ffi_streams_ext_rustbuffer_free = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("ffi_streams_ext_rustbuffer_free");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) ffi_streams_ext_rustbuffer_reserve = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("ffi_streams_ext_rustbuffer_reserve");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_streams_ext_rust_future_poll_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_poll_u8");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_cancel_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_cancel_u8");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_free_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_free_u8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_streams_ext_rust_future_complete_u8 = _dylib.lookupFunction<
+        Uint8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_streams_ext_rust_future_complete_u8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_streams_ext_rust_future_poll_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_poll_i8");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_cancel_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_cancel_i8");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_free_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_free_i8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_streams_ext_rust_future_complete_i8 = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_streams_ext_rust_future_complete_i8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_streams_ext_rust_future_poll_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_poll_u16");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_cancel_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_cancel_u16");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_free_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_free_u16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_streams_ext_rust_future_complete_u16 = _dylib.lookupFunction<
+        Uint16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_streams_ext_rust_future_complete_u16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_streams_ext_rust_future_poll_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_poll_i16");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_cancel_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_cancel_i16");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_free_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_free_i16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_streams_ext_rust_future_complete_i16 = _dylib.lookupFunction<
+        Int16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_streams_ext_rust_future_complete_i16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_streams_ext_rust_future_poll_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_poll_u32");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_cancel_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_cancel_u32");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_free_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_free_u32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_streams_ext_rust_future_complete_u32 = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_streams_ext_rust_future_complete_u32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_streams_ext_rust_future_poll_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_poll_i32");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_cancel_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_cancel_i32");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_free_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_free_i32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_streams_ext_rust_future_complete_i32 = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_streams_ext_rust_future_complete_i32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_streams_ext_rust_future_poll_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_poll_u64");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_cancel_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_cancel_u64");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_free_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_free_u64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_streams_ext_rust_future_complete_u64 = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_streams_ext_rust_future_complete_u64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_streams_ext_rust_future_poll_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_poll_i64");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_cancel_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_cancel_i64");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_free_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_free_i64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_streams_ext_rust_future_complete_i64 = _dylib.lookupFunction<
+        Int64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_streams_ext_rust_future_complete_i64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_streams_ext_rust_future_poll_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_poll_f32");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_cancel_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_cancel_f32");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_free_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_free_f32");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_streams_ext_rust_future_complete_f32 = _dylib.lookupFunction<
+        Float Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_streams_ext_rust_future_complete_f32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_streams_ext_rust_future_poll_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_poll_f64");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_cancel_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_cancel_f64");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_free_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_free_f64");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_streams_ext_rust_future_complete_f64 = _dylib.lookupFunction<
+        Double Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_streams_ext_rust_future_complete_f64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_streams_ext_rust_future_poll_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_poll_pointer");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_cancel_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_cancel_pointer");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_free_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_free_pointer");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_streams_ext_rust_future_complete_pointer = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_streams_ext_rust_future_complete_pointer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_streams_ext_rust_future_poll_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_poll_rust_buffer");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_cancel_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_cancel_rust_buffer");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_free_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_free_rust_buffer");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_streams_ext_rust_future_complete_rust_buffer = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_streams_ext_rust_future_complete_rust_buffer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_streams_ext_rust_future_poll_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_poll_void");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_cancel_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_cancel_void");late final void Function(Pointer<Void>,) ffi_streams_ext_rust_future_free_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_streams_ext_rust_future_free_void");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_streams_ext_rust_future_complete_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_streams_ext_rust_future_complete_void");late final int Function() uniffi_streams_ext_checksum_func_create_stream_async_timer_stream = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_streams_ext_checksum_func_create_stream_async_timer_stream");late final int Function() uniffi_streams_ext_checksum_func_create_stream_combined_streams = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_streams_ext_checksum_func_create_stream_combined_streams");late final int Function() uniffi_streams_ext_checksum_func_create_stream_count_stream = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_streams_ext_checksum_func_create_stream_count_stream");late final int Function() uniffi_streams_ext_checksum_func_create_stream_fibonacci_stream = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_streams_ext_checksum_func_create_stream_fibonacci_stream");late final int Function() uniffi_streams_ext_checksum_func_create_stream_simple_stream = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_streams_ext_checksum_func_create_stream_simple_stream");late final int Function() uniffi_streams_ext_checksum_method_asynctimerstreamstreamext_next = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_streams_ext_checksum_method_asynctimerstreamstreamext_next");late final int Function() uniffi_streams_ext_checksum_method_combinedstreamsstreamext_next = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_streams_ext_checksum_method_combinedstreamsstreamext_next");late final int Function() uniffi_streams_ext_checksum_method_countstreamstreamext_next = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_streams_ext_checksum_method_countstreamstreamext_next");late final int Function() uniffi_streams_ext_checksum_method_fibonaccistreamstreamext_next = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_streams_ext_checksum_method_fibonaccistreamstreamext_next");late final int Function() uniffi_streams_ext_checksum_method_simplestreamstreamext_next = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_streams_ext_checksum_method_simplestreamstreamext_next");late final int Function() uniffi_streams_ext_checksum_constructor_asynctimerstreamstreamext_new = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_streams_ext_checksum_constructor_asynctimerstreamstreamext_new");late final int Function() uniffi_streams_ext_checksum_constructor_combinedstreamsstreamext_new = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_streams_ext_checksum_constructor_combinedstreamsstreamext_new");late final int Function() uniffi_streams_ext_checksum_constructor_countstreamstreamext_new = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_streams_ext_checksum_constructor_countstreamstreamext_new");late final int Function() uniffi_streams_ext_checksum_constructor_fibonaccistreamstreamext_new = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_streams_ext_checksum_constructor_fibonaccistreamstreamext_new");late final int Function() uniffi_streams_ext_checksum_constructor_simplestreamstreamext_new = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_streams_ext_checksum_constructor_simplestreamstreamext_new");late final int Function() ffi_streams_ext_uniffi_contract_version = _dylib.lookupFunction<
+        Uint32 Function(),
+        int Function()
+    >("ffi_streams_ext_uniffi_contract_version");late final Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>) uniffi_dart_finalizer_token_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>),
+        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>)
+    >("uniffi_dart_finalizer_token_new");
+    late final void Function(Pointer<Void>) uniffi_dart_finalizer_token_free = _dylib.lookupFunction<
+        Void Function(Pointer<Void>),
+        void Function(Pointer<Void>)
+    >("uniffi_dart_finalizer_token_free");
+    late final Pointer<NativeFinalizerFunction> uniffi_dart_finalizer_run = _dylib.lookup("uniffi_dart_finalizer_run");
+
+    static void _checkApiVersion() {
+        final bindingsVersion = 26;
+        final scaffoldingVersion = _UniffiLib.instance.ffi_streams_ext_uniffi_contract_version();
+        if (bindingsVersion != scaffoldingVersion) {
+            throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version \$bindingsVersion, scaffolding version \$scaffoldingVersion");
+        }
+    }
+
+    static void _checkApiChecksums() {
+        if (_UniffiLib.instance.uniffi_streams_ext_checksum_func_create_stream_async_timer_stream() != 25494) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_streams_ext_checksum_func_create_stream_combined_streams() != 5644) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_streams_ext_checksum_func_create_stream_count_stream() != 6088) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_streams_ext_checksum_func_create_stream_fibonacci_stream() != 30098) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_streams_ext_checksum_func_create_stream_simple_stream() != 65023) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_streams_ext_checksum_method_asynctimerstreamstreamext_next() != 15368) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_streams_ext_checksum_method_combinedstreamsstreamext_next() != 5427) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_streams_ext_checksum_method_countstreamstreamext_next() != 4096) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_streams_ext_checksum_method_fibonaccistreamstreamext_next() != 54111) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_streams_ext_checksum_method_simplestreamstreamext_next() != 6169) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_streams_ext_checksum_constructor_asynctimerstreamstreamext_new() != 19547) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_streams_ext_checksum_constructor_combinedstreamsstreamext_new() != 15881) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_streams_ext_checksum_constructor_countstreamstreamext_new() != 44207) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_streams_ext_checksum_constructor_fibonaccistreamstreamext_new() != 9061) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_streams_ext_checksum_constructor_simplestreamstreamext_new() != 28556) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }
+    }
+}
+
+void initialize() {
+    _UniffiLib._open();
+}
+
+void ensureInitialized() {
+    _UniffiLib._checkApiVersion();
+    _UniffiLib._checkApiChecksums();
+}
@@ -338,4 +338,5 @@ mod tests {
     // }
 }
 
-uniffi::include_scaffolding!("api");
+uniffi::setup_scaffolding!();
+uniffi_dart::setup_finalizers!();
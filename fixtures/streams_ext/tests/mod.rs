@@ -2,5 +2,10 @@ use anyhow::Result;
 
 #[test]
 fn streams_ext() -> Result<()> {
-    uniffi_dart::testing::run_test("streams_ext", "src/api.udl", None)
+    uniffi_dart::testing::run_library_test("streams_ext", None)
+}
+
+#[test]
+fn streams_ext_golden() -> Result<()> {
+    uniffi_dart::testing::assert_library_golden("streams_ext", None)
 }
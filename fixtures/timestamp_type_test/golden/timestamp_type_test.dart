@@ -0,0 +1,776 @@
+library timestamp_type_test;
+
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io" show Platform, File, Directory;
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+class UniffiInternalError implements Exception {
+    static const int bufferOverflow = 0;
+    static const int incompleteData = 1;
+    static const int unexpectedOptionalTag = 2;
+    static const int unexpectedEnumCase = 3;
+    static const int unexpectedNullPointer = 4;
+    static const int unexpectedRustCallStatusCode = 5;
+    static const int unexpectedRustCallError = 6;
+    static const int unexpectedStaleHandle = 7;
+    static const int rustPanic = 8;
+
+    final int errorCode;
+    final String? panicMessage;
+
+    const UniffiInternalError(this.errorCode, this.panicMessage);
+
+    static UniffiInternalError panicked(String message) {
+    return UniffiInternalError(rustPanic, message);
+    }
+
+    @override
+    String toString() {
+    switch (errorCode) {
+        case bufferOverflow:
+        return "UniFfi::BufferOverflow";
+        case incompleteData:
+        return "UniFfi::IncompleteData";
+        case unexpectedOptionalTag:
+        return "UniFfi::UnexpectedOptionalTag";
+        case unexpectedEnumCase:
+        return "UniFfi::UnexpectedEnumCase";
+        case unexpectedNullPointer:
+        return "UniFfi::UnexpectedNullPointer";
+        case unexpectedRustCallStatusCode:
+        return "UniFfi::UnexpectedRustCallStatusCode";
+        case unexpectedRustCallError:
+        return "UniFfi::UnexpectedRustCallError";
+        case unexpectedStaleHandle:
+        return "UniFfi::UnexpectedStaleHandle";
+        case rustPanic:
+        return "UniFfi::rustPanic: \$\$panicMessage";
+        default:
+        return "UniFfi::UnknownError: \$\$errorCode";
+    }
+    }
+}
+
+const int CALL_SUCCESS = 0;
+const int CALL_ERROR = 1;
+const int CALL_UNEXPECTED_ERROR = 2;
+
+final class RustCallStatus extends Struct {
+    @Int8()
+    external int code;
+
+    external RustBuffer errorBuf;
+
+}
+
+void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
+
+    if (status.ref.code == CALL_SUCCESS) {
+    return;
+    } else if (status.ref.code == CALL_ERROR) {
+    throw errorHandler.lift(status.ref.errorBuf);
+    } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+    if (status.ref.errorBuf.len > 0) {
+        throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
+    } else {
+        throw UniffiInternalError.panicked("Rust panic");
+    }
+    } else {
+    throw UniffiInternalError.panicked("Unexpected RustCallStatus code: \${status.ref.code}");
+    }
+}
+
+#[doc=" Allocates the native memory the bindings pass to Rust for the duration of a call."]
+const Allocator _uniffiAllocator = calloc;
+
+T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+    final status = _uniffiAllocator<RustCallStatus>();
+    try {
+    final result = callback(status);
+    checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+    return result;
+    } finally {
+    _uniffiAllocator.free(status);
+    }
+}
+
+class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+    errorBuf.free();
+    return UniffiInternalError.panicked("Unexpected CALL_ERROR");
+    }
+}
+
+abstract class UniffiRustCallStatusErrorHandler {
+    Exception lift(RustBuffer errorBuf);
+}
+
+final class RustBuffer extends Struct {
+    @Uint64()
+    external int capacity;
+
+    @Uint64()
+    external int len;
+
+    external Pointer<Uint8> data;
+
+    static RustBuffer alloc(int size) {
+        return rustCall((status) => _UniffiLib.instance.ffi_timestamp_type_test_rustbuffer_alloc(size, status));
+    }
+
+    static RustBuffer fromBytes(ForeignBytes bytes) {
+        return rustCall((status) => _UniffiLib.instance.ffi_timestamp_type_test_rustbuffer_from_bytes(bytes, status));
+    }
+
+    void free() {
+        rustCall((status) => _UniffiLib.instance.ffi_timestamp_type_test_rustbuffer_free(this, status));
+    }
+
+    RustBuffer reserve(int additionalCapacity) {
+    return rustCall((status) => _UniffiLib.instance.ffi_timestamp_type_test_rustbuffer_reserve(this, additionalCapacity, status));
+    }
+
+    Uint8List asUint8List() {
+    final dataList = data.asTypedList(len);
+    final byteData = ByteData.sublistView(dataList);
+    return Uint8List.view(byteData.buffer);
+    }
+
+    @override
+    String toString() {
+    return "RustBuffer{capacity: \$capacity, len: \$len, data: \$data}";
+    }
+}
+
+RustBuffer toRustBuffer(Uint8List data) {
+    final length = data.length;
+
+    final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length);
+    final pointerList = frameData.asTypedList(length);
+    pointerList.setAll(0, data);
+
+    final bytes = _uniffiAllocator<ForeignBytes>();
+    bytes.ref.len = length;
+    bytes.ref.data = frameData;
+    try {
+        return RustBuffer.fromBytes(bytes.ref);
+    } finally {
+        _uniffiAllocator.free(frameData);
+        _uniffiAllocator.free(bytes);
+    }
+}
+
+T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+    try {
+        return lift(buf.asUint8List());
+    } finally {
+        buf.free();
+    }
+}
+
+final class ForeignBytes extends Struct {
+    @Int32()
+    external int len;
+    external Pointer<Uint8> data;
+
+    void free() {
+    _uniffiAllocator.free(data);
+    }
+}
+
+class LiftRetVal<T> {
+    final T value;
+    final int bytesRead;
+    const LiftRetVal(this.value, this.bytesRead);
+
+    LiftRetVal<T> copyWithOffset(int offset) {
+        return LiftRetVal(value, bytesRead + offset);
+    }
+}
+
+abstract class FfiConverter<D, F> {
+    const FfiConverter();
+
+    D lift(F value);
+    F lower(D value);
+    D read(ByteData buffer, int offset);
+    void write(D value, ByteData buffer, int offset);
+    int size(D value);
+}
+
+mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+    @override
+    T lift(T value) => value;
+
+    @override
+    T lower(T value) => value;
+}
+
+class FfiConverterDuration {
+    static Duration lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterDuration.read(data).value);
+    }
+
+    static RustBuffer lower( Duration value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static LiftRetVal<Duration> read( Uint8List buf) {
+        final bytes = buf.buffer.asByteData(buf.offsetInBytes, 12);
+        final seconds = bytes.getUint64(0);
+        final micros = (bytes.getUint32(8) ~/ 1000);
+        return LiftRetVal(Duration(seconds: seconds, microseconds: micros), 12);
+    }
+
+    static int allocationSize([Duration value = const Duration()]) {
+        return 12;
+    }
+
+    static int write( Duration value, Uint8List buf) {
+        final bytes = buf.buffer.asByteData(buf.offsetInBytes, 12);
+        bytes.setUint64(0, value.inSeconds);
+        final ms = (value.inMicroseconds - (value.inSeconds * 1000000)) * 1000;
+        bytes.setUint32(8, ms.toInt());
+        return 12;
+    }
+}class FfiConverterInt64 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt64(0), 8);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 8;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, value);
+        return FfiConverterInt64.allocationSize();
+    }
+
+}class FfiConverterOptionalTimestamp {
+
+    static DateTime? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalTimestamp.read(data).value);
+    }
+
+    static LiftRetVal<DateTime?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterTimestamp.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([DateTime? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterTimestamp.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( DateTime? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalTimestamp.allocationSize(value));
+        FfiConverterOptionalTimestamp.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( DateTime? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterTimestamp.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterSequenceTimestamp {
+
+    static List<DateTime> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceTimestamp.read(data).value);
+    }
+
+    static LiftRetVal<List<DateTime>> read( Uint8List buf) {
+        List<DateTime> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterTimestamp.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<DateTime> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterTimestamp.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<DateTime> value) {
+        return value.map((l) => FfiConverterTimestamp.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<DateTime> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterString {
+    static String lift( RustBuffer buf) {
+
+        return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
+    }
+
+    static RustBuffer lower( String value) {
+        return toRustBuffer(Utf8Encoder().convert(value));
+    }
+
+    static LiftRetVal<String> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+        return LiftRetVal(utf8.decoder.convert(buf, 4, end), end);
+    }
+
+    static int allocationSize([String value = ""]) {
+
+        return utf8.encoder.convert(value).length + 4;
+    }
+
+    static int write( String value, Uint8List buf) {
+
+        final list = utf8.encoder.convert(value);
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length);
+        buf.setAll(4, list);
+        return list.length + 4;
+    }
+}class FfiConverterTimestamp {
+    static DateTime lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterTimestamp.read(data).value);
+    }
+
+    static RustBuffer lower( DateTime value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static LiftRetVal<DateTime> read( Uint8List buf) {
+        final bytes = buf.buffer.asByteData(buf.offsetInBytes, 12);
+        final seconds = bytes.getInt64(0);
+        final nanos = bytes.getUint32(8);
+        final micros = seconds.abs() * 1000000 + nanos ~/ 1000;
+        return LiftRetVal(DateTime.fromMicrosecondsSinceEpoch(seconds < 0 ? -micros : micros, isUtc: true), 12);
+    }
+
+    static int allocationSize([DateTime? value]) {
+        return 12;
+    }
+
+    static int write( DateTime value, Uint8List buf) {
+        final bytes = buf.buffer.asByteData(buf.offsetInBytes, 12);
+        final micros = value.microsecondsSinceEpoch;
+        final sign = micros < 0 ? -1 : 1;
+        bytes.setInt64(0, sign * (micros.abs() ~/ 1000000));
+        bytes.setUint32(8, (micros.abs() % 1000000) * 1000);
+        return 12;
+    }
+}class FfiConverterUInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, value);
+        return FfiConverterUInt32.allocationSize();
+    }
+
+}
+
+const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
+
+typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+Future<T> uniffiRustCallAsync<T, F>(
+    Pointer<Void> Function() rustFutureFunc,
+    void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc,
+    F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc,
+    void Function(Pointer<Void>) freeFunc,
+    T Function(F) liftFunc, [
+    UniffiRustCallStatusErrorHandler? errorHandler,
+]) async {
+    final rustFuture = rustFutureFunc();
+    final completer = Completer<int>();
+
+    late final NativeCallable<UniffiRustFutureContinuationCallback> callback;
+
+    void poll() {
+        pollFunc(
+            rustFuture,
+            callback.nativeFunction,
+            Pointer<Void>.fromAddress(0),
+        );
+    }
+    void onResponse(int _idx, int pollResult) {
+        if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+            completer.complete(pollResult);
+        } else {
+            poll();
+        }
+    }
+    callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener(onResponse);
+
+    try {
+        poll();
+        await completer.future;
+        callback.close();
+
+        final status = _uniffiAllocator<RustCallStatus>();
+        try {
+            final result = completeFunc(rustFuture, status);
+            checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+            return liftFunc(result);
+        } finally {
+            _uniffiAllocator.free(status);
+        }
+    } finally {
+        freeFunc(rustFuture);
+    }
+}
+
+class UniffiHandleMap<T> {
+    final Map<int, T> _map = {};
+    int _counter = 0;
+
+    int insert(T obj) {
+    final handle = _counter++;
+    _map[handle] = obj;
+    return handle;
+    }
+
+    T get(int handle) {
+    final obj = _map[handle];
+    if (obj == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    return obj;
+    }
+
+    void remove(int handle) {
+    if (_map.remove(handle) == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    }
+}
+
+DateTime addDuration(DateTime timestamp,Duration duration,) {
+    return FfiConverterTimestamp.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_timestamp_type_test_fn_func_add_duration(FfiConverterTimestamp.lower(timestamp),FfiConverterDuration.lower(duration), uniffiStatus), NullRustCallStatusErrorHandler()));
+}DateTime? echoOptionalTimestamp(DateTime? timestamp,) {
+    return FfiConverterOptionalTimestamp.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_timestamp_type_test_fn_func_echo_optional_timestamp(FfiConverterOptionalTimestamp.lower(timestamp), uniffiStatus), NullRustCallStatusErrorHandler()));
+}DateTime echoTimestamp(DateTime timestamp,) {
+    return FfiConverterTimestamp.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_timestamp_type_test_fn_func_echo_timestamp(FfiConverterTimestamp.lower(timestamp), uniffiStatus), NullRustCallStatusErrorHandler()));
+}List<DateTime> echoTimestamps(List<DateTime> timestamps,) {
+    return FfiConverterSequenceTimestamp.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_timestamp_type_test_fn_func_echo_timestamps(FfiConverterSequenceTimestamp.lower(timestamps), uniffiStatus), NullRustCallStatusErrorHandler()));
+}int getNanos(DateTime timestamp,) {
+    return rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_timestamp_type_test_fn_func_get_nanos(FfiConverterTimestamp.lower(timestamp), uniffiStatus), NullRustCallStatusErrorHandler());
+}int getSeconds(DateTime timestamp,) {
+    return rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_timestamp_type_test_fn_func_get_seconds(FfiConverterTimestamp.lower(timestamp), uniffiStatus), NullRustCallStatusErrorHandler());
+}DateTime makeTimestamp(int seconds,int nanos,) {
+    return FfiConverterTimestamp.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_timestamp_type_test_fn_func_make_timestamp(seconds,nanos, uniffiStatus), NullRustCallStatusErrorHandler()));
+}
+
+class _UniffiLib {
+    _UniffiLib._();
+
+    static final DynamicLibrary _dylib = _open();
+
+    static DynamicLibrary _open() {
+        if (Platform.isAndroid) return DynamicLibrary.open("libtimestamp_type_test.so");
+        if (Platform.isIOS) return DynamicLibrary.executable();
+        if (Platform.isLinux) return DynamicLibrary.open("libtimestamp_type_test.so");
+        if (Platform.isMacOS) return DynamicLibrary.open("libtimestamp_type_test.dylib");
+        if (Platform.isWindows) return DynamicLibrary.open("timestamp_type_test.dll");
+        throw UnsupportedError("Unsupported platform: \${Platform.operatingSystem}");
+    }
+
+    static final _UniffiLib instance = _UniffiLib._();
+
+    late final RustBuffer Function(RustBuffer,RustBuffer,Pointer<RustCallStatus>) uniffi_timestamp_type_test_fn_func_add_duration = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_timestamp_type_test_fn_func_add_duration");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_timestamp_type_test_fn_func_echo_optional_timestamp = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_timestamp_type_test_fn_func_echo_optional_timestamp");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_timestamp_type_test_fn_func_echo_timestamp = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_timestamp_type_test_fn_func_echo_timestamp");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_timestamp_type_test_fn_func_echo_timestamps = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_timestamp_type_test_fn_func_echo_timestamps");late final int Function(RustBuffer,Pointer<RustCallStatus>) uniffi_timestamp_type_test_fn_func_get_nanos = _dylib.lookupFunction<
+        Uint32 Function(RustBuffer,Pointer<RustCallStatus>),
+        int Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_timestamp_type_test_fn_func_get_nanos");late final int Function(RustBuffer,Pointer<RustCallStatus>) uniffi_timestamp_type_test_fn_func_get_seconds = _dylib.lookupFunction<
+        Int64 Function(RustBuffer,Pointer<RustCallStatus>),
+        int Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_timestamp_type_test_fn_func_get_seconds");late final RustBuffer Function(int,int,Pointer<RustCallStatus>) uniffi_timestamp_type_test_fn_func_make_timestamp = _dylib.lookupFunction<
+        RustBuffer Function(Int64,Uint32,Pointer<RustCallStatus>),
+        RustBuffer Function(int,int,Pointer<RustCallStatus>)
+    >("uniffi_timestamp_type_test_fn_func_make_timestamp");late final RustBuffer Function(int,Pointer<RustCallStatus>) ffi_timestamp_type_test_rustbuffer_alloc = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("ffi_timestamp_type_test_rustbuffer_alloc");late final RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>) ffi_timestamp_type_test_rustbuffer_from_bytes = _dylib.lookupFunction<
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>),
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>)
+    >("ffi_timestamp_type_test_rustbuffer_from_bytes");late final void Function(RustBuffer,Pointer<RustCallStatus>) ffi_timestamp_type_test_rustbuffer_free = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("ffi_timestamp_type_test_rustbuffer_free");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) ffi_timestamp_type_test_rustbuffer_reserve = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("ffi_timestamp_type_test_rustbuffer_reserve");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_timestamp_type_test_rust_future_poll_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_poll_u8");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_cancel_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_cancel_u8");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_free_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_free_u8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_timestamp_type_test_rust_future_complete_u8 = _dylib.lookupFunction<
+        Uint8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_timestamp_type_test_rust_future_complete_u8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_timestamp_type_test_rust_future_poll_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_poll_i8");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_cancel_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_cancel_i8");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_free_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_free_i8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_timestamp_type_test_rust_future_complete_i8 = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_timestamp_type_test_rust_future_complete_i8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_timestamp_type_test_rust_future_poll_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_poll_u16");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_cancel_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_cancel_u16");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_free_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_free_u16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_timestamp_type_test_rust_future_complete_u16 = _dylib.lookupFunction<
+        Uint16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_timestamp_type_test_rust_future_complete_u16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_timestamp_type_test_rust_future_poll_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_poll_i16");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_cancel_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_cancel_i16");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_free_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_free_i16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_timestamp_type_test_rust_future_complete_i16 = _dylib.lookupFunction<
+        Int16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_timestamp_type_test_rust_future_complete_i16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_timestamp_type_test_rust_future_poll_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_poll_u32");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_cancel_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_cancel_u32");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_free_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_free_u32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_timestamp_type_test_rust_future_complete_u32 = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_timestamp_type_test_rust_future_complete_u32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_timestamp_type_test_rust_future_poll_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_poll_i32");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_cancel_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_cancel_i32");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_free_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_free_i32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_timestamp_type_test_rust_future_complete_i32 = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_timestamp_type_test_rust_future_complete_i32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_timestamp_type_test_rust_future_poll_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_poll_u64");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_cancel_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_cancel_u64");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_free_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_free_u64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_timestamp_type_test_rust_future_complete_u64 = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_timestamp_type_test_rust_future_complete_u64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_timestamp_type_test_rust_future_poll_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_poll_i64");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_cancel_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_cancel_i64");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_free_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_free_i64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_timestamp_type_test_rust_future_complete_i64 = _dylib.lookupFunction<
+        Int64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_timestamp_type_test_rust_future_complete_i64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_timestamp_type_test_rust_future_poll_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_poll_f32");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_cancel_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_cancel_f32");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_free_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_free_f32");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_timestamp_type_test_rust_future_complete_f32 = _dylib.lookupFunction<
+        Float Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_timestamp_type_test_rust_future_complete_f32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_timestamp_type_test_rust_future_poll_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_poll_f64");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_cancel_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_cancel_f64");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_free_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_free_f64");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_timestamp_type_test_rust_future_complete_f64 = _dylib.lookupFunction<
+        Double Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_timestamp_type_test_rust_future_complete_f64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_timestamp_type_test_rust_future_poll_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_poll_pointer");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_cancel_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_cancel_pointer");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_free_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_free_pointer");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_timestamp_type_test_rust_future_complete_pointer = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_timestamp_type_test_rust_future_complete_pointer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_timestamp_type_test_rust_future_poll_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_poll_rust_buffer");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_cancel_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_cancel_rust_buffer");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_free_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_free_rust_buffer");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_timestamp_type_test_rust_future_complete_rust_buffer = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_timestamp_type_test_rust_future_complete_rust_buffer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_timestamp_type_test_rust_future_poll_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_poll_void");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_cancel_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_cancel_void");late final void Function(Pointer<Void>,) ffi_timestamp_type_test_rust_future_free_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_timestamp_type_test_rust_future_free_void");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_timestamp_type_test_rust_future_complete_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_timestamp_type_test_rust_future_complete_void");late final int Function() uniffi_timestamp_type_test_checksum_func_add_duration = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_timestamp_type_test_checksum_func_add_duration");late final int Function() uniffi_timestamp_type_test_checksum_func_echo_optional_timestamp = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_timestamp_type_test_checksum_func_echo_optional_timestamp");late final int Function() uniffi_timestamp_type_test_checksum_func_echo_timestamp = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_timestamp_type_test_checksum_func_echo_timestamp");late final int Function() uniffi_timestamp_type_test_checksum_func_echo_timestamps = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_timestamp_type_test_checksum_func_echo_timestamps");late final int Function() uniffi_timestamp_type_test_checksum_func_get_nanos = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_timestamp_type_test_checksum_func_get_nanos");late final int Function() uniffi_timestamp_type_test_checksum_func_get_seconds = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_timestamp_type_test_checksum_func_get_seconds");late final int Function() uniffi_timestamp_type_test_checksum_func_make_timestamp = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_timestamp_type_test_checksum_func_make_timestamp");late final int Function() ffi_timestamp_type_test_uniffi_contract_version = _dylib.lookupFunction<
+        Uint32 Function(),
+        int Function()
+    >("ffi_timestamp_type_test_uniffi_contract_version");
+
+    static void _checkApiVersion() {
+        final bindingsVersion = 26;
+        final scaffoldingVersion = _UniffiLib.instance.ffi_timestamp_type_test_uniffi_contract_version();
+        if (bindingsVersion != scaffoldingVersion) {
+            throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version \$bindingsVersion, scaffolding version \$scaffoldingVersion");
+        }
+    }
+
+    static void _checkApiChecksums() {
+        if (_UniffiLib.instance.uniffi_timestamp_type_test_checksum_func_add_duration() != 34082) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_timestamp_type_test_checksum_func_echo_optional_timestamp() != 46153) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_timestamp_type_test_checksum_func_echo_timestamp() != 16509) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_timestamp_type_test_checksum_func_echo_timestamps() != 52334) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_timestamp_type_test_checksum_func_get_nanos() != 41324) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_timestamp_type_test_checksum_func_get_seconds() != 32206) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_timestamp_type_test_checksum_func_make_timestamp() != 57277) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }
+    }
+}
+
+void initialize() {
+    _UniffiLib._open();
+}
+
+void ensureInitialized() {
+    _UniffiLib._checkApiVersion();
+    _UniffiLib._checkApiChecksums();
+}
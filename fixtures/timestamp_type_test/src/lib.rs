@@ -0,0 +1,49 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[uniffi::export]
+pub fn make_timestamp(seconds: i64, nanos: u32) -> SystemTime {
+    let offset = Duration::new(seconds.unsigned_abs(), nanos);
+    if seconds < 0 {
+        UNIX_EPOCH - offset
+    } else {
+        UNIX_EPOCH + offset
+    }
+}
+
+#[uniffi::export]
+pub fn get_seconds(timestamp: SystemTime) -> i64 {
+    match timestamp.duration_since(UNIX_EPOCH) {
+        Ok(offset) => offset.as_secs() as i64,
+        Err(error) => -(error.duration().as_secs() as i64),
+    }
+}
+
+#[uniffi::export]
+pub fn get_nanos(timestamp: SystemTime) -> u32 {
+    match timestamp.duration_since(UNIX_EPOCH) {
+        Ok(offset) => offset.subsec_nanos(),
+        Err(error) => error.duration().subsec_nanos(),
+    }
+}
+
+#[uniffi::export]
+pub fn add_duration(timestamp: SystemTime, duration: Duration) -> SystemTime {
+    timestamp + duration
+}
+
+#[uniffi::export]
+pub fn echo_timestamp(timestamp: SystemTime) -> SystemTime {
+    timestamp
+}
+
+#[uniffi::export]
+pub fn echo_optional_timestamp(timestamp: Option<SystemTime>) -> Option<SystemTime> {
+    timestamp
+}
+
+#[uniffi::export]
+pub fn echo_timestamps(timestamps: Vec<SystemTime>) -> Vec<SystemTime> {
+    timestamps
+}
+
+uniffi::include_scaffolding!("api");
@@ -0,0 +1,65 @@
+import 'package:test/test.dart';
+import '../timestamp_type_test.dart';
+
+void main() {
+  test('rust return value check', () {
+    final timestamp = makeTimestamp(1700000000, 123456000);
+
+    expect(timestamp.isUtc, true);
+    expect(timestamp, DateTime.utc(2023, 11, 14, 22, 13, 20, 123, 456));
+    expect(getSeconds(timestamp), 1700000000);
+    expect(getNanos(timestamp), 123456000);
+  });
+
+  test('epoch', () {
+    final epoch = DateTime.fromMicrosecondsSinceEpoch(0, isUtc: true);
+    expect(makeTimestamp(0, 0), epoch);
+    expect(getSeconds(epoch), 0);
+    expect(getNanos(epoch), 0);
+  });
+
+  test('pre-epoch', () {
+    final timestamp = DateTime.utc(1969, 12, 31, 23, 59, 58, 500);
+    expect(getSeconds(timestamp), -1);
+    expect(getNanos(timestamp), 500000000);
+    expect(makeTimestamp(-1, 500000000), timestamp);
+    expect(echoTimestamp(DateTime.utc(1900, 1, 1)), DateTime.utc(1900, 1, 1));
+  });
+
+  test('microsecond precision', () {
+    final timestamp = DateTime.utc(2024, 2, 29, 12, 0, 0, 0, 1);
+    expect(echoTimestamp(timestamp), timestamp);
+    expect(getNanos(timestamp), 1000);
+    // Nanoseconds below a microsecond are truncated
+    expect(makeTimestamp(0, 1999).microsecondsSinceEpoch, 1);
+  });
+
+  test('local times are converted to UTC', () {
+    final local = DateTime(2024, 6, 1, 12);
+    final echoed = echoTimestamp(local);
+    expect(echoed.isUtc, true);
+    expect(echoed, local.toUtc());
+  });
+
+  test('with durations', () {
+    final timestamp = DateTime.utc(2024, 1, 1);
+    expect(addDuration(timestamp, Duration(days: 1, microseconds: 5)),
+        DateTime.utc(2024, 1, 2, 0, 0, 0, 0, 5));
+  });
+
+  test('optionals', () {
+    final timestamp = DateTime.utc(2024, 1, 1);
+    expect(echoOptionalTimestamp(timestamp), timestamp);
+    expect(echoOptionalTimestamp(null), null);
+  });
+
+  test('sequences', () {
+    final timestamps = [
+      DateTime.utc(1969, 7, 20, 20, 17),
+      DateTime.utc(2000, 1, 1),
+      DateTime.utc(2038, 1, 19, 3, 14, 8),
+    ];
+    expect(echoTimestamps(timestamps), timestamps);
+    expect(echoTimestamps([]), isEmpty);
+  });
+}
@@ -0,0 +1,11 @@
+use anyhow::Result;
+
+#[test]
+fn timestamp_type_test() -> Result<()> {
+    uniffi_dart::testing::run_test("timestamp_type_test", "src/api.udl", None)
+}
+
+#[test]
+fn timestamp_type_test_golden() -> Result<()> {
+    uniffi_dart::testing::assert_golden("timestamp_type_test", "src/api.udl", None)
+}
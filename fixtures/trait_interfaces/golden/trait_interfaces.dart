@@ -0,0 +1,1092 @@
+library trait_interfaces;
+
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io" show Platform, File, Directory;
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+class PluginEntry {
+    final Plugin plugin;final bool enabled;
+
+    PluginEntry({required this.plugin,required this.enabled,});
+}
+
+class FfiConverterPluginEntry {
+    static PluginEntry lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterPluginEntry.read(data).value);
+    }
+
+    static LiftRetVal<PluginEntry> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final plugin_lifted = FfiConverterPlugin.read(Uint8List.view(buf.buffer, new_offset));
+        final plugin = plugin_lifted.value;
+        new_offset += plugin_lifted.bytesRead;final enabled_lifted = FfiConverterBool.read(Uint8List.view(buf.buffer, new_offset));
+        final enabled = enabled_lifted.value;
+        new_offset += enabled_lifted.bytesRead;
+        return LiftRetVal(PluginEntry(
+            plugin: plugin,enabled: enabled,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( PluginEntry value) {
+        final total_length = FfiConverterPlugin.allocationSize(value.plugin) +FfiConverterBool.allocationSize(value.enabled) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( PluginEntry value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterPlugin.write(value.plugin, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterBool.write(value.enabled, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(PluginEntry value) {
+        return FfiConverterPlugin.allocationSize(value.plugin) +FfiConverterBool.allocationSize(value.enabled) + 0;
+    }
+}
+
+abstract class PipelineInterface {
+    void add(Plugin plugin,);
+    List<Plugin> plugins();
+    int run(int input,);
+}
+
+final _PipelineFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _PipelineFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_trait_interfaces_fn_free_pipeline");
+
+class Pipeline implements PipelineInterface, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    Pipeline._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_PipelineFree, _ptr);
+        _PipelineFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    Pipeline() : _ptr = rustCall((uniffiStatus) =>
+        _UniffiLib.instance.uniffi_trait_interfaces_fn_constructor_pipeline_new(
+             uniffiStatus
+        ),
+        NullRustCallStatusErrorHandler()
+    ) {
+        _attachFinalizer();
+    }
+
+    factory Pipeline.lift(Pointer<Void> ptr) {
+        return Pipeline._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("Pipeline has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_trait_interfaces_fn_clone_pipeline(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _PipelineFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_trait_interfaces_fn_free_pipeline(_ptr, status));
+    }
+
+    @override
+    void add(Plugin plugin,) {
+        rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_trait_interfaces_fn_method_pipeline_add(uniffiClonePointer(),
+        FfiConverterPlugin.lower(plugin), uniffiStatus), NullRustCallStatusErrorHandler());
+    }@override
+    List<Plugin> plugins() {
+        return FfiConverterSequencePlugin.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_trait_interfaces_fn_method_pipeline_plugins(uniffiClonePointer(),
+         uniffiStatus), NullRustCallStatusErrorHandler()));
+    }@override
+    int run(int input,) {
+        return rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_trait_interfaces_fn_method_pipeline_run(uniffiClonePointer(),
+        input, uniffiStatus), NullRustCallStatusErrorHandler());
+    }
+}
+
+class FfiConverterPipeline {
+    static Pipeline lift( Pointer<Void> value) {
+        return Pipeline.lift(value);
+    }
+
+    static Pointer<Void> lower( Pipeline value) {
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<Pipeline> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([Pipeline? value]) {
+        return 8;
+    }
+
+    static int write( Pipeline value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+abstract class Plugin {
+    String name();
+    int apply(int input,);
+    void dispose();
+}
+
+final _PluginImplFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _PluginImplFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_trait_interfaces_fn_free_plugin");
+
+class PluginImpl implements Plugin, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    PluginImpl._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_PluginImplFree, _ptr);
+        _PluginImplFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    factory PluginImpl.lift(Pointer<Void> ptr) {
+        return PluginImpl._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("PluginImpl has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_trait_interfaces_fn_clone_plugin(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _PluginImplFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_trait_interfaces_fn_free_plugin(_ptr, status));
+    }
+
+    @override
+    String name() {
+        return FfiConverterString.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_trait_interfaces_fn_method_plugin_name(uniffiClonePointer(),
+         uniffiStatus), NullRustCallStatusErrorHandler()));
+    }@override
+    int apply(int input,) {
+        return rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_trait_interfaces_fn_method_plugin_apply(uniffiClonePointer(),
+        input, uniffiStatus), NullRustCallStatusErrorHandler());
+    }
+}
+
+class FfiConverterPlugin {
+    static Plugin lift( Pointer<Void> value) {
+        return PluginImpl.lift(value);
+    }
+
+    static Pointer<Void> lower( Plugin value) {
+        if (value is! PluginImpl) {
+            throw ArgumentError.value(value, "value", "only Rust implementations of Plugin can be passed to Rust");
+        }
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<Plugin> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([Plugin? value]) {
+        return 8;
+    }
+
+    static int write( Plugin value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+class UniffiInternalError implements Exception {
+    static const int bufferOverflow = 0;
+    static const int incompleteData = 1;
+    static const int unexpectedOptionalTag = 2;
+    static const int unexpectedEnumCase = 3;
+    static const int unexpectedNullPointer = 4;
+    static const int unexpectedRustCallStatusCode = 5;
+    static const int unexpectedRustCallError = 6;
+    static const int unexpectedStaleHandle = 7;
+    static const int rustPanic = 8;
+
+    final int errorCode;
+    final String? panicMessage;
+
+    const UniffiInternalError(this.errorCode, this.panicMessage);
+
+    static UniffiInternalError panicked(String message) {
+    return UniffiInternalError(rustPanic, message);
+    }
+
+    @override
+    String toString() {
+    switch (errorCode) {
+        case bufferOverflow:
+        return "UniFfi::BufferOverflow";
+        case incompleteData:
+        return "UniFfi::IncompleteData";
+        case unexpectedOptionalTag:
+        return "UniFfi::UnexpectedOptionalTag";
+        case unexpectedEnumCase:
+        return "UniFfi::UnexpectedEnumCase";
+        case unexpectedNullPointer:
+        return "UniFfi::UnexpectedNullPointer";
+        case unexpectedRustCallStatusCode:
+        return "UniFfi::UnexpectedRustCallStatusCode";
+        case unexpectedRustCallError:
+        return "UniFfi::UnexpectedRustCallError";
+        case unexpectedStaleHandle:
+        return "UniFfi::UnexpectedStaleHandle";
+        case rustPanic:
+        return "UniFfi::rustPanic: \$\$panicMessage";
+        default:
+        return "UniFfi::UnknownError: \$\$errorCode";
+    }
+    }
+}
+
+const int CALL_SUCCESS = 0;
+const int CALL_ERROR = 1;
+const int CALL_UNEXPECTED_ERROR = 2;
+
+final class RustCallStatus extends Struct {
+    @Int8()
+    external int code;
+
+    external RustBuffer errorBuf;
+
+}
+
+void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
+
+    if (status.ref.code == CALL_SUCCESS) {
+    return;
+    } else if (status.ref.code == CALL_ERROR) {
+    throw errorHandler.lift(status.ref.errorBuf);
+    } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+    if (status.ref.errorBuf.len > 0) {
+        throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
+    } else {
+        throw UniffiInternalError.panicked("Rust panic");
+    }
+    } else {
+    throw UniffiInternalError.panicked("Unexpected RustCallStatus code: \${status.ref.code}");
+    }
+}
+
+#[doc=" Allocates the native memory the bindings pass to Rust for the duration of a call."]
+const Allocator _uniffiAllocator = calloc;
+
+T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+    final status = _uniffiAllocator<RustCallStatus>();
+    try {
+    final result = callback(status);
+    checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+    return result;
+    } finally {
+    _uniffiAllocator.free(status);
+    }
+}
+
+class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+    errorBuf.free();
+    return UniffiInternalError.panicked("Unexpected CALL_ERROR");
+    }
+}
+
+abstract class UniffiRustCallStatusErrorHandler {
+    Exception lift(RustBuffer errorBuf);
+}
+
+final class RustBuffer extends Struct {
+    @Uint64()
+    external int capacity;
+
+    @Uint64()
+    external int len;
+
+    external Pointer<Uint8> data;
+
+    static RustBuffer alloc(int size) {
+        return rustCall((status) => _UniffiLib.instance.ffi_trait_interfaces_rustbuffer_alloc(size, status));
+    }
+
+    static RustBuffer fromBytes(ForeignBytes bytes) {
+        return rustCall((status) => _UniffiLib.instance.ffi_trait_interfaces_rustbuffer_from_bytes(bytes, status));
+    }
+
+    void free() {
+        rustCall((status) => _UniffiLib.instance.ffi_trait_interfaces_rustbuffer_free(this, status));
+    }
+
+    RustBuffer reserve(int additionalCapacity) {
+    return rustCall((status) => _UniffiLib.instance.ffi_trait_interfaces_rustbuffer_reserve(this, additionalCapacity, status));
+    }
+
+    Uint8List asUint8List() {
+    final dataList = data.asTypedList(len);
+    final byteData = ByteData.sublistView(dataList);
+    return Uint8List.view(byteData.buffer);
+    }
+
+    @override
+    String toString() {
+    return "RustBuffer{capacity: \$capacity, len: \$len, data: \$data}";
+    }
+}
+
+RustBuffer toRustBuffer(Uint8List data) {
+    final length = data.length;
+
+    final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length);
+    final pointerList = frameData.asTypedList(length);
+    pointerList.setAll(0, data);
+
+    final bytes = _uniffiAllocator<ForeignBytes>();
+    bytes.ref.len = length;
+    bytes.ref.data = frameData;
+    try {
+        return RustBuffer.fromBytes(bytes.ref);
+    } finally {
+        _uniffiAllocator.free(frameData);
+        _uniffiAllocator.free(bytes);
+    }
+}
+
+T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+    try {
+        return lift(buf.asUint8List());
+    } finally {
+        buf.free();
+    }
+}
+
+final class ForeignBytes extends Struct {
+    @Int32()
+    external int len;
+    external Pointer<Uint8> data;
+
+    void free() {
+    _uniffiAllocator.free(data);
+    }
+}
+
+class LiftRetVal<T> {
+    final T value;
+    final int bytesRead;
+    const LiftRetVal(this.value, this.bytesRead);
+
+    LiftRetVal<T> copyWithOffset(int offset) {
+        return LiftRetVal(value, bytesRead + offset);
+    }
+}
+
+abstract class FfiConverter<D, F> {
+    const FfiConverter();
+
+    D lift(F value);
+    F lower(D value);
+    D read(ByteData buffer, int offset);
+    void write(D value, ByteData buffer, int offset);
+    int size(D value);
+}
+
+mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+    @override
+    T lift(T value) => value;
+
+    @override
+    T lower(T value) => value;
+}
+
+class FfiConverterBool {
+
+    static bool lift( int value) {
+        return value == 1;
+    }
+
+    static int lower( bool value) {
+        return value ? 1 :0;
+    }
+
+    static LiftRetVal<bool> read( Uint8List buf) {
+        return LiftRetVal(FfiConverterBool.lift(buf.first), 1);
+    }
+
+    static RustBuffer lowerIntoRustBuffer( bool value) {
+        return toRustBuffer(Uint8List.fromList([FfiConverterBool.lower(value)]));
+    }
+
+    static int allocationSize([bool value = false]) {
+        return 1;
+    }
+
+    static int write( bool value, Uint8List buf) {
+        buf.setAll(0, [value ? 1 : 0]);
+        return allocationSize();
+    }
+}class FfiConverterInt32 {
+
+    static int lift(int value) => value;
+
+    static LiftRetVal<int> read(Uint8List buf) {
+        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt32(0), 4);
+    }
+
+    static int lower(int value) => value;
+
+    static int allocationSize([int value = 0]) {
+        return 4;
+    }
+
+    static int write(int value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value);
+        return FfiConverterInt32.allocationSize();
+    }
+
+}class FfiConverterMapStringPlugin {
+
+    static Map<String, Plugin> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMapStringPlugin.read(data).value);
+    }
+
+    static LiftRetVal<Map<String, Plugin>> read( Uint8List buf) {
+        final Map<String, Plugin> res = <String, Plugin>{};
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final key = FfiConverterString.read(Uint8List.view(buf.buffer, offset));
+            offset += key.bytesRead;
+            final value = FfiConverterPlugin.read(Uint8List.view(buf.buffer, offset));
+            offset += value.bytesRead;
+            res[key.value] = value.value;
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( Map<String, Plugin> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (final entry in value.entries) {
+            offset += FfiConverterString.write(entry.key, Uint8List.view(buf.buffer, offset));
+            offset += FfiConverterPlugin.write(entry.value, Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Map<String, Plugin> value) {
+        return value.entries
+            .map((e) => FfiConverterString.allocationSize(e.key) + FfiConverterPlugin.allocationSize(e.value))
+            .fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( Map<String, Plugin> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterOptionalPlugin {
+
+    static Plugin? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalPlugin.read(data).value);
+    }
+
+    static LiftRetVal<Plugin?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterPlugin.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([Plugin? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterPlugin.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( Plugin? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalPlugin.allocationSize(value));
+        FfiConverterOptionalPlugin.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( Plugin? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterPlugin.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterSequencePlugin {
+
+    static List<Plugin> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequencePlugin.read(data).value);
+    }
+
+    static LiftRetVal<List<Plugin>> read( Uint8List buf) {
+        List<Plugin> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterPlugin.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<Plugin> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterPlugin.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<Plugin> value) {
+        return value.map((l) => FfiConverterPlugin.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<Plugin> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterSequencePluginEntry {
+
+    static List<PluginEntry> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequencePluginEntry.read(data).value);
+    }
+
+    static LiftRetVal<List<PluginEntry>> read( Uint8List buf) {
+        List<PluginEntry> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterPluginEntry.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<PluginEntry> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterPluginEntry.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<PluginEntry> value) {
+        return value.map((l) => FfiConverterPluginEntry.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<PluginEntry> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterString {
+    static String lift( RustBuffer buf) {
+
+        return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
+    }
+
+    static RustBuffer lower( String value) {
+        return toRustBuffer(Utf8Encoder().convert(value));
+    }
+
+    static LiftRetVal<String> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+        return LiftRetVal(utf8.decoder.convert(buf, 4, end), end);
+    }
+
+    static int allocationSize([String value = ""]) {
+
+        return utf8.encoder.convert(value).length + 4;
+    }
+
+    static int write( String value, Uint8List buf) {
+
+        final list = utf8.encoder.convert(value);
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length);
+        buf.setAll(4, list);
+        return list.length + 4;
+    }
+}
+
+const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
+
+typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+Future<T> uniffiRustCallAsync<T, F>(
+    Pointer<Void> Function() rustFutureFunc,
+    void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc,
+    F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc,
+    void Function(Pointer<Void>) freeFunc,
+    T Function(F) liftFunc, [
+    UniffiRustCallStatusErrorHandler? errorHandler,
+]) async {
+    final rustFuture = rustFutureFunc();
+    final completer = Completer<int>();
+
+    late final NativeCallable<UniffiRustFutureContinuationCallback> callback;
+
+    void poll() {
+        pollFunc(
+            rustFuture,
+            callback.nativeFunction,
+            Pointer<Void>.fromAddress(0),
+        );
+    }
+    void onResponse(int _idx, int pollResult) {
+        if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+            completer.complete(pollResult);
+        } else {
+            poll();
+        }
+    }
+    callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener(onResponse);
+
+    try {
+        poll();
+        await completer.future;
+        callback.close();
+
+        final status = _uniffiAllocator<RustCallStatus>();
+        try {
+            final result = completeFunc(rustFuture, status);
+            checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+            return liftFunc(result);
+        } finally {
+            _uniffiAllocator.free(status);
+        }
+    } finally {
+        freeFunc(rustFuture);
+    }
+}
+
+class UniffiHandleMap<T> {
+    final Map<int, T> _map = {};
+    int _counter = 0;
+
+    int insert(T obj) {
+    final handle = _counter++;
+    _map[handle] = obj;
+    return handle;
+    }
+
+    T get(int handle) {
+    final obj = _map[handle];
+    if (obj == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    return obj;
+    }
+
+    void remove(int handle) {
+    if (_map.remove(handle) == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    }
+}
+
+int applyEntries(List<PluginEntry> entries,int input,) {
+    return rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_trait_interfaces_fn_func_apply_entries(FfiConverterSequencePluginEntry.lower(entries),input, uniffiStatus), NullRustCallStatusErrorHandler());
+}int applyPlugin(Plugin plugin,int input,) {
+    return rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_trait_interfaces_fn_func_apply_plugin(FfiConverterPlugin.lower(plugin),input, uniffiStatus), NullRustCallStatusErrorHandler());
+}List<Plugin> defaultPlugins() {
+    return FfiConverterSequencePlugin.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_trait_interfaces_fn_func_default_plugins( uniffiStatus), NullRustCallStatusErrorHandler()));
+}Plugin? findPlugin(String name,) {
+    return FfiConverterOptionalPlugin.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_trait_interfaces_fn_func_find_plugin(FfiConverterString.lower(name), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Plugin makeAdder(int amount,) {
+    return FfiConverterPlugin.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_trait_interfaces_fn_func_make_adder(amount, uniffiStatus), NullRustCallStatusErrorHandler()));
+}Plugin makeDoubler() {
+    return FfiConverterPlugin.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_trait_interfaces_fn_func_make_doubler( uniffiStatus), NullRustCallStatusErrorHandler()));
+}Map<String, Plugin> pluginsByName() {
+    return FfiConverterMapStringPlugin.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_trait_interfaces_fn_func_plugins_by_name( uniffiStatus), NullRustCallStatusErrorHandler()));
+}
+
+class _UniffiLib {
+    _UniffiLib._();
+
+    static final DynamicLibrary _dylib = _open();
+
+    static DynamicLibrary _open() {
+        if (Platform.isAndroid) return DynamicLibrary.open("libtrait_interfaces.so");
+        if (Platform.isIOS) return DynamicLibrary.executable();
+        if (Platform.isLinux) return DynamicLibrary.open("libtrait_interfaces.so");
+        if (Platform.isMacOS) return DynamicLibrary.open("libtrait_interfaces.dylib");
+        if (Platform.isWindows) return DynamicLibrary.open("trait_interfaces.dll");
+        throw UnsupportedError("Unsupported platform: \${Platform.operatingSystem}");
+    }
+
+    static final _UniffiLib instance = _UniffiLib._();
+
+    late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_trait_interfaces_fn_clone_pipeline = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_trait_interfaces_fn_clone_pipeline");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_trait_interfaces_fn_free_pipeline = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_trait_interfaces_fn_free_pipeline");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_trait_interfaces_fn_constructor_pipeline_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_trait_interfaces_fn_constructor_pipeline_new");late final void Function(Pointer<Void>,Pointer<Void>,Pointer<RustCallStatus>) uniffi_trait_interfaces_fn_method_pipeline_add = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_trait_interfaces_fn_method_pipeline_add");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_trait_interfaces_fn_method_pipeline_plugins = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_trait_interfaces_fn_method_pipeline_plugins");late final int Function(Pointer<Void>,int,Pointer<RustCallStatus>) uniffi_trait_interfaces_fn_method_pipeline_run = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Int32,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,int,Pointer<RustCallStatus>)
+    >("uniffi_trait_interfaces_fn_method_pipeline_run");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_trait_interfaces_fn_clone_plugin = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_trait_interfaces_fn_clone_plugin");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_trait_interfaces_fn_free_plugin = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_trait_interfaces_fn_free_plugin");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_trait_interfaces_fn_method_plugin_name = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_trait_interfaces_fn_method_plugin_name");late final int Function(Pointer<Void>,int,Pointer<RustCallStatus>) uniffi_trait_interfaces_fn_method_plugin_apply = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Int32,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,int,Pointer<RustCallStatus>)
+    >("uniffi_trait_interfaces_fn_method_plugin_apply");late final int Function(RustBuffer,int,Pointer<RustCallStatus>) uniffi_trait_interfaces_fn_func_apply_entries = _dylib.lookupFunction<
+        Int32 Function(RustBuffer,Int32,Pointer<RustCallStatus>),
+        int Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("uniffi_trait_interfaces_fn_func_apply_entries");late final int Function(Pointer<Void>,int,Pointer<RustCallStatus>) uniffi_trait_interfaces_fn_func_apply_plugin = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Int32,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,int,Pointer<RustCallStatus>)
+    >("uniffi_trait_interfaces_fn_func_apply_plugin");late final RustBuffer Function(Pointer<RustCallStatus>) uniffi_trait_interfaces_fn_func_default_plugins = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<RustCallStatus>)
+    >("uniffi_trait_interfaces_fn_func_default_plugins");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_trait_interfaces_fn_func_find_plugin = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_trait_interfaces_fn_func_find_plugin");late final Pointer<Void> Function(int,Pointer<RustCallStatus>) uniffi_trait_interfaces_fn_func_make_adder = _dylib.lookupFunction<
+        Pointer<Void> Function(Int32,Pointer<RustCallStatus>),
+        Pointer<Void> Function(int,Pointer<RustCallStatus>)
+    >("uniffi_trait_interfaces_fn_func_make_adder");late final Pointer<Void> Function(Pointer<RustCallStatus>) uniffi_trait_interfaces_fn_func_make_doubler = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<RustCallStatus>)
+    >("uniffi_trait_interfaces_fn_func_make_doubler");late final RustBuffer Function(Pointer<RustCallStatus>) uniffi_trait_interfaces_fn_func_plugins_by_name = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<RustCallStatus>)
+    >("uniffi_trait_interfaces_fn_func_plugins_by_name");late final RustBuffer Function(int,Pointer<RustCallStatus>) ffi_trait_interfaces_rustbuffer_alloc = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("ffi_trait_interfaces_rustbuffer_alloc");late final RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>) ffi_trait_interfaces_rustbuffer_from_bytes = _dylib.lookupFunction<
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>),
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>)
+    >("ffi_trait_interfaces_rustbuffer_from_bytes");late final void Function(RustBuffer,Pointer<RustCallStatus>) ffi_trait_interfaces_rustbuffer_free = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("ffi_trait_interfaces_rustbuffer_free");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) ffi_trait_interfaces_rustbuffer_reserve = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("ffi_trait_interfaces_rustbuffer_reserve");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_trait_interfaces_rust_future_poll_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_poll_u8");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_cancel_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_cancel_u8");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_free_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_free_u8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_trait_interfaces_rust_future_complete_u8 = _dylib.lookupFunction<
+        Uint8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_trait_interfaces_rust_future_complete_u8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_trait_interfaces_rust_future_poll_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_poll_i8");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_cancel_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_cancel_i8");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_free_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_free_i8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_trait_interfaces_rust_future_complete_i8 = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_trait_interfaces_rust_future_complete_i8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_trait_interfaces_rust_future_poll_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_poll_u16");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_cancel_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_cancel_u16");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_free_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_free_u16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_trait_interfaces_rust_future_complete_u16 = _dylib.lookupFunction<
+        Uint16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_trait_interfaces_rust_future_complete_u16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_trait_interfaces_rust_future_poll_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_poll_i16");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_cancel_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_cancel_i16");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_free_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_free_i16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_trait_interfaces_rust_future_complete_i16 = _dylib.lookupFunction<
+        Int16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_trait_interfaces_rust_future_complete_i16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_trait_interfaces_rust_future_poll_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_poll_u32");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_cancel_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_cancel_u32");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_free_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_free_u32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_trait_interfaces_rust_future_complete_u32 = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_trait_interfaces_rust_future_complete_u32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_trait_interfaces_rust_future_poll_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_poll_i32");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_cancel_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_cancel_i32");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_free_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_free_i32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_trait_interfaces_rust_future_complete_i32 = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_trait_interfaces_rust_future_complete_i32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_trait_interfaces_rust_future_poll_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_poll_u64");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_cancel_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_cancel_u64");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_free_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_free_u64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_trait_interfaces_rust_future_complete_u64 = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_trait_interfaces_rust_future_complete_u64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_trait_interfaces_rust_future_poll_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_poll_i64");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_cancel_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_cancel_i64");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_free_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_free_i64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_trait_interfaces_rust_future_complete_i64 = _dylib.lookupFunction<
+        Int64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_trait_interfaces_rust_future_complete_i64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_trait_interfaces_rust_future_poll_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_poll_f32");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_cancel_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_cancel_f32");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_free_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_free_f32");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_trait_interfaces_rust_future_complete_f32 = _dylib.lookupFunction<
+        Float Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_trait_interfaces_rust_future_complete_f32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_trait_interfaces_rust_future_poll_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_poll_f64");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_cancel_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_cancel_f64");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_free_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_free_f64");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_trait_interfaces_rust_future_complete_f64 = _dylib.lookupFunction<
+        Double Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_trait_interfaces_rust_future_complete_f64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_trait_interfaces_rust_future_poll_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_poll_pointer");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_cancel_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_cancel_pointer");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_free_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_free_pointer");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_trait_interfaces_rust_future_complete_pointer = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_trait_interfaces_rust_future_complete_pointer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_trait_interfaces_rust_future_poll_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_poll_rust_buffer");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_cancel_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_cancel_rust_buffer");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_free_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_free_rust_buffer");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_trait_interfaces_rust_future_complete_rust_buffer = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_trait_interfaces_rust_future_complete_rust_buffer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_trait_interfaces_rust_future_poll_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_poll_void");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_cancel_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_cancel_void");late final void Function(Pointer<Void>,) ffi_trait_interfaces_rust_future_free_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_trait_interfaces_rust_future_free_void");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_trait_interfaces_rust_future_complete_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_trait_interfaces_rust_future_complete_void");late final int Function() uniffi_trait_interfaces_checksum_func_apply_entries = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_trait_interfaces_checksum_func_apply_entries");late final int Function() uniffi_trait_interfaces_checksum_func_apply_plugin = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_trait_interfaces_checksum_func_apply_plugin");late final int Function() uniffi_trait_interfaces_checksum_func_default_plugins = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_trait_interfaces_checksum_func_default_plugins");late final int Function() uniffi_trait_interfaces_checksum_func_find_plugin = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_trait_interfaces_checksum_func_find_plugin");late final int Function() uniffi_trait_interfaces_checksum_func_make_adder = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_trait_interfaces_checksum_func_make_adder");late final int Function() uniffi_trait_interfaces_checksum_func_make_doubler = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_trait_interfaces_checksum_func_make_doubler");late final int Function() uniffi_trait_interfaces_checksum_func_plugins_by_name = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_trait_interfaces_checksum_func_plugins_by_name");late final int Function() uniffi_trait_interfaces_checksum_method_pipeline_add = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_trait_interfaces_checksum_method_pipeline_add");late final int Function() uniffi_trait_interfaces_checksum_method_pipeline_plugins = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_trait_interfaces_checksum_method_pipeline_plugins");late final int Function() uniffi_trait_interfaces_checksum_method_pipeline_run = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_trait_interfaces_checksum_method_pipeline_run");late final int Function() uniffi_trait_interfaces_checksum_method_plugin_name = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_trait_interfaces_checksum_method_plugin_name");late final int Function() uniffi_trait_interfaces_checksum_method_plugin_apply = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_trait_interfaces_checksum_method_plugin_apply");late final int Function() uniffi_trait_interfaces_checksum_constructor_pipeline_new = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_trait_interfaces_checksum_constructor_pipeline_new");late final int Function() ffi_trait_interfaces_uniffi_contract_version = _dylib.lookupFunction<
+        Uint32 Function(),
+        int Function()
+    >("ffi_trait_interfaces_uniffi_contract_version");late final Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>) uniffi_dart_finalizer_token_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>),
+        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>)
+    >("uniffi_dart_finalizer_token_new");
+    late final void Function(Pointer<Void>) uniffi_dart_finalizer_token_free = _dylib.lookupFunction<
+        Void Function(Pointer<Void>),
+        void Function(Pointer<Void>)
+    >("uniffi_dart_finalizer_token_free");
+    late final Pointer<NativeFinalizerFunction> uniffi_dart_finalizer_run = _dylib.lookup("uniffi_dart_finalizer_run");
+
+    static void _checkApiVersion() {
+        final bindingsVersion = 26;
+        final scaffoldingVersion = _UniffiLib.instance.ffi_trait_interfaces_uniffi_contract_version();
+        if (bindingsVersion != scaffoldingVersion) {
+            throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version \$bindingsVersion, scaffolding version \$scaffoldingVersion");
+        }
+    }
+
+    static void _checkApiChecksums() {
+        if (_UniffiLib.instance.uniffi_trait_interfaces_checksum_func_apply_entries() != 25787) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_trait_interfaces_checksum_func_apply_plugin() != 39536) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_trait_interfaces_checksum_func_default_plugins() != 4686) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_trait_interfaces_checksum_func_find_plugin() != 29799) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_trait_interfaces_checksum_func_make_adder() != 22039) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_trait_interfaces_checksum_func_make_doubler() != 15851) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_trait_interfaces_checksum_func_plugins_by_name() != 24029) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_trait_interfaces_checksum_method_pipeline_add() != 54160) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_trait_interfaces_checksum_method_pipeline_plugins() != 54326) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_trait_interfaces_checksum_method_pipeline_run() != 26521) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_trait_interfaces_checksum_method_plugin_name() != 26110) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_trait_interfaces_checksum_method_plugin_apply() != 54039) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_trait_interfaces_checksum_constructor_pipeline_new() != 21287) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }
+    }
+}
+
+void initialize() {
+    _UniffiLib._open();
+}
+
+void ensureInitialized() {
+    _UniffiLib._checkApiVersion();
+    _UniffiLib._checkApiChecksums();
+}
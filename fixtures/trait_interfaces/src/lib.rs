@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// A plugin implemented in Rust, handed to Dart as `Arc<dyn Plugin>`.
+#[uniffi::export]
+pub trait Plugin: Send + Sync {
+    fn name(&self) -> String;
+    fn apply(&self, input: i32) -> i32;
+}
+
+struct Adder {
+    amount: i32,
+}
+
+impl Plugin for Adder {
+    fn name(&self) -> String {
+        format!("add {}", self.amount)
+    }
+
+    fn apply(&self, input: i32) -> i32 {
+        input + self.amount
+    }
+}
+
+struct Doubler;
+
+impl Plugin for Doubler {
+    fn name(&self) -> String {
+        "double".to_string()
+    }
+
+    fn apply(&self, input: i32) -> i32 {
+        input * 2
+    }
+}
+
+/// A record holding a trait interface.
+#[derive(uniffi::Record)]
+pub struct PluginEntry {
+    plugin: Arc<dyn Plugin>,
+    enabled: bool,
+}
+
+#[uniffi::export]
+pub fn make_adder(amount: i32) -> Arc<dyn Plugin> {
+    Arc::new(Adder { amount })
+}
+
+#[uniffi::export]
+pub fn make_doubler() -> Arc<dyn Plugin> {
+    Arc::new(Doubler)
+}
+
+#[uniffi::export]
+pub fn find_plugin(name: String) -> Option<Arc<dyn Plugin>> {
+    default_plugins().into_iter().find(|plugin| plugin.name() == name)
+}
+
+#[uniffi::export]
+pub fn default_plugins() -> Vec<Arc<dyn Plugin>> {
+    vec![make_adder(1), make_doubler()]
+}
+
+#[uniffi::export]
+pub fn plugins_by_name() -> HashMap<String, Arc<dyn Plugin>> {
+    default_plugins()
+        .into_iter()
+        .map(|plugin| (plugin.name(), plugin))
+        .collect()
+}
+
+#[uniffi::export]
+pub fn apply_plugin(plugin: Arc<dyn Plugin>, input: i32) -> i32 {
+    plugin.apply(input)
+}
+
+/// Applies the enabled plugins in order.
+#[uniffi::export]
+pub fn apply_entries(entries: Vec<PluginEntry>, input: i32) -> i32 {
+    entries
+        .iter()
+        .filter(|entry| entry.enabled)
+        .fold(input, |value, entry| entry.plugin.apply(value))
+}
+
+#[derive(uniffi::Object)]
+pub struct Pipeline {
+    plugins: Mutex<Vec<Arc<dyn Plugin>>>,
+}
+
+#[uniffi::export]
+impl Pipeline {
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Pipeline {
+            plugins: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn add(&self, plugin: Arc<dyn Plugin>) {
+        self.plugins.lock().unwrap().push(plugin);
+    }
+
+    pub fn plugins(&self) -> Vec<Arc<dyn Plugin>> {
+        self.plugins.lock().unwrap().clone()
+    }
+
+    pub fn run(&self, input: i32) -> i32 {
+        self.plugins
+            .lock()
+            .unwrap()
+            .iter()
+            .fold(input, |value, plugin| plugin.apply(value))
+    }
+}
+
+uniffi::setup_scaffolding!();
+uniffi_dart::setup_finalizers!();
@@ -0,0 +1,66 @@
+import 'package:test/test.dart';
+import '../trait_interfaces.dart';
+
+// A Dart implementation can be used from Dart, but Rust only accepts its own implementations
+class Negator implements Plugin {
+  @override
+  String name() => "negate";
+
+  @override
+  int apply(int input) => -input;
+
+  @override
+  void dispose() {}
+}
+
+void main() {
+  test('trait interfaces are returned as their abstract class', () {
+    final adder = makeAdder(2);
+    expect(adder, isA<Plugin>());
+    expect(adder.name(), "add 2");
+    expect(adder.apply(40), 42);
+    adder.dispose();
+    expect(() => adder.apply(40), throwsStateError);
+  });
+
+  test('trait interfaces can be passed back to Rust', () {
+    final doubler = makeDoubler();
+    expect(applyPlugin(doubler, 21), 42);
+    doubler.dispose();
+  });
+
+  test('trait interfaces in collections and records', () {
+    final plugins = defaultPlugins();
+    expect(plugins.map((plugin) => plugin.name()), ["add 1", "double"]);
+
+    final byName = pluginsByName();
+    expect(byName.keys, unorderedEquals(["add 1", "double"]));
+    expect(byName["double"]!.apply(4), 8);
+
+    expect(findPlugin("double")!.apply(5), 10);
+    expect(findPlugin("missing"), null);
+
+    final entries = [
+      PluginEntry(plugin: plugins[0], enabled: true),
+      PluginEntry(plugin: plugins[1], enabled: false),
+      PluginEntry(plugin: plugins[1], enabled: true),
+    ];
+    expect(applyEntries(entries, 1), 4);
+  });
+
+  test('objects hold trait interfaces', () {
+    final pipeline = Pipeline();
+    pipeline.add(makeAdder(1));
+    pipeline.add(makeDoubler());
+    expect(pipeline.run(1), 4);
+    expect(pipeline.plugins().map((plugin) => plugin.name()), ["add 1", "double"]);
+    pipeline.dispose();
+  });
+
+  test('Dart implementations are rejected by Rust', () {
+    final negator = Negator();
+    expect(negator.apply(1), -1);
+    expect(() => applyPlugin(negator, 1), throwsArgumentError);
+    expect(() => Pipeline().add(negator), throwsArgumentError);
+  });
+}
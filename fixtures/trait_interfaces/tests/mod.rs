@@ -0,0 +1,11 @@
+use anyhow::Result;
+
+#[test]
+fn trait_interfaces() -> Result<()> {
+    uniffi_dart::testing::run_library_test("trait_interfaces", None)
+}
+
+#[test]
+fn trait_interfaces_golden() -> Result<()> {
+    uniffi_dart::testing::assert_library_golden("trait_interfaces", None)
+}
@@ -0,0 +1,43 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct TraitMethods {
+    val: String,
+}
+
+impl TraitMethods {
+    fn new(val: String) -> Self {
+        Self { val }
+    }
+}
+
+impl fmt::Display for TraitMethods {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TraitMethods({})", self.val)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, uniffi::Object)]
+#[uniffi::export(Debug, Eq, Hash)]
+pub struct ProcTraitMethods {
+    val: String,
+}
+
+#[uniffi::export]
+impl ProcTraitMethods {
+    #[uniffi::constructor]
+    fn new(val: String) -> std::sync::Arc<Self> {
+        std::sync::Arc::new(Self { val })
+    }
+}
+
+// Only the name takes part in the hash, mirroring the derived `PartialEq`.
+impl Hash for ProcTraitMethods {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.val.hash(state);
+    }
+}
+
+uniffi::include_scaffolding!("api");
+uniffi_dart::setup_finalizers!();
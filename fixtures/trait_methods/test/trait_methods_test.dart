@@ -0,0 +1,51 @@
+import 'package:test/test.dart';
+import '../trait_methods.dart';
+
+void main() {
+  group('UDL trait methods', () {
+    test('toString uses Display', () {
+      expect(TraitMethods('yo').toString(), 'TraitMethods(yo)');
+    });
+
+    test('operator == uses Eq', () {
+      expect(TraitMethods('yo'), equals(TraitMethods('yo')));
+      expect(TraitMethods('yo'), isNot(equals(TraitMethods('yoyo'))));
+    });
+
+    test('hashCode uses Hash', () {
+      expect(TraitMethods('yo').hashCode, TraitMethods('yo').hashCode);
+      expect(TraitMethods('yo').hashCode,
+          isNot(TraitMethods('yoyo').hashCode));
+    });
+
+    test('usable in sets and maps', () {
+      final set = {TraitMethods('yo'), TraitMethods('yo'), TraitMethods('hi')};
+      expect(set.length, 2);
+
+      final map = {TraitMethods('yo'): 1};
+      expect(map[TraitMethods('yo')], 1);
+    });
+
+    test('disposed objects fall back to identity', () {
+      final disposed = TraitMethods('yo')..dispose();
+      expect(disposed.toString(), '<TraitMethods disposed>');
+      expect(disposed, equals(disposed));
+      expect(disposed, isNot(equals(TraitMethods('yo'))));
+      expect(TraitMethods('yo'), isNot(equals(disposed)));
+      expect(disposed.hashCode, identityHashCode(disposed));
+    });
+  });
+
+  group('Proc-macro trait methods', () {
+    test('toString falls back to Debug', () {
+      expect(ProcTraitMethods('yo').toString(),
+          'ProcTraitMethods { val: "yo" }');
+    });
+
+    test('operator == and hashCode', () {
+      expect(ProcTraitMethods('yo'), equals(ProcTraitMethods('yo')));
+      expect(ProcTraitMethods('yo').hashCode, ProcTraitMethods('yo').hashCode);
+      expect({ProcTraitMethods('yo'), ProcTraitMethods('yo')}.length, 1);
+    });
+  });
+}
@@ -0,0 +1,11 @@
+use anyhow::Result;
+
+#[test]
+fn trait_methods() -> Result<()> {
+    uniffi_dart::testing::run_test("trait_methods", "src/api.udl", None)
+}
+
+#[test]
+fn trait_methods_golden() -> Result<()> {
+    uniffi_dart::testing::assert_golden("trait_methods", "src/api.udl", None)
+}
@@ -0,0 +1,1027 @@
+library u64_bigint;
+
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io" show Platform, File, Directory;
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+class Counters {
+    final BigInt total;final List<BigInt> history;final BigInt? limit;final Map<String, BigInt> byName;
+
+    Counters({required this.total,required this.history,required this.limit,required this.byName,});
+}
+
+class FfiConverterCounters {
+    static Counters lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterCounters.read(data).value);
+    }
+
+    static LiftRetVal<Counters> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final total_lifted = FfiConverterUInt64.read(Uint8List.view(buf.buffer, new_offset));
+        final total = total_lifted.value;
+        new_offset += total_lifted.bytesRead;final history_lifted = FfiConverterSequenceUInt64.read(Uint8List.view(buf.buffer, new_offset));
+        final history = history_lifted.value;
+        new_offset += history_lifted.bytesRead;final limit_lifted = FfiConverterOptionalUInt64.read(Uint8List.view(buf.buffer, new_offset));
+        final limit = limit_lifted.value;
+        new_offset += limit_lifted.bytesRead;final byName_lifted = FfiConverterMapStringUInt64.read(Uint8List.view(buf.buffer, new_offset));
+        final byName = byName_lifted.value;
+        new_offset += byName_lifted.bytesRead;
+        return LiftRetVal(Counters(
+            total: total,history: history,limit: limit,byName: byName,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( Counters value) {
+        final total_length = FfiConverterUInt64.allocationSize(value.total) +FfiConverterSequenceUInt64.allocationSize(value.history) +FfiConverterOptionalUInt64.allocationSize(value.limit) +FfiConverterMapStringUInt64.allocationSize(value.byName) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( Counters value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterUInt64.write(value.total, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterSequenceUInt64.write(value.history, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterOptionalUInt64.write(value.limit, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterMapStringUInt64.write(value.byName, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Counters value) {
+        return FfiConverterUInt64.allocationSize(value.total) +FfiConverterSequenceUInt64.allocationSize(value.history) +FfiConverterOptionalUInt64.allocationSize(value.limit) +FfiConverterMapStringUInt64.allocationSize(value.byName) + 0;
+    }
+}class Limits {
+    final BigInt max;final BigInt min;
+
+    Limits({BigInt? max,BigInt? min,}): max = max ?? BigInt.parse('18446744073709551615'), min = min ?? BigInt.parse('0');
+}
+
+class FfiConverterLimits {
+    static Limits lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterLimits.read(data).value);
+    }
+
+    static LiftRetVal<Limits> read( Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        final max_lifted = FfiConverterUInt64.read(Uint8List.view(buf.buffer, new_offset));
+        final max = max_lifted.value;
+        new_offset += max_lifted.bytesRead;final min_lifted = FfiConverterUInt64.read(Uint8List.view(buf.buffer, new_offset));
+        final min = min_lifted.value;
+        new_offset += min_lifted.bytesRead;
+        return LiftRetVal(Limits(
+            max: max,min: min,
+        ), new_offset - buf.offsetInBytes);
+    }
+
+    static RustBuffer lower( Limits value) {
+        final total_length = FfiConverterUInt64.allocationSize(value.max) +FfiConverterUInt64.allocationSize(value.min) + 0;
+        final buf = Uint8List(total_length);
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( Limits value, Uint8List buf) {
+        int new_offset = buf.offsetInBytes;
+
+        new_offset += FfiConverterUInt64.write(value.max, Uint8List.view(buf.buffer, new_offset));new_offset += FfiConverterUInt64.write(value.min, Uint8List.view(buf.buffer, new_offset));
+        return new_offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Limits value) {
+        return FfiConverterUInt64.allocationSize(value.max) +FfiConverterUInt64.allocationSize(value.min) + 0;
+    }
+}
+
+abstract class AccumulatorInterface {
+    BigInt add(BigInt amount,);
+}
+
+final _AccumulatorFinalizer = NativeFinalizer(_UniffiLib.instance.uniffi_dart_finalizer_run);
+
+final _AccumulatorFree = _UniffiLib._dylib
+    .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>("uniffi_u64_bigint_fn_free_accumulator");
+
+class Accumulator implements AccumulatorInterface, Finalizable {
+    late final Pointer<Void> _ptr;
+    late final Pointer<Void> _finalizerToken;
+    bool _disposed = false;
+
+    Accumulator._(this._ptr) {
+        _attachFinalizer();
+    }
+
+    void _attachFinalizer() {
+        _finalizerToken = _UniffiLib.instance.uniffi_dart_finalizer_token_new(_AccumulatorFree, _ptr);
+        _AccumulatorFinalizer.attach(this, _finalizerToken, detach: this);
+    }
+
+    Accumulator(BigInt start,) : _ptr = rustCall((uniffiStatus) =>
+        _UniffiLib.instance.uniffi_u64_bigint_fn_constructor_accumulator_new(
+            FfiConverterUInt64.lower(start), uniffiStatus
+        ),
+        NullRustCallStatusErrorHandler()
+    ) {
+        _attachFinalizer();
+    }
+
+    factory Accumulator.lift(Pointer<Void> ptr) {
+        return Accumulator._(ptr);
+    }
+
+    Pointer<Void> uniffiClonePointer() {
+        if (_disposed) {
+            throw StateError("Accumulator has already been disposed");
+        }
+        return rustCall((status) => _UniffiLib.instance.uniffi_u64_bigint_fn_clone_accumulator(_ptr, status));
+    }
+
+    bool get isDisposed => _disposed;
+
+    void dispose() {
+        if (_disposed) {
+            return;
+        }
+        _disposed = true;
+        _AccumulatorFinalizer.detach(this);
+        _UniffiLib.instance.uniffi_dart_finalizer_token_free(_finalizerToken);
+        rustCall((status) => _UniffiLib.instance.uniffi_u64_bigint_fn_free_accumulator(_ptr, status));
+    }
+
+    @override
+    BigInt add(BigInt amount,) {
+        return FfiConverterUInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_u64_bigint_fn_method_accumulator_add(uniffiClonePointer(),
+        FfiConverterUInt64.lower(amount), uniffiStatus), NullRustCallStatusErrorHandler()));
+    }
+}
+
+class FfiConverterAccumulator {
+    static Accumulator lift( Pointer<Void> value) {
+        return Accumulator.lift(value);
+    }
+
+    static Pointer<Void> lower( Accumulator value) {
+        return value.uniffiClonePointer();
+    }
+
+    static LiftRetVal<Accumulator> read( Uint8List buf) {
+        final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+        return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+    }
+
+    static int allocationSize([Accumulator? value]) {
+        return 8;
+    }
+
+    static int write( Accumulator value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+        return 8;
+    }
+}
+
+class UniffiInternalError implements Exception {
+    static const int bufferOverflow = 0;
+    static const int incompleteData = 1;
+    static const int unexpectedOptionalTag = 2;
+    static const int unexpectedEnumCase = 3;
+    static const int unexpectedNullPointer = 4;
+    static const int unexpectedRustCallStatusCode = 5;
+    static const int unexpectedRustCallError = 6;
+    static const int unexpectedStaleHandle = 7;
+    static const int rustPanic = 8;
+
+    final int errorCode;
+    final String? panicMessage;
+
+    const UniffiInternalError(this.errorCode, this.panicMessage);
+
+    static UniffiInternalError panicked(String message) {
+    return UniffiInternalError(rustPanic, message);
+    }
+
+    @override
+    String toString() {
+    switch (errorCode) {
+        case bufferOverflow:
+        return "UniFfi::BufferOverflow";
+        case incompleteData:
+        return "UniFfi::IncompleteData";
+        case unexpectedOptionalTag:
+        return "UniFfi::UnexpectedOptionalTag";
+        case unexpectedEnumCase:
+        return "UniFfi::UnexpectedEnumCase";
+        case unexpectedNullPointer:
+        return "UniFfi::UnexpectedNullPointer";
+        case unexpectedRustCallStatusCode:
+        return "UniFfi::UnexpectedRustCallStatusCode";
+        case unexpectedRustCallError:
+        return "UniFfi::UnexpectedRustCallError";
+        case unexpectedStaleHandle:
+        return "UniFfi::UnexpectedStaleHandle";
+        case rustPanic:
+        return "UniFfi::rustPanic: \$\$panicMessage";
+        default:
+        return "UniFfi::UnknownError: \$\$errorCode";
+    }
+    }
+}
+
+const int CALL_SUCCESS = 0;
+const int CALL_ERROR = 1;
+const int CALL_UNEXPECTED_ERROR = 2;
+
+final class RustCallStatus extends Struct {
+    @Int8()
+    external int code;
+
+    external RustBuffer errorBuf;
+
+}
+
+void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
+
+    if (status.ref.code == CALL_SUCCESS) {
+    return;
+    } else if (status.ref.code == CALL_ERROR) {
+    throw errorHandler.lift(status.ref.errorBuf);
+    } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+    if (status.ref.errorBuf.len > 0) {
+        throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
+    } else {
+        throw UniffiInternalError.panicked("Rust panic");
+    }
+    } else {
+    throw UniffiInternalError.panicked("Unexpected RustCallStatus code: \${status.ref.code}");
+    }
+}
+
+#[doc=" Allocates the native memory the bindings pass to Rust for the duration of a call."]
+const Allocator _uniffiAllocator = calloc;
+
+T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+    final status = _uniffiAllocator<RustCallStatus>();
+    try {
+    final result = callback(status);
+    checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+    return result;
+    } finally {
+    _uniffiAllocator.free(status);
+    }
+}
+
+class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
+    @override
+    Exception lift(RustBuffer errorBuf) {
+    errorBuf.free();
+    return UniffiInternalError.panicked("Unexpected CALL_ERROR");
+    }
+}
+
+abstract class UniffiRustCallStatusErrorHandler {
+    Exception lift(RustBuffer errorBuf);
+}
+
+final class RustBuffer extends Struct {
+    @Uint64()
+    external int capacity;
+
+    @Uint64()
+    external int len;
+
+    external Pointer<Uint8> data;
+
+    static RustBuffer alloc(int size) {
+        return rustCall((status) => _UniffiLib.instance.ffi_u64_bigint_rustbuffer_alloc(size, status));
+    }
+
+    static RustBuffer fromBytes(ForeignBytes bytes) {
+        return rustCall((status) => _UniffiLib.instance.ffi_u64_bigint_rustbuffer_from_bytes(bytes, status));
+    }
+
+    void free() {
+        rustCall((status) => _UniffiLib.instance.ffi_u64_bigint_rustbuffer_free(this, status));
+    }
+
+    RustBuffer reserve(int additionalCapacity) {
+    return rustCall((status) => _UniffiLib.instance.ffi_u64_bigint_rustbuffer_reserve(this, additionalCapacity, status));
+    }
+
+    Uint8List asUint8List() {
+    final dataList = data.asTypedList(len);
+    final byteData = ByteData.sublistView(dataList);
+    return Uint8List.view(byteData.buffer);
+    }
+
+    @override
+    String toString() {
+    return "RustBuffer{capacity: \$capacity, len: \$len, data: \$data}";
+    }
+}
+
+RustBuffer toRustBuffer(Uint8List data) {
+    final length = data.length;
+
+    final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length);
+    final pointerList = frameData.asTypedList(length);
+    pointerList.setAll(0, data);
+
+    final bytes = _uniffiAllocator<ForeignBytes>();
+    bytes.ref.len = length;
+    bytes.ref.data = frameData;
+    try {
+        return RustBuffer.fromBytes(bytes.ref);
+    } finally {
+        _uniffiAllocator.free(frameData);
+        _uniffiAllocator.free(bytes);
+    }
+}
+
+T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+    try {
+        return lift(buf.asUint8List());
+    } finally {
+        buf.free();
+    }
+}
+
+final class ForeignBytes extends Struct {
+    @Int32()
+    external int len;
+    external Pointer<Uint8> data;
+
+    void free() {
+    _uniffiAllocator.free(data);
+    }
+}
+
+class LiftRetVal<T> {
+    final T value;
+    final int bytesRead;
+    const LiftRetVal(this.value, this.bytesRead);
+
+    LiftRetVal<T> copyWithOffset(int offset) {
+        return LiftRetVal(value, bytesRead + offset);
+    }
+}
+
+abstract class FfiConverter<D, F> {
+    const FfiConverter();
+
+    D lift(F value);
+    F lower(D value);
+    D read(ByteData buffer, int offset);
+    void write(D value, ByteData buffer, int offset);
+    int size(D value);
+}
+
+mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+    @override
+    T lift(T value) => value;
+
+    @override
+    T lower(T value) => value;
+}
+
+class FfiConverterMapStringUInt64 {
+
+    static Map<String, BigInt> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMapStringUInt64.read(data).value);
+    }
+
+    static LiftRetVal<Map<String, BigInt>> read( Uint8List buf) {
+        final Map<String, BigInt> res = <String, BigInt>{};
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final key = FfiConverterString.read(Uint8List.view(buf.buffer, offset));
+            offset += key.bytesRead;
+            final value = FfiConverterUInt64.read(Uint8List.view(buf.buffer, offset));
+            offset += value.bytesRead;
+            res[key.value] = value.value;
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( Map<String, BigInt> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (final entry in value.entries) {
+            offset += FfiConverterString.write(entry.key, Uint8List.view(buf.buffer, offset));
+            offset += FfiConverterUInt64.write(entry.value, Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Map<String, BigInt> value) {
+        return value.entries
+            .map((e) => FfiConverterString.allocationSize(e.key) + FfiConverterUInt64.allocationSize(e.value))
+            .fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( Map<String, BigInt> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterMapUInt64UInt64 {
+
+    static Map<BigInt, BigInt> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterMapUInt64UInt64.read(data).value);
+    }
+
+    static LiftRetVal<Map<BigInt, BigInt>> read( Uint8List buf) {
+        final Map<BigInt, BigInt> res = <BigInt, BigInt>{};
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final key = FfiConverterUInt64.read(Uint8List.view(buf.buffer, offset));
+            offset += key.bytesRead;
+            final value = FfiConverterUInt64.read(Uint8List.view(buf.buffer, offset));
+            offset += value.bytesRead;
+            res[key.value] = value.value;
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( Map<BigInt, BigInt> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (final entry in value.entries) {
+            offset += FfiConverterUInt64.write(entry.key, Uint8List.view(buf.buffer, offset));
+            offset += FfiConverterUInt64.write(entry.value, Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+
+    static int allocationSize(Map<BigInt, BigInt> value) {
+        return value.entries
+            .map((e) => FfiConverterUInt64.allocationSize(e.key) + FfiConverterUInt64.allocationSize(e.value))
+            .fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( Map<BigInt, BigInt> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterOptionalUInt64 {
+
+    static BigInt? lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterOptionalUInt64.read(data).value);
+    }
+
+    static LiftRetVal<BigInt?> read( Uint8List buf) {
+        if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){
+            return LiftRetVal(null, 1);
+        }
+        return FfiConverterUInt64.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)).copyWithOffset(1);
+    }
+
+    static int allocationSize([BigInt? value]) {
+        if (value == null) {
+            return 1;
+        }
+        return FfiConverterUInt64.allocationSize(value) + 1;
+    }
+
+    static RustBuffer lower( BigInt? value) {
+        if (value == null) {
+            return toRustBuffer(Uint8List.fromList([0]));
+        }
+
+        final buf = Uint8List(FfiConverterOptionalUInt64.allocationSize(value));
+        FfiConverterOptionalUInt64.write(value, buf);
+        return toRustBuffer(buf);
+    }
+
+    static int write( BigInt? value, Uint8List buf) {
+        if (value == null) {
+            buf[0] = 0;
+            return 1;
+        }
+
+        buf[0] = 1;
+
+        return FfiConverterUInt64.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
+    }
+}class FfiConverterSequenceUInt64 {
+
+    static List<BigInt> lift( RustBuffer buf) {
+        return liftFromRustBuffer(buf, (data) => FfiConverterSequenceUInt64.read(data).value);
+    }
+
+    static LiftRetVal<List<BigInt>> read( Uint8List buf) {
+        List<BigInt> res = [];
+        final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < length; i++) {
+            final ret = FfiConverterUInt64.read(Uint8List.view(buf.buffer, offset));
+            offset += ret.bytesRead;
+            res.add(ret.value);
+        }
+        return LiftRetVal(res, offset - buf.offsetInBytes);
+    }
+
+    static int write( List<BigInt> value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+        int offset = buf.offsetInBytes + 4;
+        for (var i = 0; i < value.length; i++) {
+            offset += FfiConverterUInt64.write(value[i], Uint8List.view(buf.buffer, offset));
+        }
+        return offset - buf.offsetInBytes;
+    }
+    static int allocationSize(List<BigInt> value) {
+        return value.map((l) => FfiConverterUInt64.allocationSize(l)).fold(4, (a, b) => a + b);
+    }
+
+    static RustBuffer lower( List<BigInt> value) {
+        final buf = Uint8List(allocationSize(value));
+        write(value, buf);
+        return toRustBuffer(buf);
+    }
+}class FfiConverterString {
+    static String lift( RustBuffer buf) {
+
+        return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
+    }
+
+    static RustBuffer lower( String value) {
+        return toRustBuffer(Utf8Encoder().convert(value));
+    }
+
+    static LiftRetVal<String> read( Uint8List buf) {
+        final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+        return LiftRetVal(utf8.decoder.convert(buf, 4, end), end);
+    }
+
+    static int allocationSize([String value = ""]) {
+
+        return utf8.encoder.convert(value).length + 4;
+    }
+
+    static int write( String value, Uint8List buf) {
+
+        final list = utf8.encoder.convert(value);
+        buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length);
+        buf.setAll(4, list);
+        return list.length + 4;
+    }
+}class FfiConverterUInt64 {
+    static final BigInt _max = (BigInt.one << 64) - BigInt.one;
+
+    static BigInt lift(int value) => BigInt.from(value).toUnsigned(64);
+
+    static LiftRetVal<BigInt> read(Uint8List buf) {
+        return LiftRetVal(lift(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0)), 8);
+    }
+
+    static int lower(BigInt value) {
+        if (value.isNegative || value > _max) {
+            throw ArgumentError.value(value, "value", "out of range for a u64");
+        }
+        return value.toSigned(64).toInt();
+    }
+
+    static int allocationSize([BigInt? value]) {
+        return 8;
+    }
+
+    static int write(BigInt value, Uint8List buf) {
+        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value));
+        return FfiConverterUInt64.allocationSize();
+    }
+}
+
+const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
+const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
+
+typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+Future<T> uniffiRustCallAsync<T, F>(
+    Pointer<Void> Function() rustFutureFunc,
+    void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc,
+    F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc,
+    void Function(Pointer<Void>) freeFunc,
+    T Function(F) liftFunc, [
+    UniffiRustCallStatusErrorHandler? errorHandler,
+]) async {
+    final rustFuture = rustFutureFunc();
+    final completer = Completer<int>();
+
+    late final NativeCallable<UniffiRustFutureContinuationCallback> callback;
+
+    void poll() {
+        pollFunc(
+            rustFuture,
+            callback.nativeFunction,
+            Pointer<Void>.fromAddress(0),
+        );
+    }
+    void onResponse(int _idx, int pollResult) {
+        if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+            completer.complete(pollResult);
+        } else {
+            poll();
+        }
+    }
+    callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener(onResponse);
+
+    try {
+        poll();
+        await completer.future;
+        callback.close();
+
+        final status = _uniffiAllocator<RustCallStatus>();
+        try {
+            final result = completeFunc(rustFuture, status);
+            checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+            return liftFunc(result);
+        } finally {
+            _uniffiAllocator.free(status);
+        }
+    } finally {
+        freeFunc(rustFuture);
+    }
+}
+
+class UniffiHandleMap<T> {
+    final Map<int, T> _map = {};
+    int _counter = 0;
+
+    int insert(T obj) {
+    final handle = _counter++;
+    _map[handle] = obj;
+    return handle;
+    }
+
+    T get(int handle) {
+    final obj = _map[handle];
+    if (obj == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    return obj;
+    }
+
+    void remove(int handle) {
+    if (_map.remove(handle) == null) {
+        throw UniffiInternalError(
+            UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+    }
+    }
+}
+
+BigInt advance(BigInt value, {BigInt? step,}) {
+    return FfiConverterUInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_u64_bigint_fn_func_advance(FfiConverterUInt64.lower(value),FfiConverterUInt64.lower((step ?? BigInt.parse('18446744073709551615'))), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Future<BigInt> asyncEchoU64(BigInt value,) {
+    return uniffiRustCallAsync(
+        () => _UniffiLib.instance.uniffi_u64_bigint_fn_func_async_echo_u64(
+            FfiConverterUInt64.lower(value),
+        ),
+        _UniffiLib.instance.ffi_u64_bigint_rust_future_poll_u64,
+        _UniffiLib.instance.ffi_u64_bigint_rust_future_complete_u64,
+        _UniffiLib.instance.ffi_u64_bigint_rust_future_free_u64,
+        FfiConverterUInt64.lift,
+        NullRustCallStatusErrorHandler(),
+    );
+}Counters echoCounters(Counters counters,) {
+    return FfiConverterCounters.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_u64_bigint_fn_func_echo_counters(FfiConverterCounters.lower(counters), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Limits echoLimits(Limits limits,) {
+    return FfiConverterLimits.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_u64_bigint_fn_func_echo_limits(FfiConverterLimits.lower(limits), uniffiStatus), NullRustCallStatusErrorHandler()));
+}BigInt? echoOptionalU64(BigInt? value,) {
+    return FfiConverterOptionalUInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_u64_bigint_fn_func_echo_optional_u64(FfiConverterOptionalUInt64.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}BigInt echoU64(BigInt value,) {
+    return FfiConverterUInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_u64_bigint_fn_func_echo_u64(FfiConverterUInt64.lower(value), uniffiStatus), NullRustCallStatusErrorHandler()));
+}Map<BigInt, BigInt> echoU64Map(Map<BigInt, BigInt> values,) {
+    return FfiConverterMapUInt64UInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_u64_bigint_fn_func_echo_u64_map(FfiConverterMapUInt64UInt64.lower(values), uniffiStatus), NullRustCallStatusErrorHandler()));
+}List<BigInt> echoU64s(List<BigInt> values,) {
+    return FfiConverterSequenceUInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_u64_bigint_fn_func_echo_u64s(FfiConverterSequenceUInt64.lower(values), uniffiStatus), NullRustCallStatusErrorHandler()));
+}BigInt historySum(Counters counters,) {
+    return FfiConverterUInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_u64_bigint_fn_func_history_sum(FfiConverterCounters.lower(counters), uniffiStatus), NullRustCallStatusErrorHandler()));
+}BigInt u64Max() {
+    return FfiConverterUInt64.lift(rustCall((uniffiStatus) => _UniffiLib.instance.uniffi_u64_bigint_fn_func_u64_max( uniffiStatus), NullRustCallStatusErrorHandler()));
+}
+
+class _UniffiLib {
+    _UniffiLib._();
+
+    static final DynamicLibrary _dylib = _open();
+
+    static DynamicLibrary _open() {
+        if (Platform.isAndroid) return DynamicLibrary.open("libu64_bigint.so");
+        if (Platform.isIOS) return DynamicLibrary.executable();
+        if (Platform.isLinux) return DynamicLibrary.open("libu64_bigint.so");
+        if (Platform.isMacOS) return DynamicLibrary.open("libu64_bigint.dylib");
+        if (Platform.isWindows) return DynamicLibrary.open("u64_bigint.dll");
+        throw UnsupportedError("Unsupported platform: \${Platform.operatingSystem}");
+    }
+
+    static final _UniffiLib instance = _UniffiLib._();
+
+    late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_u64_bigint_fn_clone_accumulator = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_u64_bigint_fn_clone_accumulator");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) uniffi_u64_bigint_fn_free_accumulator = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("uniffi_u64_bigint_fn_free_accumulator");late final Pointer<Void> Function(int,Pointer<RustCallStatus>) uniffi_u64_bigint_fn_constructor_accumulator_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Uint64,Pointer<RustCallStatus>),
+        Pointer<Void> Function(int,Pointer<RustCallStatus>)
+    >("uniffi_u64_bigint_fn_constructor_accumulator_new");late final int Function(Pointer<Void>,int,Pointer<RustCallStatus>) uniffi_u64_bigint_fn_method_accumulator_add = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Uint64,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,int,Pointer<RustCallStatus>)
+    >("uniffi_u64_bigint_fn_method_accumulator_add");late final int Function(int,int,Pointer<RustCallStatus>) uniffi_u64_bigint_fn_func_advance = _dylib.lookupFunction<
+        Uint64 Function(Uint64,Uint64,Pointer<RustCallStatus>),
+        int Function(int,int,Pointer<RustCallStatus>)
+    >("uniffi_u64_bigint_fn_func_advance");late final Pointer<Void> Function(int,) uniffi_u64_bigint_fn_func_async_echo_u64 = _dylib.lookupFunction<
+        Pointer<Void> Function(Uint64,),
+        Pointer<Void> Function(int,)
+    >("uniffi_u64_bigint_fn_func_async_echo_u64");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_u64_bigint_fn_func_echo_counters = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_u64_bigint_fn_func_echo_counters");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_u64_bigint_fn_func_echo_limits = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_u64_bigint_fn_func_echo_limits");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_u64_bigint_fn_func_echo_optional_u64 = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_u64_bigint_fn_func_echo_optional_u64");late final int Function(int,Pointer<RustCallStatus>) uniffi_u64_bigint_fn_func_echo_u64 = _dylib.lookupFunction<
+        Uint64 Function(Uint64,Pointer<RustCallStatus>),
+        int Function(int,Pointer<RustCallStatus>)
+    >("uniffi_u64_bigint_fn_func_echo_u64");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_u64_bigint_fn_func_echo_u64_map = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_u64_bigint_fn_func_echo_u64_map");late final RustBuffer Function(RustBuffer,Pointer<RustCallStatus>) uniffi_u64_bigint_fn_func_echo_u64s = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_u64_bigint_fn_func_echo_u64s");late final int Function(RustBuffer,Pointer<RustCallStatus>) uniffi_u64_bigint_fn_func_history_sum = _dylib.lookupFunction<
+        Uint64 Function(RustBuffer,Pointer<RustCallStatus>),
+        int Function(RustBuffer,Pointer<RustCallStatus>)
+    >("uniffi_u64_bigint_fn_func_history_sum");late final int Function(Pointer<RustCallStatus>) uniffi_u64_bigint_fn_func_u64_max = _dylib.lookupFunction<
+        Uint64 Function(Pointer<RustCallStatus>),
+        int Function(Pointer<RustCallStatus>)
+    >("uniffi_u64_bigint_fn_func_u64_max");late final RustBuffer Function(int,Pointer<RustCallStatus>) ffi_u64_bigint_rustbuffer_alloc = _dylib.lookupFunction<
+        RustBuffer Function(Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(int,Pointer<RustCallStatus>)
+    >("ffi_u64_bigint_rustbuffer_alloc");late final RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>) ffi_u64_bigint_rustbuffer_from_bytes = _dylib.lookupFunction<
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>),
+        RustBuffer Function(ForeignBytes,Pointer<RustCallStatus>)
+    >("ffi_u64_bigint_rustbuffer_from_bytes");late final void Function(RustBuffer,Pointer<RustCallStatus>) ffi_u64_bigint_rustbuffer_free = _dylib.lookupFunction<
+        Void Function(RustBuffer,Pointer<RustCallStatus>),
+        void Function(RustBuffer,Pointer<RustCallStatus>)
+    >("ffi_u64_bigint_rustbuffer_free");late final RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>) ffi_u64_bigint_rustbuffer_reserve = _dylib.lookupFunction<
+        RustBuffer Function(RustBuffer,Uint64,Pointer<RustCallStatus>),
+        RustBuffer Function(RustBuffer,int,Pointer<RustCallStatus>)
+    >("ffi_u64_bigint_rustbuffer_reserve");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_u64_bigint_rust_future_poll_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_poll_u8");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_cancel_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_cancel_u8");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_free_u8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_free_u8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_u64_bigint_rust_future_complete_u8 = _dylib.lookupFunction<
+        Uint8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_u64_bigint_rust_future_complete_u8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_u64_bigint_rust_future_poll_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_poll_i8");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_cancel_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_cancel_i8");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_free_i8 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_free_i8");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_u64_bigint_rust_future_complete_i8 = _dylib.lookupFunction<
+        Int8 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_u64_bigint_rust_future_complete_i8");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_u64_bigint_rust_future_poll_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_poll_u16");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_cancel_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_cancel_u16");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_free_u16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_free_u16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_u64_bigint_rust_future_complete_u16 = _dylib.lookupFunction<
+        Uint16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_u64_bigint_rust_future_complete_u16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_u64_bigint_rust_future_poll_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_poll_i16");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_cancel_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_cancel_i16");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_free_i16 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_free_i16");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_u64_bigint_rust_future_complete_i16 = _dylib.lookupFunction<
+        Int16 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_u64_bigint_rust_future_complete_i16");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_u64_bigint_rust_future_poll_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_poll_u32");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_cancel_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_cancel_u32");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_free_u32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_free_u32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_u64_bigint_rust_future_complete_u32 = _dylib.lookupFunction<
+        Uint32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_u64_bigint_rust_future_complete_u32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_u64_bigint_rust_future_poll_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_poll_i32");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_cancel_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_cancel_i32");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_free_i32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_free_i32");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_u64_bigint_rust_future_complete_i32 = _dylib.lookupFunction<
+        Int32 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_u64_bigint_rust_future_complete_i32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_u64_bigint_rust_future_poll_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_poll_u64");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_cancel_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_cancel_u64");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_free_u64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_free_u64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_u64_bigint_rust_future_complete_u64 = _dylib.lookupFunction<
+        Uint64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_u64_bigint_rust_future_complete_u64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_u64_bigint_rust_future_poll_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_poll_i64");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_cancel_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_cancel_i64");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_free_i64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_free_i64");late final int Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_u64_bigint_rust_future_complete_i64 = _dylib.lookupFunction<
+        Int64 Function(Pointer<Void>,Pointer<RustCallStatus>),
+        int Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_u64_bigint_rust_future_complete_i64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_u64_bigint_rust_future_poll_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_poll_f32");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_cancel_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_cancel_f32");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_free_f32 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_free_f32");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_u64_bigint_rust_future_complete_f32 = _dylib.lookupFunction<
+        Float Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_u64_bigint_rust_future_complete_f32");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_u64_bigint_rust_future_poll_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_poll_f64");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_cancel_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_cancel_f64");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_free_f64 = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_free_f64");late final double Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_u64_bigint_rust_future_complete_f64 = _dylib.lookupFunction<
+        Double Function(Pointer<Void>,Pointer<RustCallStatus>),
+        double Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_u64_bigint_rust_future_complete_f64");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_u64_bigint_rust_future_poll_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_poll_pointer");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_cancel_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_cancel_pointer");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_free_pointer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_free_pointer");late final Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_u64_bigint_rust_future_complete_pointer = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>),
+        Pointer<Void> Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_u64_bigint_rust_future_complete_pointer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_u64_bigint_rust_future_poll_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_poll_rust_buffer");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_cancel_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_cancel_rust_buffer");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_free_rust_buffer = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_free_rust_buffer");late final RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_u64_bigint_rust_future_complete_rust_buffer = _dylib.lookupFunction<
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>),
+        RustBuffer Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_u64_bigint_rust_future_complete_rust_buffer");late final void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,) ffi_u64_bigint_rust_future_poll_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,),
+        void Function(Pointer<Void>,Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>,Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_poll_void");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_cancel_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_cancel_void");late final void Function(Pointer<Void>,) ffi_u64_bigint_rust_future_free_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,),
+        void Function(Pointer<Void>,)
+    >("ffi_u64_bigint_rust_future_free_void");late final void Function(Pointer<Void>,Pointer<RustCallStatus>) ffi_u64_bigint_rust_future_complete_void = _dylib.lookupFunction<
+        Void Function(Pointer<Void>,Pointer<RustCallStatus>),
+        void Function(Pointer<Void>,Pointer<RustCallStatus>)
+    >("ffi_u64_bigint_rust_future_complete_void");late final int Function() uniffi_u64_bigint_checksum_func_advance = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_u64_bigint_checksum_func_advance");late final int Function() uniffi_u64_bigint_checksum_func_async_echo_u64 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_u64_bigint_checksum_func_async_echo_u64");late final int Function() uniffi_u64_bigint_checksum_func_echo_counters = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_u64_bigint_checksum_func_echo_counters");late final int Function() uniffi_u64_bigint_checksum_func_echo_limits = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_u64_bigint_checksum_func_echo_limits");late final int Function() uniffi_u64_bigint_checksum_func_echo_optional_u64 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_u64_bigint_checksum_func_echo_optional_u64");late final int Function() uniffi_u64_bigint_checksum_func_echo_u64 = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_u64_bigint_checksum_func_echo_u64");late final int Function() uniffi_u64_bigint_checksum_func_echo_u64_map = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_u64_bigint_checksum_func_echo_u64_map");late final int Function() uniffi_u64_bigint_checksum_func_echo_u64s = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_u64_bigint_checksum_func_echo_u64s");late final int Function() uniffi_u64_bigint_checksum_func_history_sum = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_u64_bigint_checksum_func_history_sum");late final int Function() uniffi_u64_bigint_checksum_func_u64_max = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_u64_bigint_checksum_func_u64_max");late final int Function() uniffi_u64_bigint_checksum_method_accumulator_add = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_u64_bigint_checksum_method_accumulator_add");late final int Function() uniffi_u64_bigint_checksum_constructor_accumulator_new = _dylib.lookupFunction<
+        Uint16 Function(),
+        int Function()
+    >("uniffi_u64_bigint_checksum_constructor_accumulator_new");late final int Function() ffi_u64_bigint_uniffi_contract_version = _dylib.lookupFunction<
+        Uint32 Function(),
+        int Function()
+    >("ffi_u64_bigint_uniffi_contract_version");late final Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>) uniffi_dart_finalizer_token_new = _dylib.lookupFunction<
+        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>),
+        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>)
+    >("uniffi_dart_finalizer_token_new");
+    late final void Function(Pointer<Void>) uniffi_dart_finalizer_token_free = _dylib.lookupFunction<
+        Void Function(Pointer<Void>),
+        void Function(Pointer<Void>)
+    >("uniffi_dart_finalizer_token_free");
+    late final Pointer<NativeFinalizerFunction> uniffi_dart_finalizer_run = _dylib.lookup("uniffi_dart_finalizer_run");
+
+    static void _checkApiVersion() {
+        final bindingsVersion = 26;
+        final scaffoldingVersion = _UniffiLib.instance.ffi_u64_bigint_uniffi_contract_version();
+        if (bindingsVersion != scaffoldingVersion) {
+            throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version \$bindingsVersion, scaffolding version \$scaffoldingVersion");
+        }
+    }
+
+    static void _checkApiChecksums() {
+        if (_UniffiLib.instance.uniffi_u64_bigint_checksum_func_advance() != 17444) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_u64_bigint_checksum_func_async_echo_u64() != 60986) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_u64_bigint_checksum_func_echo_counters() != 47541) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_u64_bigint_checksum_func_echo_limits() != 45467) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_u64_bigint_checksum_func_echo_optional_u64() != 15051) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_u64_bigint_checksum_func_echo_u64() != 3672) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_u64_bigint_checksum_func_echo_u64_map() != 45923) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_u64_bigint_checksum_func_echo_u64s() != 23580) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_u64_bigint_checksum_func_history_sum() != 48982) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_u64_bigint_checksum_func_u64_max() != 18239) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_u64_bigint_checksum_method_accumulator_add() != 34320) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }if (_UniffiLib.instance.uniffi_u64_bigint_checksum_constructor_accumulator_new() != 48934) {
+            throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+        }
+    }
+}
+
+void initialize() {
+    _UniffiLib._open();
+}
+
+void ensureInitialized() {
+    _UniffiLib._checkApiVersion();
+    _UniffiLib._checkApiChecksums();
+}
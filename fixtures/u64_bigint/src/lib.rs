@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// A record using `u64` everywhere it can appear.
+#[derive(uniffi::Record)]
+pub struct Counters {
+    total: u64,
+    history: Vec<u64>,
+    limit: Option<u64>,
+    by_name: HashMap<String, u64>,
+}
+
+/// A record whose `u64` fields default to values Dart can't declare as a `BigInt` constant.
+#[derive(uniffi::Record)]
+pub struct Limits {
+    #[uniffi(default = 18446744073709551615)]
+    max: u64,
+    #[uniffi(default = 0)]
+    min: u64,
+}
+
+#[uniffi::export]
+pub fn echo_limits(limits: Limits) -> Limits {
+    limits
+}
+
+#[uniffi::export(default(step = 18446744073709551615))]
+pub fn advance(value: u64, step: u64) -> u64 {
+    value.wrapping_add(step)
+}
+
+#[uniffi::export]
+pub fn u64_max() -> u64 {
+    u64::MAX
+}
+
+#[uniffi::export]
+pub fn echo_u64(value: u64) -> u64 {
+    value
+}
+
+#[uniffi::export]
+pub fn echo_u64s(values: Vec<u64>) -> Vec<u64> {
+    values
+}
+
+#[uniffi::export]
+pub fn echo_optional_u64(value: Option<u64>) -> Option<u64> {
+    value
+}
+
+#[uniffi::export]
+pub fn echo_u64_map(values: HashMap<u64, u64>) -> HashMap<u64, u64> {
+    values
+}
+
+#[uniffi::export]
+pub fn echo_counters(counters: Counters) -> Counters {
+    counters
+}
+
+/// The sum of `history`, which must match `total` for the record to have survived the trip.
+#[uniffi::export]
+pub fn history_sum(counters: Counters) -> u64 {
+    counters.history.iter().fold(0, |sum, value| sum.wrapping_add(*value))
+}
+
+#[uniffi::export]
+pub async fn async_echo_u64(value: u64) -> u64 {
+    value
+}
+
+#[derive(uniffi::Object)]
+pub struct Accumulator {
+    value: Mutex<u64>,
+}
+
+#[uniffi::export]
+impl Accumulator {
+    #[uniffi::constructor]
+    pub fn new(start: u64) -> Self {
+        Self {
+            value: Mutex::new(start),
+        }
+    }
+
+    pub fn add(&self, amount: u64) -> u64 {
+        let mut value = self.value.lock().unwrap();
+        *value = value.wrapping_add(amount);
+        *value
+    }
+}
+
+uniffi::setup_scaffolding!();
+uniffi_dart::setup_finalizers!();
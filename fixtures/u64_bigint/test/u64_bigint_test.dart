@@ -0,0 +1,61 @@
+import 'package:test/test.dart';
+import '../u64_bigint.dart';
+
+// `u64_as_bigint` is enabled in uniffi.toml, so values above i64::MAX keep their sign
+final maxU64 = BigInt.parse('18446744073709551615');
+final aboveI64Max = BigInt.parse('9223372036854775808');
+
+void main() {
+  test('u64 values above i64::MAX', () {
+    expect(u64Max(), maxU64);
+    expect(echoU64(maxU64), maxU64);
+    expect(echoU64(aboveI64Max), aboveI64Max);
+    expect(echoU64(BigInt.zero), BigInt.zero);
+  });
+
+  test('out of range values are rejected', () {
+    expect(() => echoU64(BigInt.from(-1)), throwsArgumentError);
+    expect(() => echoU64(maxU64 + BigInt.one), throwsArgumentError);
+    expect(() => echoU64s([BigInt.one, BigInt.from(-1)]), throwsArgumentError);
+  });
+
+  test('collections', () {
+    expect(echoU64s([BigInt.zero, aboveI64Max, maxU64]), [BigInt.zero, aboveI64Max, maxU64]);
+    expect(echoOptionalU64(maxU64), maxU64);
+    expect(echoOptionalU64(null), null);
+    expect(echoU64Map({maxU64: aboveI64Max, BigInt.one: maxU64}), {maxU64: aboveI64Max, BigInt.one: maxU64});
+  });
+
+  test('records', () {
+    final counters = echoCounters(Counters(
+      total: maxU64,
+      history: [aboveI64Max, aboveI64Max - BigInt.one],
+      limit: maxU64,
+      byName: {'max': maxU64},
+    ));
+    expect(counters.total, maxU64);
+    expect(counters.history, [aboveI64Max, aboveI64Max - BigInt.one]);
+    expect(counters.limit, maxU64);
+    expect(counters.byName, {'max': maxU64});
+    expect(historySum(counters), maxU64);
+  });
+
+  test('default values', () {
+    expect(advance(BigInt.one), BigInt.zero);
+    expect(advance(BigInt.one, step: BigInt.two), BigInt.from(3));
+    final limits = echoLimits(Limits());
+    expect(limits.max, maxU64);
+    expect(limits.min, BigInt.zero);
+    expect(Limits(min: BigInt.one).min, BigInt.one);
+  });
+
+  test('async', () async {
+    expect(await asyncEchoU64(maxU64), maxU64);
+  });
+
+  test('objects', () {
+    final accumulator = Accumulator(aboveI64Max);
+    expect(accumulator.add(aboveI64Max - BigInt.one), maxU64);
+    expect(accumulator.add(BigInt.one), BigInt.zero);
+  });
+}
@@ -0,0 +1,11 @@
+use anyhow::Result;
+
+#[test]
+fn u64_bigint() -> Result<()> {
+    uniffi_dart::testing::run_library_test("u64_bigint", None)
+}
+
+#[test]
+fn u64_bigint_golden() -> Result<()> {
+    uniffi_dart::testing::assert_library_golden("u64_bigint", None)
+}
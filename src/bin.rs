@@ -1,4 +1,93 @@
-fn main() {
-    println!("uniffi_bindgen_dart binary");
-    println!("This binary is a placeholder and will be implemented in the future.");
+use anyhow::{bail, Context, Result};
+use camino::Utf8PathBuf;
+use uniffi_dart::gen::DartBindingGenerator;
+
+const USAGE: &str = "\
+Generate Dart bindings for a UniFFI component
+
+Usage: uniffi_bindgen_dart [OPTIONS] <SOURCE>
+
+Arguments:
+  <SOURCE>  A UDL file, or a built cdylib to generate the bindings of every crate in it
+
+Options:
+  -o, --out-dir <DIR>    Directory to write the bindings to (required for a cdylib)
+  -c, --config <FILE>    Path to a uniffi.toml to use instead of the crate's own
+  -l, --lib-file <FILE>  cdylib to read proc-macro metadata from when generating from a UDL file
+      --crate <NAME>     Only generate the bindings for this crate
+      --no-format        Do not format the generated bindings
+  -h, --help             Print this help
+";
+
+#[derive(Debug, Default)]
+struct Args {
+    source: Option<Utf8PathBuf>,
+    out_dir: Option<Utf8PathBuf>,
+    config: Option<Utf8PathBuf>,
+    lib_file: Option<Utf8PathBuf>,
+    crate_name: Option<String>,
+    no_format: bool,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Option<Self>> {
+        let mut parsed = Args::default();
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .with_context(|| format!("`{arg}` expects a value"))
+            };
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "-o" | "--out-dir" => parsed.out_dir = Some(value()?.into()),
+                "-c" | "--config" => parsed.config = Some(value()?.into()),
+                "-l" | "--lib-file" => parsed.lib_file = Some(value()?.into()),
+                "--crate" => parsed.crate_name = Some(value()?),
+                "--no-format" => parsed.no_format = true,
+                flag if flag.starts_with('-') => bail!("unknown option `{flag}`"),
+                _ if parsed.source.is_some() => bail!("unexpected argument `{arg}`"),
+                _ => parsed.source = Some(arg.into()),
+            }
+        }
+        Ok(Some(parsed))
+    }
+}
+
+fn main() -> Result<()> {
+    let Some(args) = Args::parse(std::env::args().skip(1))? else {
+        print!("{USAGE}");
+        return Ok(());
+    };
+    let Some(source) = args.source else {
+        bail!("missing <SOURCE>\n\n{USAGE}");
+    };
+    let try_format_code = !args.no_format;
+
+    if source.extension() == Some("udl") {
+        uniffi_bindgen::generate_external_bindings(
+            &DartBindingGenerator,
+            &source,
+            args.config.as_deref(),
+            args.out_dir.as_deref(),
+            args.lib_file.as_deref(),
+            args.crate_name.as_deref(),
+            try_format_code,
+        )
+    } else {
+        if args.lib_file.is_some() {
+            bail!("`--lib-file` is only used with a UDL file, {source} is already a library");
+        }
+        let Some(out_dir) = args.out_dir else {
+            bail!("`--out-dir` is required when generating from a library");
+        };
+        uniffi_bindgen::library_mode::generate_external_bindings(
+            &DartBindingGenerator,
+            &source,
+            args.crate_name,
+            args.config.as_deref(),
+            &out_dir,
+            try_format_code,
+        )?;
+        Ok(())
+    }
 }
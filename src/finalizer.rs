@@ -0,0 +1,65 @@
+//! The native side of the finalizers of the generated Dart objects.
+//!
+//! A Dart `NativeFinalizer` calls a C function with a single pointer, while the free functions
+//! UniFFI exports for objects also take a `RustCallStatus`. The generated bindings attach a
+//! [`FinalizerToken`] holding both the free function and the object instead, which
+//! [`uniffi_dart_finalizer_run`] passes to the free function with a status of its own. Crates
+//! exporting objects link these functions with [`setup_finalizers!`](crate::setup_finalizers).
+
+use std::ffi::c_void;
+use uniffi::{RustCallStatus, RustCallStatusCode};
+
+/// The signature of the `uniffi_*_fn_free_*` functions.
+pub type FreeFunction = unsafe extern "C" fn(*const c_void, &mut RustCallStatus);
+
+/// An object to free, with the function to free it.
+pub struct FinalizerToken {
+    free: FreeFunction,
+    ptr: *const c_void,
+}
+
+/// Creates the token a `NativeFinalizer` is attached with. It is given back by
+/// [`uniffi_dart_finalizer_run`] or [`uniffi_dart_finalizer_token_free`].
+#[no_mangle]
+pub extern "C" fn uniffi_dart_finalizer_token_new(
+    free: FreeFunction,
+    ptr: *const c_void,
+) -> *mut FinalizerToken {
+    Box::into_raw(Box::new(FinalizerToken { free, ptr }))
+}
+
+/// Frees a token without freeing its object, after the object was disposed.
+///
+/// # Safety
+///
+/// `token` must come from [`uniffi_dart_finalizer_token_new`] and not have been given back yet.
+#[no_mangle]
+pub unsafe extern "C" fn uniffi_dart_finalizer_token_free(token: *mut FinalizerToken) {
+    drop(Box::from_raw(token));
+}
+
+/// The `NativeFinalizer` callback: frees the object of `token`, then the token. A panic while
+/// dropping the object has nobody to be reported to, so its message is discarded.
+///
+/// # Safety
+///
+/// `token` must come from [`uniffi_dart_finalizer_token_new`] and not have been given back yet.
+#[no_mangle]
+pub unsafe extern "C" fn uniffi_dart_finalizer_run(token: *mut FinalizerToken) {
+    let token = Box::from_raw(token);
+    let mut status = RustCallStatus::default();
+    (token.free)(token.ptr, &mut status);
+    if status.code != RustCallStatusCode::Success {
+        status.error_buf.assume_init().destroy();
+    }
+}
+
+/// Links the finalizer functions into the cdylib of a crate exporting objects. Call it once at
+/// the root of the crate, next to `uniffi::setup_scaffolding!()`.
+#[macro_export]
+macro_rules! setup_finalizers {
+    () => {
+        const _: unsafe extern "C" fn(*mut $crate::finalizer::FinalizerToken) =
+            $crate::finalizer::uniffi_dart_finalizer_run;
+    };
+}
@@ -1,3 +1,4 @@
+use anyhow::Result;
 use genco::prelude::*;
 use uniffi_bindgen::backend::{CodeType, Type};
 use uniffi_bindgen::interface::{AsType, Method, CallbackInterface};
@@ -39,10 +40,10 @@ impl Renderable for CallbackInterfaceCodeType {
         let callback = type_helper.get_ci().get_callback_interface_definition(&self.name).unwrap();
         
         // Generate all necessary components for the callback interface
-        let interface = generate_callback_interface(&self, type_helper);
-        let vtable_interface = generate_callback_vtable_interface(&callback, type_helper);
-        let functions = generate_callback_functions(&callback, type_helper);
-        let vtable_init = generate_callback_interface_vtable_init_funtion(&callback, type_helper);
+        let interface = generate_callback_interface(self, type_helper);
+        let vtable_interface = generate_callback_vtable_interface(callback, type_helper);
+        let functions = generate_callback_functions(callback, type_helper);
+        let vtable_init = generate_callback_interface_vtable_init_funtion(callback, type_helper);
         
         quote! {
             $interface
@@ -108,14 +109,14 @@ fn generate_callback_interface(
 }
 
 fn generate_callback_methods_definitions(method: &Method, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-    let method_name = DartCodeOracle::fn_name(&method.name());
+    let method_name = DartCodeOracle::fn_name(method.name());
     let dart_args = &method.arguments().iter().map(|arg| {
         let arg_type = arg.as_renderable().render_type(&arg.as_type(), type_helper);
         let arg_name = DartCodeOracle::var_name(arg.name());
 
 
         quote!($arg_type $arg_name)
-    }).into_iter().collect::<Vec<_>>();
+    }).collect::<Vec<_>>();
 
     let ret_type = if let Some(ret) = method.return_type() {
         ret.as_renderable().render_type(ret, type_helper)
@@ -128,7 +129,15 @@ fn generate_callback_methods_definitions(method: &Method, type_helper: &dyn Type
     )
 }
 
-fn generate_callback_methods_signatures(callback_name: &str, methods: &Vec<&Method>, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+/// Unwraps a type label, reporting a type the callback method `owner` can't use.
+fn reported_label(label: Result<dart::Tokens>, owner: &str, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+    label.unwrap_or_else(|e| {
+        type_helper.report_unsupported(owner, &e.to_string());
+        quote!()
+    })
+}
+
+fn generate_callback_methods_signatures(callback_name: &str, methods: &Vec<&Method>, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
     let mut tokens = dart::Tokens::new();
     for (method_index, method) in methods.iter().enumerate() {
         //let method_name = DartCodeOracle::fn_name(method.name());
@@ -145,18 +154,19 @@ fn generate_callback_methods_signatures(callback_name: &str, methods: &Vec<&Meth
             method_index
         );
         
+        let owner = &format!("method `{}.{}`", callback_name, method.name());
         let method_return_type = if let Some(ret) = method.return_type() {
-            DartCodeOracle::native_type_label(Some(ret))
+            reported_label(DartCodeOracle::native_type_label(Some(ret)), owner, type_helper)
         } else {
             quote!(Void)
         };
 
         tokens.append(quote! {
             typedef $ffi_method_type = Void Function(
-                Uint64, $(for arg in &method.arguments() => $(DartCodeOracle::native_type_label(Some(&arg.as_type()))),)
+                Uint64, $(for arg in &method.arguments() => $(reported_label(DartCodeOracle::native_type_label(Some(&arg.as_type())), owner, type_helper)),)
                 Pointer<$(&method_return_type)>, Pointer<RustCallStatus>);
             typedef $dart_method_type = void Function(
-                int, $(for arg in &method.arguments() => $(DartCodeOracle::native_dart_type_label(Some(&arg.as_type()))),)
+                int, $(for arg in &method.arguments() => $(reported_label(DartCodeOracle::native_dart_type_label(Some(&arg.as_type())), owner, type_helper)),)
                 Pointer<$(&method_return_type)>, Pointer<RustCallStatus>);
         });
     }
@@ -184,19 +194,20 @@ fn generate_callback_vtable_interface(callback: &CallbackInterface, _type_helper
     }
 }
 
-fn generate_callback_functions(callback: &CallbackInterface, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {    
+fn generate_callback_functions(callback: &CallbackInterface, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {    
     let cls_name = &DartCodeOracle::class_name(callback.name());
     let methods = callback.methods();
 
     let functions: Vec<dart::Tokens> = methods.iter().enumerate().map(|(index, m)| {
-        let method_name = &format!("{}", &DartCodeOracle::fn_name(m.name()));
+        let method_name = &DartCodeOracle::fn_name(m.name());
         let ffi_method_type = &format!("UniffiCallbackInterface{}Method{}", callback.name(), index);
         let _dart_method_type = &format!("UniffiCallbackInterface{}Method{}Dart", callback.name(), index);
 
         // Get parameter types using the oracle
         let param_types: Vec<dart::Tokens> = m.arguments().iter().map(|arg| {
             let arg_name = DartCodeOracle::var_name(arg.name());
-            DartCodeOracle::callback_param_type(&arg.as_type(), &arg_name)
+            let owner = &format!("method `{}.{}`", callback.name(), m.name());
+            reported_label(DartCodeOracle::callback_param_type(&arg.as_type(), &arg_name), owner, type_helper)
         }).collect();
 
         // Get argument lifts using the oracle
@@ -1,7 +1,7 @@
 use genco::lang::dart;
 use genco::prelude::*;
 use paste::paste;
-use uniffi_bindgen::backend::CodeType;
+use uniffi_bindgen::backend::{CodeType, Literal};
 use uniffi_bindgen::interface::Type;
 
 use super::oracle::{AsCodeType, DartCodeOracle};
@@ -33,6 +33,15 @@ macro_rules! impl_code_type_for_compound {
                 fn canonical_name(&self) -> String {
                     format!($canonical_name_pattern, DartCodeOracle::find(self.inner()).canonical_name())
                 }
+
+                fn literal(&self, literal: &Literal) -> String {
+                    match literal {
+                        Literal::None => "null".into(),
+                        Literal::Some { inner } => DartCodeOracle::find(self.inner()).literal(inner),
+                        Literal::EmptySequence => "const []".into(),
+                        _ => unreachable!("Invalid literal for {}: {literal:?}", self.type_label()),
+                    }
+                }
             }
         }
     }
@@ -43,14 +52,13 @@ macro_rules! impl_renderable_for_compound {
        paste! {
             impl Renderable for $T {
                 fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-                    type_helper.include_once_check($canonical_name_pattern, &self.self_type);
+                    type_helper.include_once_check(&self.canonical_name(), &self.self_type);
                     let inner_codetype = DartCodeOracle::find(self.inner());
-                    let inner_type_label = inner_codetype.type_label();
 
                     type_helper.include_once_check(&inner_codetype.canonical_name(), &self.inner()); // Add the Inner FFI Converter
 
                     let cl_name = &format!($canonical_name_pattern, inner_codetype.canonical_name());
-                    let type_label = &format!($type_label_pattern, &inner_type_label);
+                    let type_label = &self.render_type(&self.self_type, type_helper);
 
                     let inner_cl_converter_name = &inner_codetype.ffi_converter_name();
                     let inner_data_type = &inner_codetype.canonical_name().replace("UInt", "Uint").replace("Double", "Float");
@@ -61,7 +69,7 @@ macro_rules! impl_renderable_for_compound {
                         class $cl_name {
 
                             static $type_label lift( RustBuffer buf) {
-                                return $cl_name.read(buf.asUint8List()).value;
+                                return liftFromRustBuffer(buf, (data) => $cl_name.read(data).value);
                             }
 
                             static LiftRetVal<$type_label> read( Uint8List buf) {
@@ -84,17 +92,9 @@ macro_rules! impl_renderable_for_compound {
                                     return toRustBuffer(Uint8List.fromList([0]));
                                 }
 
-                                final length = $cl_name.allocationSize(value);
-
-                                final Pointer<Uint8> frameData = calloc<Uint8>(length); // Allocate a pointer large enough.
-                                final buf = frameData.asTypedList(length); // Create a list that uses our pointer to copy in the data.
-
+                                final buf = Uint8List($cl_name.allocationSize(value));
                                 $cl_name.write(value, buf);
-
-                                final bytes = calloc<ForeignBytes>();
-                                bytes.ref.len = length;
-                                bytes.ref.data = frameData;
-                                return RustBuffer.fromBytes(bytes.ref);
+                                return toRustBuffer(buf);
                             }
 
                             static int write( $type_label value, Uint8List buf) {
@@ -119,14 +119,13 @@ macro_rules! impl_renderable_for_compound {
             impl Renderable for SequenceCodeType {
                 fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
 
-                    type_helper.include_once_check($canonical_name_pattern, &self.self_type);
+                    type_helper.include_once_check(&self.canonical_name(), &self.self_type);
                     let inner_codetype = self.inner().as_codetype();
-                    let inner_type_label = inner_codetype.type_label();
 
                     type_helper.include_once_check(&inner_codetype.canonical_name(), &self.inner()); // Add the Inner FFI Converter
 
                     let cl_name = &format!($canonical_name_pattern, inner_codetype.canonical_name());
-                    let type_label = &format!("List<{}>", &inner_type_label);
+                    let type_label = &self.render_type(&self.self_type, type_helper);
 
                     let inner_cl_converter_name = &inner_codetype.ffi_converter_name();
                     let inner_data_type = &inner_codetype.canonical_name().replace("UInt", "Uint").replace("Double", "Float");
@@ -137,7 +136,7 @@ macro_rules! impl_renderable_for_compound {
                         class $cl_name {
 
                             static $type_label lift( RustBuffer buf) {
-                                return $cl_name.read(buf.asUint8List()).value;
+                                return liftFromRustBuffer(buf, (data) => $cl_name.read(data).value);
                             }
 
                             static LiftRetVal<$type_label> read( Uint8List buf) {
@@ -161,7 +160,7 @@ macro_rules! impl_renderable_for_compound {
                                 return offset - buf.offsetInBytes;
                             }
                             static int allocationSize($type_label value) {
-                                return value.map((l) => $inner_cl_converter_name.allocationSize(l)).reduce((a, b) => a + b) + 4;
+                                return value.map((l) => $inner_cl_converter_name.allocationSize(l)).fold(4, (a, b) => a + b);
                             }
 
                             static RustBuffer lower( $type_label value) {
@@ -182,3 +181,117 @@ impl_code_type_for_compound!(SequenceCodeType, "List<{}>", "Sequence{}");
 
 impl_renderable_for_compound!(OptionalCodeType, "{}?", "FfiConverterOptional{}");
 impl_renderable_for_compound!(SequenceCodeType, "FfiConverterSequence{}");
+
+#[derive(Debug)]
+pub struct MapCodeType {
+    self_type: Type,
+    key: Type,
+    value: Type,
+}
+
+impl MapCodeType {
+    pub fn new(self_type: Type, key: Type, value: Type) -> Self {
+        Self {
+            self_type,
+            key,
+            value,
+        }
+    }
+
+    fn key(&self) -> &Type {
+        &self.key
+    }
+
+    fn value(&self) -> &Type {
+        &self.value
+    }
+}
+
+impl CodeType for MapCodeType {
+    fn type_label(&self) -> String {
+        format!(
+            "Map<{}, {}>",
+            DartCodeOracle::find(self.key()).type_label(),
+            DartCodeOracle::find(self.value()).type_label()
+        )
+    }
+
+    fn canonical_name(&self) -> String {
+        format!(
+            "Map{}{}",
+            DartCodeOracle::find(self.key()).canonical_name(),
+            DartCodeOracle::find(self.value()).canonical_name()
+        )
+    }
+
+    fn literal(&self, literal: &Literal) -> String {
+        match literal {
+            Literal::EmptyMap => "const {}".into(),
+            _ => unreachable!("Invalid literal for {}: {literal:?}", self.type_label()),
+        }
+    }
+}
+
+impl Renderable for MapCodeType {
+    fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+        type_helper.include_once_check(&self.canonical_name(), &self.self_type);
+        let key_codetype = self.key().as_codetype();
+        let value_codetype = self.value().as_codetype();
+
+        // Add the key and value FFI Converters
+        type_helper.include_once_check(&key_codetype.canonical_name(), self.key());
+        type_helper.include_once_check(&value_codetype.canonical_name(), self.value());
+
+        let cl_name = &self.ffi_converter_name();
+        let type_label = &self.render_type(&self.self_type, type_helper);
+        let key_type_label = &self.render_type(self.key(), type_helper);
+        let value_type_label = &self.render_type(self.value(), type_helper);
+        let key_cl_converter_name = &key_codetype.ffi_converter_name();
+        let value_cl_converter_name = &value_codetype.ffi_converter_name();
+
+        quote! {
+            class $cl_name {
+
+                static $type_label lift( RustBuffer buf) {
+                    return liftFromRustBuffer(buf, (data) => $cl_name.read(data).value);
+                }
+
+                static LiftRetVal<$type_label> read( Uint8List buf) {
+                    final $type_label res = <$key_type_label, $value_type_label>{};
+                    final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+                    int offset = buf.offsetInBytes + 4;
+                    for (var i = 0; i < length; i++) {
+                        final key = $key_cl_converter_name.read(Uint8List.view(buf.buffer, offset));
+                        offset += key.bytesRead;
+                        final value = $value_cl_converter_name.read(Uint8List.view(buf.buffer, offset));
+                        offset += value.bytesRead;
+                        res[key.value] = value.value;
+                    }
+                    return LiftRetVal(res, offset - buf.offsetInBytes);
+                }
+
+                static int write( $type_label value, Uint8List buf) {
+                    buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+                    int offset = buf.offsetInBytes + 4;
+                    for (final entry in value.entries) {
+                        offset += $key_cl_converter_name.write(entry.key, Uint8List.view(buf.buffer, offset));
+                        offset += $value_cl_converter_name.write(entry.value, Uint8List.view(buf.buffer, offset));
+                    }
+                    return offset - buf.offsetInBytes;
+                }
+
+                static int allocationSize($type_label value) {
+                    return value.entries
+                        .map((e) => $key_cl_converter_name.allocationSize(e.key) + $value_cl_converter_name.allocationSize(e.value))
+                        .fold(4, (a, b) => a + b);
+                }
+
+                static RustBuffer lower( $type_label value) {
+                    final buf = Uint8List(allocationSize(value));
+                    write(value, buf);
+                    return toRustBuffer(buf);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use genco::prelude::*;
+use uniffi_bindgen::backend::CodeType;
+use uniffi_bindgen::interface::Type;
+
+use super::oracle::{AsCodeType, DartCodeOracle};
+use super::render::{Renderable, TypeHelperRenderer};
+
+#[derive(Debug)]
+pub struct CustomCodeType {
+    name: String,
+    builtin: Type,
+}
+
+impl CustomCodeType {
+    pub fn new(name: String, builtin: Type) -> Self {
+        Self { name, builtin }
+    }
+}
+
+impl CodeType for CustomCodeType {
+    fn type_label(&self) -> String {
+        DartCodeOracle::class_name(&self.name)
+    }
+
+    fn canonical_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl Renderable for CustomCodeType {
+    fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+        generate_custom_type(self, type_helper)
+    }
+}
+
+/// Without a `[bindings.dart.custom_types.<Name>]` section the custom type is an alias of its
+/// builtin type, otherwise it is converted using the configured `into_custom`/`from_custom`.
+fn generate_custom_type(custom: &CustomCodeType, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+    let builtin_codetype = custom.builtin.as_codetype();
+    // Add the builtin FFI Converter
+    type_helper.include_once_check(&builtin_codetype.canonical_name(), &custom.builtin);
+
+    let type_label = &custom.type_label();
+    let ffi_converter_name = &custom.ffi_converter_name();
+    let builtin_converter = &builtin_codetype.ffi_converter_name();
+    let ffi_type = &DartCodeOracle::native_dart_type_label(Some(&custom.builtin)).unwrap_or_else(|e| {
+        type_helper.report_unsupported(&format!("custom type `{}`", custom.name), &e.to_string());
+        quote!()
+    });
+
+    let config = type_helper.get_config().custom_type(&custom.name);
+    let alias = match config {
+        None => quote!(typedef $type_label = $(custom.render_type(&custom.builtin, type_helper));),
+        Some(config) => match config.type_name() {
+            Some(type_name) => quote!(typedef $type_label = $type_name;),
+            // The configured imports are expected to provide a type with the custom type's name
+            None => quote!(),
+        },
+    };
+    let into_custom = |value: &str| config.map_or(value.to_string(), |c| c.into_custom(value));
+    let from_custom = |value: &str| config.map_or(value.to_string(), |c| c.from_custom(value));
+
+    quote! {
+        $alias
+
+        class $ffi_converter_name {
+            static $type_label lift( $ffi_type value) {
+                final builtin = $builtin_converter.lift(value);
+                return $(into_custom("builtin"));
+            }
+
+            static $ffi_type lower( $type_label value) {
+                final builtin = $(from_custom("value"));
+                return $builtin_converter.lower(builtin);
+            }
+
+            static LiftRetVal<$type_label> read( Uint8List buf) {
+                final builtin = $builtin_converter.read(buf);
+                return LiftRetVal($(into_custom("builtin.value")), builtin.bytesRead);
+            }
+
+            static int allocationSize($type_label value) {
+                return $builtin_converter.allocationSize($(from_custom("value")));
+            }
+
+            static int write( $type_label value, Uint8List buf) {
+                return $builtin_converter.write($(from_custom("value")), buf);
+            }
+        }
+    }
+}
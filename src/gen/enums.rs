@@ -1,6 +1,6 @@
 use genco::prelude::*;
 use uniffi_bindgen::backend::{CodeType, Literal};
-use uniffi_bindgen::interface::{AsType, Enum};
+use uniffi_bindgen::interface::{AsType, Enum, Type};
 
 use super::oracle::{AsCodeType, DartCodeOracle};
 use super::render::{AsRenderable, Renderable, TypeHelperRenderer};
@@ -26,14 +26,15 @@ impl CodeType for EnumCodeType {
     }
 
     fn literal(&self, literal: &Literal) -> String {
+        // Only flat enums have literals, and those are rendered as a Dart `enum`
         if let Literal::Enum(v, _) = literal {
             format!(
-                "{}{}",
+                "{}.{}",
                 self.type_label(),
                 DartCodeOracle::enum_variant_name(v)
             )
         } else {
-            unreachable!();
+            unreachable!("Invalid literal for {}: {literal:?}", self.type_label());
         }
     }
 
@@ -49,16 +50,32 @@ impl Renderable for EnumCodeType {
         } else if let Some(enum_) = type_helper.get_enum(&self.id) {
             generate_enum(enum_, type_helper)
         } else {
-            unreachable!()
+            type_helper.report_unsupported(
+                &format!("enum `{}`", self.id),
+                "no definition found in this component",
+            );
+            quote!()
         }
     }
 }
 
 pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-    let cls_name = &obj.as_codetype().canonical_name();
     let dart_cls_name = &DartCodeOracle::class_name(obj.name());
     let ffi_converter_name = &obj.as_codetype().ffi_converter_name();
-    if obj.is_flat() {
+    let is_error = type_helper.get_ci().is_name_used_as_error(obj.name());
+    let error_handler = if is_error {
+        generate_error_handler(obj.name(), ffi_converter_name)
+    } else {
+        quote!()
+    };
+
+    if obj.is_flat() && is_error {
+        quote! {
+            $(generate_flat_error(obj, type_helper))
+
+            $error_handler
+        }
+    } else if obj.is_flat() {
         quote! {
             enum $dart_cls_name {
                 $(for variant in obj.variants() =>
@@ -68,11 +85,15 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
 
             class $ffi_converter_name {
                 static $dart_cls_name lift( RustBuffer buffer) {
-                    final index = buffer.asUint8List().buffer.asByteData().getInt32(0);
+                    return liftFromRustBuffer(buffer, (data) => $ffi_converter_name.read(data).value);
+                }
+
+                static LiftRetVal<$dart_cls_name> read( Uint8List buf) {
+                    final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
                     switch(index) {
                         $(for (index, variant) in obj.variants().iter().enumerate() =>
                         case $(index + 1):
-                            return $dart_cls_name.$(DartCodeOracle::enum_variant_name(variant.name()));
+                            return LiftRetVal($dart_cls_name.$(DartCodeOracle::enum_variant_name(variant.name())), 4);
                         )
                         default:
                             throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
@@ -80,7 +101,18 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
                 }
 
                 static RustBuffer lower( $dart_cls_name input) {
-                    return toRustBuffer(createUint8ListFromInt(input.index + 1));
+                    final buf = Uint8List(allocationSize(input));
+                    write(input, buf);
+                    return toRustBuffer(buf);
+                }
+
+                static int allocationSize($dart_cls_name value) {
+                    return 4;
+                }
+
+                static int write( $dart_cls_name value, Uint8List buf) {
+                    buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.index + 1);
+                    return 4;
                 }
             }
         }
@@ -91,12 +123,11 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
             for f in variant_obj.fields() {
                 type_helper.include_once_check(&f.as_codetype().canonical_name(), &f.as_type());
             }
-            let variant_dart_cls_name = &format!("{}{}", DartCodeOracle::class_name(variant_obj.name()), dart_cls_name);
+            let variant_dart_cls_name = &DartCodeOracle::variant_class_name(variant_obj.name(), obj.name());
             
             // Prepare constructor parameters
             let constructor_params = variant_obj.fields().iter().map(|field| {
                 let param_name = DartCodeOracle::var_name(field.name());
-                let param_type = field.as_type().as_renderable().render_type(&field.as_type(), type_helper);
                 if variant_obj.fields().len() > 1 {
                     quote!(required this.$param_name)
                 } else {
@@ -130,7 +161,7 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
                         )
                         return LiftRetVal($variant_dart_cls_name._(
                             $(for f in variant_obj.fields() => $(DartCodeOracle::var_name(f.name())),)
-                        ), new_offset);
+                        ), new_offset - buf.offsetInBytes);
                     }
 
                     @override
@@ -154,13 +185,13 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
                         new_offset += $(f.as_type().as_codetype().ffi_converter_name()).write($(DartCodeOracle::var_name(f.name())), Uint8List.view(buf.buffer, new_offset));
                         )
 
-                        return new_offset;
+                        return new_offset - buf.offsetInBytes;
                     }
                 }
             });
         }
 
-        let implements_exception = if dart_cls_name.ends_with("Exception") {
+        let implements_exception = if is_error || dart_cls_name.ends_with("Exception") {
             quote!( implements Exception)
         } else {
             quote!()
@@ -175,7 +206,7 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
 
             class $ffi_converter_name {
                 static $dart_cls_name lift( RustBuffer buffer) {
-                    return $ffi_converter_name.read(buffer.asUint8List()).value;
+                    return liftFromRustBuffer(buffer, (data) => $ffi_converter_name.read(data).value);
                 }
 
                 static LiftRetVal<$dart_cls_name> read( Uint8List buf) {
@@ -184,7 +215,7 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
                     switch(index) {
                         $(for (index, variant) in obj.variants().iter().enumerate() =>
                         case $(index + 1):
-                            return $(format!("{}{}", DartCodeOracle::class_name(variant.name()), dart_cls_name)).read(subview);
+                            return $(DartCodeOracle::variant_class_name(variant.name(), obj.name())).read(subview).copyWithOffset(4);
                         )
                         default:  throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
                     }
@@ -204,6 +235,110 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
             }
 
             $(variants)
+
+            $error_handler
+        }
+    }
+}
+
+/// Flat errors cross the FFI as the variant index followed by the Rust `Display` string of the
+/// error, so they are rendered as an exception hierarchy carrying that message.
+fn generate_flat_error(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+    let dart_cls_name = &DartCodeOracle::class_name(obj.name());
+    let ffi_converter_name = &obj.as_codetype().ffi_converter_name();
+    type_helper.include_once_check(&Type::String.as_codetype().canonical_name(), &Type::String);
+
+    let variants = obj.variants().iter().enumerate().map(|(index, variant)| {
+        let variant_dart_cls_name = &DartCodeOracle::variant_class_name(variant.name(), obj.name());
+
+        quote! {
+            class $variant_dart_cls_name extends $dart_cls_name {
+                $variant_dart_cls_name(String message) : super(message);
+
+                @override
+                RustBuffer lower() {
+                    final buf = Uint8List(allocationSize());
+                    write(buf);
+                    return toRustBuffer(buf);
+                }
+
+                @override
+                int allocationSize() {
+                    return FfiConverterString.allocationSize(message) + 4;
+                }
+
+                @override
+                int write( Uint8List buf) {
+                    buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, $(index + 1)); // write index into first position;
+                    return FfiConverterString.write(message, Uint8List.view(buf.buffer, buf.offsetInBytes + 4)) + 4;
+                }
+
+                @override
+                String toString() {
+                    return $(format!("\"{}.{}: $message\"", dart_cls_name, variant.name()));
+                }
+            }
+        }
+    });
+
+    quote! {
+        abstract class $dart_cls_name implements Exception {
+            final String message;
+
+            $dart_cls_name(this.message);
+
+            RustBuffer lower();
+            int allocationSize();
+            int write( Uint8List buf);
+        }
+
+        class $ffi_converter_name {
+            static $dart_cls_name lift( RustBuffer buffer) {
+                return liftFromRustBuffer(buffer, (data) => $ffi_converter_name.read(data).value);
+            }
+
+            static LiftRetVal<$dart_cls_name> read( Uint8List buf) {
+                final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+                // The variant index is followed by the message produced by the Rust `Display` impl
+                final message = FfiConverterString.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 4));
+                switch(index) {
+                    $(for (index, variant) in obj.variants().iter().enumerate() =>
+                    case $(index + 1):
+                        return LiftRetVal($(DartCodeOracle::variant_class_name(variant.name(), obj.name()))(message.value), message.bytesRead + 4);
+                    )
+                    default:
+                        throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+                }
+            }
+
+            static RustBuffer lower( $dart_cls_name value) {
+                return value.lower();
+            }
+
+            static int allocationSize($dart_cls_name value) {
+                return value.allocationSize();
+            }
+
+            static int write( $dart_cls_name value, Uint8List buf) {
+                return value.write(buf);
+            }
+        }
+
+        $(for variant in variants => $variant)
+    }
+}
+
+/// Generates the `UniffiRustCallStatusErrorHandler` that lifts the error buffer of a failed
+/// call into the Dart exception for the error type `name`.
+pub fn generate_error_handler(name: &str, ffi_converter_name: &str) -> dart::Tokens {
+    let handler_name = &DartCodeOracle::error_handler_name(name);
+
+    quote! {
+        class $handler_name extends UniffiRustCallStatusErrorHandler {
+            @override
+            Exception lift(RustBuffer errorBuf) {
+                return $ffi_converter_name.lift(errorBuf);
+            }
         }
     }
 }
@@ -0,0 +1,113 @@
+use genco::prelude::*;
+use uniffi_bindgen::backend::CodeType;
+use uniffi_bindgen::interface::ExternalKind;
+
+use super::oracle::DartCodeOracle;
+use super::render::{Renderable, TypeHelperRenderer};
+
+/// A type defined by another crate. It is used from that crate's generated library, imported
+/// under the crate's namespace, and lifted and lowered through that library's converters.
+#[derive(Debug)]
+pub struct ExternalCodeType {
+    name: String,
+    namespace: String,
+    kind: ExternalKind,
+}
+
+impl ExternalCodeType {
+    pub fn new(name: String, namespace: String, kind: ExternalKind) -> Self {
+        Self {
+            name,
+            namespace,
+            kind,
+        }
+    }
+
+    fn class_name(&self) -> String {
+        DartCodeOracle::class_name(&self.name)
+    }
+
+    /// The converter of the type in its own library.
+    fn external_converter_name(&self) -> String {
+        format!("{}.FfiConverter{}", self.namespace, self.class_name())
+    }
+}
+
+impl CodeType for ExternalCodeType {
+    fn type_label(&self) -> String {
+        format!("{}.{}", self.namespace, self.class_name())
+    }
+
+    fn canonical_name(&self) -> String {
+        format!("External{}", self.class_name())
+    }
+}
+
+impl Renderable for ExternalCodeType {
+    fn render_type_helper(&self, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+        let cl_name = &self.ffi_converter_name();
+        let type_label = &self.type_label();
+
+        match self.kind {
+            // Objects are passed by pointer, which both libraries agree on. Only the object's own
+            // library can lift a trait interface, whose class is abstract, and library mode
+            // reports those as `Interface` too, so every object goes through that library's converter
+            ExternalKind::Interface | ExternalKind::Trait => {
+                let external_converter = &self.external_converter_name();
+                quote! {
+                    class $cl_name {
+                        static $type_label lift( Pointer<Void> value) {
+                            return $external_converter.lift(value);
+                        }
+
+                        static Pointer<Void> lower( $type_label value) {
+                            return $external_converter.lower(value);
+                        }
+
+                        static LiftRetVal<$type_label> read( Uint8List buf) {
+                            return $external_converter.read(buf);
+                        }
+
+                        static int allocationSize([$type_label? value]) {
+                            return 8;
+                        }
+
+                        static int write( $type_label value, Uint8List buf) {
+                            return $external_converter.write(value, buf);
+                        }
+                    }
+                }
+            }
+            // Each library has its own `RustBuffer`, so go through the serialized bytes
+            ExternalKind::DataClass => {
+                let external_converter = &self.external_converter_name();
+                quote! {
+                    class $cl_name {
+                        static $type_label lift( RustBuffer buf) {
+                            return liftFromRustBuffer(buf, (data) => $cl_name.read(data).value);
+                        }
+
+                        static RustBuffer lower( $type_label value) {
+                            final buf = Uint8List(allocationSize(value));
+                            write(value, buf);
+                            return toRustBuffer(buf);
+                        }
+
+                        static LiftRetVal<$type_label> read( Uint8List buf) {
+                            final lifted = $external_converter.read(buf);
+                            return LiftRetVal(lifted.value, lifted.bytesRead);
+                        }
+
+                        static int allocationSize($type_label value) {
+                            return $external_converter.allocationSize(value);
+                        }
+
+                        static int write( $type_label value, Uint8List buf) {
+                            return $external_converter.write(value, buf);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,15 +1,69 @@
 use genco::prelude::*;
-use uniffi_bindgen::interface::{AsType, Function};
+use uniffi_bindgen::interface::{Argument, AsType, Function};
 
-use crate::gen::oracle::DartCodeOracle;
+use crate::gen::oracle::{DartCodeOracle, DefaultValue};
 use crate::gen::render::AsRenderable;
 
 use super::oracle::AsCodeType;
 use super::render::TypeHelperRenderer;
 
+fn argument_default(arg: &Argument, type_helper: &dyn TypeHelperRenderer) -> Option<DefaultValue> {
+    arg.default_value()
+        .and_then(|literal| DartCodeOracle::default_value(&arg.as_type(), literal, type_helper.get_config()))
+}
+
+/// Renders the parameters of a function, method or constructor, named by `owner` in reports.
+/// Arguments with a default value become optional named parameters after the positional ones.
+pub fn generate_arguments(
+    args: &[&Argument],
+    owner: &str,
+    type_helper: &dyn TypeHelperRenderer,
+) -> dart::Tokens {
+    let mut positional = quote!();
+    let mut named = quote!();
+    for arg in args {
+        let ty = arg.as_type();
+        let type_label = arg.as_renderable().render_type(&ty, type_helper);
+        let name = DartCodeOracle::var_name(arg.name());
+        match argument_default(arg, type_helper) {
+            Some(DefaultValue::Const(default)) => named.append(quote!($type_label $name = $default,)),
+            Some(DefaultValue::Fallback(_)) => named.append(quote!($type_label? $name,)),
+            None => {
+                if arg.default_value().is_some() {
+                    type_helper.report_unsupported(
+                        &format!("argument `{}` of {owner}", arg.name()),
+                        "a `BigInt` default value for an optional `u64`",
+                    );
+                }
+                positional.append(quote!($type_label $name,))
+            }
+        }
+    }
+    if named.is_empty() {
+        positional
+    } else {
+        quote!($positional {$named})
+    }
+}
+
+/// Lowers an argument for the FFI call, replacing `null` with the default value of a parameter
+/// that couldn't declare it, see [`DefaultValue::Fallback`].
+pub fn lower_argument(arg: &Argument, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+    let name = DartCodeOracle::var_name(arg.name());
+    let value = match argument_default(arg, type_helper) {
+        Some(DefaultValue::Fallback(default)) => quote!(($name ?? $default)),
+        _ => quote!($name),
+    };
+    DartCodeOracle::type_lower_fn(&arg.as_type(), value)
+}
+
 pub fn generate_function(func: &Function, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
     // if func.takes_self() {} // TODO: Do something about this condition
-    let args = quote!($(for arg in &func.arguments() => $(&arg.as_renderable().render_type(&arg.as_type(), type_helper)) $(DartCodeOracle::var_name(arg.name())),));
+    let args = generate_arguments(
+        &func.arguments(),
+        &format!("function `{}`", func.name()),
+        type_helper,
+    );
 
     let (ret, lifter) = if let Some(ret) = func.return_type() {
         (
@@ -25,23 +79,35 @@ pub fn generate_function(func: &Function, type_helper: &dyn TypeHelperRenderer)
             Future<$ret> $(DartCodeOracle::fn_name(func.name()))($args) {
                 return uniffiRustCallAsync(
                   () => $(DartCodeOracle::find_lib_instance()).$(func.ffi_func().name())(
-                    $(for arg in &func.arguments() => $(DartCodeOracle::type_lower_fn(&arg.as_type(), quote!($(DartCodeOracle::var_name(arg.name()))))),)
+                    $(for arg in &func.arguments() => $(lower_argument(arg, type_helper)),)
                   ),
                   $(DartCodeOracle::async_poll(func, type_helper.get_ci())),
                   $(DartCodeOracle::async_complete(func, type_helper.get_ci())),
                   $(DartCodeOracle::async_free(func, type_helper.get_ci())),
                   $lifter,
+                  $(DartCodeOracle::error_handler(func.throws_type())),
                 );
             }
 
         )
     } else {
-        quote!(
-            $ret $(DartCodeOracle::fn_name(func.name()))($args) {
-                return rustCall((status) => $lifter($(DartCodeOracle::find_lib_instance()).$(func.ffi_func().name())(
-                    $(for arg in &func.arguments() => $(DartCodeOracle::type_lower_fn(&arg.as_type(), quote!($(DartCodeOracle::var_name(arg.name()))))),) status
-                )));
-            }
-        )
+        let error_handler = &DartCodeOracle::error_handler(func.throws_type());
+        let ffi_call = quote!(rustCall((uniffiStatus) => $(DartCodeOracle::find_lib_instance()).$(func.ffi_func().name())(
+            $(for arg in &func.arguments() => $(lower_argument(arg, type_helper)),) uniffiStatus
+        ), $error_handler));
+
+        if let Some(ret_type) = func.return_type() {
+            quote!(
+                $ret $(DartCodeOracle::fn_name(func.name()))($args) {
+                    return $(DartCodeOracle::type_lift_fn(ret_type, ffi_call));
+                }
+            )
+        } else {
+            quote!(
+                void $(DartCodeOracle::fn_name(func.name()))($args) {
+                    $ffi_call;
+                }
+            )
+        }
     }
 }
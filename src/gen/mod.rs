@@ -1,36 +1,101 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::process::Command;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use camino::Utf8Path;
 
 use genco::fmt;
 use genco::prelude::*;
 use serde::{Deserialize, Serialize};
 // use uniffi_bindgen::MergeWith;
-use self::render::Renderer;
+use self::render::{Renderer, TypeHelperRenderer};
 use self::types::TypeHelpersRenderer;
 use crate::gen::oracle::DartCodeOracle;
+use uniffi_bindgen::interface::FfiType;
 use uniffi_bindgen::{BindingGenerator, BindingsConfig, ComponentInterface};
 
 mod callback_interface;
 mod compounds;
+mod custom;
 mod enums;
+mod external;
 mod functions;
 mod objects;
-mod oracle;
+pub(crate) mod oracle;
 mod primitives;
 mod records;
 mod render;
 pub mod stream;
 mod types;
 
+/// The contents of a crate's `uniffi.toml`, of which we only care about the `[bindings.dart]` table.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ConfigFile {
+    #[serde(default)]
+    bindings: BindingsSection,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct BindingsSection {
+    #[serde(default)]
+    dart: Config,
+}
+
+impl ConfigFile {
+    pub fn dart(&self) -> &Config {
+        &self.bindings.dart
+    }
+}
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Config {
     package_name: Option<String>,
     cdylib_name: Option<String>,
     #[serde(default)]
     external_packages: HashMap<String, String>,
+    #[serde(default)]
+    custom_types: HashMap<String, CustomTypeConfig>,
+    format_code: Option<bool>,
+    u64_as_bigint: Option<bool>,
+    replaceable_allocator: Option<bool>,
+}
+
+/// Configuration of a `[Custom]` type, in `[bindings.dart.custom_types.<Name>]`.
+///
+/// `into_custom` and `from_custom` are Dart expressions where `{}` is replaced by the value to
+/// convert. Without them the custom type stays an alias of its builtin type.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CustomTypeConfig {
+    type_name: Option<String>,
+    #[serde(default)]
+    imports: Vec<String>,
+    into_custom: Option<String>,
+    from_custom: Option<String>,
+}
+
+impl CustomTypeConfig {
+    pub fn type_name(&self) -> Option<&str> {
+        self.type_name.as_deref()
+    }
+
+    pub fn imports(&self) -> &[String] {
+        &self.imports
+    }
+
+    pub fn into_custom(&self, value: &str) -> String {
+        match &self.into_custom {
+            Some(expr) => expr.replace("{}", value),
+            None => value.to_string(),
+        }
+    }
+
+    pub fn from_custom(&self, value: &str) -> String {
+        match &self.from_custom {
+            Some(expr) => expr.replace("{}", value),
+            None => value.to_string(),
+        }
+    }
 }
 
 impl From<&ComponentInterface> for Config {
@@ -39,6 +104,10 @@ impl From<&ComponentInterface> for Config {
             package_name: Some(ci.namespace().to_owned()),
             cdylib_name: Some(ci.namespace().to_owned()),
             external_packages: HashMap::new(),
+            custom_types: HashMap::new(),
+            format_code: None,
+            u64_as_bigint: None,
+            replaceable_allocator: None,
         }
     }
 }
@@ -59,6 +128,62 @@ impl Config {
             "uniffi".into()
         }
     }
+
+    /// Whether to run `dart format` on the generated bindings, on by default.
+    pub fn format_code(&self) -> bool {
+        self.format_code.unwrap_or(true)
+    }
+
+    /// Whether `u64` is exposed as a Dart `BigInt` rather than an `int`, which can't hold values
+    /// above `i64::MAX`. Off by default.
+    pub fn u64_as_bigint(&self) -> bool {
+        self.u64_as_bigint.unwrap_or(false)
+    }
+
+    /// Whether the bindings export a `uniffiAllocator` setter replacing the allocator of the
+    /// native memory they pass to Rust, e.g. to count what is still allocated in a test. Off by
+    /// default.
+    pub fn replaceable_allocator(&self) -> bool {
+        self.replaceable_allocator.unwrap_or(false)
+    }
+
+    /// The Dart import of the library generated for an external crate. Configured
+    /// `external_packages` entries are either a complete import (ending in `.dart`) or the name of
+    /// a library generated next to this one; by default that is the crate's namespace.
+    pub fn external_import(&self, crate_name: &str, namespace: &str) -> String {
+        match self.external_packages.get(crate_name) {
+            Some(import) if import.ends_with(".dart") => import.clone(),
+            Some(package_name) => format!("{package_name}.dart"),
+            None => format!("{namespace}.dart"),
+        }
+    }
+
+    pub fn custom_type(&self, name: &str) -> Option<&CustomTypeConfig> {
+        self.custom_types.get(name)
+    }
+
+    pub fn custom_types(&self) -> impl Iterator<Item = &CustomTypeConfig> {
+        self.custom_types.values()
+    }
+}
+
+impl BindingsConfig for ConfigFile {
+    fn update_from_ci(&mut self, ci: &ComponentInterface) {
+        self.bindings.dart.update_from_ci(ci);
+    }
+
+    fn update_from_cdylib_name(&mut self, cdylib_name: &str) {
+        self.bindings.dart.update_from_cdylib_name(cdylib_name);
+    }
+
+    fn update_from_dependency_configs(&mut self, config_map: HashMap<&str, &Self>) {
+        self.bindings.dart.update_from_dependency_configs(
+            config_map
+                .into_iter()
+                .map(|(crate_name, config)| (crate_name, config.dart()))
+                .collect(),
+        );
+    }
 }
 
 impl BindingsConfig for Config {
@@ -88,7 +213,7 @@ pub struct DartWrapper<'a> {
 
 impl<'a> DartWrapper<'a> {
     pub fn new(ci: &'a ComponentInterface, config: &'a Config) -> Self {
-        let type_renderer = TypeHelpersRenderer::new(ci);
+        let type_renderer = TypeHelpersRenderer::new(ci, config);
         DartWrapper {
             ci,
             config,
@@ -96,13 +221,43 @@ impl<'a> DartWrapper<'a> {
         }
     }
 
-    fn generate(&self) -> dart::Tokens {
+    fn generate(&self) -> Result<dart::Tokens> {
         let package_name = self.config.package_name();
         let libname = self.config.cdylib_name();
 
         let (type_helper_code, functions_definitions) = &self.type_renderer.render();
 
-        fn uniffi_function_definitions(ci: &ComponentInterface) -> dart::Tokens {
+        // The item an FFI function belongs to, as users know it, to name it in error reports
+        fn ffi_function_owners(ci: &ComponentInterface) -> HashMap<String, String> {
+            let mut owners = HashMap::new();
+            for fun in ci.function_definitions() {
+                owners.insert(fun.ffi_func().name().to_owned(), format!("function `{}`", fun.name()));
+            }
+            for obj in ci.object_definitions() {
+                for cons in obj.constructors() {
+                    owners.insert(
+                        cons.ffi_func().name().to_owned(),
+                        format!("constructor `{}.{}`", obj.name(), cons.name()),
+                    );
+                }
+                for meth in obj.methods() {
+                    owners.insert(
+                        meth.ffi_func().name().to_owned(),
+                        format!("method `{}.{}`", obj.name(), meth.name()),
+                    );
+                }
+                for fun in [obj.ffi_object_clone(), obj.ffi_object_free()] {
+                    owners.insert(fun.name().to_owned(), format!("object `{}`", obj.name()));
+                }
+            }
+            owners
+        }
+
+        fn uniffi_function_definitions(
+            ci: &ComponentInterface,
+            type_helper: &dyn TypeHelperRenderer,
+        ) -> dart::Tokens {
+            let owners = ffi_function_owners(ci);
             let mut definitions = quote!();
             let mut defined_functions = HashSet::new(); // Track defined function names
 
@@ -115,11 +270,28 @@ impl<'a> DartWrapper<'a> {
                     continue;
                 }
 
+                // Unsupported FFI types are reported, and the function is left out
+                let owner = owners
+                    .get(&fun_name)
+                    .cloned()
+                    .unwrap_or_else(|| format!("FFI function `{fun_name}`"));
+                let labels = |ffi_type: &FfiType| {
+                    DartCodeOracle::ffi_native_type_label(Some(ffi_type))
+                        .and_then(|native| {
+                            Ok((native, DartCodeOracle::ffi_dart_type_label(Some(ffi_type))?))
+                        })
+                        .map_err(|e| {
+                            type_helper
+                                .report_unsupported(&owner, &e.to_string())
+                        })
+                        .ok()
+                };
+
                 let (native_return_type, dart_return_type) = match fun.return_type() {
-                    Some(return_type) => (
-                        quote! { $(DartCodeOracle::ffi_native_type_label(Some(return_type))) },
-                        quote! { $(DartCodeOracle::ffi_dart_type_label(Some(return_type))) },
-                    ),
+                    Some(return_type) => match labels(return_type) {
+                        Some(labels) => labels,
+                        None => continue,
+                    },
                     None => (quote! { Void }, quote! { void }),
                 };
 
@@ -128,12 +300,11 @@ impl<'a> DartWrapper<'a> {
                     let mut dart_args = quote!();
 
                     for arg in fun.arguments() {
-                        native_args.append(
-                            quote!($(DartCodeOracle::ffi_native_type_label(Some(&arg.type_()))),),
-                        );
-                        dart_args.append(
-                            quote!($(DartCodeOracle::ffi_dart_type_label(Some(&arg.type_()))),),
-                        );
+                        let Some((native_arg, dart_arg)) = labels(&arg.type_()) else {
+                            continue;
+                        };
+                        native_args.append(quote!($native_arg,));
+                        dart_args.append(quote!($dart_arg,));
                     }
 
                     if fun.has_rust_call_status_arg() {
@@ -156,10 +327,25 @@ impl<'a> DartWrapper<'a> {
                 });
             }
 
+            // Exported by `uniffi_dart::finalizer`, for the finalizers of the objects
+            if !ci.object_definitions().is_empty() {
+                definitions.append(quote! {
+                    late final Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>) uniffi_dart_finalizer_token_new = _dylib.lookupFunction<
+                        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>),
+                        Pointer<Void> Function(Pointer<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>, Pointer<Void>)
+                    >("uniffi_dart_finalizer_token_new");
+                    late final void Function(Pointer<Void>) uniffi_dart_finalizer_token_free = _dylib.lookupFunction<
+                        Void Function(Pointer<Void>),
+                        void Function(Pointer<Void>)
+                    >("uniffi_dart_finalizer_token_free");
+                    late final Pointer<NativeFinalizerFunction> uniffi_dart_finalizer_run = _dylib.lookup("uniffi_dart_finalizer_run");
+                });
+            }
+
             definitions
         }
 
-        quote! {
+        let tokens = quote! {
             library $package_name;
 
             $(type_helper_code) // Imports, Types and Type Helper
@@ -182,7 +368,7 @@ impl<'a> DartWrapper<'a> {
 
                 static final _UniffiLib instance = _UniffiLib._();
 
-                $(uniffi_function_definitions(self.ci))
+                $(uniffi_function_definitions(self.ci, &self.type_renderer))
 
                 static void _checkApiVersion() {
                     final bindingsVersion = $(self.ci.uniffi_contract_version());
@@ -209,44 +395,76 @@ impl<'a> DartWrapper<'a> {
                 _UniffiLib._checkApiVersion();
                 _UniffiLib._checkApiChecksums();
             }
+        };
+
+        let unsupported = self.type_renderer.unsupported();
+        if !unsupported.is_empty() {
+            bail!(
+                "Unable to generate the Dart bindings for `{}`, found {} unsupported item(s):\n  - {}",
+                self.ci.namespace(),
+                unsupported.len(),
+                unsupported.join("\n  - ")
+            );
         }
+        Ok(tokens)
     }
 }
 
 pub struct DartBindingGenerator;
 
 impl BindingGenerator for DartBindingGenerator {
-    type Config = Config;
+    type Config = ConfigFile;
 
     fn write_bindings(
         &self,
         ci: &ComponentInterface,
         config: &Self::Config,
         out_dir: &Utf8Path,
-        _try_format_code: bool,
+        try_format_code: bool,
     ) -> Result<()> {
-        let filename = out_dir.join(format!("{}.dart", config.cdylib_name()));
-        let tokens = DartWrapper::new(ci, config).generate();
-        let file = std::fs::File::create(filename)?;
+        let config = config.dart();
+        let filename = out_dir.join(format!("{}.dart", config.package_name()));
+        let tokens = DartWrapper::new(ci, config).generate()?;
+        let file = std::fs::File::create(&filename)?;
 
         let mut w = fmt::IoWriter::new(file);
 
         let fmt = fmt::Config::from_lang::<Dart>().with_indentation(fmt::Indentation::Space(4));
-        let config = dart::Config::default();
+        let dart_config = dart::Config::default();
 
-        tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+        tokens.format_file(&mut w.as_formatter(&fmt), &dart_config)?;
+
+        if try_format_code && config.format_code() {
+            format_dart_file(&filename);
+        }
         Ok(())
     }
-    fn check_library_path(
-        &self,
-        _library_path: &Utf8Path,
-        _cdylib_name: Option<&str>,
-    ) -> Result<()> {
-        // FIXME: not sure what to check for here...?
+    fn check_library_path(&self, library_path: &Utf8Path, cdylib_name: Option<&str>) -> Result<()> {
+        // Dart loads the bindings through `DynamicLibrary.open`, which needs a dynamic library
+        if cdylib_name.is_none() {
+            bail!(
+                "Generating bindings for Dart requires a cdylib, but {library_path} was given"
+            );
+        }
         Ok(())
     }
 }
 
+/// Runs `dart format` on a generated file. The bindings work unformatted, so a missing SDK or a
+/// failing formatter is only a warning.
+fn format_dart_file(filename: &Utf8Path) {
+    match Command::new("dart").arg("format").arg(filename).output() {
+        Ok(output) if output.status.success() => {}
+        Ok(output) => println!(
+            "Warning: Unable to auto-format {filename} using dart format: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ),
+        Err(e) => println!(
+            "Warning: Unable to auto-format {filename} using dart format: {e}. Is the Dart SDK installed?"
+        ),
+    }
+}
+
 pub fn generate_dart_bindings(
     udl_file: &Utf8Path,
     config_file_override: Option<&Utf8Path>,
@@ -263,3 +481,23 @@ pub fn generate_dart_bindings(
         true,
     )
 }
+
+/// Generates the bindings of every component crate in a built cdylib from the UniFFI metadata it
+/// contains, one Dart library per crate. Each crate's `uniffi.toml` is used unless
+/// `config_file_override` is given, and `crate_name` restricts the output to a single crate.
+pub fn generate_dart_bindings_from_library(
+    library_file: &Utf8Path,
+    crate_name: Option<String>,
+    config_file_override: Option<&Utf8Path>,
+    out_dir: &Utf8Path,
+) -> Result<()> {
+    uniffi_bindgen::library_mode::generate_external_bindings(
+        &DartBindingGenerator {},
+        library_file,
+        crate_name,
+        config_file_override,
+        out_dir,
+        true,
+    )?;
+    Ok(())
+}
@@ -1,11 +1,12 @@
 use genco::prelude::*;
 use uniffi_bindgen::backend::{CodeType, Literal};
-use uniffi_bindgen::interface::{AsType, Method, Object};
+use uniffi_bindgen::interface::{AsType, Method, Object, UniffiTrait};
 
 use crate::gen::oracle::{AsCodeType, DartCodeOracle};
 use crate::gen::render::AsRenderable;
 use crate::gen::render::{Renderable, TypeHelperRenderer};
 
+use super::functions::{generate_arguments, lower_argument};
 use super::stream::generate_stream;
 
 #[derive(Debug)]
@@ -33,7 +34,7 @@ impl CodeType for ObjectCodeType {
     }
 
     fn ffi_converter_name(&self) -> String {
-        self.canonical_name().to_string() // Objects will use factory methods
+        format!("FfiConverter{}", self.type_label())
     }
 }
 
@@ -44,14 +45,19 @@ impl Renderable for ObjectCodeType {
         } else if let Some(obj) = type_helper.get_object(&self.id) {
             generate_object(obj, type_helper)
         } else {
-            unreachable!()
+            type_helper.report_unsupported(
+                &format!("object `{}`", self.id),
+                "no definition found in this component",
+            );
+            quote!()
         }
     }
 }
 pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
     type_helper.include_once_check(obj.name(), &obj.as_type());
 
-    let cls_name = &DartCodeOracle::class_name(obj.name());
+    let cls_name = &impl_class_name(obj);
+    let interface_name = &interface_name(obj);
     let finalizer_cls_name = &format!("{}Finalizer", cls_name);
     let lib_instance = &DartCodeOracle::find_lib_instance();
     let ffi_object_free_name = obj.ffi_object_free().name();
@@ -64,7 +70,26 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
     } else {
         quote!()
     };
-    
+
+    // Objects thrown as errors are exceptions in Dart
+    let is_error = type_helper.get_ci().is_name_used_as_error(obj.name());
+    let (implements_exception, error_handler) = if is_error {
+        (quote!(Exception,), generate_object_error_handler(obj))
+    } else {
+        (quote!(), quote!())
+    };
+    // A trait interface's abstract class is the exception, its implementation only inherits that
+    let (interface_implements, class_implements) = if obj.is_trait_interface() {
+        let interface_implements = if is_error {
+            quote!(implements Exception)
+        } else {
+            quote!()
+        };
+        (interface_implements, quote!())
+    } else {
+        (quote!(), implements_exception)
+    };
+
     let constructor_definitions = obj.constructors().into_iter().map(|constructor| {
         let ffi_func_name = constructor.ffi_func().name();
         let constructor_name = constructor.name();
@@ -75,12 +100,14 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
             quote!($cls_name.$(DartCodeOracle::fn_name(constructor_name)))
         };
 
-        let dart_params = quote!($(for arg in constructor.arguments() =>
-            $(DartCodeOracle::dart_type_label(Some(&arg.as_type()))) $(DartCodeOracle::var_name(arg.name())),
-        ));
+        let dart_params = generate_arguments(
+            &constructor.arguments(),
+            &format!("constructor `{}.{}`", obj.name(), constructor_name),
+            type_helper,
+        );
 
         let ffi_call_args = quote!($(for arg in constructor.arguments() =>
-            $(DartCodeOracle::type_lower_fn(&arg.as_type(), quote!($(DartCodeOracle::var_name(arg.name()))))),) 
+            $(lower_argument(arg, type_helper)),) 
         );
         
         // Ensure argument types are included
@@ -88,29 +115,44 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
             type_helper.include_once_check(&arg.as_codetype().canonical_name(), &arg.as_type());
         }
 
+        let error_handler = DartCodeOracle::error_handler(constructor.throws_type());
+
         quote! {
             // Public constructor
-            $dart_constructor_decl($dart_params) : _ptr = rustCall((status) =>
+            $dart_constructor_decl($dart_params) : _ptr = rustCall((uniffiStatus) =>
                 $lib_instance.$ffi_func_name(
-                    $ffi_call_args status
-                )
+                    $ffi_call_args uniffiStatus
+                ),
+                $error_handler
             ) {
-                 _$finalizer_cls_name.attach(this, _ptr, detach: this);
+                _attachFinalizer();
             }
         }
     });
 
+    // The `NativeFinalizer` runs even when the isolate exits. It can only pass a single pointer,
+    // so it gets a token from `uniffi_dart::finalizer` holding the free function and the object.
     quote! {
-        final _$finalizer_cls_name = Finalizer<Pointer<Void>>((ptr) {
-          rustCall((status) => $lib_instance.$ffi_object_free_name(ptr, status));
-        });
+        $(generate_object_interface(obj, interface_implements, type_helper))
+
+        final _$finalizer_cls_name = NativeFinalizer($lib_instance.uniffi_dart_finalizer_run);
+
+        final _$(cls_name)Free = _UniffiLib._dylib
+            .lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<RustCallStatus>)>>($(format!("\"{ffi_object_free_name}\"")));
 
-        class $cls_name {
+        class $cls_name implements $class_implements $interface_name, Finalizable {
             late final Pointer<Void> _ptr;
+            late final Pointer<Void> _finalizerToken;
+            bool _disposed = false;
 
             // Private constructor for internal use / lift
             $cls_name._(this._ptr) {
-                _$finalizer_cls_name.attach(this, _ptr, detach: this);
+                _attachFinalizer();
+            }
+
+            void _attachFinalizer() {
+                _finalizerToken = $lib_instance.uniffi_dart_finalizer_token_new(_$(cls_name)Free, _ptr);
+                _$finalizer_cls_name.attach(this, _finalizerToken, detach: this);
             }
 
             // Public constructors generated from UDL
@@ -121,73 +163,268 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
                 return $cls_name._(ptr);
             }
 
+            // Every use of the object goes through here, which keeps a freed pointer from reaching Rust
             Pointer<Void> uniffiClonePointer() {
+                if (_disposed) {
+                    throw StateError($(format!("\"{cls_name} has already been disposed\"")));
+                }
                 return rustCall((status) => $lib_instance.$ffi_object_clone_name(_ptr, status));
             }
 
+            bool get isDisposed => _disposed;
+
             void dispose() {
+                if (_disposed) {
+                    return;
+                }
+                _disposed = true;
                 _$finalizer_cls_name.detach(this);
+                $lib_instance.uniffi_dart_finalizer_token_free(_finalizerToken);
                 rustCall((status) => $lib_instance.$ffi_object_free_name(_ptr, status));
             }
 
-            $(for mt in &obj.methods() => $(generate_method(mt, type_helper)))
+            $(for mt in &obj.methods() => $(generate_method(obj, mt, type_helper)))
+
+            $(generate_uniffi_trait_methods(obj))
         }
 
+        $(generate_object_converter(obj))
+
         $(stream_glue)
+
+        $(error_handler)
     }
 }
 
-#[allow(unused_variables)]
-pub fn generate_method(func: &Method, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-    // if func.takes_self_by_arc() {} // TODO: Do something about this condition
-    let args = quote!($(for arg in &func.arguments() => $(&arg.as_renderable().render_type(&arg.as_type(), type_helper)) $(DartCodeOracle::var_name(arg.name())),));
+/// The name of the abstract class holding an object's method signatures, see
+/// [`generate_object_interface`]. A trait interface is only known by its trait in Rust, so its
+/// abstract class takes the name.
+fn interface_name(obj: &Object) -> String {
+    let cls_name = DartCodeOracle::class_name(obj.name());
+    if obj.is_trait_interface() {
+        cls_name
+    } else {
+        format!("{cls_name}Interface")
+    }
+}
 
-    let (ret, lifter) = if let Some(ret) = func.return_type() {
-        (
-            ret.as_renderable().render_type(ret, type_helper),
-            quote!($(ret.as_codetype().lift())),
-        )
+/// The name of the class calling into Rust: a trait interface's is its implementation.
+fn impl_class_name(obj: &Object) -> String {
+    let cls_name = DartCodeOracle::class_name(obj.name());
+    if obj.is_trait_interface() {
+        format!("{cls_name}Impl")
     } else {
-        (quote!(void), quote!((_) {}))
+        cls_name
+    }
+}
+
+/// Declares an object's methods in an abstract class the object implements, so code using the
+/// object can be tested against a fake. Arguments and return values keep the concrete class, as
+/// only an object backed by Rust can be passed to Rust.
+///
+/// Trait interfaces are typed as their abstract class instead, which then also declares
+/// `dispose()` since it is all the code holding one sees.
+fn generate_object_interface(
+    obj: &Object,
+    implements: dart::Tokens,
+    type_helper: &dyn TypeHelperRenderer,
+) -> dart::Tokens {
+    let methods = obj.methods();
+    quote! {
+        abstract class $(interface_name(obj)) $implements {
+            $(for mt in &methods join ($['\r']) => $(generate_method_signature(obj, mt, type_helper));)
+            $(if obj.is_trait_interface() {
+                $['\r']
+                void dispose();
+            })
+        }
+    }
+}
+
+/// Objects cross the FFI as pointers, also inside buffers where they are written as a `u64`
+/// address. Lowering hands Rust a clone of the pointer to own, and lifting takes ownership of
+/// the one Rust passed.
+fn generate_object_converter(obj: &Object) -> dart::Tokens {
+    let cls_name = &DartCodeOracle::class_name(obj.name());
+    let impl_cls_name = &impl_class_name(obj);
+    let converter_name = &obj.as_codetype().ffi_converter_name();
+
+    // Rust can only be handed its own implementations of a trait interface
+    let lower_check = if obj.is_trait_interface() {
+        quote! {
+            if (value is! $impl_cls_name) {
+                throw ArgumentError.value(value, "value", $(format!("\"only Rust implementations of {cls_name} can be passed to Rust\"")));
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    quote! {
+        class $converter_name {
+            static $cls_name lift( Pointer<Void> value) {
+                return $impl_cls_name.lift(value);
+            }
+
+            static Pointer<Void> lower( $cls_name value) {
+                $lower_check
+                return value.uniffiClonePointer();
+            }
+
+            static LiftRetVal<$cls_name> read( Uint8List buf) {
+                final address = buf.buffer.asByteData(buf.offsetInBytes).getUint64(0);
+                return LiftRetVal(lift(Pointer<Void>.fromAddress(address)), 8);
+            }
+
+            static int allocationSize([$cls_name? value]) {
+                return 8;
+            }
+
+            static int write( $cls_name value, Uint8List buf) {
+                buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value).address);
+                return 8;
+            }
+        }
+    }
+}
+
+/// Objects used as errors are transmitted as their pointer, written into the error buffer.
+fn generate_object_error_handler(obj: &Object) -> dart::Tokens {
+    let handler_name = &DartCodeOracle::error_handler_name(obj.name());
+    let converter_name = &obj.as_codetype().ffi_converter_name();
+
+    quote! {
+        class $handler_name extends UniffiRustCallStatusErrorHandler {
+            @override
+            Exception lift(RustBuffer errorBuf) {
+                return liftFromRustBuffer(errorBuf, (buf) => $converter_name.read(buf).value);
+            }
+        }
+    }
+}
+
+/// Renders the Rust trait implementations exported with `[Traits=(...)]` as Dart overrides.
+/// `Display` takes precedence over `Debug` for `toString()`. The trait methods' types are not
+/// registered with the interface, so booleans are lifted inline instead of via `FfiConverterBool`.
+fn generate_uniffi_trait_methods(obj: &Object) -> dart::Tokens {
+    let lib_instance = &DartCodeOracle::find_lib_instance();
+    let cls_name = &impl_class_name(obj);
+    let (mut display, mut debug, mut eq, mut hash) = (None, None, None, None);
+    for uniffi_trait in obj.uniffi_traits() {
+        match uniffi_trait {
+            UniffiTrait::Display { fmt } => display = display.or(Some(fmt)),
+            UniffiTrait::Debug { fmt } => debug = debug.or(Some(fmt)),
+            UniffiTrait::Eq { eq: eq_method, .. } => eq = eq.or(Some(eq_method)),
+            UniffiTrait::Hash { hash: hash_method } => hash = hash.or(Some(hash_method)),
+        }
+    }
+
+    // A disposed object can't reach Rust anymore, so it falls back to Dart's identity semantics
+    quote! {
+        $(if let Some(fmt) = display.or(debug) {
+            @override
+            String toString() {
+                if (_disposed) {
+                    return $(format!("\"<{cls_name} disposed>\""));
+                }
+                return FfiConverterString.lift(rustCall((status) => $lib_instance.$(fmt.ffi_func().name())(uniffiClonePointer(), status)));
+            }
+        })
+
+        $(if let Some(eq) = eq {
+            @override
+            bool operator ==(Object other) {
+                if (identical(this, other)) {
+                    return true;
+                }
+                if (other is! $cls_name || _disposed || other._disposed) {
+                    return false;
+                }
+                return rustCall((status) => $lib_instance.$(eq.ffi_func().name())(uniffiClonePointer(), other.uniffiClonePointer(), status)) == 1;
+            }
+        })
+
+        $(if let Some(hash) = hash {
+            @override
+            int get hashCode {
+                if (_disposed) {
+                    return identityHashCode(this);
+                }
+                return rustCall((status) => $lib_instance.$(hash.ffi_func().name())(uniffiClonePointer(), status));
+            }
+        })
+    }
+}
+
+fn generate_method_signature(
+    obj: &Object,
+    func: &Method,
+    type_helper: &dyn TypeHelperRenderer,
+) -> dart::Tokens {
+    let args = generate_arguments(
+        &func.arguments(),
+        &format!("method `{}.{}`", obj.name(), func.name()),
+        type_helper,
+    );
+    let ret = match func.return_type() {
+        Some(ret) => ret.as_renderable().render_type(ret, type_helper),
+        None => quote!(void),
+    };
+
+    if func.is_async() {
+        quote!(Future<$ret> $(DartCodeOracle::fn_name(func.name()))($args))
+    } else {
+        quote!($ret $(DartCodeOracle::fn_name(func.name()))($args))
+    }
+}
+
+pub fn generate_method(obj: &Object, func: &Method, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+    // if func.takes_self_by_arc() {} // TODO: Do something about this condition
+    let signature = generate_method_signature(obj, func, type_helper);
+    let lifter = match func.return_type() {
+        Some(ret) => quote!($(ret.as_codetype().lift())),
+        None => quote!((_) {}),
     };
 
     if func.is_async() {
         quote!(
-            Future<$ret> $(DartCodeOracle::fn_name(func.name()))($args) {
+            @override
+            $signature {
                 return uniffiRustCallAsync(
                   () => $(DartCodeOracle::find_lib_instance()).$(func.ffi_func().name())(
                     uniffiClonePointer(),
-                    $(for arg in &func.arguments() => $(DartCodeOracle::type_lower_fn(&arg.as_type(), quote!($(DartCodeOracle::var_name(arg.name()))))),)
+                    $(for arg in &func.arguments() => $(lower_argument(arg, type_helper)),)
                   ),
                   $(DartCodeOracle::async_poll(func, type_helper.get_ci())),
                   $(DartCodeOracle::async_complete(func, type_helper.get_ci())),
                   $(DartCodeOracle::async_free(func, type_helper.get_ci())),
                   $lifter,
+                  $(DartCodeOracle::error_handler(func.throws_type())),
                 );
             }
 
         )
     } else {
-        if ret == quote!(void) {
+        let error_handler = &DartCodeOracle::error_handler(func.throws_type());
+        let ffi_call = quote!(rustCall((uniffiStatus) => $(DartCodeOracle::find_lib_instance()).$(func.ffi_func().name())(
+            uniffiClonePointer(),
+            $(for arg in &func.arguments() => $(lower_argument(arg, type_helper)),) uniffiStatus
+        ), $error_handler));
+
+        if let Some(ret_type) = func.return_type() {
             quote!(
-                $ret $(DartCodeOracle::fn_name(func.name()))($args) {
-                    return rustCall((status) {
-                        $(DartCodeOracle::find_lib_instance()).$(func.ffi_func().name())(
-                            uniffiClonePointer(),
-                            $(for arg in &func.arguments() => $(DartCodeOracle::type_lower_fn(&arg.as_type(), quote!($(DartCodeOracle::var_name(arg.name()))))),) status
-                        );
-                    });
+                @override
+                $signature {
+                    return $(DartCodeOracle::type_lift_fn(ret_type, ffi_call));
                 }
             )
         } else {
             quote!(
-                $ret $(DartCodeOracle::fn_name(func.name()))($args) {
-                    return rustCall((status) => $lifter($(DartCodeOracle::find_lib_instance()).$(func.ffi_func().name())(
-                        uniffiClonePointer(),
-                        $(for arg in &func.arguments() => $(DartCodeOracle::type_lower_fn(&arg.as_type(), quote!($(DartCodeOracle::var_name(arg.name()))))),) status
-                    )));
+                @override
+                $signature {
+                    $ffi_call;
                 }
             )
-      }
+        }
     }
 }
@@ -1,17 +1,27 @@
+use anyhow::{bail, Result};
 use genco::lang::dart;
 use genco::quote;
 use heck::{ToLowerCamelCase, ToUpperCamelCase};
-use uniffi_bindgen::backend::CodeType;
+use uniffi_bindgen::backend::{CodeType, Literal};
 use uniffi_bindgen::interface::{AsType, Callable, ExternalKind, FfiType, Type};
 use uniffi_bindgen::ComponentInterface;
 
-use crate::gen::primitives;
+use crate::gen::{primitives, Config};
 
 // use super::render::{AsRenderable, Renderable};
-use super::{callback_interface, compounds, enums, objects, records};
+use super::{callback_interface, compounds, custom, enums, external, objects, records};
 
 pub struct DartCodeOracle;
 
+/// The Dart rendering of an argument or record field's default value.
+pub enum DefaultValue {
+    /// A constant, declared as the parameter's default.
+    Const(String),
+    /// A value that isn't a constant, like a `BigInt`, which Dart doesn't allow as a default. The
+    /// parameter is nullable instead, and `null` is replaced by the value where it's used.
+    Fallback(String),
+}
+
 impl DartCodeOracle {
     pub fn find(type_: &Type) -> Box<dyn CodeType> {
         type_.clone().as_type().as_codetype()
@@ -33,10 +43,35 @@ impl DartCodeOracle {
 
     /// Get the idiomatic Dart rendering of a class name (for enums, records, errors, etc).
     pub fn class_name(nm: &str) -> String {
-        let name = Self::sanitize_identifier(&nm.to_upper_camel_case());
         // Replace "Error" with "Exception" in the name
-        let name = name.replace("Error", "Exception");
-        name
+        Self::sanitize_class_name(&nm.to_upper_camel_case().replace("Error", "Exception"))
+    }
+
+    /// Get the class name of an enum variant with fields, `{Variant}{Enum}`. The enum's name isn't
+    /// repeated when the variant's already ends with it, so `Error::InnerError` is `InnerException`.
+    pub fn variant_class_name(variant_nm: &str, enum_nm: &str) -> String {
+        let variant_name = variant_nm.to_upper_camel_case().replace("Error", "Exception");
+        let enum_name = enum_nm.to_upper_camel_case().replace("Error", "Exception");
+        if variant_name.len() > enum_name.len() && variant_name.ends_with(&enum_name) {
+            Self::sanitize_class_name(&variant_name)
+        } else {
+            Self::sanitize_class_name(&format!("{variant_name}{enum_name}"))
+        }
+    }
+
+    /// Append an underscore to a class name that would shadow a type the bindings or their users
+    /// rely on, like a Rust `Error` that would otherwise become `Exception`.
+    fn sanitize_class_name(name: &str) -> String {
+        if Self::is_reserved_class_name(name) {
+            format!("{}_", name)
+        } else {
+            Self::sanitize_identifier(name)
+        }
+    }
+
+    /// Check if the given name is one of the [`RESERVED_CLASS_NAMES`].
+    pub fn is_reserved_class_name(name: &str) -> bool {
+        RESERVED_CLASS_NAMES.contains(&name)
     }
 
     /// Get the idiomatic Dart rendering of a function name.
@@ -67,22 +102,7 @@ impl DartCodeOracle {
         format!("Uniffi{}", name.to_upper_camel_case())
     }
 
-    /// Helper method to generate the struct name based on `Type`.
-    fn struct_name(name: &str) -> String {
-        format!("Uniffi{}", name.to_upper_camel_case())
-    }
-
-
-    /// Helper method to generate external Dart type labels.
-    fn external_type_label(name: &str) -> String {
-        format!("External{}", name.to_upper_camel_case())
-    }
-
-    /// Helper method to generate external native type labels.
-    fn external_native_type_label(name: &str) -> String {
-        format!("Pointer<{}>", Self::external_type_label(name))
-    }
-    /// Get the idiomatic Dart rendering of an exception name
+    // Get the idiomatic Dart rendering of an exception name
     // pub fn error_name(nm: &str) -> String {
     //     let name = Self::class_name(nm);
     //     match name.strip_suffix("Error") {
@@ -95,9 +115,24 @@ impl DartCodeOracle {
         quote!(_UniffiLib.instance)
     }
 
+    /// Get the name of the `UniffiRustCallStatusErrorHandler` generated for an error type.
+    pub fn error_handler_name(nm: &str) -> String {
+        format!("{}ErrorHandler", Self::class_name(nm))
+    }
+
+    /// Get the error handler to pass to `rustCall` for a callable that may throw `throws_type`.
+    pub fn error_handler(throws_type: Option<&Type>) -> dart::Tokens {
+        match throws_type {
+            Some(Type::Enum { name, .. }) | Some(Type::Object { name, .. }) => {
+                quote!($(Self::error_handler_name(name))())
+            }
+            _ => quote!(NullRustCallStatusErrorHandler()),
+        }
+    }
+
     // TODO: Replace instances of `generate_ffi_dart_type` with ffi_type_label
-    pub fn ffi_dart_type_label(ffi_type: Option<&FfiType>) -> dart::Tokens {
-        if let Some(ret_type) = ffi_type {
+    pub fn ffi_dart_type_label(ffi_type: Option<&FfiType>) -> Result<dart::Tokens> {
+        Ok(if let Some(ret_type) = ffi_type {
             match ret_type {
                 FfiType::Int8 => quote!(int),
                 FfiType::UInt8 => quote!(int),
@@ -114,16 +149,16 @@ impl DartCodeOracle {
                 FfiType::ForeignBytes => quote!(ForeignBytes),
                 FfiType::Handle => quote!(Pointer<Void>),
                 FfiType::Callback(name) => quote!($(Self::ffi_callback_name(name))),
-                FfiType::Reference(inner) => quote!($(Self::ffi_type_label_by_reference(inner))),
-                _ => panic!("Unimplemented FfiType: {:?}", ret_type), // Fallback implementation
+                FfiType::Reference(inner) => Self::ffi_type_label_by_reference(inner)?,
+                _ => bail!("unsupported FFI type {ret_type:?}"),
             }
         } else {
             quote!(void)
-        }
+        })
     }
 
-    pub fn ffi_native_type_label(ffi_ret_type: Option<&FfiType>) -> dart::Tokens {
-        if let Some(ret_type) = ffi_ret_type {
+    pub fn ffi_native_type_label(ffi_ret_type: Option<&FfiType>) -> Result<dart::Tokens> {
+        Ok(if let Some(ret_type) = ffi_ret_type {
             match ret_type {
                 FfiType::Int8 => quote!(Int8),
                 FfiType::UInt8 => quote!(Uint8),
@@ -140,16 +175,16 @@ impl DartCodeOracle {
                 FfiType::ForeignBytes => quote!(ForeignBytes),
                 FfiType::Handle => quote!(Pointer<Void>),
                 FfiType::Callback(name) => quote!($(Self::ffi_callback_name(name))),
-                FfiType::Reference(inner) => quote!($(Self::ffi_type_label_by_reference(inner))),
-                _ => panic!("Unimplemented FfiType: {:?}", ret_type), // Fallback implementation
+                FfiType::Reference(inner) => Self::ffi_type_label_by_reference(inner)?,
+                _ => bail!("unsupported FFI type {ret_type:?}"),
             }
         } else {
             quote!(void)
-        }
+        })
     }
 
-    fn ffi_type_label_by_reference(ffi_type: &FfiType) -> dart::Tokens {
-        match ffi_type {
+    fn ffi_type_label_by_reference(ffi_type: &FfiType) -> Result<dart::Tokens> {
+        Ok(match ffi_type {
             FfiType::Int8 => quote!(Int8),
             FfiType::UInt8 => quote!(Uint8),
             FfiType::Int16 => quote!(Int16),
@@ -164,8 +199,8 @@ impl DartCodeOracle {
             FfiType::RustBuffer(_) => quote!(RustBuffer),
             FfiType::Callback(name) => quote!(Pointer<$(Self::ffi_callback_name(name))>),
             FfiType::Struct(name) => quote!(Pointer<$(Self::ffi_struct_name(name))>),
-            _ => quote!(Pointer<Void>), // Fallback implementation
-        }
+            _ => bail!("unsupported FFI type {ffi_type:?} behind a reference"),
+        })
     }
 
     pub fn ffi_struct_name(name: &str) -> dart::Tokens {
@@ -200,12 +235,15 @@ impl DartCodeOracle {
             | Type::Int32
             | Type::Int64
             | Type::UInt32
-            | Type::UInt64
             | Type::Float32
             | Type::Float64 => inner,
-            Type::Boolean
+            // Converted in case it is a `BigInt`, see `u64_as_bigint`
+            Type::UInt64
+            | Type::Boolean
             | Type::Duration
+            | Type::Timestamp
             | Type::String
+            | Type::Bytes
             | Type::Object { .. }
             | Type::Enum { .. }
             | Type::Record { .. }
@@ -226,12 +264,15 @@ impl DartCodeOracle {
             | Type::UInt16
             | Type::Int32
             | Type::Int64
-            | Type::UInt64
             | Type::Float32
             | Type::Float64 => inner,
-            Type::Boolean
+            // Converted in case it is a `BigInt`, see `u64_as_bigint`
+            Type::UInt64
+            | Type::Boolean
             | Type::Duration
+            | Type::Timestamp
             | Type::String
+            | Type::Bytes
             | Type::Object { .. }
             | Type::Enum { .. }
             | Type::Optional { .. }
@@ -247,19 +288,10 @@ impl DartCodeOracle {
         quote!($(Self::find_lib_instance()).$ffi_func)
     }
 
+    // External data classes are lifted from our own `RustBuffer`, see `ExternalCodeType`
     pub fn async_complete(callable: impl Callable, ci: &ComponentInterface) -> dart::Tokens {
         let ffi_func = callable.ffi_rust_future_complete(ci);
-        let call = quote!($(Self::find_lib_instance()).$ffi_func);
-        match callable.return_type() {
-            Some(Type::External {
-                kind: ExternalKind::DataClass,
-                name: _,
-                ..
-            }) => {
-                todo!("Need to convert the RustBuffer from our package to the RustBuffer of the external package")
-            }
-            _ => call,
-        }
+        quote!($(Self::find_lib_instance()).$ffi_func)
     }
 
     pub fn async_free(callable: impl Callable, ci: &ComponentInterface) -> dart::Tokens {
@@ -267,21 +299,41 @@ impl DartCodeOracle {
         quote!($(Self::find_lib_instance()).$ffi_func)
     }
 
+    /// The Dart default value of an argument or record field, or `None` if Dart can't express it:
+    /// an optional `u64` can't use `null` for its `BigInt` default, as `null` is `None` already.
+    pub fn default_value(ty: &Type, literal: &Literal, config: &Config) -> Option<DefaultValue> {
+        match (ty, literal) {
+            (Type::Optional { .. }, Literal::None) => Some(DefaultValue::Const("null".into())),
+            (Type::Optional { inner_type }, Literal::Some { inner }) => {
+                match DartCodeOracle::default_value(inner_type, inner, config)? {
+                    DefaultValue::Const(value) => Some(DefaultValue::Const(value)),
+                    DefaultValue::Fallback(_) => None,
+                }
+            }
+            (Type::UInt64, Literal::UInt(value, _, _)) if config.u64_as_bigint() => {
+                Some(DefaultValue::Fallback(format!("BigInt.parse('{value}')")))
+            }
+            _ => Some(DefaultValue::Const(ty.as_codetype().literal(literal))),
+        }
+    }
+
     /// Get the idiomatic Dart rendering of a class name based on `Type`.
-    pub fn dart_type_label(type_: Option<&Type>) -> dart::Tokens {
-        if let Some(ret_type) = type_ {
+    pub fn dart_type_label(type_: Option<&Type>) -> Result<dart::Tokens> {
+        Ok(if let Some(ret_type) = type_ {
             match ret_type {
                 Type::UInt8
                 | Type::UInt16
                 | Type::UInt32
-                | Type::UInt64
                 | Type::Int8
                 | Type::Int16
                 | Type::Int32
-                | Type::Int64 => quote!(int),
+                | Type::Int64
+                // The callbacks receive a `u64` as an `int` and lift it through its converter
+                | Type::UInt64 => quote!(int),
                 Type::Float32 | Type::Float64 => quote!(double),
                 Type::Boolean => quote!(bool),
                 Type::String => quote!(String),
+                Type::Bytes => quote!(Uint8List),
                 Type::Timestamp => quote!(DateTime),
                 Type::Duration => quote!(Duration),
                 // Reference types
@@ -290,22 +342,19 @@ impl DartCodeOracle {
                     quote!($class_name)
                 }
                 Type::Optional { inner_type } => {
-                    let inner = DartCodeOracle::dart_type_label(Some(inner_type));
+                    let inner = DartCodeOracle::dart_type_label(Some(inner_type))?;
                     quote!($inner?)
                 }
                 Type::Sequence { inner_type } => {
-                    let inner = DartCodeOracle::dart_type_label(Some(inner_type));
+                    let inner = DartCodeOracle::dart_type_label(Some(inner_type))?;
                     quote!(List<$inner>)
                 }
                 Type::Map { key_type, value_type, .. } => {
-                    let key = DartCodeOracle::dart_type_label(Some(key_type));
-                    let value = DartCodeOracle::dart_type_label(Some(value_type));
+                    let key = DartCodeOracle::dart_type_label(Some(key_type))?;
+                    let value = DartCodeOracle::dart_type_label(Some(value_type))?;
                     quote!(Map<$key, $value>)
                 }
-                Type::External { name, .. } => {
-                    let external_name = &DartCodeOracle::external_type_label(name);
-                    quote!($external_name)
-                }
+                Type::External { .. } => quote!($(ret_type.as_codetype().type_label())),
                 Type::Enum { name, .. } => {
                     let enum_name = &DartCodeOracle::class_name(name);
                     quote!($enum_name)
@@ -318,16 +367,16 @@ impl DartCodeOracle {
                     let type_name = &DartCodeOracle::class_name(name);
                     quote!($type_name)
                 }
-                _ => quote!(dynamic),
+                _ => bail!("unsupported type {ret_type:?}"),
             }
         } else {
             quote!(void)
-        }
+        })
     }
 
     /// Get the native Dart FFI type rendering based on `Type`.
-    pub fn native_type_label(native_ret_type: Option<&Type>) -> dart::Tokens {
-        if let Some(ret_type) = native_ret_type {
+    pub fn native_type_label(native_ret_type: Option<&Type>) -> Result<dart::Tokens> {
+        Ok(if let Some(ret_type) = native_ret_type {
             match ret_type {
                 Type::UInt8 => quote!(Uint8),
                 Type::UInt16 => quote!(Uint16),
@@ -340,9 +389,8 @@ impl DartCodeOracle {
                 Type::Float32 => quote!(Float),
                 Type::Float64 => quote!(Double),
                 Type::Boolean => quote!(Int8),
-                Type::String => quote!(RustBuffer),
-                Type::Timestamp => quote!(Int64),
-                Type::Duration => quote!(Int64),
+                Type::String | Type::Bytes => quote!(RustBuffer),
+                Type::Timestamp | Type::Duration => quote!(RustBuffer),
                 Type::Optional { inner_type } => match **inner_type {
                     Type::String => quote!(RustBuffer),
                     _ => quote!(RustBuffer),
@@ -352,24 +400,20 @@ impl DartCodeOracle {
                 Type::Object { .. } => quote!(Pointer<Void>),
                 Type::Enum { .. } => quote!(Int32),
                 Type::Record { .. } => quote!(RustBuffer),
-                Type::External { name, .. } => {
-                    let external_name = &DartCodeOracle::external_native_type_label(name);
-                    quote!($external_name)
-                },
-                Type::Custom { name, .. } => {
-                    let class_name = &DartCodeOracle::class_name(name);
-                    quote!($class_name)
-                },
-                _ => quote!(Pointer<Void>),
+                Type::External { kind: ExternalKind::DataClass, .. } => quote!(RustBuffer),
+                Type::External { .. } => quote!(Pointer<Void>),
+                // Custom types cross the FFI as their builtin type
+                Type::Custom { builtin, .. } => DartCodeOracle::native_type_label(Some(builtin))?,
+                _ => bail!("unsupported type {ret_type:?}"),
             }
         } else {
             quote!(Void)
-        }
+        })
     }
 
     /// Get the native Dart FFI type rendering based on `Type`.
-    pub fn native_dart_type_label(native_ret_type: Option<&Type>) -> dart::Tokens {
-        if let Some(ret_type) = native_ret_type {
+    pub fn native_dart_type_label(native_ret_type: Option<&Type>) -> Result<dart::Tokens> {
+        Ok(if let Some(ret_type) = native_ret_type {
             match ret_type {
                 Type::UInt8
                 | Type::UInt16
@@ -382,9 +426,8 @@ impl DartCodeOracle {
                 Type::Float32 => quote!(double),
                 Type::Float64 => quote!(double),
                 Type::Boolean => quote!(int),
-                Type::String => quote!(RustBuffer),
-                Type::Timestamp => quote!(int),
-                Type::Duration => quote!(int),
+                Type::String | Type::Bytes => quote!(RustBuffer),
+                Type::Timestamp | Type::Duration => quote!(RustBuffer),
                 Type::Optional { inner_type } => match **inner_type {
                     Type::String => quote!(RustBuffer),
                     _ => quote!(RustBuffer),
@@ -394,24 +437,19 @@ impl DartCodeOracle {
                 Type::Object { .. } => quote!(Pointer<Void>),
                 Type::Enum { .. } => quote!(int),
                 Type::Record { .. } => quote!(RustBuffer),
-                Type::External { name, .. } => {
-                    let external_name = &DartCodeOracle::external_type_label(name);
-                    quote!($external_name)
-                },
-                Type::Custom { name, .. } => {
-                    let type_name = &DartCodeOracle::class_name(name);
-                    quote!($type_name)
-                },
-                _ => quote!(dynamic),
+                Type::External { kind: ExternalKind::DataClass, .. } => quote!(RustBuffer),
+                Type::External { .. } => quote!(Pointer<Void>),
+                Type::Custom { builtin, .. } => DartCodeOracle::native_dart_type_label(Some(builtin))?,
+                _ => bail!("unsupported type {ret_type:?}"),
             }
         } else {
             quote!(void)
-        }
+        })
     }
 
     // Method to get the appropriate callback parameter type
-    pub fn callback_param_type(arg_type: &Type, arg_name: &str) -> dart::Tokens {
-        match arg_type {
+    pub fn callback_param_type(arg_type: &Type, arg_name: &str) -> Result<dart::Tokens> {
+        Ok(match arg_type {
             Type::Boolean => quote!(int $arg_name),
             Type::String => quote!(RustBuffer $(arg_name)Buffer),
             Type::Optional { inner_type } => {
@@ -431,10 +469,10 @@ impl DartCodeOracle {
                 }
             },
             _ => {
-                let type_label = DartCodeOracle::dart_type_label(Some(arg_type));
+                let type_label = DartCodeOracle::dart_type_label(Some(arg_type))?;
                 quote!($type_label $arg_name)
             }
-        }
+        })
     }
 
     // Method to generate code for handling callback return values
@@ -447,33 +485,13 @@ impl DartCodeOracle {
                     outReturn.value = result ? 1 : 0;
                 )
             },
-            Type::Optional { inner_type } => {
-                // For optional return values
-                if let Type::String = **inner_type {
-                    quote!(
-                        final result = obj.$method_name($(for arg in &args => $arg,));
-                        if (result == null) {
-                            outReturn.ref = toRustBuffer(Uint8List.fromList([0]));
-                        } else {
-                            final lowered = FfiConverterOptionalString.lower(result);
-                            outReturn.ref = toRustBuffer(lowered.asUint8List());
-                        }
-                    )
-                } else {
-                    let lowered = ret_type.as_codetype().ffi_converter_name();
-                    quote!(
-                        final result = obj.$method_name($(for arg in &args => $arg,));
-                        if (result == null) {
-                            outReturn.ref = toRustBuffer(Uint8List.fromList([0]));
-                        } else {
-                            final lowered = $lowered.lower(result);
-                            final buffer = Uint8List(1 + lowered.length);
-                            buffer[0] = 1;
-                            buffer.setAll(1, lowered.asUint8List());
-                            outReturn.ref = toRustBuffer(buffer);
-                        }
-                    )
-                }
+            Type::Optional { .. } => {
+                // For optional return values, the converter handles `null` itself
+                let lowered = ret_type.as_codetype().ffi_converter_name();
+                quote!(
+                    final result = obj.$method_name($(for arg in &args => $arg,));
+                    outReturn.ref = $lowered.lower(result);
+                )
             },
             Type::String => {
                 // For string return values
@@ -635,6 +653,67 @@ pub static RESERVED_IDENTIFIERS: [&str; 63] = [
     "yield",
 ];
 
+// The `dart:core` types, and the types of other libraries the generated bindings use
+pub static RESERVED_CLASS_NAMES: [&str; 57] = [
+    "ArgumentError",
+    "AssertionError",
+    "BigInt",
+    "Comparable",
+    "DateTime",
+    "Deprecated",
+    "Directory",
+    "Duration",
+    "Enum",
+    "Error",
+    "Exception",
+    "Expando",
+    "File",
+    "Finalizable",
+    "Finalizer",
+    "FormatException",
+    "Function",
+    "Future",
+    "Invocation",
+    "Isolate",
+    "Iterable",
+    "Iterator",
+    "List",
+    "Map",
+    "MapEntry",
+    "Match",
+    "NativeFinalizer",
+    "NativeFunction",
+    "Never",
+    "Null",
+    "Object",
+    "Opaque",
+    "Pattern",
+    "Platform",
+    "Pointer",
+    "RangeError",
+    "Record",
+    "RegExp",
+    "Runes",
+    "Set",
+    "Sink",
+    "StackTrace",
+    "StateError",
+    "Stopwatch",
+    "Stream",
+    "String",
+    "StringBuffer",
+    "StringSink",
+    "Struct",
+    "Symbol",
+    "Type",
+    "TypeError",
+    "Uint8List",
+    "UnimplementedError",
+    "UnsupportedError",
+    "Uri",
+    "Void",
+];
+
 pub trait AsCodeType {
     fn as_codetype(&self) -> Box<dyn CodeType>;
 }
@@ -654,7 +733,9 @@ impl<T: AsType> AsCodeType for T {
             Type::Float64 => Box::new(primitives::Float64CodeType),
             Type::Boolean => Box::new(primitives::BooleanCodeType),
             Type::String => Box::new(primitives::StringCodeType),
+            Type::Bytes => Box::new(primitives::BytesCodeType),
             Type::Duration => Box::new(primitives::DurationCodeType),
+            Type::Timestamp => Box::new(primitives::TimestampCodeType),
             Type::Object { name, .. } => Box::new(objects::ObjectCodeType::new(name)),
             Type::Optional { inner_type } => Box::new(compounds::OptionalCodeType::new(
                 self.as_type(),
@@ -664,10 +745,19 @@ impl<T: AsType> AsCodeType for T {
                 self.as_type(),
                 *inner_type,
             )),
+            Type::Map {
+                key_type,
+                value_type,
+            } => Box::new(compounds::MapCodeType::new(
+                self.as_type(),
+                *key_type,
+                *value_type,
+            )),
             Type::Enum { name, .. } => Box::new(enums::EnumCodeType::new(name)),
             Type::Record {name, .. } => Box::new(records::RecordCodeType::new(name)),
             Type::CallbackInterface { name, .. } => Box::new(callback_interface::CallbackInterfaceCodeType::new(name, self.as_type())),
-            _ => todo!("As Type for Type::{:?}", self.as_type()),
+            Type::Custom { name, builtin, .. } => Box::new(custom::CustomCodeType::new(name, *builtin)),
+            Type::External { name, namespace, kind, .. } => Box::new(external::ExternalCodeType::new(name, namespace, kind)),
         }
     }
 }
@@ -0,0 +1,53 @@
+use crate::gen::{
+    quote,
+    render::{Renderable, TypeHelperRenderer},
+};
+
+use genco::lang::dart;
+use uniffi_bindgen::backend::CodeType;
+
+#[derive(Debug)]
+pub struct BytesCodeType;
+impl CodeType for BytesCodeType {
+    fn type_label(&self) -> String {
+        "Uint8List".to_owned()
+    }
+
+    fn canonical_name(&self) -> String {
+        "Bytes".to_owned()
+    }
+}
+
+impl Renderable for BytesCodeType {
+    fn render_type_helper(&self, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+        quote! {
+            class FfiConverterBytes {
+                static Uint8List lift( RustBuffer buf) {
+                    return liftFromRustBuffer(buf, (data) => FfiConverterBytes.read(data).value);
+                }
+
+                static RustBuffer lower( Uint8List value) {
+                    final buf = Uint8List(allocationSize(value));
+                    write(value, buf);
+                    return toRustBuffer(buf);
+                }
+
+                static LiftRetVal<Uint8List> read( Uint8List buf) {
+                    final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4;
+                    // copy the bytes out, the buffer they were read from is owned by Rust
+                    return LiftRetVal(buf.sublist(4, end), end);
+                }
+
+                static int allocationSize([Uint8List? value]) {
+                    return (value?.length ?? 0) + 4; // Four additional bytes for the length data
+                }
+
+                static int write( Uint8List value, Uint8List buf) {
+                    buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+                    buf.setAll(4, value);
+                    return value.length + 4;
+                }
+            }
+        }
+    }
+}
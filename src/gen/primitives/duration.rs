@@ -13,7 +13,7 @@ impl Renderable for DurationCodeType {
         quote! {
             class FfiConverterDuration {
                 static Duration lift( RustBuffer buf) {
-                    return FfiConverterDuration.read(buf.asUint8List()).value;
+                    return liftFromRustBuffer(buf, (data) => FfiConverterDuration.read(data).value);
                 }
 
                 static RustBuffer lower( Duration value) {
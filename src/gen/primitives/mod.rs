@@ -1,8 +1,11 @@
 #[macro_use]
 mod macros;
 mod boolean;
+mod bytes;
 mod duration;
 mod string;
+mod timestamp;
+mod uint64;
 
 use crate::gen::render::{Renderable, TypeHelperRenderer};
 use genco::prelude::*;
@@ -11,8 +14,28 @@ use uniffi_bindgen::backend::Literal;
 use uniffi_bindgen::interface::{Radix, Type};
 
 pub use boolean::BooleanCodeType;
+pub use bytes::BytesCodeType;
 pub use duration::DurationCodeType;
 pub use string::StringCodeType;
+pub use timestamp::TimestampCodeType;
+pub use uint64::{u64_type_label, UInt64CodeType};
+
+/// Escapes `s` for a single-quoted Dart string, in which `$` starts an interpolation.
+fn escape_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\'' => escaped.push_str("\\'"),
+            '$' => escaped.push_str("\\$"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
 
 fn render_literal(literal: &Literal) -> String {
     fn typed_number(type_: &Type, num_str: String) -> String {
@@ -23,6 +46,7 @@ fn render_literal(literal: &Literal) -> String {
             | Type::UInt16
             | Type::Int32
             | Type::UInt32
+            | Type::Int64
             | Type::UInt64
             | Type::Float32
             | Type::Float64
@@ -33,20 +57,22 @@ fn render_literal(literal: &Literal) -> String {
 
     match literal {
         Literal::Boolean(v) => format!("{}", v),
-        Literal::String(s) => format!("'{}'", s),
+        Literal::String(s) => format!("'{}'", escape_string(s)),
+        // Dart has no octal literals, so those are rendered in decimal
         Literal::Int(i, radix, type_) => typed_number(
             type_,
             match radix {
-                Radix::Octal => format!("{:#x}", i),
-                Radix::Decimal => format!("{}", i),
+                Radix::Octal | Radix::Decimal => format!("{}", i),
+                Radix::Hexadecimal if *i < 0 => format!("-{:#x}", i.unsigned_abs()),
                 Radix::Hexadecimal => format!("{:#x}", i),
             },
         ),
+        // Dart only accepts values above i64::MAX as hex literals, which wrap to the same bits
         Literal::UInt(i, radix, type_) => typed_number(
             type_,
             match radix {
-                Radix::Octal => format!("{:#x}", i),
-                Radix::Decimal => format!("{}", i),
+                _ if *i > i64::MAX as u64 => format!("{:#x}", i),
+                Radix::Octal | Radix::Decimal => format!("{}", i),
                 Radix::Hexadecimal => format!("{:#x}", i),
             },
         ),
@@ -55,7 +81,6 @@ fn render_literal(literal: &Literal) -> String {
     }
 }
 
-impl_code_type_for_primitive!(BytesCodeType, "Uint8List", "Uint8List");
 impl_code_type_for_primitive!(Int8CodeType, "int", "Int8");
 impl_code_type_for_primitive!(Int16CodeType, "int", "Int16");
 impl_code_type_for_primitive!(Int32CodeType, "int", "Int32");
@@ -63,12 +88,9 @@ impl_code_type_for_primitive!(Int64CodeType, "int", "Int64");
 impl_code_type_for_primitive!(UInt8CodeType, "int", "UInt8");
 impl_code_type_for_primitive!(UInt16CodeType, "int", "UInt16");
 impl_code_type_for_primitive!(UInt32CodeType, "int", "UInt32");
-impl_code_type_for_primitive!(UInt64CodeType, "int", "UInt64");
 impl_code_type_for_primitive!(Float32CodeType, "double", "Double32");
 impl_code_type_for_primitive!(Float64CodeType, "double", "Double64");
 
-// TODO: implement BytesCodeType
-impl_renderable_for_primitive!(BytesCodeType, "Uint8List", "Uint8List", 1);
 impl_renderable_for_primitive!(Int8CodeType, "int", "Int8", 1);
 impl_renderable_for_primitive!(Int16CodeType, "int", "Int16", 2);
 impl_renderable_for_primitive!(Int32CodeType, "int", "Int32", 4);
@@ -76,6 +98,5 @@ impl_renderable_for_primitive!(Int64CodeType, "int", "Int64", 8);
 impl_renderable_for_primitive!(UInt8CodeType, "int", "UInt8", 1);
 impl_renderable_for_primitive!(UInt16CodeType, "int", "UInt16", 2);
 impl_renderable_for_primitive!(UInt32CodeType, "int", "UInt32", 4);
-impl_renderable_for_primitive!(UInt64CodeType, "int", "UInt64", 8);
 impl_renderable_for_primitive!(Float32CodeType, "double", "Double32", 4);
 impl_renderable_for_primitive!(Float64CodeType, "double", "Double64", 8);
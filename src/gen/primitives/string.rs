@@ -4,7 +4,7 @@ use crate::gen::{
 };
 
 use genco::lang::dart;
-use uniffi_bindgen::backend::CodeType;
+use uniffi_bindgen::backend::{CodeType, Literal};
 
 #[derive(Debug)]
 pub struct StringCodeType;
@@ -12,6 +12,10 @@ impl CodeType for StringCodeType {
     fn type_label(&self) -> String {
         "String".to_owned()
     }
+
+    fn literal(&self, literal: &Literal) -> String {
+        super::render_literal(literal)
+    }
 }
 
 impl Renderable for StringCodeType {
@@ -20,7 +24,7 @@ impl Renderable for StringCodeType {
             class FfiConverterString {
                 static String lift( RustBuffer buf) {
                     // reading the entire buffer, the len is where the string finishes
-                    return utf8.decoder.convert(buf.asUint8List());
+                    return liftFromRustBuffer(buf, (data) => utf8.decoder.convert(data));
                 }
 
                 static RustBuffer lower( String value) {
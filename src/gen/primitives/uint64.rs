@@ -0,0 +1,92 @@
+use crate::gen::{
+    quote,
+    render::{Renderable, TypeHelperRenderer},
+    Config,
+};
+
+use genco::lang::dart;
+use uniffi_bindgen::backend::{CodeType, Literal};
+
+/// The Dart type of a `u64`: an `int`, or a `BigInt` with the `u64_as_bigint` option.
+pub fn u64_type_label(config: &Config) -> &'static str {
+    if config.u64_as_bigint() {
+        "BigInt"
+    } else {
+        "int"
+    }
+}
+
+/// The `CodeType` doesn't know the `Config`, so its label and literals are the `int` ones: the
+/// renderers label a `u64` through [`u64_type_label`].
+#[derive(Debug)]
+pub struct UInt64CodeType;
+
+impl CodeType for UInt64CodeType {
+    fn type_label(&self) -> String {
+        "int".into()
+    }
+
+    fn literal(&self, literal: &Literal) -> String {
+        super::render_literal(literal)
+    }
+
+    fn canonical_name(&self) -> String {
+        "UInt64".into()
+    }
+}
+
+impl Renderable for UInt64CodeType {
+    fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+        if !type_helper.get_config().u64_as_bigint() {
+            return quote! {
+                class FfiConverterUInt64 {
+                    static int lift(int value) => value;
+
+                    static LiftRetVal<int> read(Uint8List buf) {
+                        return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0), 8);
+                    }
+
+                    static int lower(int value) => value;
+
+                    static int allocationSize([int value = 0]) {
+                      return 8;
+                    }
+
+                    static int write(int value, Uint8List buf) {
+                        buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, value);
+                        return FfiConverterUInt64.allocationSize();
+                    }
+                }
+            };
+        }
+
+        // A Dart `int` is a signed 64 bit integer: the FFI passes the bits of the `u64` through it
+        quote! {
+            class FfiConverterUInt64 {
+                static final BigInt _max = (BigInt.one << 64) - BigInt.one;
+
+                static BigInt lift(int value) => BigInt.from(value).toUnsigned(64);
+
+                static LiftRetVal<BigInt> read(Uint8List buf) {
+                    return LiftRetVal(lift(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0)), 8);
+                }
+
+                static int lower(BigInt value) {
+                    if (value.isNegative || value > _max) {
+                        throw ArgumentError.value(value, "value", "out of range for a u64");
+                    }
+                    return value.toSigned(64).toInt();
+                }
+
+                static int allocationSize([BigInt? value]) {
+                  return 8;
+                }
+
+                static int write(BigInt value, Uint8List buf) {
+                    buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value));
+                    return FfiConverterUInt64.allocationSize();
+                }
+            }
+        }
+    }
+}
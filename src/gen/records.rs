@@ -1,9 +1,10 @@
-use super::oracle::{AsCodeType, DartCodeOracle};
+use super::oracle::{AsCodeType, DartCodeOracle, DefaultValue};
 use super::render::{Renderable, TypeHelperRenderer};
 use super::types::generate_type;
+use super::Config;
 use genco::prelude::*;
 use uniffi_bindgen::backend::{CodeType, Literal};
-use uniffi_bindgen::interface::{AsType, Record};
+use uniffi_bindgen::interface::{AsType, Field, Record};
 
 #[derive(Debug)]
 pub struct RecordCodeType {
@@ -29,7 +30,7 @@ impl CodeType for RecordCodeType {
     }
 
     fn literal(&self, _literal: &Literal) -> String {
-        todo!("literal not implemented for RecordCodeType");
+        unreachable!("UniFFI has no record literals");
     }
 }
 
@@ -40,11 +41,64 @@ impl Renderable for RecordCodeType {
         } else if let Some(record_) = type_helper.get_record(&self.id) {
             generate_record(record_, type_helper)
         } else {
-            todo!("render_type_helper not implemented for unknown record type");
+            type_helper.report_unsupported(
+                &format!("record `{}`", self.id),
+                "no definition found in this component",
+            );
+            quote!()
+        }
+    }
+}
+
+fn field_default(field: &Field, config: &Config) -> Option<DefaultValue> {
+    field
+        .default_value()
+        .and_then(|literal| DartCodeOracle::default_value(&field.as_type(), literal, config))
+}
+
+/// A record constructor parameter, which is optional when the field has a default value.
+fn generate_field_parameter(
+    record: &Record,
+    field: &Field,
+    type_helper: &dyn TypeHelperRenderer,
+) -> dart::Tokens {
+    let name = DartCodeOracle::var_name(field.name());
+    match field_default(field, type_helper.get_config()) {
+        Some(DefaultValue::Const(default)) => quote!(this.$name = $default),
+        Some(DefaultValue::Fallback(_)) => {
+            quote!($(generate_type(&field.as_type(), type_helper.get_config()))? $name)
+        }
+        None => {
+            if field.default_value().is_some() {
+                type_helper.report_unsupported(
+                    &format!("field `{}` of record `{}`", field.name(), record.name()),
+                    "a `BigInt` default value for an optional `u64`",
+                );
+            }
+            quote!(required this.$name)
         }
     }
 }
 
+/// The initializer list assigning the fields whose parameter is nullable in place of a default.
+fn generate_field_fallbacks(fields: &[Field], config: &Config) -> dart::Tokens {
+    let fallbacks: Vec<dart::Tokens> = fields
+        .iter()
+        .filter_map(|field| match field_default(field, config) {
+            Some(DefaultValue::Fallback(default)) => {
+                let name = DartCodeOracle::var_name(field.name());
+                Some(quote!($(&name) = $(&name) ?? $default))
+            }
+            _ => None,
+        })
+        .collect();
+    if fallbacks.is_empty() {
+        quote!()
+    } else {
+        quote!( : $(for fallback in fallbacks join (, ) => $fallback))
+    }
+}
+
 pub fn generate_record(obj: &Record, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
     let cls_name = &DartCodeOracle::class_name(obj.name());
     let ffi_conv_name = &DartCodeOracle::class_name(&obj.as_codetype().ffi_converter_name());
@@ -53,27 +107,31 @@ pub fn generate_record(obj: &Record, type_helper: &dyn TypeHelperRenderer) -> da
     }
     quote! {
         class $cls_name {
-            $(for f in obj.fields() => final $(generate_type(&f.as_type())) $(DartCodeOracle::var_name(f.name()));)
+            $(for f in obj.fields() => final $(generate_type(&f.as_type(), type_helper.get_config())) $(DartCodeOracle::var_name(f.name()));)
 
-            $(cls_name)._($(for f in obj.fields() => this.$(DartCodeOracle::var_name(f.name())), ));
+            $(if obj.fields().is_empty() {
+                $(cls_name)();
+            } else {
+                $(cls_name)({$(for f in obj.fields() => $(generate_field_parameter(obj, f, type_helper)), )})$(generate_field_fallbacks(obj.fields(), type_helper.get_config()));
+            })
         }
 
         class $ffi_conv_name {
             static $cls_name lift( RustBuffer buf) {
-                return $ffi_conv_name.read(buf.asUint8List()).value;
+                return liftFromRustBuffer(buf, (data) => $ffi_conv_name.read(data).value);
             }
 
             static LiftRetVal<$cls_name> read( Uint8List buf) {
-                int new_offset = 0;
+                int new_offset = buf.offsetInBytes;
 
                 $(for f in obj.fields() =>
                     final $(DartCodeOracle::var_name(f.name()))_lifted = $(f.as_type().as_codetype().ffi_converter_name()).read(Uint8List.view(buf.buffer, new_offset));
                     final $(DartCodeOracle::var_name(f.name())) = $(DartCodeOracle::var_name(f.name()))_lifted.value;
                     new_offset += $(DartCodeOracle::var_name(f.name()))_lifted.bytesRead;
                 )
-                return LiftRetVal($(cls_name)._(
-                    $(for f in obj.fields() => $(DartCodeOracle::var_name(f.name())),)
-                ), new_offset);
+                return LiftRetVal($(cls_name)(
+                    $(for f in obj.fields() => $(DartCodeOracle::var_name(f.name())): $(DartCodeOracle::var_name(f.name())),)
+                ), new_offset - buf.offsetInBytes);
             }
 
             static RustBuffer lower( $cls_name value) {
@@ -89,7 +147,7 @@ pub fn generate_record(obj: &Record, type_helper: &dyn TypeHelperRenderer) -> da
                 $(for f in obj.fields() =>
                 new_offset += $(f.as_type().as_codetype().ffi_converter_name()).write(value.$(DartCodeOracle::var_name(f.name())), Uint8List.view(buf.buffer, new_offset));
                 )
-                return new_offset;
+                return new_offset - buf.offsetInBytes;
             }
 
             static int allocationSize($cls_name value) {
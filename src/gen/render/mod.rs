@@ -1,17 +1,23 @@
-use super::{callback_interface, compounds, enums, primitives, records};
-use super::{objects, oracle::AsCodeType};
+use super::{callback_interface, compounds, custom, enums, external, primitives, records};
+use super::{objects, oracle::AsCodeType, oracle::DartCodeOracle};
 use genco::{lang::dart, quote};
 use uniffi_bindgen::interface::{AsType, Enum, Object, Record, Type};
 use uniffi_bindgen::ComponentInterface;
 
+use super::Config;
+
 pub trait Renderer<T> {
     fn render(&self) -> T;
 }
 
 pub trait TypeHelperRenderer {
     fn get_ci(&self) -> &ComponentInterface;
+    fn get_config(&self) -> &Config;
     fn include_once_check(&self, name: &str, ty: &Type) -> bool;
     fn check(&self, name: &str) -> bool;
+    /// Records something the generator can't express in Dart, so that generation can carry on and
+    /// report every problem at once. `owner` names the item it was found in.
+    fn report_unsupported(&self, owner: &str, what: &str);
 
     fn get_object(&self, name: &str) -> Option<&Object>;
     fn get_enum(&self, name: &str) -> Option<&Enum>;
@@ -27,12 +33,13 @@ pub trait Renderable {
             | Type::Int16
             | Type::UInt32
             | Type::Int32
-            | Type::UInt64
             | Type::Int64 => quote!(int),
+            Type::UInt64 => quote!($(primitives::u64_type_label(type_helper.get_config()))),
             Type::Float32 | Type::Float64 => quote!(double),
             Type::String => quote!(String),
+            Type::Bytes => quote!(Uint8List),
             Type::Boolean => quote!(bool),
-            Type::Object { name, .. } => quote!($name),
+            Type::Object { name, .. } => quote!($(DartCodeOracle::class_name(name))),
             Type::Optional { inner_type } => quote!($(&self.render_type(inner_type, type_helper))?),
             Type::Sequence { inner_type } => {
                 quote!(List<$(&self.render_type(inner_type, type_helper))>)
@@ -43,11 +50,13 @@ pub trait Renderable {
             } => {
                 quote!(Map<$(&self.render_type(key_type, type_helper)), $(&self.render_type(value_type, type_helper))>)
             }
-            Type::Enum { name, .. } => quote!($name),
-            Type::Record { name, .. } => quote!($name),
+            Type::Enum { name, .. } => quote!($(DartCodeOracle::class_name(name))),
+            Type::Record { name, .. } => quote!($(DartCodeOracle::class_name(name))),
             Type::Duration => quote!(Duration),
-            Type::CallbackInterface { name, .. } => quote!($name),
-            _ => todo!("Type::{:?}", ty),
+            Type::Timestamp => quote!(DateTime),
+            Type::CallbackInterface { name, .. } => quote!($(DartCodeOracle::class_name(name))),
+            Type::Custom { name, .. } => quote!($(DartCodeOracle::class_name(name))),
+            Type::External { .. } => quote!($(ty.as_codetype().type_label())),
         };
 
         if !type_helper.include_once_check(&ty.as_codetype().canonical_name(), ty) {
@@ -79,7 +88,9 @@ impl<T: AsType> AsRenderable for T {
             Type::Float64 => Box::new(primitives::Float64CodeType),
             Type::Boolean => Box::new(primitives::BooleanCodeType),
             Type::String => Box::new(primitives::StringCodeType),
+            Type::Bytes => Box::new(primitives::BytesCodeType),
             Type::Duration => Box::new(primitives::DurationCodeType),
+            Type::Timestamp => Box::new(primitives::TimestampCodeType),
             Type::Object { name, .. } => Box::new(objects::ObjectCodeType::new(name)),
             Type::Optional { inner_type } => Box::new(compounds::OptionalCodeType::new(
                 self.as_type(),
@@ -89,10 +100,19 @@ impl<T: AsType> AsRenderable for T {
                 self.as_type(),
                 *inner_type,
             )),
+            Type::Map {
+                key_type,
+                value_type,
+            } => Box::new(compounds::MapCodeType::new(
+                self.as_type(),
+                *key_type,
+                *value_type,
+            )),
             Type::Enum { name, .. } => Box::new(enums::EnumCodeType::new(name)),
             Type::Record {name, .. } => Box::new(records::RecordCodeType::new(name)),
             Type::CallbackInterface { name, .. } => Box::new(callback_interface::CallbackInterfaceCodeType::new(name, self.as_type())),
-            _ => todo!("Renderable for Type::{:?}", self.as_type()),
+            Type::Custom { name, builtin, .. } => Box::new(custom::CustomCodeType::new(name, *builtin)),
+            Type::External { name, namespace, kind, .. } => Box::new(external::ExternalCodeType::new(name, namespace, kind)),
         }
     }
 }
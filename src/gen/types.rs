@@ -1,30 +1,41 @@
-use std::{cell::RefCell, collections::HashMap};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+};
 
 use genco::prelude::*;
 use uniffi_bindgen::interface::AsType;
 use uniffi_bindgen::{interface::Type, ComponentInterface};
-use uniffi_bindgen::backend::CodeType;
 
 use super::render::{AsRenderable, Renderer, TypeHelperRenderer, Renderable};
-use super::{enums, functions, objects, oracle::AsCodeType, records};
-use crate::gen::DartCodeOracle;
+use super::{enums, functions, objects, oracle::AsCodeType, primitives, records};
+use crate::gen::{Config, DartCodeOracle};
 
 type FunctionDefinition = dart::Tokens;
 
 pub struct TypeHelpersRenderer<'a> {
     ci: &'a ComponentInterface,
-    include_once_names: RefCell<HashMap<String, Type>>,
+    config: &'a Config,
+    include_once_names: RefCell<BTreeMap<String, Type>>,
+    unsupported: RefCell<Vec<String>>,
 }
 
 impl<'a> TypeHelpersRenderer<'a> {
-    pub fn new(ci: &'a ComponentInterface) -> Self {
+    pub fn new(ci: &'a ComponentInterface, config: &'a Config) -> Self {
         Self {
             ci,
-            include_once_names: RefCell::new(HashMap::new()),
+            config,
+            include_once_names: RefCell::new(BTreeMap::new()),
+            unsupported: RefCell::new(vec![]),
         }
     }
 
-    pub fn get_include_names(&self) -> HashMap<String, Type> {
+    /// Everything reported through `report_unsupported` so far.
+    pub fn unsupported(&self) -> Vec<String> {
+        self.unsupported.borrow().clone()
+    }
+
+    pub fn get_include_names(&self) -> BTreeMap<String, Type> {
         self.include_once_names.clone().into_inner()
     }
 }
@@ -45,6 +56,12 @@ impl TypeHelperRenderer for TypeHelpersRenderer<'_> {
         contains
     }
 
+    fn report_unsupported(&self, owner: &str, what: &str) {
+        self.unsupported
+            .borrow_mut()
+            .push(format!("{owner}: {what}"));
+    }
+
     fn get_object(&self, name: &str) -> Option<&uniffi_bindgen::interface::Object> {
         self.ci.get_object_definition(name)
     }
@@ -57,6 +74,10 @@ impl TypeHelperRenderer for TypeHelpersRenderer<'_> {
         self.ci
     }
 
+    fn get_config(&self) -> &Config {
+        self.config
+    }
+
     fn get_record(&self, name: &str) -> Option<&uniffi_bindgen::interface::Record> {
         self.ci.get_record_definition(name)
     }
@@ -67,16 +88,43 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
     // this is so the generator knows what helper code to include.
 
     fn render(&self) -> (dart::Tokens, dart::Tokens) {
-        // Render all the types and their helpers
+        // Render all the types and their helpers. Records and enums are already sorted by name, so
+        // sort objects too to keep the output stable.
+        let mut objects: Vec<_> = self.ci.object_definitions().iter().collect();
+        objects.sort_by_key(|obj| obj.name());
         let types_definitions = quote! {
             $( for rec in self.ci.record_definitions() => $(records::generate_record(rec, self)))
 
             $( for enm in self.ci.enum_definitions() => $(enums::generate_enum(enm, self)))
-            $( for obj in self.ci.object_definitions() => $(objects::generate_object(obj, self)))
+            $( for obj in objects => $(objects::generate_object(obj, self)))
         };
 
         // Render all the imports
-        let imports: dart::Tokens = quote!();
+        let imports: BTreeSet<_> = self
+            .config
+            .custom_types()
+            .flat_map(|custom| custom.imports())
+            .collect();
+        // External types are used through the library generated for their crate
+        let external_imports: BTreeMap<_, _> = self
+            .ci
+            .iter_types()
+            .filter_map(|ty| match ty {
+                Type::External {
+                    module_path,
+                    namespace,
+                    ..
+                } => {
+                    let crate_name = module_path.split("::").next().unwrap_or(module_path);
+                    Some((namespace, self.config.external_import(crate_name, namespace)))
+                }
+                _ => None,
+            })
+            .collect();
+        let imports: dart::Tokens = quote! {
+            $(for import in imports => import $(quoted(import));)
+            $(for (namespace, import) in external_imports => import $(quoted(import)) as $namespace;)
+        };
 
         // let function_definitions = quote!($( for fun in self.ci.function_definitions() => $(functions::generate_function("this", fun, self))));
 
@@ -96,8 +144,45 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
 
         // Let's include the string converter
         self.include_once_check(&Type::String.as_codetype().canonical_name(), &Type::String);
-        let helpers_definitions = quote! {
-            $(for (_, ty) in self.get_include_names().iter() => $(ty.as_renderable().render_type_helper(self)) )
+        // Custom types need their definitions, even when only used inside other types
+        for ty in self.ci.iter_types() {
+            if let Type::Custom { name, .. } = ty {
+                self.include_once_check(name, ty);
+            }
+        }
+        // Rendering a helper can include further helpers (e.g. the key and value converters of a
+        // map), so keep going until no new names show up. Each round goes in name order.
+        let mut helpers_definitions = quote!();
+        let mut rendered = BTreeSet::new();
+        loop {
+            let pending: Vec<_> = self
+                .get_include_names()
+                .into_iter()
+                .filter(|(name, _)| !rendered.contains(name))
+                .collect();
+            if pending.is_empty() {
+                break;
+            }
+            for (name, ty) in pending {
+                helpers_definitions.append(ty.as_renderable().render_type_helper(self));
+                rendered.insert(name);
+            }
+        }
+
+        let allocator_definition = if self.config.replaceable_allocator() {
+            quote! {
+                /// Allocates the native memory the bindings pass to Rust for the duration of a call.
+                Allocator _uniffiAllocator = calloc;
+
+                /// Replaces the allocator of the native memory passed to Rust, e.g. by one that
+                /// counts what is still allocated.
+                set uniffiAllocator(Allocator allocator) => _uniffiAllocator = allocator;
+            }
+        } else {
+            quote! {
+                /// Allocates the native memory the bindings pass to Rust for the duration of a call.
+                const Allocator _uniffiAllocator = calloc;
+            }
         };
 
         let types_helper_code = quote! {
@@ -190,12 +275,16 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
                 }
             }
 
-            T rustCall<T>(T Function(Pointer<RustCallStatus>) callback) {
-                final status = calloc<RustCallStatus>();
+            $(allocator_definition)
+
+            T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+                final status = _uniffiAllocator<RustCallStatus>();
                 try {
-                return callback(status);
+                final result = callback(status);
+                checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
+                return result;
                 } finally {
-                calloc.free(status);
+                _uniffiAllocator.free(status);
                 }
             }
 
@@ -256,14 +345,28 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
             RustBuffer toRustBuffer(Uint8List data) {
                 final length = data.length;
 
-                final Pointer<Uint8> frameData = calloc<Uint8>(length); // Allocate a pointer large enough.
+                final Pointer<Uint8> frameData = _uniffiAllocator<Uint8>(length); // Allocate a pointer large enough.
                 final pointerList = frameData.asTypedList(length); // Create a list that uses our pointer and copy in the data.
                 pointerList.setAll(0, data); // FIXME: can we remove this memcopy somehow?
 
-                final bytes = calloc<ForeignBytes>();
+                final bytes = _uniffiAllocator<ForeignBytes>();
                 bytes.ref.len = length;
                 bytes.ref.data = frameData;
-                return RustBuffer.fromBytes(bytes.ref);
+                try {
+                    return RustBuffer.fromBytes(bytes.ref); // Rust copies the bytes into a buffer of its own
+                } finally {
+                    _uniffiAllocator.free(frameData);
+                    _uniffiAllocator.free(bytes);
+                }
+            }
+
+            // Buffers received from Rust are ours to free once lifted
+            T liftFromRustBuffer<T>(RustBuffer buf, T Function(Uint8List) lift) {
+                try {
+                    return lift(buf.asUint8List());
+                } finally {
+                    buf.free();
+                }
             }
 
             final class ForeignBytes extends Struct {
@@ -281,7 +384,7 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
                 // }
 
                 void free() {
-                calloc.free(data);
+                _uniffiAllocator.free(data);
                 }
             }
 
@@ -313,24 +416,6 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
                 T lower(T value) => value;
             }
 
-            Uint8List createUint8ListFromInt(int value) {
-                int length = value.bitLength ~/ 8 + 1;
-
-                // Ensure the length is either 4 or 8
-                if (length != 4 && length != 8) {
-                length = (value < 0x100000000) ? 4 : 8;
-                }
-
-                Uint8List uint8List = Uint8List(length);
-
-                for (int i = length - 1; i >= 0; i--) {
-                uint8List[i] = value & 0xFF;
-                value >>= 8;
-                }
-
-                return uint8List;
-            }
-
             $(helpers_definitions)
 
             const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
@@ -373,14 +458,13 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
                     callback.close();
 
 
-                    final status = calloc<RustCallStatus>();
+                    final status = _uniffiAllocator<RustCallStatus>();
                     try {
-
                         final result = completeFunc(rustFuture, status);
-
+                        checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
                         return liftFunc(result);
                     } finally {
-                        calloc.free(status);
+                        _uniffiAllocator.free(status);
                     }
                 } finally {
                     freeFunc(rustFuture);
@@ -420,7 +504,7 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
     }
 }
 
-pub fn generate_type(ty: &Type) -> dart::Tokens {
+pub fn generate_type(ty: &Type, config: &Config) -> dart::Tokens {
     match ty {
         Type::UInt8
         | Type::UInt32
@@ -428,16 +512,25 @@ pub fn generate_type(ty: &Type) -> dart::Tokens {
         | Type::Int16
         | Type::Int64
         | Type::UInt16
-        | Type::Int32
-        | Type::UInt64 => quote!(int),
+        | Type::Int32 => quote!(int),
+        Type::UInt64 => quote!($(primitives::u64_type_label(config))),
         Type::Float32 | Type::Float64 => quote!(double),
         Type::String => quote!(String),
-        Type::Object { name, .. } => quote!($name),
+        Type::Bytes => quote!(Uint8List),
+        Type::Object { name, .. } => quote!($(DartCodeOracle::class_name(name))),
         Type::Boolean => quote!(bool),
-        Type::Optional { inner_type } => quote!($(generate_type(inner_type))?),
-        Type::Sequence { inner_type } => quote!(List<$(generate_type(inner_type))>),
-        Type::Enum { name, .. } => quote!($name),
+        Type::Optional { inner_type } => quote!($(generate_type(inner_type, config))?),
+        Type::Sequence { inner_type } => quote!(List<$(generate_type(inner_type, config))>),
+        Type::Map {
+            key_type,
+            value_type,
+        } => quote!(Map<$(generate_type(key_type, config)), $(generate_type(value_type, config))>),
+        Type::Enum { name, .. } => quote!($(DartCodeOracle::class_name(name))),
+        Type::Record { name, .. } => quote!($(DartCodeOracle::class_name(name))),
         Type::Duration => quote!(Duration),
-        _ => todo!("Type::{:?}", ty),
+        Type::Timestamp => quote!(DateTime),
+        Type::Custom { name, .. } => quote!($(DartCodeOracle::class_name(name))),
+        Type::CallbackInterface { name, .. } => quote!($(DartCodeOracle::class_name(name))),
+        Type::External { .. } => quote!($(ty.as_codetype().type_label())),
     }
 }
@@ -5,6 +5,7 @@ pub mod testing;
 #[cfg(feature = "build")]
 pub use build::generate_scaffolding;
 
+pub mod finalizer;
 pub mod gen;
 
 pub use uniffi_dart_macro::*;
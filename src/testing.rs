@@ -1,7 +1,7 @@
 use crate::gen;
 use anyhow::{bail, Result};
 use camino::{Utf8Path, Utf8PathBuf};
-use std::fs::{copy, create_dir_all, File};
+use std::fs::{copy, create_dir_all, read, read_to_string, remove_dir_all, File};
 use std::io::Write;
 use std::process::Command;
 use std::thread;
@@ -16,13 +16,41 @@ pub struct CompileSource {
 }
 
 pub fn run_test(fixture: &str, udl_path: &str, config_path: Option<&str>) -> Result<()> {
+    let udl_path = Utf8Path::new(".").canonicalize_utf8()?.join(udl_path);
+    run_test_with(fixture, config_path, |test_helper, config_path, out_dir| {
+        gen::generate_dart_bindings(
+            &udl_path,
+            config_path,
+            Some(out_dir),
+            Some(&test_helper.cdylib_path()?),
+        )
+    })
+}
+
+/// Like [`run_test`], but generates the bindings of every crate in the fixture's cdylib from the
+/// metadata in the library, so that fixtures can use types from other crates.
+pub fn run_library_test(fixture: &str, config_path: Option<&str>) -> Result<()> {
+    run_test_with(fixture, config_path, |test_helper, config_path, out_dir| {
+        gen::generate_dart_bindings_from_library(
+            &test_helper.cdylib_path()?,
+            None,
+            config_path,
+            out_dir,
+        )
+    })
+}
+
+fn run_test_with(
+    fixture: &str,
+    config_path: Option<&str>,
+    generate: impl FnOnce(&UniFFITestHelper, Option<&Utf8Path>, &Utf8Path) -> Result<()>,
+) -> Result<()> {
     let tmp_dir = camino_tempfile::tempdir()?;
 
     let script_path = Utf8Path::new(".").canonicalize_utf8()?;
     let test_helper = UniFFITestHelper::new(fixture)?;
     let out_dir = test_helper.create_out_dir(&tmp_dir, &script_path)?;
 
-    let udl_path = Utf8Path::new(".").canonicalize_utf8()?.join(udl_path);
     let config_path = if let Some(path) = config_path {
         Some(Utf8Path::new(".").canonicalize_utf8()?.join(path))
     } else {
@@ -32,7 +60,7 @@ pub fn run_test(fixture: &str, udl_path: &str, config_path: Option<&str>) -> Res
     println!("{out_dir}");
 
     let mut pubspec = File::create(out_dir.join("pubspec.yaml"))?;
-    pubspec.write(
+    pubspec.write_all(
         b"
     name: uniffi_test
     description: testing module for uniffi
@@ -51,13 +79,8 @@ pub fn run_test(fixture: &str, udl_path: &str, config_path: Option<&str>) -> Res
     create_dir_all(&test_outdir)?;
 
     test_helper.copy_cdylib_to_out_dir(&out_dir)?;
-    gen::generate_dart_bindings(
-        &udl_path,
-        config_path.as_deref(),
-        Some(&out_dir),
-        Some(&test_helper.cdylib_path()?),
-    )?;
-    for file in glob::glob(&format!("**/*.dart"))?.filter_map(Result::ok) {
+    generate(&test_helper, config_path.as_deref(), &out_dir)?;
+    for file in glob::glob("**/*.dart")?.filter_map(Result::ok) {
         copy(
             &file,
             out_dir.join(file.as_os_str().to_str().expect("bad filename")),
@@ -79,6 +102,169 @@ pub fn run_test(fixture: &str, udl_path: &str, config_path: Option<&str>) -> Res
     Ok(())
 }
 
+/// Compares the bindings generated for a fixture with the goldens committed in its `golden`
+/// directory, without needing the Dart SDK. Run with `UNIFFI_DART_UPDATE_GOLDENS=1` to rewrite
+/// the goldens after an intended change to the generator. The bindings are generated twice and
+/// must come out byte-for-byte equal.
+pub fn assert_golden(fixture: &str, udl_path: &str, config_path: Option<&str>) -> Result<()> {
+    let udl_path = Utf8Path::new(".").canonicalize_utf8()?.join(udl_path);
+    check_goldens(fixture, config_path, |test_helper, config_path, out_dir| {
+        uniffi_bindgen::generate_external_bindings(
+            &gen::DartBindingGenerator,
+            &udl_path,
+            config_path,
+            Some(out_dir),
+            Some(&test_helper.cdylib_path()?),
+            None,
+            false,
+        )
+    })
+}
+
+/// Like [`assert_golden`], for fixtures generated from their cdylib as in [`run_library_test`].
+/// Only the fixture's own crate is compared, the crates it depends on have goldens of their own.
+pub fn assert_library_golden(fixture: &str, config_path: Option<&str>) -> Result<()> {
+    check_goldens(fixture, config_path, |test_helper, config_path, out_dir| {
+        uniffi_bindgen::library_mode::generate_external_bindings(
+            &gen::DartBindingGenerator,
+            &test_helper.cdylib_path()?,
+            Some(fixture.to_string()),
+            config_path,
+            out_dir,
+            false,
+        )?;
+        Ok(())
+    })
+}
+
+fn check_goldens(
+    fixture: &str,
+    config_path: Option<&str>,
+    generate: impl Fn(&UniFFITestHelper, Option<&Utf8Path>, &Utf8Path) -> Result<()>,
+) -> Result<()> {
+    let tmp_dir = camino_tempfile::tempdir()?;
+
+    let script_path = Utf8Path::new(".").canonicalize_utf8()?;
+    let test_helper = UniFFITestHelper::new(fixture)?;
+    let out_dir = test_helper.create_out_dir(&tmp_dir, &script_path)?;
+    let config_path = config_path.map(|path| script_path.join(path));
+    generate(&test_helper, config_path.as_deref(), &out_dir)?;
+    let rerun_dir = out_dir.join("rerun");
+    create_dir_all(&rerun_dir)?;
+    generate(&test_helper, config_path.as_deref(), &rerun_dir)?;
+
+    let generated = dart_files(&out_dir)?;
+    if generated != dart_files(&rerun_dir)?
+        || generated.iter().any(|name| read(out_dir.join(name)).ok() != read(rerun_dir.join(name)).ok())
+    {
+        bail!("generating the bindings of `{fixture}` twice gave different output");
+    }
+
+    let golden_dir = script_path.join("golden");
+    let update = std::env::var_os("UNIFFI_DART_UPDATE_GOLDENS").is_some();
+    if update {
+        if golden_dir.exists() {
+            remove_dir_all(&golden_dir)?;
+        }
+        create_dir_all(&golden_dir)?;
+    }
+
+    let mut mismatches = vec![];
+    for file_name in &generated {
+        let golden_path = golden_dir.join(file_name);
+        let actual = read_to_string(out_dir.join(file_name))?;
+        for name in shadowing_class_names(&actual) {
+            mismatches.push(format!("{file_name} declares a class `{name}`, shadowing Dart's"));
+        }
+        if update {
+            copy(out_dir.join(file_name), &golden_path)?;
+            continue;
+        }
+        // genco only keeps the template's whitespace on nightly, so compare it normalized
+        match read_to_string(&golden_path) {
+            Ok(golden) if normalize_whitespace(&golden) == normalize_whitespace(&actual) => {}
+            Ok(_) => mismatches.push(format!("{golden_path} differs")),
+            Err(_) => mismatches.push(format!("{golden_path} is missing")),
+        }
+    }
+    if !update && golden_dir.exists() {
+        for entry in golden_dir.read_dir_utf8()? {
+            let entry = entry?;
+            if !generated.iter().any(|name| name == entry.file_name()) {
+                mismatches.push(format!("{} is no longer generated", entry.path()));
+            }
+        }
+    }
+
+    if !mismatches.is_empty() {
+        bail!(
+            "the generated bindings of `{fixture}` don't match the goldens:\n  {}\n\
+             Rerun with UNIFFI_DART_UPDATE_GOLDENS=1 and review the diff if the change is intended.",
+            mismatches.join("\n  ")
+        );
+    }
+    Ok(())
+}
+
+/// The names of the Dart files in `dir`, sorted.
+fn dart_files(dir: &Utf8Path) -> Result<Vec<String>> {
+    let mut files = vec![];
+    for entry in dir.read_dir_utf8()? {
+        let entry = entry?;
+        if entry.path().extension() == Some("dart") {
+            files.push(entry.file_name().to_owned());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// The classes declared in `code` with one of the names reserved for Dart's own types.
+fn shadowing_class_names(code: &str) -> Vec<&str> {
+    let is_identifier_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '$';
+    code.match_indices("class ")
+        .filter(|(start, _)| !code[..*start].ends_with(is_identifier_char))
+        .filter_map(|(start, keyword)| {
+            let rest = &code[start + keyword.len()..];
+            let end = rest.find(|c| !is_identifier_char(c)).unwrap_or(rest.len());
+            let name = &rest[..end];
+            gen::oracle::DartCodeOracle::is_reserved_class_name(name).then_some(name)
+        })
+        .collect()
+}
+
+/// Drops the whitespace that genco renders differently per toolchain. Whitespace is kept where
+/// it separates two identifier characters, so that merged tokens still differ, and string literals
+/// are kept as they are.
+fn normalize_whitespace(code: &str) -> String {
+    let is_identifier_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '$';
+    let mut normalized = String::with_capacity(code.len());
+    let mut chars = code.chars();
+    let mut after_whitespace = false;
+    while let Some(c) = chars.next() {
+        if c.is_whitespace() {
+            after_whitespace = true;
+            continue;
+        }
+        if after_whitespace && is_identifier_char(c) && normalized.ends_with(is_identifier_char) {
+            normalized.push(' ');
+        }
+        after_whitespace = false;
+        normalized.push(c);
+        if c == '"' || c == '\'' {
+            while let Some(literal_char) = chars.next() {
+                normalized.push(literal_char);
+                if literal_char == '\\' {
+                    normalized.extend(chars.next());
+                } else if literal_char == c {
+                    break;
+                }
+            }
+        }
+    }
+    normalized
+}
+
 pub fn get_compile_sources() -> Result<Vec<CompileSource>> {
     todo!("Not implemented")
 }